@@ -23,15 +23,19 @@ use c2pa::settings::load_settings_from_str;
 #[cfg(feature = "file_io")]
 use c2pa::Ingredient;
 use c2pa::{
-    assertions::DataHash, identity::validator::CawgValidator, Builder as C2paBuilder,
-    CallbackSigner, Reader as C2paReader, SigningAlg,
+    assertions::DataHash, identity::validator::CawgValidator, settings::Settings,
+    Builder as C2paBuilder, CallbackSigner, Reader as C2paReader, SigningAlg,
 };
 use scopeguard::guard;
 use tokio::runtime::Runtime; // cawg validator requires async
 
 #[cfg(feature = "file_io")]
 use crate::json_api::{read_file, sign_file};
-use crate::{c2pa_stream::C2paStream, error::Error, signer_info::SignerInfo};
+use crate::{
+    c2pa_stream::C2paStream,
+    error::Error,
+    signer_info::{IdentityInfo, SignerInfo},
+};
 
 // Work around limitations in cbindgen.
 mod cbindgen_fix {
@@ -54,6 +58,9 @@ pub enum C2paSigningAlg {
     Ps384,
     Ps512,
     Ed25519,
+    MlDsa44,
+    MlDsa65,
+    MlDsa87,
 }
 
 impl From<C2paSigningAlg> for SigningAlg {
@@ -66,6 +73,9 @@ impl From<C2paSigningAlg> for SigningAlg {
             C2paSigningAlg::Ps384 => SigningAlg::Ps384,
             C2paSigningAlg::Ps512 => SigningAlg::Ps512,
             C2paSigningAlg::Ed25519 => SigningAlg::Ed25519,
+            C2paSigningAlg::MlDsa44 => SigningAlg::MlDsa44,
+            C2paSigningAlg::MlDsa65 => SigningAlg::MlDsa65,
+            C2paSigningAlg::MlDsa87 => SigningAlg::MlDsa87,
         }
     }
 }
@@ -75,6 +85,11 @@ pub struct C2paSigner {
     pub signer: Box<dyn c2pa::Signer>,
 }
 
+#[repr(C)]
+pub struct C2paAsyncSigner {
+    pub signer: Box<dyn c2pa::AsyncSigner + Send + Sync>,
+}
+
 // Null check macro for C pointers.
 #[macro_export]
 macro_rules! null_check {
@@ -367,6 +382,34 @@ pub struct C2paSignerInfo {
     pub ta_url: *const c_char,
 }
 
+#[repr(C)]
+/// Defines the X.509 credential and content of a CAWG identity assertion.
+///
+/// The identity assertion is signed with the sign_cert and private_key fields.
+/// referenced_assertions and roles are JSON arrays of strings, or NULL.
+pub struct C2paIdentityInfo {
+    /// The signing algorithm.
+    pub alg: *const c_char,
+    /// The credential holder's public certificate chain in PEM format.
+    pub sign_cert: *const c_char,
+    /// The credential holder's private key in PEM format.
+    pub private_key: *const c_char,
+    /// The timestamp authority URL or NULL.
+    pub ta_url: *const c_char,
+    /// A JSON array of labels of assertions to reference, or NULL.
+    pub referenced_assertions: *const c_char,
+    /// A JSON array of named actor roles, or NULL.
+    pub roles: *const c_char,
+}
+
+// Internal routine to read an optional JSON array of strings.
+unsafe fn string_array_from_json(json: *const c_char) -> Result<Vec<String>, Error> {
+    match from_cstr_option!(json) {
+        Some(json) => serde_json::from_str(&json).map_err(|e| Error::Json(e.to_string())),
+        None => Ok(Vec::new()),
+    }
+}
+
 /// Add a signed manifest to the file at path with the given signer information.
 ///
 /// # Errors
@@ -396,6 +439,7 @@ pub unsafe extern "C" fn c2pa_sign_file(
         sign_cert: from_cstr_or_return_null!(signer_info.sign_cert).into_bytes(),
         private_key: from_cstr_or_return_null!(signer_info.private_key).into_bytes(),
         ta_url: from_cstr_option!(signer_info.ta_url),
+        identity: None,
     };
     // Read manifest from JSON and then sign and write it.
     let result = sign_file(&source_path, &dest_path, &manifest, &signer_info, data_dir);
@@ -968,6 +1012,66 @@ pub unsafe extern "C" fn c2pa_builder_sign(
     })
 }
 
+/// Creates and writes signed manifest from the C2paBuilder to the destination stream
+/// using a C2paAsyncSigner.
+///
+/// Signing runs on an internal async runtime; this call blocks until it completes.
+///
+/// # Parameters
+/// * builder_ptr: pointer to a Builder.
+/// * format: pointer to a C string with the mime type or extension.
+/// * source: pointer to a C2paStream.
+/// * dest: pointer to a writable C2paStream.
+/// * signer: pointer to a C2paAsyncSigner.
+/// * c2pa_bytes_ptr: pointer to a pointer to a c_uchar to return manifest_bytes (optional, can be NULL).
+///
+/// # Errors
+/// Returns -1 if there were errors, otherwise returns the size of the c2pa data.
+/// The error string can be retrieved by calling c2pa_error.
+///
+/// # Safety
+/// Reads from NULL-terminated C strings
+/// If manifest_bytes_ptr is not NULL, the returned value MUST be released by calling c2pa_manifest_bytes_free
+/// and it is no longer valid after that call.
+#[no_mangle]
+pub unsafe extern "C" fn c2pa_builder_sign_async(
+    builder_ptr: *mut C2paBuilder,
+    format: *const c_char,
+    source: *mut C2paStream,
+    dest: *mut C2paStream,
+    signer_ptr: *mut C2paAsyncSigner,
+    manifest_bytes_ptr: *mut *const c_uchar,
+) -> i64 {
+    check_or_return_int!(builder_ptr);
+    let format = from_cstr_or_return_int!(format);
+    check_or_return_int!(source);
+    check_or_return_int!(dest);
+    check_or_return_int!(signer_ptr);
+    check_or_return_int!(manifest_bytes_ptr);
+
+    let mut builder = guard_boxed!(builder_ptr);
+    let c2pa_signer = guard_boxed!(signer_ptr);
+
+    let result = Runtime::new()
+        .map_err(|err| c2pa::Error::OtherError(Box::new(err)))
+        .and_then(|runtime| {
+            runtime.block_on(builder.sign_async(
+                c2pa_signer.signer.as_ref(),
+                &format,
+                &mut *source,
+                &mut *dest,
+            ))
+        });
+    ok_or_return_int!(result, |manifest_bytes: Vec<u8>| {
+        let len = manifest_bytes.len() as i64;
+        if !manifest_bytes_ptr.is_null() {
+            *manifest_bytes_ptr =
+                Box::into_raw(manifest_bytes.into_boxed_slice()) as *const c_uchar;
+        };
+        len
+    })
+}
+
 /// Frees a C2PA manifest returned by c2pa_builder_sign.
 ///
 /// # Safety
@@ -1231,6 +1335,7 @@ pub unsafe extern "C" fn c2pa_signer_from_info(signer_info: &C2paSignerInfo) ->
         sign_cert: from_cstr_or_return_null!(signer_info.sign_cert).into_bytes(),
         private_key: from_cstr_or_return_null!(signer_info.private_key).into_bytes(),
         ta_url: from_cstr_option!(signer_info.ta_url),
+        identity: None,
     };
 
     let signer = signer_info.signer();
@@ -1245,6 +1350,113 @@ pub unsafe extern "C" fn c2pa_signer_from_info(signer_info: &C2paSignerInfo) ->
     }
 }
 
+/// Creates a C2paSigner from a SignerInfo that also adds a CAWG identity
+/// assertion, signed by the X.509 credential in identity_info, to every
+/// manifest it signs.
+///
+/// # Parameters
+/// * signer_info: pointer to a C2paSignerInfo.
+/// * identity_info: pointer to a C2paIdentityInfo.
+/// # Errors
+/// Returns NULL if there were errors, otherwise returns a pointer to a C2paSigner.
+/// The error string can be retrieved by calling c2pa_error.
+/// # Safety
+/// Reads from NULL-terminated C strings.
+/// The returned value MUST be released by calling c2pa_signer_free
+/// and it is no longer valid after that call.
+/// # Example
+/// ```c
+/// C2paIdentityInfo identity_info = {
+///     "ed25519", cawg_certs, cawg_private_key, NULL,
+///     "[\"c2pa.actions\"]", "[\"cawg.creator\"]"
+/// };
+/// auto result = c2pa_signer_from_info_with_identity(signer_info, &identity_info);
+/// if (result == NULL) {
+///     auto error = c2pa_error();
+///     printf("Error: %s\n", error);
+///     c2pa_string_free(error);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn c2pa_signer_from_info_with_identity(
+    signer_info: &C2paSignerInfo,
+    identity_info: &C2paIdentityInfo,
+) -> *mut C2paSigner {
+    let identity = IdentityInfo {
+        alg: from_cstr_or_return_null!(identity_info.alg),
+        sign_cert: from_cstr_or_return_null!(identity_info.sign_cert).into_bytes(),
+        private_key: from_cstr_or_return_null!(identity_info.private_key).into_bytes(),
+        ta_url: from_cstr_option!(identity_info.ta_url),
+        referenced_assertions: match string_array_from_json(identity_info.referenced_assertions) {
+            Ok(labels) => labels,
+            Err(err) => {
+                err.set_last();
+                return std::ptr::null_mut();
+            }
+        },
+        roles: match string_array_from_json(identity_info.roles) {
+            Ok(roles) => roles,
+            Err(err) => {
+                err.set_last();
+                return std::ptr::null_mut();
+            }
+        },
+    };
+
+    let signer_info = SignerInfo {
+        alg: from_cstr_or_return_null!(signer_info.alg),
+        sign_cert: from_cstr_or_return_null!(signer_info.sign_cert).into_bytes(),
+        private_key: from_cstr_or_return_null!(signer_info.private_key).into_bytes(),
+        ta_url: from_cstr_option!(signer_info.ta_url),
+        identity: Some(identity),
+    };
+
+    match signer_info.signer() {
+        Ok(signer) => Box::into_raw(Box::new(C2paSigner { signer })),
+        Err(err) => {
+            err.set_last();
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Creates a C2paSigner from the signer configured in the loaded settings.
+///
+/// Settings are loaded with c2pa_load_settings.
+///
+/// # Errors
+/// Returns NULL if there were errors, otherwise returns a pointer to a C2paSigner.
+/// The error string can be retrieved by calling c2pa_error.
+///
+/// # Safety
+/// The returned value MUST be released by calling c2pa_signer_free
+/// and it is no longer valid after that call.
+#[no_mangle]
+pub unsafe extern "C" fn c2pa_signer_from_settings() -> *mut C2paSigner {
+    ok_or_return_null!(Settings::signer(), |signer| {
+        Box::into_raw(Box::new(C2paSigner { signer }))
+    })
+}
+
+/// Creates a C2paAsyncSigner from the signer configured in the loaded settings.
+///
+/// Remote signing and time stamp requests made by this signer do not block
+/// the runtime used by c2pa_builder_sign_async.
+///
+/// # Errors
+/// Returns NULL if there were errors, otherwise returns a pointer to a C2paAsyncSigner.
+/// The error string can be retrieved by calling c2pa_error.
+///
+/// # Safety
+/// The returned value MUST be released by calling c2pa_async_signer_free
+/// and it is no longer valid after that call.
+#[no_mangle]
+pub unsafe extern "C" fn c2pa_async_signer_from_settings() -> *mut C2paAsyncSigner {
+    ok_or_return_null!(Settings::async_signer(), |signer| {
+        Box::into_raw(Box::new(C2paAsyncSigner { signer }))
+    })
+}
+
 /// Returns the size to reserve for the signature for this signer.
 ///
 /// # Parameters
@@ -1274,6 +1486,32 @@ pub unsafe extern "C" fn c2pa_signer_free(signer_ptr: *const C2paSigner) {
     }
 }
 
+/// Returns the size to reserve for the signature for this async signer.
+///
+/// # Errors
+/// Returns -1 if there were errors, otherwise returns the size to reserve.
+/// The error string can be retrieved by calling c2pa_error.
+///
+/// # Safety
+/// The signer_ptr must be a valid pointer to a C2paAsyncSigner.
+#[no_mangle]
+pub unsafe extern "C" fn c2pa_async_signer_reserve_size(signer_ptr: *mut C2paAsyncSigner) -> i64 {
+    check_or_return_int!(signer_ptr);
+    let c2pa_signer = guard_boxed!(signer_ptr);
+    c2pa_signer.signer.reserve_size() as i64
+}
+
+/// Frees a C2paAsyncSigner allocated by Rust.
+///
+/// # Safety
+/// The C2paAsyncSigner can only be freed once and is invalid after this call.
+#[no_mangle]
+pub unsafe extern "C" fn c2pa_async_signer_free(signer_ptr: *const C2paAsyncSigner) {
+    if !signer_ptr.is_null() {
+        drop(Box::from_raw(signer_ptr as *mut C2paAsyncSigner));
+    }
+}
+
 #[no_mangle]
 /// Signs a byte array using the Ed25519 algorithm.
 /// # Safety
@@ -1454,6 +1692,99 @@ mod tests {
         unsafe { c2pa_signer_free(signer) };
     }
 
+    #[test]
+    fn test_sign_with_identity() {
+        let source_image = include_bytes!(fixture_path!("IMG_0003.jpg"));
+        let mut source_stream = TestC2paStream::from_bytes(source_image.to_vec());
+        let mut dest_stream = TestC2paStream::new(Vec::new()).into_c_stream();
+
+        let alg = CString::new("Es256").unwrap();
+        let sign_cert = CString::new(include_str!(fixture_path!("certs/es256.pub"))).unwrap();
+        let private_key = CString::new(include_bytes!(fixture_path!("certs/es256.pem"))).unwrap();
+        let signer_info = C2paSignerInfo {
+            alg: alg.as_ptr(),
+            sign_cert: sign_cert.as_ptr(),
+            private_key: private_key.as_ptr(),
+            ta_url: std::ptr::null(),
+        };
+
+        let cawg_alg = CString::new("Ed25519").unwrap();
+        let cawg_cert = CString::new(include_str!(fixture_path!("certs/ed25519.pub"))).unwrap();
+        let cawg_key = CString::new(include_bytes!(fixture_path!("certs/ed25519.pem"))).unwrap();
+        let referenced_assertions = CString::new(r#"["c2pa.actions"]"#).unwrap();
+        let roles = CString::new(r#"["cawg.creator"]"#).unwrap();
+        let identity_info = C2paIdentityInfo {
+            alg: cawg_alg.as_ptr(),
+            sign_cert: cawg_cert.as_ptr(),
+            private_key: cawg_key.as_ptr(),
+            ta_url: std::ptr::null(),
+            referenced_assertions: referenced_assertions.as_ptr(),
+            roles: roles.as_ptr(),
+        };
+        let signer = unsafe { c2pa_signer_from_info_with_identity(&signer_info, &identity_info) };
+        assert!(!signer.is_null());
+
+        let manifest_def = CString::new(r#"{"claim_version": 2}"#).unwrap();
+        let builder = unsafe { c2pa_builder_from_json(manifest_def.as_ptr()) };
+        assert!(!builder.is_null());
+        let format = CString::new("image/jpeg").unwrap();
+        let mut manifest_bytes_ptr = std::ptr::null();
+        let result = unsafe {
+            c2pa_builder_sign(
+                builder,
+                format.as_ptr(),
+                &mut source_stream,
+                &mut dest_stream,
+                signer,
+                &mut manifest_bytes_ptr,
+            )
+        };
+        assert!(result > 0);
+
+        let mut read_stream = TestC2paStream::from_c_stream(dest_stream).into_c_stream();
+        let reader = unsafe { c2pa_reader_from_stream(format.as_ptr(), &mut read_stream) };
+        assert!(!reader.is_null());
+        let json = unsafe { CString::from_raw(c2pa_reader_json(reader)) };
+        let json = json.to_str().unwrap();
+        assert!(json.contains("cawg.x509.cose"));
+        assert!(json.contains("cawg.identity.well-formed"));
+
+        TestC2paStream::drop_c_stream(source_stream);
+        TestC2paStream::drop_c_stream(read_stream);
+        unsafe {
+            c2pa_manifest_bytes_free(manifest_bytes_ptr);
+            c2pa_reader_free(reader);
+            c2pa_builder_free(builder);
+            c2pa_signer_free(signer);
+        }
+    }
+
+    #[test]
+    fn test_signer_from_info_with_identity_bad_roles() {
+        let alg = CString::new("Es256").unwrap();
+        let sign_cert = CString::new(include_str!(fixture_path!("certs/es256.pub"))).unwrap();
+        let private_key = CString::new(include_bytes!(fixture_path!("certs/es256.pem"))).unwrap();
+        let signer_info = C2paSignerInfo {
+            alg: alg.as_ptr(),
+            sign_cert: sign_cert.as_ptr(),
+            private_key: private_key.as_ptr(),
+            ta_url: std::ptr::null(),
+        };
+        let roles = CString::new("cawg.creator").unwrap();
+        let identity_info = C2paIdentityInfo {
+            alg: alg.as_ptr(),
+            sign_cert: sign_cert.as_ptr(),
+            private_key: private_key.as_ptr(),
+            ta_url: std::ptr::null(),
+            referenced_assertions: std::ptr::null(),
+            roles: roles.as_ptr(),
+        };
+        let signer = unsafe { c2pa_signer_from_info_with_identity(&signer_info, &identity_info) };
+        assert!(signer.is_null());
+        let error = unsafe { CString::from_raw(c2pa_error()) };
+        assert!(error.to_str().unwrap().starts_with("Json: "));
+    }
+
     #[test]
     fn test_c2pa_builder_no_embed_null() {
         let builder: *mut c2pa::Builder = std::ptr::null_mut();
@@ -1504,6 +1835,66 @@ mod tests {
         assert_eq!(result, 0);
     }
 
+    fn load_signer_settings() {
+        let settings = serde_json::json!({
+            "signer": {
+                "local": {
+                    "alg": "ed25519",
+                    "sign_cert": include_str!(fixture_path!("certs/ed25519.pub")),
+                    "private_key": include_str!(fixture_path!("certs/ed25519.pem")),
+                }
+            }
+        });
+        let settings = CString::new(settings.to_string()).unwrap();
+        let format = CString::new("json").unwrap();
+        let result = unsafe { c2pa_load_settings(settings.as_ptr(), format.as_ptr()) };
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn test_signer_from_settings() {
+        load_signer_settings();
+        let signer = unsafe { c2pa_signer_from_settings() };
+        assert!(!signer.is_null());
+        assert!(unsafe { c2pa_signer_reserve_size(signer) } > 0);
+        unsafe { c2pa_signer_free(signer) };
+    }
+
+    #[test]
+    fn test_sign_with_async_signer_from_settings() {
+        load_signer_settings();
+        let signer = unsafe { c2pa_async_signer_from_settings() };
+        assert!(!signer.is_null());
+        assert!(unsafe { c2pa_async_signer_reserve_size(signer) } > 0);
+
+        let source_image = include_bytes!(fixture_path!("IMG_0003.jpg"));
+        let mut source_stream = TestC2paStream::from_bytes(source_image.to_vec());
+        let mut dest_stream = TestC2paStream::new(Vec::new()).into_c_stream();
+        let manifest_def = CString::new("{}").unwrap();
+        let builder = unsafe { c2pa_builder_from_json(manifest_def.as_ptr()) };
+        assert!(!builder.is_null());
+        let format = CString::new("image/jpeg").unwrap();
+        let mut manifest_bytes_ptr = std::ptr::null();
+        let result = unsafe {
+            c2pa_builder_sign_async(
+                builder,
+                format.as_ptr(),
+                &mut source_stream,
+                &mut dest_stream,
+                signer,
+                &mut manifest_bytes_ptr,
+            )
+        };
+        assert!(result > 0);
+        TestC2paStream::drop_c_stream(source_stream);
+        TestC2paStream::drop_c_stream(dest_stream);
+        unsafe {
+            c2pa_manifest_bytes_free(manifest_bytes_ptr);
+            c2pa_builder_free(builder);
+            c2pa_async_signer_free(signer);
+        }
+    }
+
     #[test]
     #[cfg(feature = "file_io")]
     fn test_c2pa_read_file_null_path() {
@@ -24,4 +24,4 @@ pub use c2pa::{
 pub use c2pa_stream::*;
 pub use c_api::*;
 pub use error::{Error, Result};
-pub use signer_info::SignerInfo;
+pub use signer_info::{IdentityInfo, SignerInfo};
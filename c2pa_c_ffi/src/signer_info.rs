@@ -10,7 +10,7 @@
 // specific language governing permissions and limitations under
 // each license.
 
-use c2pa::{create_signer, Signer, SigningAlg};
+use c2pa::{create_signer, identity::x509::X509IdentitySigner, Signer, SigningAlg};
 use serde::Deserialize;
 
 use crate::{Error, Result};
@@ -27,6 +27,9 @@ pub struct SignerInfo {
     pub private_key: Vec<u8>,
     /// The ta_url field is an optional URL used to specify a timestamp server.
     pub ta_url: Option<String>,
+    /// An optional X.509 credential used to add a CAWG identity assertion.
+    #[serde(default)]
+    pub identity: Option<IdentityInfo>,
 }
 
 impl SignerInfo {
@@ -37,24 +40,81 @@ impl SignerInfo {
 
     // Returns the signing algorithm converted from string format
     fn alg(&self) -> Result<SigningAlg> {
-        self.alg
-            .to_lowercase()
-            .parse()
-            .map_err(|_| Error::Other("Invalid signing algorithm".to_string()))
+        parse_alg(&self.alg)
     }
 
     /// Create a signer from the SignerInfo
+    ///
+    /// If identity information is present, the signer will also add a
+    /// CAWG identity assertion to each manifest it signs.
     pub fn signer(&self) -> Result<Box<dyn Signer>> {
-        create_signer::from_keys(
+        let signer = create_signer::from_keys(
             &self.sign_cert,
             &self.private_key,
             self.alg()?,
             self.ta_url.clone(),
         )
-        .map_err(Error::from_c2pa_error)
+        .map_err(Error::from_c2pa_error)?;
+
+        match self.identity.as_ref() {
+            Some(identity) => identity.signer(signer),
+            None => Ok(signer),
+        }
     }
 }
 
+/// IdentityInfo provides the X.509 credential used to sign a CAWG
+/// identity assertion, and what that assertion should contain.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct IdentityInfo {
+    /// The alg field is used to determine the signing algorithm.
+    pub alg: String,
+    /// The public certificate of the credential holder in PEM format.
+    pub sign_cert: Vec<u8>,
+    /// The private key of the credential holder in PEM format.
+    pub private_key: Vec<u8>,
+    /// The ta_url field is an optional URL used to specify a timestamp server.
+    pub ta_url: Option<String>,
+    /// Labels of the assertions the identity assertion should reference.
+    #[serde(default)]
+    pub referenced_assertions: Vec<String>,
+    /// Roles of the named actor, such as `cawg.creator`.
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+impl IdentityInfo {
+    /// Wrap a signer so that it adds a CAWG identity assertion when signing.
+    pub fn signer(&self, signer: Box<dyn Signer>) -> Result<Box<dyn Signer>> {
+        let mut identity_signer = X509IdentitySigner::from_keys(
+            signer,
+            &self.sign_cert,
+            &self.private_key,
+            parse_alg(&self.alg)?,
+            self.ta_url.clone(),
+        )
+        .map_err(Error::from_c2pa_error)?;
+
+        let labels: Vec<&str> = self
+            .referenced_assertions
+            .iter()
+            .map(String::as_str)
+            .collect();
+        identity_signer.add_referenced_assertions(&labels);
+
+        let roles: Vec<&str> = self.roles.iter().map(String::as_str).collect();
+        identity_signer.add_roles(&roles);
+
+        Ok(Box::new(identity_signer))
+    }
+}
+
+fn parse_alg(alg: &str) -> Result<SigningAlg> {
+    alg.to_lowercase()
+        .parse()
+        .map_err(|_| Error::Other("Invalid signing algorithm".to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -128,6 +188,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_signer_info_with_identity() {
+        let certs = include_bytes!("../../sdk/tests/fixtures/certs/es256.pub");
+        let private_key = include_bytes!("../../sdk/tests/fixtures/certs/es256.pem");
+        let cawg_certs = include_bytes!("../../sdk/tests/fixtures/certs/ed25519.pub");
+        let cawg_private_key = include_bytes!("../../sdk/tests/fixtures/certs/ed25519.pem");
+
+        let json = json!({
+            "alg": "es256",
+            "sign_cert": certs.to_vec(),
+            "private_key": private_key.to_vec(),
+            "identity": {
+                "alg": "ed25519",
+                "sign_cert": cawg_certs.to_vec(),
+                "private_key": cawg_private_key.to_vec(),
+                "referenced_assertions": ["c2pa.actions"],
+                "roles": ["cawg.creator"]
+            }
+        })
+        .to_string();
+
+        let signer_info = SignerInfo::from_json(&json).unwrap();
+        let identity = signer_info.identity.as_ref().unwrap();
+        assert_eq!(identity.referenced_assertions, vec!["c2pa.actions"]);
+        assert_eq!(identity.roles, vec!["cawg.creator"]);
+
+        let signer = signer_info.signer().unwrap();
+        assert_eq!(signer.alg(), SigningAlg::Es256);
+        assert_eq!(signer.dynamic_assertions().len(), 1);
+    }
+
     #[test]
     fn test_signer_creation_fail() {
         let json = json!({
@@ -54,7 +54,14 @@ mod signer;
 
 /// Tool for displaying and creating C2PA manifests.
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None, arg_required_else_help(true))]
+#[command(
+    author,
+    version,
+    about,
+    long_about = None,
+    arg_required_else_help(true),
+    subcommand_negates_reqs(true)
+)]
 struct CliArgs {
     /// Path to manifest definition JSON file.
     #[clap(short, long, requires = "output")]
@@ -81,7 +88,8 @@ struct CliArgs {
     force: bool,
 
     /// The path to an asset to examine or embed a manifest into.
-    path: PathBuf,
+    #[clap(required = true)]
+    path: Option<PathBuf>,
 
     /// Embed remote URL manifest reference.
     #[clap(short, long)]
@@ -103,6 +111,14 @@ struct CliArgs {
     #[clap(long = "certs")]
     cert_chain: bool,
 
+    /// Explain the trust decision for each signature: the certificate path,
+    /// the trust anchor it matched, and why other anchors were rejected.
+    #[clap(
+        long = "trust_report",
+        conflicts_with_all = ["manifest", "config", "output", "parent", "detailed", "ingredient", "tree", "cert_chain", "info"]
+    )]
+    trust_report: bool,
+
     /// Do not perform validation of signature after signing.
     #[clap(long = "no_signing_verify")]
     no_signing_verify: bool,
@@ -118,6 +134,13 @@ struct CliArgs {
     #[clap(long)]
     signer_path: Option<PathBuf>,
 
+    /// Sign with the signer configured in the `[signer]` section of the settings file.
+    ///
+    /// Local and remote signers are run asynchronously, so remote signing and
+    /// time stamp requests don't block.
+    #[clap(long, conflicts_with = "signer_path")]
+    settings_signer: bool,
+
     /// To be used with the [callback_signer] argument. This value should at least: size of CoseSign1 CBOR +
     /// the size of certificate chain provided in the manifest definition's `sign_cert` field + the size of the
     /// signature of the Time Stamp Authority response. A typical size of CoseSign1 CBOR is in the 1-2K range. If
@@ -186,6 +209,14 @@ enum Commands {
         #[arg(long = "trust_anchors", env="C2PATOOL_TRUST_ANCHORS", value_parser = parse_resource_string)]
         trust_anchors: Option<TrustResource>,
 
+        /// URL or path to file containing a C2PA trust list in JSON format
+        #[arg(long = "trust_list", env="C2PATOOL_TRUST_LIST", value_parser = parse_resource_string)]
+        trust_list: Option<TrustResource>,
+
+        /// URL or path to file containing a C2PA time stamp authority trust list in JSON format
+        #[arg(long = "timestamp_trust_list", env="C2PATOOL_TIMESTAMP_TRUST_LIST", value_parser = parse_resource_string)]
+        timestamp_trust_list: Option<TrustResource>,
+
         /// URL or path to file containing specific manifest signing certificates in PEM format to implicitly trust
         #[arg(long = "allowed_list", env="C2PATOOL_ALLOWED_LIST", value_parser = parse_resource_string)]
         allowed_list: Option<TrustResource>,
@@ -193,6 +224,14 @@ enum Commands {
         /// URL or path to file containing configured EKUs in Oid dot notation
         #[arg(long = "trust_config", env="C2PATOOL_TRUST_CONFIG", value_parser = parse_resource_string)]
         trust_config: Option<TrustResource>,
+
+        /// URL or path to file containing list of time stamp authority trust anchors in PEM format
+        #[arg(long = "timestamp_trust_anchors", env="C2PATOOL_TIMESTAMP_TRUST_ANCHORS", value_parser = parse_resource_string)]
+        timestamp_trust_anchors: Option<TrustResource>,
+
+        /// URL or path to file containing list of CAWG X.509 identity trust anchors in PEM format
+        #[arg(long = "cawg_trust_anchors", env="C2PATOOL_CAWG_TRUST_ANCHORS", value_parser = parse_resource_string)]
+        cawg_trust_anchors: Option<TrustResource>,
     },
     /// Sub-command to add manifest to fragmented BMFF content
     ///
@@ -210,6 +249,19 @@ enum Commands {
         #[arg(long = "fragments_glob", verbatim_doc_comment)]
         fragments_glob: Option<PathBuf>,
     },
+    /// Sub-command to compare the manifest stores of two assets
+    ///
+    /// Prints a JSON report of the manifests, assertions, ingredients, actions and signatures
+    /// that were added, removed or changed, for example:
+    ///
+    /// c2patool diff original.jpg edited.jpg
+    Diff {
+        /// Path to the asset to compare from
+        before: PathBuf,
+
+        /// Path to the asset to compare against
+        after: PathBuf,
+    },
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -390,8 +442,12 @@ fn configure_sdk(args: &CliArgs) -> Result<()> {
 
     if let Some(Commands::Trust {
         trust_anchors,
+        trust_list,
+        timestamp_trust_list,
         allowed_list,
         trust_config,
+        timestamp_trust_anchors,
+        cawg_trust_anchors,
     }) = &args.command
     {
         if let Some(trust_list) = &trust_anchors {
@@ -409,6 +465,36 @@ fn configure_sdk(args: &CliArgs) -> Result<()> {
             enable_trust_checks = true;
         }
 
+        if let Some(trust_list) = &trust_list {
+            debug!("Using trust list from {trust_list:?}");
+
+            let data = load_trust_resource(trust_list)?;
+            Settings::from_toml(
+                &toml::toml! {
+                    [trust]
+                    trust_list = data
+                }
+                .to_string(),
+            )?;
+
+            enable_trust_checks = true;
+        }
+
+        if let Some(trust_list) = &timestamp_trust_list {
+            debug!("Using time stamp trust list from {trust_list:?}");
+
+            let data = load_trust_resource(trust_list)?;
+            Settings::from_toml(
+                &toml::toml! {
+                    [timestamp_trust]
+                    trust_list = data
+                }
+                .to_string(),
+            )?;
+
+            enable_trust_checks = true;
+        }
+
         if let Some(allowed_list) = &allowed_list {
             debug!("Using allowed list from {allowed_list:?}");
 
@@ -438,6 +524,36 @@ fn configure_sdk(args: &CliArgs) -> Result<()> {
 
             enable_trust_checks = true;
         }
+
+        if let Some(trust_list) = &timestamp_trust_anchors {
+            debug!("Using time stamp trust anchors from {trust_list:?}");
+
+            let data = load_trust_resource(trust_list)?;
+            Settings::from_toml(
+                &toml::toml! {
+                    [timestamp_trust]
+                    trust_anchors = data
+                }
+                .to_string(),
+            )?;
+
+            enable_trust_checks = true;
+        }
+
+        if let Some(trust_list) = &cawg_trust_anchors {
+            debug!("Using CAWG identity trust anchors from {trust_list:?}");
+
+            let data = load_trust_resource(trust_list)?;
+            Settings::from_toml(
+                &toml::toml! {
+                    [cawg_trust]
+                    trust_anchors = data
+                }
+                .to_string(),
+            )?;
+
+            enable_trust_checks = true;
+        }
     }
 
     // if any trust setting is provided enable the trust checks
@@ -570,6 +686,29 @@ fn verify_fragmented(init_pattern: &Path, frag_pattern: &Path) -> Result<Vec<Rea
     Ok(readers)
 }
 
+// sign with the async signer configured in the settings
+fn sign_file_with_settings_signer(
+    builder: &mut Builder,
+    source: &Path,
+    dest: &Path,
+) -> Result<Vec<u8>> {
+    #[cfg(not(target_os = "wasi"))]
+    {
+        let signer = Settings::async_signer()?;
+        Runtime::new()?
+            .block_on(builder.sign_file_async(signer.as_ref(), source, dest))
+            .map_err(anyhow::Error::from)
+    }
+    #[cfg(target_os = "wasi")]
+    {
+        // Async signers aren't available on WASI, use the blocking signer instead.
+        let signer = Settings::signer()?;
+        builder
+            .sign_file(signer.as_ref(), source, dest)
+            .map_err(anyhow::Error::from)
+    }
+}
+
 // run cawg validation if supported
 fn validate_cawg(reader: &mut Reader) -> Result<()> {
     #[cfg(not(target_os = "wasi"))]
@@ -593,7 +732,15 @@ fn main() -> Result<()> {
     }
     env_logger::init();
 
-    let path = &args.path;
+    // diff names both of its assets, every other command works on PATH
+    let path = match (&args.path, &args.command) {
+        (None, Some(Commands::Diff { before, .. })) => before,
+        (Some(_), Some(Commands::Diff { .. })) => {
+            bail!("diff takes both paths: c2patool diff <BEFORE> <AFTER>")
+        }
+        (Some(path), _) => path,
+        (None, _) => bail!("the following required argument was not provided: <PATH>"),
+    };
 
     if args.info {
         return info(path);
@@ -625,6 +772,35 @@ fn main() -> Result<()> {
     // configure the SDK
     configure_sdk(&args).context("Could not configure c2pa-rs")?;
 
+    if args.trust_report {
+        if matches!(args.command, Some(Commands::Diff { .. })) {
+            bail!("--trust_report cannot be used with diff");
+        }
+
+        Settings::from_toml(
+            &toml::toml! {
+                [verify]
+                trust_reports = true
+            }
+            .to_string(),
+        )?;
+
+        let reader = Reader::from_file(path).map_err(special_errs)?;
+        let trust_reports = reader
+            .validation_results()
+            .map(|results| results.trust_reports())
+            .unwrap_or_default();
+        println!("{}", serde_json::to_string_pretty(trust_reports)?);
+        return Ok(());
+    }
+
+    if let Some(Commands::Diff { before, after }) = &args.command {
+        let before = Reader::from_file(before).map_err(special_errs)?;
+        let after = Reader::from_file(after).map_err(special_errs)?;
+        println!("{}", before.diff(&after)?.json());
+        return Ok(());
+    }
+
     // Remove manifest needs to also remove XMP provenance
     // if args.remove_manifest {
     //     match args.output {
@@ -709,7 +885,7 @@ fn main() -> Result<()> {
         // note: This could be treated as an update manifest eventually since the image is the same
         let has_parent = builder.definition.ingredients.iter().any(|i| i.is_parent());
         if !has_parent && !is_fragment {
-            let mut source_ingredient = Ingredient::from_file(&args.path)?;
+            let mut source_ingredient = Ingredient::from_file(path)?;
             if source_ingredient.manifest_data().is_some() {
                 source_ingredient.set_is_parent();
                 builder.add_ingredient(source_ingredient);
@@ -727,18 +903,26 @@ fn main() -> Result<()> {
             builder.set_no_embed(true);
         }
 
-        let signer = if let Some(signer_process_name) = args.signer_path {
-            let cb_config = CallbackSignerConfig::new(&sign_config, args.reserve_size)?;
-
-            let process_runner = Box::new(ExternalProcessRunner::new(
-                cb_config.clone(),
-                signer_process_name,
-            ));
-            let signer = CallbackSigner::new(process_runner, cb_config);
-
-            Box::new(signer)
-        } else {
-            sign_config.signer()?
+        let signer_path = args.signer_path;
+        let reserve_size = args.reserve_size;
+        let settings_signer = args.settings_signer;
+        // identity assertions are added by a blocking signer wrapper
+        let has_identity = sign_config.cawg_identity.is_some();
+        let make_signer = || -> Result<Box<dyn Signer>> {
+            let signer: Box<dyn Signer> = if let Some(signer_process_name) = signer_path {
+                let cb_config = CallbackSignerConfig::new(&sign_config, reserve_size)?;
+
+                let process_runner = Box::new(ExternalProcessRunner::new(
+                    cb_config.clone(),
+                    signer_process_name,
+                ));
+                Box::new(CallbackSigner::new(process_runner, cb_config))
+            } else if settings_signer {
+                Settings::signer()?
+            } else {
+                sign_config.signer()?
+            };
+            sign_config.with_identity(signer)
         };
 
         if let Some(output) = args.output {
@@ -749,12 +933,13 @@ fn main() -> Result<()> {
                 }
 
                 if let Some(fg) = &fragments_glob {
-                    return sign_fragmented(&mut builder, signer.as_ref(), &args.path, fg, &output);
+                    let signer = make_signer()?;
+                    return sign_fragmented(&mut builder, signer.as_ref(), path, fg, &output);
                 } else {
                     bail!("fragments_glob must be set");
                 }
             } else {
-                if ext_normal(&output) != ext_normal(&args.path) {
+                if ext_normal(&output) != ext_normal(path) {
                     bail!("Output type must match source type");
                 }
                 if output.exists() {
@@ -772,9 +957,15 @@ fn main() -> Result<()> {
                     bail!("Missing extension output");
                 }
 
-                let manifest_data = builder
-                    .sign_file(signer.as_ref(), &args.path, &output)
-                    .context("embedding manifest")?;
+                let manifest_data = if settings_signer && !has_identity {
+                    sign_file_with_settings_signer(&mut builder, path, &output)
+                } else {
+                    let signer = make_signer()?;
+                    builder
+                        .sign_file(signer.as_ref(), path, &output)
+                        .map_err(anyhow::Error::from)
+                }
+                .context("embedding manifest")?;
 
                 if args.sidecar {
                     let sidecar = output.with_extension("c2pa");
@@ -809,13 +1000,13 @@ fn main() -> Result<()> {
         }
         create_dir_all(&output)?;
         if args.ingredient {
-            let report = Ingredient::from_file_with_folder(&args.path, &output)
+            let report = Ingredient::from_file_with_folder(path, &output)
                 .map_err(special_errs)?
                 .to_string();
             File::create(output.join("ingredient.json"))?.write_all(&report.into_bytes())?;
             println!("Ingredient report written to the directory {:?}", &output);
         } else {
-            let mut reader = Reader::from_file(&args.path).map_err(special_errs)?;
+            let mut reader = Reader::from_file(path).map_err(special_errs)?;
             validate_cawg(&mut reader)?;
             reader.to_folder(&output)?;
             let report = reader.to_string();
@@ -829,19 +1020,16 @@ fn main() -> Result<()> {
             println!("Manifest report written to the directory {:?}", &output);
         }
     } else if args.ingredient {
-        println!(
-            "{}",
-            Ingredient::from_file(&args.path).map_err(special_errs)?
-        )
+        println!("{}", Ingredient::from_file(path).map_err(special_errs)?)
     } else if args.detailed {
-        let mut reader = Reader::from_file(&args.path).map_err(special_errs)?;
+        let mut reader = Reader::from_file(path).map_err(special_errs)?;
         validate_cawg(&mut reader)?;
         println!("{reader:#?}");
     } else if let Some(Commands::Fragment {
         fragments_glob: Some(fg),
     }) = &args.command
     {
-        let mut stores = verify_fragmented(&args.path, fg)?;
+        let mut stores = verify_fragmented(path, fg)?;
         if stores.len() == 1 {
             validate_cawg(&mut stores[0])?;
             println!("{}", stores[0]);
@@ -852,7 +1040,7 @@ fn main() -> Result<()> {
             println!("{} Init manifests validated", stores.len());
         }
     } else {
-        let mut reader = Reader::from_file(&args.path).map_err(special_errs)?;
+        let mut reader = Reader::from_file(path).map_err(special_errs)?;
         validate_cawg(&mut reader)?;
         println!("{reader}");
     }
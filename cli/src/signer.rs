@@ -17,7 +17,7 @@ use std::{
 };
 
 use anyhow::{Context, Result};
-use c2pa::{create_signer, Signer, SigningAlg};
+use c2pa::{create_signer, identity::x509::X509IdentitySigner, Signer, SigningAlg};
 use serde::Deserialize;
 
 // Pull in default certs so the binary can self config
@@ -40,6 +40,74 @@ pub struct SignConfig {
     pub sign_cert: Option<PathBuf>,
     /// A Url to a Time Authority to use when signing the manifest
     pub ta_url: Option<String>,
+    /// An X.509 credential used to add a CAWG identity assertion to the manifest
+    pub cawg_identity: Option<IdentityConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct IdentityConfig {
+    /// Signing algorithm to use - must match the associated certs
+    ///
+    /// Defaults to ed25519
+    pub alg: Option<String>,
+    /// A path to a file containing the credential holder's private key
+    pub private_key: PathBuf,
+    /// A path to a file containing the credential holder's certificate
+    pub sign_cert: PathBuf,
+    /// A Url to a Time Authority to use when signing the identity assertion
+    pub ta_url: Option<String>,
+    /// Labels of the assertions the identity assertion should reference
+    #[serde(default)]
+    pub referenced_assertions: Vec<String>,
+    /// Roles of the named actor, such as cawg.creator
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+impl IdentityConfig {
+    // set a base for all non-absolute paths
+    fn set_base_path<P: AsRef<Path>>(&mut self, base: P) {
+        if !self.private_key.is_absolute() {
+            self.private_key = base.as_ref().join(&self.private_key);
+        }
+        if !self.sign_cert.is_absolute() {
+            self.sign_cert = base.as_ref().join(&self.sign_cert);
+        }
+    }
+
+    /// Wraps a signer so that it also adds a CAWG identity assertion.
+    pub fn signer(&self, signer: Box<dyn Signer>) -> Result<Box<dyn Signer>> {
+        let alg = self.alg.as_deref().unwrap_or("ed25519").to_lowercase();
+        let alg: SigningAlg = alg.parse().map_err(|_| c2pa::Error::UnsupportedType)?;
+
+        let private_key = std::fs::read(&self.private_key).context(format!(
+            "Reading identity private key: {:?}",
+            &self.private_key
+        ))?;
+        let sign_cert = std::fs::read(&self.sign_cert)
+            .context(format!("Reading identity sign cert: {:?}", &self.sign_cert))?;
+
+        let mut identity_signer = X509IdentitySigner::from_keys(
+            signer,
+            &sign_cert,
+            &private_key,
+            alg,
+            self.ta_url.clone(),
+        )
+        .context("Invalid identity certification data")?;
+
+        let labels: Vec<&str> = self
+            .referenced_assertions
+            .iter()
+            .map(String::as_str)
+            .collect();
+        identity_signer.add_referenced_assertions(&labels);
+
+        let roles: Vec<&str> = self.roles.iter().map(String::as_str).collect();
+        identity_signer.add_roles(&roles);
+
+        Ok(Box::new(identity_signer))
+    }
 }
 
 impl SignConfig {
@@ -59,9 +127,20 @@ impl SignConfig {
                 self.sign_cert = Some(base.as_ref().join(path));
             }
         }
+        if let Some(identity) = self.cawg_identity.as_mut() {
+            identity.set_base_path(base);
+        }
         self
     }
 
+    /// Adds a CAWG identity assertion to the signer if one is configured.
+    pub fn with_identity(&self, signer: Box<dyn Signer>) -> Result<Box<dyn Signer>> {
+        match self.cawg_identity.as_ref() {
+            Some(identity) => identity.signer(signer),
+            None => Ok(signer),
+        }
+    }
+
     pub fn signer(&self) -> Result<Box<dyn Signer>> {
         let alg = self.alg.as_deref().unwrap_or("es256").to_lowercase();
         let alg: SigningAlg = alg.parse().map_err(|_| c2pa::Error::UnsupportedType)?;
@@ -133,6 +212,30 @@ pub mod tests {
         assert_eq!(signer.alg(), SigningAlg::Es256);
     }
 
+    #[test]
+    fn test_sign_config_with_identity() {
+        let mut sign_config = SignConfig::from_json(
+            r#"{
+                "alg": "es256",
+                "private_key": "es256_private.key",
+                "sign_cert": "es256_certs.pem",
+                "cawg_identity": {
+                    "private_key": "ed25519.pem",
+                    "sign_cert": "ed25519.pub",
+                    "referenced_assertions": ["c2pa.actions"],
+                    "roles": ["cawg.creator"]
+                }
+            }"#,
+        )
+        .expect("from_json");
+        sign_config.set_base_path("sample");
+
+        let signer = sign_config.signer().expect("get signer");
+        let signer = sign_config.with_identity(signer).expect("add identity");
+        assert_eq!(signer.alg(), SigningAlg::Es256);
+        assert_eq!(signer.dynamic_assertions().len(), 1);
+    }
+
     #[test]
     fn test_sign_default() {
         let sign_config = SignConfig::default();
@@ -78,6 +78,69 @@ fn tool_info() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+// c2patool tests/fixtures/C.jpg --trust_report
+fn tool_trust_report() -> Result<(), Box<dyn Error>> {
+    let output = Command::cargo_bin("c2patool")?
+        .arg(fixture_path(TEST_IMAGE_WITH_MANIFEST))
+        .arg("--trust_report")
+        .output()?;
+    assert!(output.status.success());
+    let reports: Value = serde_json::from_slice(&output.stdout)?;
+    assert!(reports.is_array());
+
+    // the report is its own output, so it can't be combined with others
+    Command::cargo_bin("c2patool")?
+        .arg(fixture_path(TEST_IMAGE_WITH_MANIFEST))
+        .arg("--trust_report")
+        .arg("--detailed")
+        .assert()
+        .failure()
+        .stderr(str::contains("cannot be used with"));
+
+    Command::cargo_bin("c2patool")?
+        .arg("--trust_report")
+        .arg("diff")
+        .arg(fixture_path(TEST_IMAGE_WITH_MANIFEST))
+        .arg(fixture_path(TEST_IMAGE_WITH_MANIFEST))
+        .assert()
+        .failure()
+        .stderr(str::contains("cannot be used with diff"));
+    Ok(())
+}
+
+#[test]
+// c2patool diff tests/fixtures/C.jpg tests/fixtures/C_with_CAWG_data.jpg
+fn tool_diff() -> Result<(), Box<dyn Error>> {
+    Command::cargo_bin("c2patool")?
+        .arg("diff")
+        .arg(fixture_path(TEST_IMAGE_WITH_MANIFEST))
+        .arg(fixture_path(TEST_IMAGE_WITH_MANIFEST))
+        .assert()
+        .success()
+        .stdout("{}\n");
+
+    let output = Command::cargo_bin("c2patool")?
+        .arg("diff")
+        .arg(fixture_path(TEST_IMAGE_WITH_MANIFEST))
+        .arg(fixture_path("C_with_CAWG_data.jpg"))
+        .output()?;
+    assert!(output.status.success());
+    let diff: Value = serde_json::from_slice(&output.stdout)?;
+    assert!(!diff["manifests"].as_array().unwrap().is_empty());
+
+    // the paths belong to the subcommand
+    Command::cargo_bin("c2patool")?
+        .arg(fixture_path(TEST_IMAGE_WITH_MANIFEST))
+        .arg("diff")
+        .arg(fixture_path(TEST_IMAGE_WITH_MANIFEST))
+        .arg(fixture_path("C_with_CAWG_data.jpg"))
+        .assert()
+        .failure()
+        .stderr(str::contains("diff takes both paths"));
+    Ok(())
+}
+
 #[test]
 fn tool_embed_jpeg_report() -> Result<(), Box<dyn Error>> {
     Command::cargo_bin("c2patool")?
@@ -94,6 +157,54 @@ fn tool_embed_jpeg_report() -> Result<(), Box<dyn Error>> {
         .stdout(str::contains("My Title"));
     Ok(())
 }
+#[test]
+fn tool_embed_jpeg_with_settings_signer() -> Result<(), Box<dyn Error>> {
+    let settings_path = temp_path("settings_signer.toml");
+    fs::write(
+        &settings_path,
+        format!(
+            "[signer.local]\nalg = \"es256\"\nsign_cert = '''{}'''\nprivate_key = '''{}'''\n",
+            fs::read_to_string("sample/es256_certs.pem")?,
+            fs::read_to_string("sample/es256_private.key")?,
+        ),
+    )?;
+
+    Command::cargo_bin("c2patool")?
+        .arg(fixture_path(TEST_IMAGE))
+        .arg("-m")
+        .arg("sample/test.json")
+        .arg("-o")
+        .arg(temp_path("out_settings_signer.jpg"))
+        .arg("-f")
+        .arg("--settings")
+        .arg(&settings_path)
+        .arg("--settings-signer")
+        .assert()
+        .success()
+        .stdout(str::contains("My Title"));
+    Ok(())
+}
+
+#[test]
+fn tool_settings_signer_requires_signer_settings() -> Result<(), Box<dyn Error>> {
+    let settings_path = temp_path("settings_no_signer.toml");
+    fs::write(&settings_path, "")?;
+
+    Command::cargo_bin("c2patool")?
+        .arg(fixture_path(TEST_IMAGE))
+        .arg("-m")
+        .arg("sample/test.json")
+        .arg("-o")
+        .arg(temp_path("out_no_settings_signer.jpg"))
+        .arg("-f")
+        .arg("--settings")
+        .arg(&settings_path)
+        .arg("--settings-signer")
+        .assert()
+        .failure();
+    Ok(())
+}
+
 #[test]
 fn tool_fs_output_report() -> Result<(), Box<dyn Error>> {
     let path = temp_path("output_dir");
@@ -536,6 +647,53 @@ fn tool_tree() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn tool_embed_jpeg_with_cawg_identity() -> Result<(), Box<dyn Error>> {
+    let manifest_path = temp_path("cawg_identity.json");
+    fs::write(
+        &manifest_path,
+        serde_json::json!({
+            "claim_version": 2,
+            "alg": "es256",
+            "private_key": fs::canonicalize("sample/es256_private.key")?,
+            "sign_cert": fs::canonicalize("sample/es256_certs.pem")?,
+            "cawg_identity": {
+                "alg": "ed25519",
+                "private_key": fs::canonicalize("sample/ed25519.pem")?,
+                "sign_cert": fs::canonicalize("sample/ed25519.pub")?,
+                "referenced_assertions": ["c2pa.actions"],
+                "roles": ["cawg.creator"]
+            },
+            "title": "My Title",
+            "assertions": [
+                {
+                    "label": "c2pa.actions",
+                    "data": {
+                        "actions": [{
+                            "action": "c2pa.created",
+                            "digitalSourceType": "http://cv.iptc.org/newscodes/digitalsourcetype/digitalCapture"
+                        }]
+                    }
+                }
+            ]
+        })
+        .to_string(),
+    )?;
+
+    Command::cargo_bin("c2patool")?
+        .arg(fixture_path(TEST_IMAGE))
+        .arg("-m")
+        .arg(&manifest_path)
+        .arg("-o")
+        .arg(temp_path("out_cawg_identity.jpg"))
+        .arg("-f")
+        .assert()
+        .success()
+        .stdout(str::contains("cawg.x509.cose"))
+        .stdout(str::contains("cawg.identity.well-formed"));
+    Ok(())
+}
+
 #[test]
 // c2patool C_with_CAWG_data.jpg
 fn tool_read_image_with_cawg_data() -> Result<(), Box<dyn Error>> {
@@ -33,13 +33,13 @@ use crate::{
     asset_handlers::bmff_io::{
         bmff_to_jumbf_exclusions, read_bmff_c2pa_boxes, BoxInfoLite, C2PABmffBoxes,
     },
-    asset_io::CAIRead,
+    asset_io::{CAIRead, StreamFactory},
     cbor_types::UriT,
     settings::get_settings_value,
     utils::{
         hash_utils::{
-            concat_and_hash, hash_stream_by_alg, vec_compare, verify_stream_by_alg, HashRange,
-            Hasher,
+            concat_and_hash, hash_batch, hash_stream_by_alg, vec_compare, verify_stream_by_alg,
+            HashJob, HashRange, Hasher,
         },
         io_utils::stream_len,
         merkle::C2PAMerkleTree,
@@ -444,7 +444,19 @@ impl BmffHash {
     ) -> crate::error::Result<()> {
         let mut reader = Cursor::new(data);
 
-        self.verify_stream_hash(&mut reader, alg)
+        self.verify_hash_with_factory(&mut reader, Some(&data), alg)
+    }
+
+    /// Verify the hashes using readers from `factory`, which lets Merkle leaves
+    /// be hashed in parallel when `core.parallel_hashing` is enabled.
+    pub fn verify_factory_hash(
+        &self,
+        factory: &dyn StreamFactory,
+        alg: Option<&str>,
+    ) -> crate::error::Result<()> {
+        let mut reader = factory.open_stream()?;
+
+        self.verify_hash_with_factory(&mut reader, Some(factory), alg)
     }
 
     // The BMFFMerklMaps are stored contiguous in the file.  Break this Vec into groups based on
@@ -503,7 +515,7 @@ impl BmffHash {
         alg: Option<&str>,
     ) -> crate::error::Result<()> {
         let mut data = std::fs::File::open(asset_path)?;
-        self.verify_stream_hash(&mut data, alg)
+        self.verify_hash_with_factory(&mut data, Some(&asset_path), alg)
     }
 
     fn verify_self(&self) -> crate::error::Result<()> {
@@ -559,6 +571,15 @@ impl BmffHash {
         &self,
         reader: &mut dyn CAIRead,
         alg: Option<&str>,
+    ) -> crate::error::Result<()> {
+        self.verify_hash_with_factory(reader, None, alg)
+    }
+
+    fn verify_hash_with_factory(
+        &self,
+        reader: &mut dyn CAIRead,
+        factory: Option<&dyn StreamFactory>,
+        alg: Option<&str>,
     ) -> crate::error::Result<()> {
         self.verify_self()?;
 
@@ -579,7 +600,7 @@ impl BmffHash {
 
         // handle file level hashing
         if let Some(hash) = self.hash() {
-            if !verify_stream_by_alg(&curr_alg, hash, reader, Some(exclusions.clone()), true) {
+            if !verify_stream_by_alg(&curr_alg, hash, reader, Some(exclusions.clone()), true)? {
                 return Err(Error::HashMismatch(
                     "BMFF file level hash mismatch".to_string(),
                 ));
@@ -614,7 +635,7 @@ impl BmffHash {
                         let mut mm_exclusions = exclusions.clone();
                         mm_exclusions.push(moof_exclusion);
 
-                        if !verify_stream_by_alg(alg, init_hash, reader, Some(mm_exclusions), true)
+                        if !verify_stream_by_alg(alg, init_hash, reader, Some(mm_exclusions), true)?
                         {
                             return Err(Error::HashMismatch(
                                 "BMFF file level hash mismatch".to_string(),
@@ -648,7 +669,8 @@ impl BmffHash {
                     }
 
                     // build Merkle tree for the moof chucks minus the excluded ranges
-                    for (index, boxes) in moof_chunks.iter().enumerate() {
+                    let mut jobs = Vec::with_capacity(moof_chunks.len());
+                    for boxes in &moof_chunks {
                         // include just the range of this chunk so exclude boxes before and after
                         let mut curr_exclusions = exclusions.clone();
 
@@ -670,13 +692,17 @@ impl BmffHash {
                         let after_box_exclusion = HashRange::new(after_box_start, after_box_len);
                         curr_exclusions.push(after_box_exclusion);
 
-                        // hash the specified range
-                        let hash = hash_stream_by_alg(alg, reader, Some(curr_exclusions), true)?;
+                        jobs.push(HashJob::new(alg, Some(curr_exclusions), true));
+                    }
+
+                    // hash the specified ranges
+                    let hashes = hash_batch(reader, factory, &jobs)?;
 
+                    for (index, hash) in hashes.iter().enumerate() {
                         let bmff_mm = &bmff_merkle[index];
 
                         // check MerkleMap for the hash
-                        if !mm.check_merkle_tree(alg, &hash, bmff_mm.location, &bmff_mm.hashes) {
+                        if !mm.check_merkle_tree(alg, hash, bmff_mm.location, &bmff_mm.hashes) {
                             return Err(Error::HashMismatch("Fragment not valid".to_string()));
                         }
                     }
@@ -686,7 +712,7 @@ impl BmffHash {
                 .iter()
                 .all(|mm| mm.fixed_block_size.is_some() || mm.variable_block_sizes.is_some())
             {
-                self.validate_merkle_maps_mdat_boxes(reader, &c2pa_boxes)?;
+                self.validate_merkle_maps_mdat_boxes(reader, factory, &c2pa_boxes)?;
             } else if box_infos.iter().any(|b| b.path == "moov") && !bmff_merkle.is_empty() {
                 // timed media case
 
@@ -809,7 +835,7 @@ impl BmffHash {
                 }
             } else {
                 // try the no boxes defined fallback
-                self.validate_merkle_maps_mdat_boxes(reader, &c2pa_boxes)?;
+                self.validate_merkle_maps_mdat_boxes(reader, None, &c2pa_boxes)?;
             }
         }
 
@@ -890,7 +916,7 @@ impl BmffHash {
                                     init_stream,
                                     Some(exclusions),
                                     true,
-                                ) {
+                                )? {
                                     return Err(Error::HashMismatch(
                                         "BMFF inithash mismatch".to_string(),
                                     ));
@@ -998,7 +1024,7 @@ impl BmffHash {
                             init_stream,
                             Some(exclusions),
                             true,
-                        ) {
+                        )? {
                             return Err(Error::HashMismatch("BMFF inithash mismatch".to_string()));
                         }
 
@@ -1431,6 +1457,7 @@ impl BmffHash {
     pub(crate) fn validate_merkle_maps_mdat_boxes(
         &self,
         reader: &mut dyn CAIRead,
+        factory: Option<&dyn StreamFactory>,
         c2pa_boxes: &C2PABmffBoxes,
     ) -> crate::Result<()> {
         let mm_vec = self
@@ -1529,9 +1556,9 @@ impl BmffHash {
                     ));
                 }
 
-                for (range_index, range) in ranges.iter().enumerate() {
-                    let hash = hash_stream_by_alg(alg, reader, Some(vec![range.clone()]), false)?;
-                    if !mm.check_merkle_tree(alg, &hash, range_index, &None) {
+                let hashes = hash_batch(reader, factory, &leaf_jobs(alg, ranges))?;
+                for (range_index, hash) in hashes.iter().enumerate() {
+                    if !mm.check_merkle_tree(alg, hash, range_index, &None) {
                         return Err(Error::HashMismatch("chunk hash did not match".to_string()));
                     }
                 }
@@ -1564,14 +1591,12 @@ impl BmffHash {
                 };
 
                 // check all the ranges in this for this mdat
-                for (range_index, range) in ranges.iter().enumerate() {
-                    // hash the entire fragment minus exclusions
-                    let hash = hash_stream_by_alg(alg, reader, Some(vec![range.clone()]), false)?;
-
+                let hashes = hash_batch(reader, factory, &leaf_jobs(alg, ranges))?;
+                for (range_index, hash) in hashes.iter().enumerate() {
                     // check MerkleMap for the hash
                     if !current_mm.check_merkle_tree(
                         alg,
-                        &hash,
+                        hash,
                         bmff_mm[range_index].location,
                         &bmff_mm[range_index].hashes,
                     ) {
@@ -1605,6 +1630,14 @@ impl AssertionBase for BmffHash {
     }
 }
 
+// one job per Merkle leaf, each hashing a single range
+fn leaf_jobs(alg: &str, ranges: &[HashRange]) -> Vec<HashJob> {
+    ranges
+        .iter()
+        .map(|range| HashJob::new(alg, Some(vec![range.clone()]), false))
+        .collect()
+}
+
 fn stsc_index(track: &Mp4Track, sample_id: u32) -> crate::Result<usize> {
     if track.trak.mdia.minf.stbl.stsc.entries.is_empty() {
         return Err(Error::InvalidAsset("BMFF has no stsc entries".to_string()));
@@ -19,9 +19,9 @@ use serde_bytes::ByteBuf;
 use crate::{
     assertion::{Assertion, AssertionBase, AssertionCbor, AssertionJson},
     assertions::labels,
-    asset_io::{AssetBoxHash, CAIRead},
+    asset_io::{AssetBoxHash, CAIRead, StreamFactory},
     error::{Error, Result},
-    utils::hash_utils::{hash_stream_by_alg, verify_stream_by_alg, HashRange},
+    utils::hash_utils::{hash_batch, hash_stream_by_alg, vec_compare, HashJob, HashRange},
     validation_results::validation_codes::ASSERTION_BOXHASH_UNKNOWN_BOX,
 };
 
@@ -63,7 +63,7 @@ impl BoxHash {
     ) -> Result<()> {
         let mut file = File::open(asset_path)?;
 
-        self.verify_stream_hash(&mut file, alg, bhp)
+        self.verify_hash_with_factory(&mut file, Some(&asset_path), alg, bhp)
     }
 
     pub fn verify_in_memory_hash(
@@ -74,7 +74,20 @@ impl BoxHash {
     ) -> Result<()> {
         let mut reader = Cursor::new(data);
 
-        self.verify_stream_hash(&mut reader, alg, bhp)
+        self.verify_hash_with_factory(&mut reader, Some(&data), alg, bhp)
+    }
+
+    /// Verify the hashes using readers from `factory`, which lets the box maps
+    /// be hashed in parallel when `core.parallel_hashing` is enabled.
+    pub fn verify_factory_hash(
+        &self,
+        factory: &dyn StreamFactory,
+        alg: Option<&str>,
+        bhp: &dyn AssetBoxHash,
+    ) -> Result<()> {
+        let mut reader = factory.open_stream()?;
+
+        self.verify_hash_with_factory(&mut reader, Some(factory), alg, bhp)
     }
 
     pub fn verify_stream_hash(
@@ -82,6 +95,16 @@ impl BoxHash {
         reader: &mut dyn CAIRead,
         alg: Option<&str>,
         bhp: &dyn AssetBoxHash,
+    ) -> Result<()> {
+        self.verify_hash_with_factory(reader, None, alg, bhp)
+    }
+
+    fn verify_hash_with_factory(
+        &self,
+        reader: &mut dyn CAIRead,
+        factory: Option<&dyn StreamFactory>,
+        alg: Option<&str>,
+        bhp: &dyn AssetBoxHash,
     ) -> Result<()> {
         // it is a failure if no hashes are listed
         if self.boxes.is_empty() {
@@ -101,6 +124,10 @@ impl BoxHash {
             return Err(Error::HashMismatch("No data boxes found".to_string()));
         }
 
+        // the box maps are hashed together once their ranges are known
+        let mut jobs = Vec::new();
+        let mut expected = Vec::new();
+
         for bm in &self.boxes {
             let mut inclusions = Vec::new();
 
@@ -109,36 +136,29 @@ impl BoxHash {
             let mut inclusion = HashRange::new(0u64, 0u64);
             for name in &bm.names {
                 match source_bms.get(source_index) {
-                    Some(next_source_bm) => {
-                        if name == &next_source_bm.names[0] {
-                            if inclusion.length() == 0 {
-                                // this is a new item
-                                inclusion.set_start(next_source_bm.range_start);
-                                inclusion.set_length(next_source_bm.range_len);
-
-                                if name == C2PA_BOXHASH {
-                                    // there should only be 1 collapsed C2PA range
-                                    if bm.names.len() != 1 {
-                                        return Err(Error::HashMismatch(
-                                            "Malformed C2PA box hash".to_owned(),
-                                        ));
-                                    }
-                                    skip_c2pa = true;
+                    Some(next_source_bm) if name == &next_source_bm.names[0] => {
+                        if inclusion.length() == 0 {
+                            // this is a new item
+                            inclusion.set_start(next_source_bm.range_start);
+                            inclusion.set_length(next_source_bm.range_len);
+
+                            if name == C2PA_BOXHASH {
+                                // there should only be 1 collapsed C2PA range
+                                if bm.names.len() != 1 {
+                                    return Err(Error::HashMismatch(
+                                        "Malformed C2PA box hash".to_owned(),
+                                    ));
                                 }
-                            } else {
-                                // count any unknown data between named segments
-                                let len_to_this_seg =
-                                    next_source_bm.range_start - inclusion.start();
-                                // update item
-                                inclusion.set_length(len_to_this_seg + next_source_bm.range_len);
+                                skip_c2pa = true;
                             }
                         } else {
-                            return Err(Error::HashMismatch(
-                                ASSERTION_BOXHASH_UNKNOWN_BOX.to_owned(),
-                            ));
+                            // count any unknown data between named segments
+                            let len_to_this_seg = next_source_bm.range_start - inclusion.start();
+                            // update item
+                            inclusion.set_length(len_to_this_seg + next_source_bm.range_len);
                         }
                     }
-                    None => {
+                    _ => {
                         return Err(Error::HashMismatch(
                             ASSERTION_BOXHASH_UNKNOWN_BOX.to_owned(),
                         ))
@@ -162,9 +182,22 @@ impl BoxHash {
                 },
             };
 
-            if !verify_stream_by_alg(&curr_alg, &bm.hash, reader, Some(inclusions), false) {
-                return Err(Error::HashMismatch("Hashes do not match".to_owned()));
-            }
+            jobs.push(HashJob::new(&curr_alg, Some(inclusions), false));
+            expected.push(&bm.hash);
+        }
+
+        let hashes = match hash_batch(reader, factory, &jobs) {
+            Ok(hashes) => hashes,
+            Err(Error::OperationCancelled) => return Err(Error::OperationCancelled),
+            Err(_) => return Err(Error::HashMismatch("Hashes do not match".to_owned())),
+        };
+
+        if hashes
+            .iter()
+            .zip(expected)
+            .any(|(hash, expected)| !vec_compare(hash, expected))
+        {
+            return Err(Error::HashMismatch("Hashes do not match".to_owned()));
         }
 
         Ok(())
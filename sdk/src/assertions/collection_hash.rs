@@ -0,0 +1,377 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use std::collections::HashMap;
+#[cfg(feature = "file_io")]
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+
+use crate::{
+    assertion::{Assertion, AssertionBase, AssertionCbor},
+    assertions::{labels, AssetType},
+    asset_io::CAIRead,
+    error::{Error, Result},
+    log_item,
+    status_tracker::StatusTracker,
+    utils::{
+        hash_utils::{hash_stream_by_alg, vec_compare},
+        io_utils::stream_len,
+        mime::format_from_path,
+    },
+    validation_results::validation_codes::{
+        ASSERTION_COLLECTIONHASH_INCORRECT_FILE_COUNT, ASSERTION_COLLECTIONHASH_INVALID_URI,
+        ASSERTION_COLLECTIONHASH_MALFORMED, ASSERTION_COLLECTIONHASH_MATCH,
+        ASSERTION_COLLECTIONHASH_MISMATCH,
+    },
+};
+
+const ASSERTION_CREATION_VERSION: usize = 1;
+
+/// A file in a collection, as listed in a [`CollectionHash`] assertion.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct UriHashedDataMap {
+    /// Location of the file relative to the collection, using `/` as separator.
+    pub uri: String,
+
+    /// Hash of the contents of the file.
+    pub hash: ByteBuf,
+
+    /// Size of the file in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// Media type of the file.
+    #[serde(rename = "dc:format", skip_serializing_if = "Option::is_none")]
+    pub dc_format: Option<String>,
+
+    /// Additional information about the type of data in the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_types: Option<Vec<AssetType>>,
+}
+
+/// Helper class to create a collection data hash assertion.
+///
+/// A collection hash binds a manifest to a set of files, such as an image
+/// sequence with its sidecars or the files of a web bundle, by listing the
+/// hash of each file. The manifest is kept in a standalone `.c2pa` file.
+///
+/// See <https://c2pa.org/specifications/specifications/2.1/specs/C2PA_Specification.html#_collection_data_hash>.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone)]
+pub struct CollectionHash {
+    /// The files in the collection.
+    pub uris: Vec<UriHashedDataMap>,
+
+    /// Hash algorithm used for the files, the claim's algorithm if missing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+
+    /// Hash of the central directory of a ZIP archive holding the collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip_central_directory_hash: Option<ByteBuf>,
+}
+
+impl CollectionHash {
+    pub const LABEL: &'static str = labels::COLLECTION_HASH;
+
+    /// Creates an empty collection whose files are hashed with `alg`.
+    pub fn new(alg: &str) -> Self {
+        CollectionHash {
+            uris: Vec::new(),
+            alg: Some(alg.to_owned()),
+            zip_central_directory_hash: None,
+        }
+    }
+
+    /// Hashes `stream` and adds it to the collection as `uri`.
+    ///
+    /// `uri` must be a relative path that doesn't contain `.` or `..`
+    /// segments and must not already be part of the collection.
+    pub fn add_uri(&mut self, uri: &str, stream: &mut dyn CAIRead) -> Result<&mut Self> {
+        if !Self::is_valid_uri(uri) {
+            return Err(Error::BadParam(format!("invalid collection uri: {uri}")));
+        }
+        if self.uris.iter().any(|u| u.uri == uri) {
+            return Err(Error::BadParam(format!("duplicate collection uri: {uri}")));
+        }
+
+        let alg = self.alg.as_deref().unwrap_or("sha256");
+        let size = stream_len(stream)?;
+        stream.rewind()?;
+        let hash = hash_stream_by_alg(alg, stream, None, true)?;
+
+        self.uris.push(UriHashedDataMap {
+            uri: uri.to_owned(),
+            hash: ByteBuf::from(hash),
+            size: Some(size),
+            dc_format: format_from_path(uri),
+            data_types: None,
+        });
+
+        Ok(self)
+    }
+
+    /// Returns true if `uri` is relative and has no empty, `.` or `..` segments.
+    pub fn is_valid_uri(uri: &str) -> bool {
+        !uri.contains(['\\', ':'])
+            && uri
+                .split('/')
+                .all(|segment| !segment.is_empty() && segment != "." && segment != "..")
+    }
+
+    /// Re-hashes every file of the collection, logging missing and altered
+    /// files to `validation_log`.
+    ///
+    /// `uri` is the location of this assertion and `alg` the claim's hash
+    /// algorithm.
+    pub(crate) fn verify_members(
+        &self,
+        members: &mut CollectionMembers<'_>,
+        alg: Option<&str>,
+        uri: &str,
+        validation_log: &mut StatusTracker,
+    ) -> Result<()> {
+        let alg = self
+            .alg
+            .as_deref()
+            .or(alg)
+            .ok_or(Error::HashMismatch("no algorithm found".to_string()))?;
+
+        if self.uris.is_empty() {
+            log_item!(
+                uri.to_owned(),
+                "collection hash lists no files",
+                "verify_members"
+            )
+            .validation_status(ASSERTION_COLLECTIONHASH_MALFORMED)
+            .failure(
+                validation_log,
+                Error::HashMismatch("collection hash lists no files".to_string()),
+            )?;
+            return Ok(());
+        }
+
+        let mut valid = true;
+        for member in &self.uris {
+            if !Self::is_valid_uri(&member.uri) {
+                valid = false;
+                log_item!(
+                    uri.to_owned(),
+                    format!("invalid collection uri: {}", member.uri),
+                    "verify_members"
+                )
+                .validation_status(ASSERTION_COLLECTIONHASH_INVALID_URI)
+                .failure(
+                    validation_log,
+                    Error::HashMismatch(format!("invalid collection uri: {}", member.uri)),
+                )?;
+                continue;
+            }
+
+            let Some(mut stream) = members.open_member(&member.uri)? else {
+                valid = false;
+                log_item!(
+                    uri.to_owned(),
+                    format!("file missing from collection: {}", member.uri),
+                    "verify_members"
+                )
+                .validation_status(ASSERTION_COLLECTIONHASH_INCORRECT_FILE_COUNT)
+                .failure(
+                    validation_log,
+                    Error::HashMismatch(format!("file missing from collection: {}", member.uri)),
+                )?;
+                continue;
+            };
+
+            let size = stream_len(&mut stream)?;
+            stream.rewind()?;
+            let size_matches = member.size.is_none() || member.size == Some(size);
+            if !size_matches
+                || !vec_compare(
+                    &hash_stream_by_alg(alg, &mut stream, None, true)?,
+                    &member.hash,
+                )
+            {
+                valid = false;
+                log_item!(
+                    uri.to_owned(),
+                    format!("collection file altered: {}", member.uri),
+                    "verify_members"
+                )
+                .validation_status(ASSERTION_COLLECTIONHASH_MISMATCH)
+                .failure(
+                    validation_log,
+                    Error::HashMismatch(format!("collection file altered: {}", member.uri)),
+                )?;
+            }
+        }
+
+        if valid {
+            log_item!(uri.to_owned(), "collection hash valid", "verify_members")
+                .validation_status(ASSERTION_COLLECTIONHASH_MATCH)
+                .success(validation_log);
+        }
+
+        Ok(())
+    }
+}
+
+impl AssertionCbor for CollectionHash {}
+
+impl AssertionBase for CollectionHash {
+    const LABEL: &'static str = Self::LABEL;
+    const VERSION: Option<usize> = Some(ASSERTION_CREATION_VERSION);
+
+    fn to_assertion(&self) -> Result<Assertion> {
+        Self::to_cbor_assertion(self)
+    }
+
+    fn from_assertion(assertion: &Assertion) -> Result<Self> {
+        Self::from_cbor_assertion(assertion)
+    }
+}
+
+/// The files of a collection, as provided when a [`CollectionHash`] is validated.
+pub enum CollectionMembers<'a> {
+    /// Streams keyed by their collection uri.
+    Streams(HashMap<String, &'a mut dyn CAIRead>),
+    /// Files stored below a folder.
+    #[cfg(feature = "file_io")]
+    Folder(&'a Path),
+}
+
+impl CollectionMembers<'_> {
+    /// Returns a reader over the file at `uri`, or `None` if it is missing.
+    fn open_member(&mut self, uri: &str) -> Result<Option<Box<dyn CAIRead + '_>>> {
+        match self {
+            CollectionMembers::Streams(streams) => Ok(streams
+                .get_mut(uri)
+                .map(|stream| Box::new(&mut **stream) as Box<dyn CAIRead>)),
+            #[cfg(feature = "file_io")]
+            CollectionMembers::Folder(base_path) => {
+                match std::fs::File::open(base_path.join(uri)) {
+                    Ok(file) => Ok(Some(Box::new(file))),
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::Cursor;
+
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::{status_tracker::ErrorBehavior, validation_results::validation_codes};
+
+    fn collection() -> CollectionHash {
+        let mut ch = CollectionHash::new("sha256");
+        ch.add_uri("frames/0001.jpg", &mut Cursor::new(b"frame one".to_vec()))
+            .unwrap()
+            .add_uri("frames/0002.jpg", &mut Cursor::new(b"frame two".to_vec()))
+            .unwrap();
+        ch
+    }
+
+    fn statuses(ch: &CollectionHash, members: HashMap<String, &mut dyn CAIRead>) -> Vec<String> {
+        let mut log = StatusTracker::with_error_behavior(ErrorBehavior::ContinueWhenPossible);
+        ch.verify_members(
+            &mut CollectionMembers::Streams(members),
+            None,
+            "self#jumbf=c2pa.assertions/c2pa.hash.collection.data",
+            &mut log,
+        )
+        .unwrap();
+        log.logged_items()
+            .iter()
+            .filter_map(|item| item.validation_status.as_ref().map(|s| s.to_string()))
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn test_add_uri() {
+        let ch = collection();
+        assert_eq!(ch.uris.len(), 2);
+        assert_eq!(ch.uris[0].size, Some(9));
+        assert_eq!(ch.uris[0].dc_format.as_deref(), Some("image/jpeg"));
+
+        let assertion = ch.to_assertion().unwrap();
+        assert_eq!(assertion.label(), CollectionHash::LABEL);
+        assert_eq!(CollectionHash::from_assertion(&assertion).unwrap(), ch);
+
+        let mut ch = collection();
+        let mut stream = Cursor::new(Vec::new());
+        assert!(ch.add_uri("frames/0001.jpg", &mut stream).is_err());
+        for uri in ["../secret", "/etc/passwd", "a/./b", "c:\\file", "a//b", ""] {
+            assert!(ch.add_uri(uri, &mut stream).is_err(), "{uri}");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn test_verify_members() {
+        let ch = collection();
+
+        let mut one = Cursor::new(b"frame one".to_vec());
+        let mut two = Cursor::new(b"frame two".to_vec());
+        let mut members: HashMap<String, &mut dyn CAIRead> = HashMap::new();
+        members.insert("frames/0001.jpg".to_owned(), &mut one);
+        members.insert("frames/0002.jpg".to_owned(), &mut two);
+        assert_eq!(
+            statuses(&ch, members),
+            vec![validation_codes::ASSERTION_COLLECTIONHASH_MATCH]
+        );
+
+        // an altered file and a missing one are both reported
+        let mut altered = Cursor::new(b"frame 1".to_vec());
+        let mut members: HashMap<String, &mut dyn CAIRead> = HashMap::new();
+        members.insert("frames/0001.jpg".to_owned(), &mut altered);
+        assert_eq!(
+            statuses(&ch, members),
+            vec![
+                validation_codes::ASSERTION_COLLECTIONHASH_MISMATCH,
+                validation_codes::ASSERTION_COLLECTIONHASH_INCORRECT_FILE_COUNT
+            ]
+        );
+
+        let mut ch = ch;
+        ch.uris[1].uri = "../frames/0002.jpg".to_owned();
+        assert_eq!(
+            statuses(&ch, HashMap::new()),
+            vec![
+                validation_codes::ASSERTION_COLLECTIONHASH_INCORRECT_FILE_COUNT,
+                validation_codes::ASSERTION_COLLECTIONHASH_INVALID_URI
+            ]
+        );
+
+        assert_eq!(
+            statuses(&CollectionHash::new("sha256"), HashMap::new()),
+            vec![validation_codes::ASSERTION_COLLECTIONHASH_MALFORMED]
+        );
+    }
+}
@@ -22,11 +22,12 @@ use serde_bytes::ByteBuf;
 use crate::{
     assertion::{Assertion, AssertionBase, AssertionCbor},
     assertions::labels,
-    asset_io::CAIRead,
+    asset_io::{CAIRead, StreamFactory},
     cbor_types::UriT,
     error::{Error, Result},
     utils::hash_utils::{
-        hash_stream_by_alg, verify_asset_by_alg, verify_by_alg, verify_stream_by_alg, HashRange,
+        hash_stream_by_alg_with_factory, verify_asset_by_alg, verify_by_alg,
+        verify_stream_by_alg_with_factory, HashRange,
     },
 };
 
@@ -116,6 +117,20 @@ impl DataHash {
         Ok(())
     }
 
+    /// generate the hash value for the Asset stream, reading its ranges on several
+    /// threads when `core.parallel_hashing` is enabled and `factory` is provided
+    pub(crate) fn gen_hash_from_stream_with_factory<R>(
+        &mut self,
+        stream: &mut R,
+        factory: Option<&dyn StreamFactory>,
+    ) -> Result<()>
+    where
+        R: Read + Seek + ?Sized,
+    {
+        self.hash = self.hash_from_stream_with_factory(stream, factory)?;
+        Ok(())
+    }
+
     // add padding to match size
     pub fn pad_to_size(&mut self, desired_size: usize) -> Result<()> {
         let mut curr_size = self.to_assertion()?.data().len();
@@ -162,6 +177,17 @@ impl DataHash {
     /// generate the asset hash from a stream using the constructed
     /// start and length values
     pub fn hash_from_stream<R>(&mut self, stream: &mut R) -> Result<Vec<u8>>
+    where
+        R: Read + Seek + ?Sized,
+    {
+        self.hash_from_stream_with_factory(stream, None)
+    }
+
+    fn hash_from_stream_with_factory<R>(
+        &mut self,
+        stream: &mut R,
+        factory: Option<&dyn StreamFactory>,
+    ) -> Result<Vec<u8>>
     where
         R: Read + Seek + ?Sized,
     {
@@ -178,8 +204,10 @@ impl DataHash {
 
         // sort the exclusions
         let hash = match self.exclusions {
-            Some(ref e) => hash_stream_by_alg(&alg, stream, Some(e.clone()), true)?,
-            None => hash_stream_by_alg(&alg, stream, None, true)?,
+            Some(ref e) => {
+                hash_stream_by_alg_with_factory(&alg, stream, factory, Some(e.clone()), true)?
+            }
+            None => hash_stream_by_alg_with_factory(&alg, stream, factory, None, true)?,
         };
 
         if hash.is_empty() {
@@ -229,7 +257,7 @@ impl DataHash {
 
         let exclusions = self.exclusions.as_ref().cloned();
 
-        if verify_asset_by_alg(&curr_alg, &self.hash, asset_path, exclusions) {
+        if verify_asset_by_alg(&curr_alg, &self.hash, asset_path, exclusions)? {
             Ok(())
         } else {
             Err(Error::HashMismatch("Hashes do not match".to_owned()))
@@ -238,6 +266,17 @@ impl DataHash {
 
     // verify data using currently set algorithm or default alg is none currently set
     pub fn verify_stream_hash(&self, reader: &mut dyn CAIRead, alg: Option<&str>) -> Result<()> {
+        self.verify_stream_hash_with_factory(reader, None, alg)
+    }
+
+    // verify data, reading its ranges on several threads when `core.parallel_hashing`
+    // is enabled and `factory` is provided
+    pub(crate) fn verify_stream_hash_with_factory(
+        &self,
+        reader: &mut dyn CAIRead,
+        factory: Option<&dyn StreamFactory>,
+        alg: Option<&str>,
+    ) -> Result<()> {
         if self.is_remote_hash() {
             return Err(Error::BadParam("asset hash is remote".to_owned()));
         }
@@ -252,7 +291,9 @@ impl DataHash {
 
         let exclusions = self.exclusions.as_ref().cloned();
 
-        if verify_stream_by_alg(&curr_alg, &self.hash, reader, exclusions, true) {
+        if verify_stream_by_alg_with_factory(
+            &curr_alg, &self.hash, reader, factory, exclusions, true,
+        )? {
             Ok(())
         } else {
             Err(Error::HashMismatch("Hashes do not match".to_owned()))
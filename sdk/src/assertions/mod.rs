@@ -31,6 +31,10 @@ pub use bmff_hash::{
 mod box_hash;
 pub use box_hash::{BoxHash, BoxMap, C2PA_BOXHASH};
 
+mod collection_hash;
+pub(crate) use collection_hash::CollectionMembers;
+pub use collection_hash::{CollectionHash, UriHashedDataMap};
+
 mod data_hash;
 pub use data_hash::DataHash;
 
@@ -1272,7 +1272,7 @@ fn c2pa_boxes_from_tree_and_map<R: Read + Seek + ?Sized>(
 
     // get position ordered list of boxes
     let mut box_infos: Vec<BoxInfoLite> = get_top_level_boxes(bmff_tree, bmff_map);
-    box_infos.sort_by(|a, b| a.offset.cmp(&b.offset));
+    box_infos.sort_by_key(|a| a.offset);
 
     Ok(C2PABmffBoxes {
         manifest_bytes,
@@ -1609,7 +1609,7 @@ impl CAIWriter for BmffIO {
         // write content before ContentProvenanceBox
         input_stream.rewind()?;
         let mut before_manifest = input_stream.take(start as u64);
-        std::io::copy(&mut before_manifest, output_stream)?;
+        crate::progress::copy(&mut before_manifest, output_stream)?;
 
         // write ContentProvenanceBox
         output_stream.write_all(&new_c2pa_box)?;
@@ -1634,9 +1634,9 @@ impl CAIWriter for BmffIO {
                 .ok_or(Error::BadParam("no update manifest".to_string()))?;
             let len_to_update = update_offset - end as u64;
             let mut truncating_reader = input_stream.take(len_to_update);
-            std::io::copy(&mut truncating_reader, output_stream)?;
+            crate::progress::copy(&mut truncating_reader, output_stream)?;
         } else {
-            std::io::copy(input_stream, output_stream)?;
+            crate::progress::copy(input_stream, output_stream)?;
         }
 
         // Manipulating the UUID box means we may need some patch offsets if they are file absolute offsets.
@@ -1728,7 +1728,7 @@ impl CAIWriter for BmffIO {
                 (uuid_info.offset, Some(uuid_info.size))
             } else {
                 input_stream.rewind()?;
-                std::io::copy(input_stream, output_stream)?;
+                crate::progress::copy(input_stream, output_stream)?;
                 return Ok(()); // no box to remove, propagate source to output
             };
 
@@ -1747,7 +1747,7 @@ impl CAIWriter for BmffIO {
         // write content before ContentProvenanceBox
         input_stream.rewind()?;
         let mut before_manifest = input_stream.take(start as u64);
-        std::io::copy(&mut before_manifest, output_stream)?;
+        crate::progress::copy(&mut before_manifest, output_stream)?;
 
         // calc offset adjustments
         // value will be negative since the box is truncated
@@ -1757,7 +1757,7 @@ impl CAIWriter for BmffIO {
 
         // write content after ContentProvenanceBox
         input_stream.seek(SeekFrom::Start(end as u64))?;
-        std::io::copy(input_stream, output_stream)?;
+        crate::progress::copy(input_stream, output_stream)?;
 
         // Manipulating the UUID box means we may need some patch offsets if they are file absolute offsets.
 
@@ -1987,7 +1987,7 @@ impl RemoteRefEmbed for BmffIO {
                 // write content before XMP box
                 input_stream.rewind()?;
                 let mut before_manifest = input_stream.take(start as u64);
-                std::io::copy(&mut before_manifest, output_stream)?;
+                crate::progress::copy(&mut before_manifest, output_stream)?;
 
                 // write ContentProvenanceBox
                 output_stream.write_all(&new_xmp_box)?;
@@ -2004,7 +2004,7 @@ impl RemoteRefEmbed for BmffIO {
 
                 // write content after XMP box
                 input_stream.seek(SeekFrom::Start(end as u64))?;
-                std::io::copy(input_stream, output_stream)?;
+                crate::progress::copy(input_stream, output_stream)?;
 
                 // Manipulating the UUID box means we may need some patch offsets if they are file absolute offsets.
 
@@ -13,7 +13,7 @@
 
 use std::{
     fs::{self, File},
-    io::{self, Cursor, Read, SeekFrom},
+    io::{Cursor, Read, SeekFrom},
     path::Path,
     str,
 };
@@ -151,7 +151,7 @@ impl CAIWriter for GifIO {
             }
             None => {
                 input_stream.rewind()?;
-                io::copy(input_stream, output_stream)?;
+                crate::progress::copy(input_stream, output_stream)?;
                 Ok(())
             }
         }
@@ -454,11 +454,11 @@ impl GifIO {
         output_stream.rewind()?;
 
         let mut start_stream = input_stream.take(block_meta.start());
-        io::copy(&mut start_stream, output_stream)?;
+        crate::progress::copy(&mut start_stream, output_stream)?;
 
         let input_stream = start_stream.into_inner();
         input_stream.seek(SeekFrom::Current(i64::try_from(block_meta.len())?))?;
-        io::copy(input_stream, output_stream)?;
+        crate::progress::copy(input_stream, output_stream)?;
 
         Ok(())
     }
@@ -475,14 +475,14 @@ impl GifIO {
 
         // Write everything before the replacement block.
         let mut start_stream = input_stream.take(old_block_marker.start());
-        io::copy(&mut start_stream, output_stream)?;
+        crate::progress::copy(&mut start_stream, output_stream)?;
 
         output_stream.write_all(&new_block.to_bytes()?)?;
 
         // Write everything after the replacement block.
         let input_stream = start_stream.into_inner();
         input_stream.seek(SeekFrom::Current(i64::try_from(old_block_marker.len())?))?;
-        io::copy(input_stream, output_stream)?;
+        crate::progress::copy(input_stream, output_stream)?;
 
         Ok(())
     }
@@ -521,12 +521,12 @@ impl GifIO {
         output_stream.rewind()?;
 
         let mut start_stream = input_stream.take(end_preamble_pos);
-        io::copy(&mut start_stream, output_stream)?;
+        crate::progress::copy(&mut start_stream, output_stream)?;
 
         output_stream.write_all(&block.to_bytes()?)?;
 
         let input_stream = start_stream.into_inner();
-        io::copy(input_stream, output_stream)?;
+        crate::progress::copy(input_stream, output_stream)?;
 
         self.update_to_89a(output_stream)
     }
@@ -1141,7 +1141,8 @@ fn gif_chunks(mut encoded_bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
-    use io::{Cursor, Seek};
+    use std::io::{Cursor, Seek};
+
     use xmp_inmemory_utils::extract_provenance;
 
     use super::*;
@@ -101,7 +101,7 @@ fn add_required_segs_to_stream(
             // just clone
             input_stream.rewind()?;
             output_stream.rewind()?;
-            std::io::copy(input_stream, output_stream)?;
+            crate::progress::copy(input_stream, output_stream)?;
         }
     } else {
         return Err(Error::UnsupportedType);
@@ -579,7 +579,7 @@ impl RemoteRefEmbed for JpegIO {
                     .open(asset_path)
                     .map_err(Error::IoError)?;
                 temp.set_position(0);
-                std::io::copy(&mut temp, &mut output).map_err(Error::IoError)?;
+                crate::progress::copy(&mut temp, &mut output)?;
                 Ok(())
             }
             crate::asset_io::RemoteRefEmbedType::StegoS(_) => Err(Error::UnsupportedType),
@@ -249,7 +249,7 @@ impl RemoteRefEmbed for Mp3IO {
                     .map_err(|_e| Error::EmbeddingError)?;
 
                 source_stream.seek(SeekFrom::Start(header.map_or(0, |h| h.get_size()) as u64))?;
-                std::io::copy(source_stream, output_stream)?;
+                crate::progress::copy(source_stream, output_stream)?;
 
                 Ok(())
             }
@@ -272,7 +272,7 @@ fn add_required_frame(
             // just clone
             input_stream.rewind()?;
             output_stream.rewind()?;
-            std::io::copy(input_stream, output_stream)?;
+            crate::progress::copy(input_stream, output_stream)?;
             Ok(())
         }
         Err(_) => {
@@ -412,7 +412,7 @@ impl CAIWriter for Mp3IO {
         input_stream.seek(SeekFrom::Start(header.map_or(0, |h| h.get_size()) as u64))?;
 
         // copy source data to output
-        std::io::copy(input_stream, output_stream)?;
+        crate::progress::copy(input_stream, output_stream)?;
 
         Ok(())
     }
@@ -167,7 +167,7 @@ fn add_required_chunks_to_stream(
             // just clone
             input_stream.rewind()?;
             output_stream.rewind()?;
-            std::io::copy(input_stream, output_stream)?;
+            crate::progress::copy(input_stream, output_stream)?;
         }
     } else {
         return Err(Error::UnsupportedType);
@@ -321,7 +321,7 @@ fn add_required_chunks(
             // just clone
             input_stream.rewind()?;
             output_stream.rewind()?;
-            std::io::copy(input_stream, output_stream)?;
+            crate::progress::copy(input_stream, output_stream)?;
             Ok(())
         }
         Err(_) => {
@@ -1277,7 +1277,7 @@ fn add_required_tags_to_stream(
             // just clone
             input_stream.rewind()?;
             output_stream.rewind()?;
-            std::io::copy(input_stream, output_stream)?;
+            crate::progress::copy(input_stream, output_stream)?;
             Ok(())
         }
         Err(Error::JumbfNotFound) => {
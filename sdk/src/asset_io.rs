@@ -40,8 +40,10 @@ pub struct HashObjectPositions {
     pub htype: HashBlockObjectType, // type of hash block object
 }
 
+/// A seekable stream of asset data, implemented for any `Read + Seek` type.
 #[cfg(not(target_arch = "wasm32"))]
 pub trait CAIRead: Read + Seek + Send {}
+/// A seekable stream of asset data, implemented for any `Read + Seek` type.
 #[cfg(target_arch = "wasm32")]
 pub trait CAIRead: Read + Seek {}
 
@@ -50,6 +52,59 @@ impl<T> CAIRead for T where T: Read + Seek + Send {}
 #[cfg(target_arch = "wasm32")]
 impl<T> CAIRead for T where T: Read + Seek {}
 
+/// A source that can open any number of independent readers over the same asset.
+///
+/// Seekable sources such as files or memory maps can be read from several
+/// threads at once, which lets independent hashes over the asset be computed
+/// in parallel when the `core.parallel_hashing` setting is enabled.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+///
+/// use c2pa::{CAIRead, Reader, StreamFactory};
+///
+/// struct Asset(Vec<u8>);
+///
+/// impl StreamFactory for Asset {
+///     fn open_stream(&self) -> c2pa::Result<Box<dyn CAIRead + '_>> {
+///         Ok(Box::new(Cursor::new(self.0.as_slice())))
+///     }
+/// }
+///
+/// let asset = Asset(include_bytes!("../tests/fixtures/CA.jpg").to_vec());
+/// let reader = Reader::from_stream_factory("image/jpeg", &asset).unwrap();
+/// assert!(reader.active_manifest().is_some());
+/// ```
+pub trait StreamFactory: Sync {
+    /// Open a new reader positioned at the start of the asset.
+    fn open_stream(&self) -> Result<Box<dyn CAIRead + '_>>;
+}
+
+impl StreamFactory for &[u8] {
+    fn open_stream(&self) -> Result<Box<dyn CAIRead + '_>> {
+        Ok(Box::new(Cursor::new(*self)))
+    }
+}
+
+impl StreamFactory for Vec<u8> {
+    fn open_stream(&self) -> Result<Box<dyn CAIRead + '_>> {
+        Ok(Box::new(Cursor::new(self.as_slice())))
+    }
+}
+
+impl StreamFactory for &Path {
+    fn open_stream(&self) -> Result<Box<dyn CAIRead + '_>> {
+        Ok(Box::new(std::fs::File::open(self)?))
+    }
+}
+
+impl StreamFactory for std::path::PathBuf {
+    fn open_stream(&self) -> Result<Box<dyn CAIRead + '_>> {
+        Ok(Box::new(std::fs::File::open(self)?))
+    }
+}
+
 impl From<String> for Box<dyn CAIRead> {
     fn from(val: String) -> Self {
         Box::new(Cursor::new(val))
@@ -32,9 +32,9 @@ use crate::{
     assertions::{
         c2pa_action,
         labels::{self, METADATA_LABEL_REGEX},
-        Action, ActionTemplate, Actions, AssertionMetadata, BmffHash, BoxHash, CreativeWork,
-        DataHash, DigitalSourceType, EmbeddedData, Exif, Metadata, SoftwareAgent, Thumbnail, User,
-        UserCbor,
+        Action, ActionTemplate, Actions, AssertionMetadata, BmffHash, BoxHash, CollectionHash,
+        CreativeWork, DataHash, DigitalSourceType, EmbeddedData, Exif, Metadata, SoftwareAgent,
+        Thumbnail, User, UserCbor,
     },
     cbor_types::value_cbor_to_type,
     claim::Claim,
@@ -45,6 +45,7 @@ use crate::{
     settings::{
         self,
         builder::{ActionSettings, ActionTemplateSettings, ClaimGeneratorInfoSettings},
+        cawg_x509_signer::{AsyncCawgX509Signer, CawgX509Signer, CawgX509SignerSettings},
     },
     store::Store,
     utils::mime::format_to_mime,
@@ -560,6 +561,67 @@ impl Builder {
         Ok(self)
     }
 
+    /// Redacts an assertion from an ingredient's manifest.
+    ///
+    /// The assertion is listed in the new claim's redactions and a `c2pa.redacted`
+    /// action recording the reason is added, so the two always agree.
+    /// The ingredient whose manifest has the given label must be added to the builder
+    /// before signing.
+    ///
+    /// # Arguments
+    /// * `ingredient_manifest_label` - The label of the ingredient's manifest.
+    /// * `assertion_label` - The label of the assertion to redact, including any instance suffix.
+    /// * `reason` - Why the assertion is being redacted, such as `c2pa.PII.present`.
+    /// # Returns
+    /// * A mutable reference to the [`Builder`].
+    /// # Errors
+    /// * Returns [`Error::AssertionInvalidRedaction`] if the assertion is an actions
+    ///   assertion or a hard binding, which the C2PA specification does not allow to be redacted.
+    /// # Example
+    /// ```rust
+    /// use c2pa::Builder;
+    ///
+    /// let mut builder = Builder::edit();
+    /// builder
+    ///     .redact(
+    ///         "urn:c2pa:3fad1ead-8ed5-44d0-873b-ea5f58adea82",
+    ///         "stds.schema-org.CreativeWork",
+    ///         "c2pa.PII.present",
+    ///     )
+    ///     .unwrap();
+    /// assert!(builder
+    ///     .redact(
+    ///         "urn:c2pa:3fad1ead-8ed5-44d0-873b-ea5f58adea82",
+    ///         "c2pa.hash.data",
+    ///         ""
+    ///     )
+    ///     .is_err());
+    /// ```
+    pub fn redact(
+        &mut self,
+        ingredient_manifest_label: &str,
+        assertion_label: &str,
+        reason: &str,
+    ) -> Result<&mut Self> {
+        if !Claim::is_redactable(assertion_label) {
+            return Err(Error::AssertionInvalidRedaction);
+        }
+
+        let redacted_uri =
+            crate::jumbf::labels::to_assertion_uri(ingredient_manifest_label, assertion_label);
+
+        let redactions = self.definition.redactions.get_or_insert_with(Vec::new);
+        if redactions.contains(&redacted_uri) {
+            return Ok(self);
+        }
+        redactions.push(redacted_uri.clone());
+
+        let action = Action::new(c2pa_action::REDACTED)
+            .set_reason(reason)
+            .set_parameter("redacted", redacted_uri)?;
+        self.add_action(action)
+    }
+
     /// Adds an [`Ingredient`] to the manifest with JSON and a stream.
     // TODO: Add example.
     ///
@@ -1379,6 +1441,115 @@ impl Builder {
         Store::get_composed_manifest(&bytes, format)
     }
 
+    /// Create a signed manifest for a collection of files using a supplied signer.
+    ///
+    /// The manifest is not embedded in any of the files. Instead a collection hash
+    /// assertion lists the uri, size and hash of each file, and the returned manifest
+    /// is typically saved as a standalone `.c2pa` file alongside the collection.
+    ///
+    /// # Arguments
+    /// * `signer` - The signer to use.
+    /// * `members` - The uri and stream of each file in the collection.
+    ///   Uris are paths relative to the collection using `/` as separator.
+    /// # Returns
+    /// * The bytes of the c2pa_manifest that was created.
+    /// # Errors
+    /// * Returns an [`Error`] if a uri is invalid or the manifest cannot be signed.
+    #[async_generic(async_signature(
+        &mut self,
+        signer: &dyn AsyncSigner,
+        members: I,
+    ))]
+    pub fn sign_collection<'a, I, R>(&mut self, signer: &dyn Signer, members: I) -> Result<Vec<u8>>
+    where
+        I: IntoIterator<Item = (&'a str, &'a mut R)>,
+        R: Read + Seek + Send + 'a,
+    {
+        self.definition.instance_id = format!("xmp:iid:{}", Uuid::new_v4());
+
+        let mut claim = self.to_claim()?;
+
+        let mut collection_hash = CollectionHash::new(claim.alg());
+        for (uri, stream) in members {
+            collection_hash.add_uri(uri, stream)?;
+        }
+        if collection_hash.uris.is_empty() {
+            return Err(Error::BadParam(
+                "Collection must contain at least one file".to_string(),
+            ));
+        }
+        claim.add_assertion(&collection_hash)?;
+
+        let mut store = Store::new();
+        store.commit_claim(claim)?;
+
+        if _sync {
+            store.get_collection_hashed_manifest(signer)
+        } else {
+            store.get_collection_hashed_manifest_async(signer).await
+        }
+    }
+
+    /// Sign a collection of files into a standalone manifest file.
+    ///
+    /// # Arguments
+    /// * `signer` - The signer to use.
+    /// * `base_path` - The folder holding the collection, uris are relative to it.
+    /// * `files` - The paths of the files in the collection.
+    /// * `dest` - The path of the `.c2pa` file to write (must not already exist).
+    /// # Returns
+    /// * The bytes of the c2pa_manifest that was created.
+    /// # Errors
+    /// * Returns an [`Error`] if a file is not below `base_path`, the manifest
+    ///   cannot be signed or the destination file already exists.
+    #[cfg(feature = "file_io")]
+    pub fn sign_collection_files<B, P, D>(
+        &mut self,
+        signer: &dyn Signer,
+        base_path: B,
+        files: &[P],
+        dest: D,
+    ) -> Result<Vec<u8>>
+    where
+        B: AsRef<Path>,
+        P: AsRef<Path>,
+        D: AsRef<Path>,
+    {
+        let dest = dest.as_ref();
+        if dest.exists() {
+            return Err(crate::Error::BadParam(
+                "Destination file already exists".to_string(),
+            ));
+        }
+
+        let mut members = Vec::with_capacity(files.len());
+        for path in files {
+            let path = path.as_ref();
+            let uri = path
+                .strip_prefix(base_path.as_ref())
+                .map_err(|_| {
+                    crate::Error::BadParam(format!("{} is not below the base path", path.display()))
+                })?
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            members.push((uri, std::fs::File::open(path)?));
+        }
+
+        let manifest_bytes = self.sign_collection(
+            signer,
+            members.iter_mut().map(|(uri, file)| (uri.as_str(), file)),
+        )?;
+
+        if let Some(output_dir) = dest.parent() {
+            std::fs::create_dir_all(output_dir)?;
+        }
+        std::fs::write(dest, &manifest_bytes)?;
+
+        Ok(manifest_bytes)
+    }
+
     /// Embed a signed manifest into a stream using a supplied signer.
     ///
     /// # Arguments
@@ -1427,13 +1598,33 @@ impl Builder {
         // convert the manifest to a store
         let mut store = self.to_store()?;
 
-        // sign and write our store to to the output image file
+        // sign and write our store to to the output image file, adding the
+        // identity assertion configured in the settings, if any
         if _sync {
-            store.save_to_stream(&format, source, dest, signer)
+            match CawgX509SignerSettings::identity_assertion()? {
+                Some(iab) => {
+                    store.save_to_stream(&format, source, dest, &CawgX509Signer::new(signer, iab))
+                }
+                None => store.save_to_stream(&format, source, dest, signer),
+            }
         } else {
-            store
-                .save_to_stream_async(&format, source, dest, signer)
-                .await
+            match CawgX509SignerSettings::async_identity_assertion()? {
+                Some(iab) => {
+                    store
+                        .save_to_stream_async(
+                            &format,
+                            source,
+                            dest,
+                            &AsyncCawgX509Signer::new(signer, iab),
+                        )
+                        .await
+                }
+                None => {
+                    store
+                        .save_to_stream_async(&format, source, dest, signer)
+                        .await
+                }
+            }
         }
     }
 
@@ -1607,9 +1798,12 @@ mod tests {
         crypto::raw_signature::SigningAlg,
         hash_stream_by_alg,
         settings::Settings,
-        utils::{test::write_jpeg_placeholder_stream, test_signer::test_signer},
+        utils::{
+            test::write_jpeg_placeholder_stream,
+            test_signer::{cert_chain_and_private_key_for_alg, test_signer},
+        },
         validation_results::ValidationState,
-        HashedUri, Reader,
+        validation_status, HashedUri, Reader,
     };
 
     #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
@@ -1859,6 +2053,359 @@ mod tests {
         assert_eq!(test_assertion.answer, 42);
     }
 
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn test_builder_sign_parallel_hashing() {
+        Settings::from_toml(
+            &toml::toml! {
+                [core]
+                parallel_hashing = true
+                max_hash_workers = 4
+            }
+            .to_string(),
+        )
+        .unwrap();
+
+        let format = "image/jpeg";
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+        let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+        builder
+            .sign(
+                &test_signer(SigningAlg::Ps256),
+                format,
+                &mut source,
+                &mut dest,
+            )
+            .unwrap();
+
+        let signed = dest.into_inner();
+        let reader = Reader::from_stream_factory(format, &signed.as_slice()).unwrap();
+        crate::settings::reset_default_settings().unwrap();
+
+        assert_eq!(reader.validation_state(), ValidationState::Trusted);
+        assert!(reader
+            .validation_results()
+            .unwrap()
+            .active_manifest()
+            .unwrap()
+            .success()
+            .iter()
+            .any(|s| s.code() == validation_status::ASSERTION_DATAHASH_MATCH));
+    }
+
+    #[test]
+    fn test_builder_sign_completes_cert_chain() {
+        // reserves room for its own certificate only, without the usual
+        // time stamp slack that would hide a missing intermediate
+        struct TightSigner(Box<dyn Signer>);
+
+        impl Signer for TightSigner {
+            fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+                self.0.sign(data)
+            }
+
+            fn alg(&self) -> SigningAlg {
+                self.0.alg()
+            }
+
+            fn certs(&self) -> Result<Vec<Vec<u8>>> {
+                self.0.certs()
+            }
+
+            fn reserve_size(&self) -> usize {
+                1024 + self.0.certs().unwrap().iter().map(Vec::len).sum::<usize>()
+            }
+        }
+
+        let chain = include_bytes!("../tests/fixtures/certs/ps256.pub");
+        let end_entity = x509_parser::pem::Pem::iter_from_buffer(chain)
+            .next()
+            .unwrap()
+            .unwrap();
+        let end_entity_pem = pem::encode(&pem::Pem::new("CERTIFICATE", end_entity.contents));
+
+        let signer = TightSigner(
+            crate::create_signer::from_keys(
+                end_entity_pem.as_bytes(),
+                include_bytes!("../tests/fixtures/certs/ps256.pem"),
+                SigningAlg::Ps256,
+                None,
+            )
+            .unwrap(),
+        );
+
+        Settings::from_toml(
+            &toml::toml! {
+                [trust]
+                intermediates = (String::from_utf8_lossy(chain).into_owned())
+                [builder]
+                certificate_chain_completion = "local"
+            }
+            .to_string(),
+        )
+        .unwrap();
+
+        // the intermediate is larger than the slack in the signer's reserve
+        // size, so the store has to make room for it
+        let format = "image/jpeg";
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+        let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+        builder
+            .sign(&signer, format, &mut source, &mut dest)
+            .unwrap();
+
+        dest.rewind().unwrap();
+        let reader = Reader::from_stream(format, &mut dest).unwrap();
+        let signature_info = reader.active_manifest().unwrap().signature_info().unwrap();
+        assert_eq!(
+            signature_info
+                .cert_chain()
+                .matches("BEGIN CERTIFICATE")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_builder_sign_uncompressed_by_default() {
+        #[cfg(target_os = "wasi")]
+        Settings::reset().unwrap();
+
+        let format = "image/jpeg";
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+
+        let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+        builder
+            .add_ingredient_from_stream(parent_json(), format, &mut source)
+            .unwrap();
+
+        let signer = test_signer(SigningAlg::Ps256);
+        let manifest_data = builder
+            .sign(signer.as_ref(), format, &mut source, &mut dest)
+            .unwrap();
+
+        // neither the ingredient's manifest nor the active one is compressed
+        assert!(!manifest_data.windows(4).any(|w| w == b"brob"));
+
+        dest.rewind().unwrap();
+        let reader = Reader::from_stream(format, &mut dest).unwrap();
+        assert_ne!(reader.validation_state(), ValidationState::Invalid);
+        assert_eq!(reader.manifests().len(), 2);
+    }
+
+    #[test]
+    fn test_builder_sign_compressed_manifests() {
+        let mut results = Vec::new();
+        for compress in [true, false] {
+            Settings::from_toml(
+                &toml::toml! {
+                    [core]
+                    compress_manifests = compress
+                }
+                .to_string(),
+            )
+            .unwrap();
+
+            let format = "image/jpeg";
+            let mut source = Cursor::new(TEST_IMAGE);
+            let mut dest = Cursor::new(Vec::new());
+
+            let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+            builder
+                .add_ingredient_from_stream(parent_json(), format, &mut source)
+                .unwrap();
+
+            let signer = test_signer(SigningAlg::Ps256);
+            let manifest_data = builder
+                .sign(signer.as_ref(), format, &mut source, &mut dest)
+                .unwrap();
+
+            // the ingredient's manifest is compressed whole, the active one
+            // only assertion by assertion so its signature can be filled in
+            assert_eq!(manifest_data.windows(4).any(|w| w == b"brob"), compress);
+
+            dest.rewind().unwrap();
+            let reader = Reader::from_stream(format, &mut dest).unwrap();
+            assert_ne!(reader.validation_state(), ValidationState::Invalid);
+            assert_eq!(reader.manifests().len(), 2);
+
+            let ingredient = &reader.active_manifest().unwrap().ingredients()[0];
+            assert!(ingredient.active_manifest().is_some());
+            let label = reader.active_label().unwrap();
+            let statuses = serde_json::to_string(&reader.validation_results()).unwrap();
+            results.push(statuses.replace(label, "active"));
+        }
+
+        // compression must not change how the manifests validate
+        assert_eq!(results[0], results[1]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn test_builder_sign_compresses_active_assertions() {
+        // an uncompressed bitmap thumbnail dominates the size of the manifest
+        let thumbnail: Vec<u8> = (0..256 * 256 * 3).map(|i| (i / 768) as u8).collect();
+
+        let mut sizes = Vec::new();
+        for compress in [true, false] {
+            Settings::from_toml(
+                &toml::toml! {
+                    [core]
+                    compress_manifests = compress
+                }
+                .to_string(),
+            )
+            .unwrap();
+
+            let format = "image/jpeg";
+            let mut source = Cursor::new(TEST_IMAGE);
+            let mut dest = Cursor::new(Vec::new());
+
+            let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+            builder
+                .set_thumbnail("image/bmp", &mut Cursor::new(thumbnail.clone()))
+                .unwrap();
+
+            let signer = test_signer(SigningAlg::Ps256);
+            let manifest_data = builder
+                .sign(signer.as_ref(), format, &mut source, &mut dest)
+                .unwrap();
+            sizes.push(manifest_data.len());
+
+            dest.rewind().unwrap();
+            let reader = Reader::from_stream(format, &mut dest).unwrap();
+            assert_ne!(reader.validation_state(), ValidationState::Invalid);
+
+            let (thumbnail_format, thumbnail_data) =
+                reader.active_manifest().unwrap().thumbnail().unwrap();
+            assert_eq!(thumbnail_format, "image/bmp");
+            assert_eq!(thumbnail_data.as_slice(), thumbnail.as_slice());
+        }
+
+        assert!(sizes[0] < thumbnail.len() / 10);
+        assert!(sizes[1] > thumbnail.len());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn test_builder_sign_collection() {
+        let mut frame1 = Cursor::new(TEST_IMAGE);
+        let mut frame2 = Cursor::new(b"second frame".as_slice());
+
+        let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+        let signer = test_signer(SigningAlg::Ps256);
+        let manifest_data = builder
+            .sign_collection(
+                signer.as_ref(),
+                [("frames/1.jpg", &mut frame1), ("frames/2.bin", &mut frame2)],
+            )
+            .unwrap();
+
+        let failures = |frame2: &[u8]| {
+            let mut frame1 = Cursor::new(TEST_IMAGE);
+            let mut frame2 = Cursor::new(frame2);
+            let members = [("frames/1.jpg", &mut frame1), ("frames/2.bin", &mut frame2)];
+
+            let reader = Reader::from_collection(&manifest_data, members).unwrap();
+            let collection_hash: CollectionHash = reader
+                .active_manifest()
+                .unwrap()
+                .find_assertion(CollectionHash::LABEL)
+                .unwrap();
+            assert_eq!(collection_hash.uris.len(), 2);
+            assert_eq!(collection_hash.uris[1].size, Some(12));
+
+            let codes = reader
+                .validation_results()
+                .unwrap()
+                .active_manifest()
+                .unwrap();
+            codes
+                .failure()
+                .iter()
+                .map(|s| s.code().to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(failures(b"second frame").is_empty());
+        assert_eq!(
+            failures(b"altered frame"),
+            vec![validation_status::ASSERTION_COLLECTIONHASH_MISMATCH]
+        );
+
+        // a file missing from the collection is reported
+        let mut frame1 = Cursor::new(TEST_IMAGE);
+        let reader =
+            Reader::from_collection(&manifest_data, [("frames/1.jpg", &mut frame1)]).unwrap();
+        assert_eq!(reader.validation_state(), ValidationState::Invalid);
+        let codes = reader
+            .validation_results()
+            .unwrap()
+            .active_manifest()
+            .unwrap();
+        assert_eq!(
+            codes.failure()[0].code(),
+            validation_status::ASSERTION_COLLECTIONHASH_INCORRECT_FILE_COUNT
+        );
+
+        assert!(builder
+            .sign_collection(signer.as_ref(), [("../escape.jpg", &mut frame1)])
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "file_io")]
+    fn test_builder_sign_collection_files() {
+        use crate::utils::io_utils::tempdirectory;
+
+        let dir = tempdirectory().unwrap();
+        let base_path = dir.path().join("collection");
+        std::fs::create_dir_all(base_path.join("frames")).unwrap();
+        let files = [base_path.join("frames/1.jpg"), base_path.join("audio.bin")];
+        std::fs::write(&files[0], TEST_IMAGE).unwrap();
+        std::fs::write(&files[1], b"audio").unwrap();
+
+        let dest = dir.path().join("collection.c2pa");
+        let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+        let signer = test_signer(SigningAlg::Ps256);
+        let manifest_data = builder
+            .sign_collection_files(signer.as_ref(), &base_path, &files, &dest)
+            .unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), manifest_data);
+
+        let reader = Reader::from_collection_folder(&manifest_data, &base_path).unwrap();
+        assert_ne!(reader.validation_state(), ValidationState::Invalid);
+        let collection_hash: CollectionHash = reader
+            .active_manifest()
+            .unwrap()
+            .find_assertion(CollectionHash::LABEL)
+            .unwrap();
+        let uris: Vec<_> = collection_hash
+            .uris
+            .iter()
+            .map(|u| u.uri.as_str())
+            .collect();
+        assert_eq!(uris, ["frames/1.jpg", "audio.bin"]);
+
+        std::fs::remove_file(&files[1]).unwrap();
+        let reader = Reader::from_collection_folder(&manifest_data, &base_path).unwrap();
+        assert_eq!(reader.validation_state(), ValidationState::Invalid);
+
+        // the destination is never overwritten
+        assert!(builder
+            .sign_collection_files(signer.as_ref(), &base_path, &files[..1], &dest)
+            .is_err());
+    }
+
     #[test]
     fn test_builder_settings_auto_created() {
         #[cfg(target_os = "wasi")]
@@ -2382,6 +2929,90 @@ mod tests {
         assert_eq!(reader.validation_status(), None);
     }
 
+    #[test]
+    fn test_builder_sign_ml_dsa() {
+        const CLOUD_IMAGE: &[u8] = include_bytes!("../tests/fixtures/cloud.jpg");
+
+        for alg in [
+            SigningAlg::MlDsa44,
+            SigningAlg::MlDsa65,
+            SigningAlg::MlDsa87,
+        ] {
+            let mut source = Cursor::new(CLOUD_IMAGE);
+            let mut dest = Cursor::new(Vec::new());
+
+            let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+
+            let signer = test_signer(alg);
+            builder
+                .sign(signer.as_ref(), "image/jpeg", &mut source, &mut dest)
+                .unwrap();
+
+            dest.set_position(0);
+            let reader = Reader::from_stream("image/jpeg", &mut dest).unwrap();
+
+            assert_eq!(reader.validation_status(), None);
+            assert_eq!(
+                reader
+                    .active_manifest()
+                    .unwrap()
+                    .signature_info()
+                    .unwrap()
+                    .alg,
+                Some(alg)
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder_sign_local_tsa() {
+        const CLOUD_IMAGE: &[u8] = include_bytes!("../tests/fixtures/cloud.jpg");
+
+        crate::settings::set_settings_value("verify.verify_timestamp_trust", true).unwrap();
+
+        let (sign_cert, private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Ps256);
+        let (tsa_cert, tsa_key) = cert_chain_and_private_key_for_alg(SigningAlg::Es256);
+        let signer = crate::create_signer::from_keys_with_local_tsa(
+            sign_cert,
+            private_key,
+            SigningAlg::Ps256,
+            tsa_cert,
+            tsa_key,
+            SigningAlg::Es256,
+        )
+        .unwrap();
+
+        let mut source = Cursor::new(CLOUD_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+
+        let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+        builder
+            .sign(signer.as_ref(), "image/jpeg", &mut source, &mut dest)
+            .unwrap();
+
+        dest.set_position(0);
+        let reader = Reader::from_stream("image/jpeg", &mut dest).unwrap();
+
+        assert_eq!(reader.validation_status(), None);
+        assert!(reader
+            .active_manifest()
+            .unwrap()
+            .signature_info()
+            .unwrap()
+            .time
+            .is_some());
+
+        let success = &reader
+            .validation_results()
+            .unwrap()
+            .active_manifest()
+            .unwrap()
+            .success;
+        assert!(success
+            .iter()
+            .any(|s| s.code() == crate::validation_status::TIMESTAMP_TRUSTED));
+    }
+
     #[test]
     fn test_builder_data_hashed_embeddable() {
         const CLOUD_IMAGE: &[u8] = include_bytes!("../tests/fixtures/cloud.jpg");
@@ -2892,6 +3523,140 @@ mod tests {
         assert_eq!(parent.assertions().len(), 1);
     }
 
+    #[test]
+    fn test_redact() {
+        Settings::from_toml(include_str!("../tests/fixtures/test_settings.toml")).unwrap();
+
+        const ASSERTION_LABEL: &str = "stds.schema-org.CreativeWork";
+
+        let mut input = Cursor::new(TEST_IMAGE);
+
+        let parent = Reader::from_stream("image/jpeg", &mut input).expect("from_stream");
+        let parent_manifest_label = parent.active_label().unwrap().to_owned();
+        input.set_position(0);
+
+        let mut builder = Builder::edit();
+
+        // Actions and hard bindings can't be redacted.
+        for label in [Actions::LABEL, "c2pa.actions", DataHash::LABEL] {
+            assert!(matches!(
+                builder.redact(&parent_manifest_label, label, "c2pa.PII.present"),
+                Err(Error::AssertionInvalidRedaction)
+            ));
+        }
+        assert!(builder.definition.redactions.is_none());
+
+        builder
+            .redact(&parent_manifest_label, ASSERTION_LABEL, "c2pa.PII.present")
+            .unwrap()
+            // Redacting twice has no further effect.
+            .redact(&parent_manifest_label, ASSERTION_LABEL, "c2pa.PII.present")
+            .unwrap();
+        assert_eq!(builder.definition.redactions.as_ref().unwrap().len(), 1);
+        builder
+            .add_assertion("org.test.note", &serde_json::json!({ "note": "private" }))
+            .unwrap();
+
+        let signer = test_signer(SigningAlg::Ps256);
+        let mut output = Cursor::new(Vec::new());
+        builder
+            .sign(signer.as_ref(), "image/jpeg", &mut input, &mut output)
+            .expect("builder sign");
+
+        output.set_position(0);
+        let reader = Reader::from_stream("image/jpeg", &mut output).expect("from_stream");
+        assert_ne!(reader.validation_state(), ValidationState::Invalid);
+
+        let parent = reader.get_manifest(&parent_manifest_label).unwrap();
+        assert!(parent
+            .assertions()
+            .iter()
+            .all(|a| a.label() != ASSERTION_LABEL));
+
+        let redactions = reader.redactions();
+        assert_eq!(redactions.len(), 1);
+        let redaction = &redactions[0];
+        assert_eq!(
+            redaction.manifest.as_deref(),
+            Some(parent_manifest_label.as_str())
+        );
+        assert_eq!(redaction.assertion.as_deref(), Some(ASSERTION_LABEL));
+        assert_eq!(redaction.redacted_by, reader.active_label().unwrap());
+        assert_eq!(redaction.reason.as_deref(), Some("c2pa.PII.present"));
+        assert!(redaction.issuer.is_some());
+
+        // Redact from the manifest that made the first redaction. Redactions
+        // are listed starting with the active manifest.
+        let first_label = reader.active_label().unwrap().to_owned();
+        output.set_position(0);
+        let mut builder = Builder::edit();
+        builder
+            .redact(&first_label, "org.test.note", "c2pa.PII.present")
+            .unwrap();
+
+        let mut second = Cursor::new(Vec::new());
+        builder
+            .sign(signer.as_ref(), "image/jpeg", &mut output, &mut second)
+            .expect("builder sign");
+
+        second.set_position(0);
+        let reader = Reader::from_stream("image/jpeg", &mut second).expect("from_stream");
+        let redacted_by: Vec<_> = reader
+            .redactions()
+            .into_iter()
+            .map(|r| r.redacted_by)
+            .collect();
+        assert_eq!(
+            redacted_by,
+            [reader.active_label().unwrap().to_owned(), first_label]
+        );
+    }
+
+    #[test]
+    fn test_sign_progress_and_cancel() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::progress::{ProgressPhase, ProgressScope};
+
+        let signer = test_signer(SigningAlg::Ps256);
+
+        let phases = Arc::new(Mutex::new(Vec::new()));
+        {
+            let phases = phases.clone();
+            let _scope = ProgressScope::new(move |progress| {
+                phases.lock().unwrap().push(progress.phase);
+                true
+            });
+
+            let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+            let mut output = Cursor::new(Vec::new());
+            builder
+                .sign(
+                    signer.as_ref(),
+                    "image/jpeg",
+                    &mut Cursor::new(TEST_IMAGE_CLEAN),
+                    &mut output,
+                )
+                .unwrap();
+        }
+
+        let phases = phases.lock().unwrap();
+        for phase in [ProgressPhase::Writing, ProgressPhase::Hashing] {
+            assert!(phases.contains(&phase), "no {phase:?} progress reported");
+        }
+
+        // Cancel as soon as the output starts being written.
+        let _scope = ProgressScope::new(|progress| progress.phase != ProgressPhase::Writing);
+        let mut builder = Builder::from_json(&simple_manifest_json()).unwrap();
+        let result = builder.sign(
+            signer.as_ref(),
+            "image/jpeg",
+            &mut Cursor::new(TEST_IMAGE_CLEAN),
+            &mut Cursor::new(Vec::new()),
+        );
+        assert!(matches!(result, Err(Error::OperationCancelled)));
+    }
+
     #[test]
     fn test_supported_mime_types() {
         let mime_types = Builder::supported_mime_types();
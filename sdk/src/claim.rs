@@ -35,10 +35,11 @@ use crate::{
             self, ACTIONS, ASSERTION_STORE, BMFF_HASH, CLAIM_THUMBNAIL, DATABOX_STORE,
             METADATA_LABEL_REGEX,
         },
-        Actions, AssertionMetadata, AssetType, BmffHash, BoxHash, DataBox, DataHash, Ingredient,
-        Metadata, Relationship, V2_DEPRECATED_ACTIONS,
+        Actions, AssertionMetadata, AssetType, BmffHash, BoxHash, CollectionHash,
+        CollectionMembers, DataBox, DataHash, Ingredient, Metadata, Relationship,
+        V2_DEPRECATED_ACTIONS,
     },
-    asset_io::CAIRead,
+    asset_io::{CAIRead, StreamFactory},
     cbor_types::{map_cbor_to_type, value_cbor_to_type},
     cose_validator::{
         get_signing_cert_serial_num, get_signing_info, get_signing_info_async, verify_cose,
@@ -75,6 +76,7 @@ use crate::{
     status_tracker::{ErrorBehavior, StatusTracker},
     store::StoreValidationInfo,
     utils::hash_utils::{hash_by_alg, vec_compare},
+    validation_results::ValidationLevel,
     validation_status, ClaimGeneratorInfo, HashRange,
 };
 
@@ -115,6 +117,8 @@ pub enum ClaimAssetData<'a> {
     StreamFragment(&'a mut dyn CAIRead, &'a mut dyn CAIRead, &'a str),
     #[cfg(feature = "file_io")]
     StreamFragments(&'a mut dyn CAIRead, &'a Vec<std::path::PathBuf>, &'a str),
+    Factory(&'a dyn StreamFactory, &'a str),
+    Collection(CollectionMembers<'a>),
 }
 
 #[derive(PartialEq, Debug, Eq, Clone, Hash)]
@@ -322,6 +326,10 @@ pub struct Claim {
     data_boxes: Vec<(HashedUri, DataBox)>, /* list of the data boxes and their hashed URIs found for this manifest */
 
     claim_version: usize,
+
+    // labels of assertions whose contents are patched after the manifest has
+    // been laid out for signing, such as dynamic assertions
+    patched_assertions: Vec<String>,
 }
 
 /// Enum to define how assertions are are stored when output to json
@@ -334,20 +342,15 @@ pub enum AssertionStoreJsonFormat {
 }
 
 /// Remote manifest options. Use 'set_remote_manifest' to generate external manifests.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub enum RemoteManifest {
-    NoRemote,                // No external manifest (default)
+    #[default]
+    NoRemote, // No external manifest (default)
     SideCar,        // Manifest will be saved as a side car file, output asset is untouched.
     Remote(String), /* Manifest will be saved as a side car file, output asset will contain remote reference */
     EmbedWithRemote(String), /* Manifest will be embedded with a remote reference, sidecar will be generated */
 }
 
-impl Default for RemoteManifest {
-    fn default() -> Self {
-        Self::NoRemote
-    }
-}
-
 #[derive(Serialize, Deserialize, Debug)]
 pub struct JsonOrderedAssertionData {
     label: String,
@@ -445,6 +448,7 @@ impl Claim {
 
             update_manifest: false,
             data_boxes: Vec::new(),
+            patched_assertions: Vec::new(),
             metadata: None,
             claim_version,
             created_assertions: Vec::new(),
@@ -542,6 +546,7 @@ impl Claim {
 
             update_manifest: false,
             data_boxes: Vec::new(),
+            patched_assertions: Vec::new(),
             metadata: None,
             claim_version,
             created_assertions: Vec::new(),
@@ -666,6 +671,7 @@ impl Claim {
                 claim_generator_hints,
                 metadata,
                 data_boxes: Vec::new(),
+                patched_assertions: Vec::new(),
                 claim_version,
                 created_assertions: Vec::new(),
                 gathered_assertions: None,
@@ -772,6 +778,7 @@ impl Claim {
                 claim_generator_hints: None,
                 metadata,
                 data_boxes: Vec::new(),
+                patched_assertions: Vec::new(),
                 claim_version,
                 created_assertions,
                 gathered_assertions,
@@ -1729,13 +1736,18 @@ impl Claim {
         )
     }
 
+    /// Returns `true` if the spec allows the assertion with this label to be
+    /// redacted. Actions assertions and hard bindings must never be redacted.
+    pub(crate) fn is_redactable(assertion_label: &str) -> bool {
+        !(assertion_label.starts_with(assertions::labels::ACTIONS)
+            || assertion_label.starts_with("c2pa.hash."))
+    }
+
     /// Redact an assertion from a prior claim.
     /// This will remove the assertion from the JUMBF
     fn redact_assertion(&mut self, assertion_uri: &str) -> Result<()> {
-        // cannot redact action assertions per the spec
-        // cannot redact hash bindings
         let (label, _instance) = Claim::assertion_label_from_link(assertion_uri);
-        if label.starts_with(assertions::labels::ACTIONS) || label.starts_with("c2pa.hash.") {
+        if !Claim::is_redactable(&label) {
             return Err(Error::AssertionInvalidRedaction);
         }
 
@@ -1849,6 +1861,12 @@ impl Claim {
             .failure(validation_log, Error::ClaimMissingSignatureBox)?;
         }
 
+        if svi.level == ValidationLevel::Structural {
+            let result = Claim::verify_internal(claim, asset_data, svi, None, validation_log);
+            validation_log.pop_current_uri();
+            return result;
+        }
+
         let sign1 = parse_cose_sign1(&sig, &data, validation_log)?;
         let certificate_serial_num = get_signing_cert_serial_num(&sign1)?.to_string();
 
@@ -1873,7 +1891,7 @@ impl Claim {
         )
         .await;
 
-        let result = Claim::verify_internal(claim, asset_data, svi, verified, validation_log);
+        let result = Claim::verify_internal(claim, asset_data, svi, Some(verified), validation_log);
         validation_log.pop_current_uri();
         result
     }
@@ -1915,6 +1933,12 @@ impl Claim {
             .failure(validation_log, Error::ClaimMissingSignatureBox)?;
         }
 
+        if svi.level == ValidationLevel::Structural {
+            let result = Claim::verify_internal(claim, asset_data, svi, None, validation_log);
+            validation_log.pop_current_uri();
+            return result;
+        }
+
         // If we are validating a claim that has been loaded from a file
         // we need the original data but if we are signing, we generate the data
         // This avoids cloning the data when we are only referencing it.
@@ -1950,7 +1974,7 @@ impl Claim {
             validation_log,
         );
 
-        let result = Claim::verify_internal(claim, asset_data, svi, verified, validation_log);
+        let result = Claim::verify_internal(claim, asset_data, svi, Some(verified), validation_log);
         validation_log.pop_current_uri();
         result
     }
@@ -2654,15 +2678,16 @@ impl Claim {
         claim: &Claim,
         asset_data: &mut ClaimAssetData<'_>,
         svi: &StoreValidationInfo,
-        verified: Result<CertificateInfo>,
+        verified: Option<Result<CertificateInfo>>,
         validation_log: &mut StatusTracker,
     ) -> Result<()> {
         const UNNAMED: &str = "unnamed";
         let default_str = |s: &String| s.clone();
 
-        // signature check
+        // signature check, unless only the structure is being validated
         match verified {
-            Ok(vi) => {
+            None => (),
+            Some(Ok(vi)) => {
                 if !vi.validated {
                     log_item!(
                         to_signature_uri(claim.label()),
@@ -2691,7 +2716,7 @@ impl Claim {
                     .success(validation_log);
                 }
             }
-            Err(parse_err) => {
+            Some(Err(parse_err)) => {
                 // handle case where lower level failed to log
                 log_item!(
                     to_signature_uri(claim.label()),
@@ -3019,6 +3044,13 @@ impl Claim {
                 .failure(validation_log, Error::UpdateManifestInvalid)?;
             }
 
+            // hashing the asset is left for a full validation
+            let hash_assertions = if svi.level == ValidationLevel::Full {
+                hash_assertions
+            } else {
+                Vec::new()
+            };
+
             // while this is a vec the spec only expects one at the moment and is checked above
             for hash_binding_assertion in hash_assertions {
                 if hash_binding_assertion
@@ -3059,6 +3091,15 @@ impl Claim {
                             ClaimAssetData::Stream(stream_data, _) => {
                                 dh.verify_stream_hash(*stream_data, Some(claim.alg()))
                             }
+                            ClaimAssetData::Factory(factory, _) => {
+                                factory.open_stream().and_then(|mut stream| {
+                                    dh.verify_stream_hash_with_factory(
+                                        &mut stream,
+                                        Some(*factory),
+                                        Some(claim.alg()),
+                                    )
+                                })
+                            }
                             _ => return Err(Error::UnsupportedType), /* this should never happen (coding error) */
                         };
 
@@ -3074,6 +3115,9 @@ impl Claim {
 
                                 continue;
                             }
+                            Err(Error::OperationCancelled) => {
+                                return Err(Error::OperationCancelled)
+                            }
                             Err(e) => {
                                 log_item!(
                                     claim.assertion_uri(&hash_binding_assertion.label()),
@@ -3134,6 +3178,10 @@ impl Claim {
                                 fragment_paths,
                                 Some(claim.alg()),
                             ),
+                        ClaimAssetData::Factory(factory, _) => {
+                            dh.verify_factory_hash(*factory, Some(claim.alg()))
+                        }
+                        ClaimAssetData::Collection(_) => Err(Error::UnsupportedType),
                     };
 
                     match hash_result {
@@ -3148,6 +3196,7 @@ impl Claim {
 
                             continue;
                         }
+                        Err(Error::OperationCancelled) => return Err(Error::OperationCancelled),
                         Err(e) => {
                             let err_str = match e {
                                 Error::C2PAValidation(es) => {
@@ -3221,6 +3270,16 @@ impl Claim {
                                 box_hash_processor,
                             )
                         }
+                        ClaimAssetData::Factory(factory, asset_type) => {
+                            let box_hash_processor = get_assetio_handler(asset_type)
+                                .ok_or(Error::UnsupportedType)?
+                                .asset_box_hash_ref()
+                                .ok_or(Error::HashMismatch(format!(
+                                    "Box hash not supported for: {asset_type}"
+                                )))?;
+
+                            bh.verify_factory_hash(*factory, Some(claim.alg()), box_hash_processor)
+                        }
                         _ => return Err(Error::UnsupportedType),
                     };
 
@@ -3236,6 +3295,7 @@ impl Claim {
 
                             continue;
                         }
+                        Err(Error::OperationCancelled) => return Err(Error::OperationCancelled),
                         Err(e) => {
                             log_item!(
                                 claim.assertion_uri(&hash_binding_assertion.label()),
@@ -3249,6 +3309,29 @@ impl Claim {
                             )?;
                         }
                     }
+                } else if hash_binding_assertion
+                    .label_raw()
+                    .starts_with(CollectionHash::LABEL)
+                {
+                    // collection hash case, each file of the collection is hashed separately
+                    let ch = CollectionHash::from_assertion(hash_binding_assertion.assertion())?;
+                    let uri = claim.assertion_uri(&hash_binding_assertion.label());
+
+                    match asset_data {
+                        ClaimAssetData::Collection(members) => {
+                            ch.verify_members(members, Some(claim.alg()), &uri, validation_log)?
+                        }
+                        _ => {
+                            log_item!(uri, "collection files not provided", "verify_internal")
+                                .validation_status(
+                                    validation_status::ASSERTION_COLLECTIONHASH_MISMATCH,
+                                )
+                                .failure(
+                                    validation_log,
+                                    Error::HashMismatch("collection files not provided".into()),
+                                )?;
+                        }
+                    }
                 } else {
                     log_item!(
                         claim.assertion_uri(&hash_binding_assertion.label()),
@@ -3301,6 +3384,27 @@ impl Claim {
             .collect()
     }
 
+    /// Records that the assertion with this label is patched after the
+    /// manifest has been laid out for signing, so its size in the manifest
+    /// must not depend on its contents.
+    pub(crate) fn add_patched_assertion(&mut self, label: &str) {
+        self.patched_assertions.push(label.to_owned());
+    }
+
+    /// Records that every assertion currently in the claim may be patched.
+    #[cfg(feature = "v1_api")]
+    pub(crate) fn mark_assertions_patched(&mut self) {
+        let labels: Vec<String> = self.assertion_store.iter().map(|ca| ca.label()).collect();
+        self.patched_assertions.extend(labels);
+    }
+
+    /// Returns true if the assertion with this label is patched after the
+    /// manifest has been laid out for signing. Hash assertions always are.
+    pub(crate) fn is_patched_assertion(&self, label: &str) -> bool {
+        self.patched_assertions.iter().any(|l| l == label)
+            || self.hash_assertions().iter().any(|ca| ca.label() == label)
+    }
+
     /// Return list of data hash assertions
     pub fn hash_assertions(&self) -> Vec<&ClaimAssertion> {
         let dummy_data = AssertionData::Cbor(Vec::new());
@@ -3317,6 +3421,9 @@ impl Claim {
         let dummy_box_hash = Assertion::new(assertions::labels::BOX_HASH, None, dummy_box_data);
         data_hashes.append(&mut self.assertions_by_type(&dummy_box_hash, None));
 
+        // add in any collection hashes
+        data_hashes.append(&mut self.collection_hash_assertions());
+
         data_hashes
     }
 
@@ -3334,6 +3441,16 @@ impl Claim {
         self.assertions_by_type(&dummy_box_hash, None)
     }
 
+    pub fn collection_hash_assertions(&self) -> Vec<&ClaimAssertion> {
+        let dummy_collection_data = AssertionData::Cbor(Vec::new());
+        let dummy_collection_hash = Assertion::new(
+            assertions::labels::COLLECTION_HASH,
+            None,
+            dummy_collection_data,
+        );
+        self.assertions_by_type(&dummy_collection_hash, None)
+    }
+
     /// Return list of ingredient assertions. This function
     /// is only useful on committed or loaded claims since ingredients
     /// are resolved at commit time.
@@ -3530,6 +3647,12 @@ impl Claim {
         self.original_bytes = None;
     }
 
+    /// Returns true if this claim was read from a file and has not been
+    /// modified since.
+    pub(crate) fn has_original_data(&self) -> bool {
+        self.original_bytes.is_some()
+    }
+
     /// Create claim from binary data (not including assertions).
     pub fn from_data(label: &str, data: &[u8]) -> Result<Claim> {
         let claim_value: serde_cbor::Value = serde_cbor::from_slice(data)
@@ -23,13 +23,14 @@ use crate::{
     cose_validator::verify_cose,
     crypto::{
         cose::{
-            check_end_entity_certificate_profile, sign, sign_async, CertificateTrustPolicy,
-            TimeStampStorage,
+            check_end_entity_certificate_profile, complete_certificate_chain,
+            complete_certificate_chain_async, sign, sign_async, CertificateTrustPolicy,
+            IntermediateCertificateStore, TimeStampStorage,
         },
         raw_signature::{AsyncRawSigner, RawSigner, RawSignerError, SigningAlg},
         time_stamp::{AsyncTimeStampProvider, TimeStampError, TimeStampProvider},
     },
-    settings::get_settings_value,
+    settings::{builder::CertificateChainCompletion, get_settings_value},
     status_tracker::{ErrorBehavior, StatusTracker},
     AsyncSigner, Error, Result, Signer,
 };
@@ -68,9 +69,11 @@ pub fn sign_claim(claim_bytes: &[u8], signer: &dyn Signer, box_size: usize) -> R
     };
 
     let signed_bytes = if _sync {
-        cose_sign(signer, claim_bytes, box_size, tss)
+        let reserve = SignatureReserve::with_size(signer, box_size)?;
+        cose_sign(signer, claim_bytes, &reserve, tss)
     } else {
-        cose_sign_async(signer, claim_bytes, box_size, tss).await
+        let reserve = SignatureReserve::with_size_async(signer, box_size).await?;
+        cose_sign_async(signer, claim_bytes, &reserve, tss).await
     };
 
     match signed_bytes {
@@ -103,17 +106,18 @@ pub fn sign_claim(claim_bytes: &[u8], signer: &dyn Signer, box_size: usize) -> R
 }
 
 /// Returns signed Cose_Sign1 bytes for `data`.
-/// The Cose_Sign1 will be signed with the algorithm from [`Signer`].
+/// The Cose_Sign1 will be signed with the algorithm from [`Signer`], using the
+/// certificate chain `reserve` was sized for.
 #[async_generic(async_signature(
     signer: &dyn AsyncSigner,
     data: &[u8],
-    box_size: usize,
+    reserve: &SignatureReserve,
     time_stamp_storage: TimeStampStorage,
 ))]
 pub(crate) fn cose_sign(
     signer: &dyn Signer,
     data: &[u8],
-    box_size: usize,
+    reserve: &SignatureReserve,
     time_stamp_storage: TimeStampStorage,
 ) -> Result<Vec<u8>> {
     // Make sure the signing cert is valid.
@@ -124,27 +128,126 @@ pub(crate) fn cose_sign(
         return Err(Error::CoseNoCerts);
     }
 
+    // A completed chain has to be supplied through the wrapper since the
+    // signer itself only reports the certificates it was configured with.
+    let box_size = reserve.size;
+    let completed_certs = reserve.completed_certs.clone();
+
     if _sync {
-        match signer.raw_signer() {
-            Some(raw_signer) => Ok(sign(*raw_signer, data, Some(box_size), time_stamp_storage)?),
-            None => {
-                let wrapper = SignerWrapper(signer);
+        match (signer.raw_signer(), completed_certs) {
+            (Some(raw_signer), None) => {
+                Ok(sign(*raw_signer, data, Some(box_size), time_stamp_storage)?)
+            }
+            (_, certs) => {
+                let wrapper = SignerWrapper(signer, certs);
                 Ok(sign(&wrapper, data, Some(box_size), time_stamp_storage)?)
             }
         }
     } else {
-        match signer.async_raw_signer() {
-            Some(raw_signer) => {
+        match (signer.async_raw_signer(), completed_certs) {
+            (Some(raw_signer), None) => {
                 Ok(sign_async(*raw_signer, data, Some(box_size), time_stamp_storage).await?)
             }
-            None => {
-                let wrapper = AsyncSignerWrapper(signer);
+            (_, certs) => {
+                let wrapper = AsyncSignerWrapper(signer, certs);
                 Ok(sign_async(&wrapper, data, Some(box_size), time_stamp_storage).await?)
             }
         }
     }
 }
 
+/// The space reserved for the `c2pa.signature` box of a claim and the
+/// certificate chain it was sized for.
+///
+/// Completing the chain may fetch certificates over the network, so it is
+/// resolved once per signing operation and the same chain is used to lay out
+/// the manifest and to sign it.
+#[derive(Debug)]
+pub(crate) struct SignatureReserve {
+    size: usize,
+    completed_certs: Option<Vec<Vec<u8>>>,
+}
+
+impl SignatureReserve {
+    /// Resolves the certificate chain of `signer` and reserves the signer's own
+    /// reserve size plus room for any certificates added by
+    /// `builder.certificate_chain_completion`.
+    #[async_generic(async_signature(signer: &dyn AsyncSigner))]
+    pub(crate) fn new(signer: &dyn Signer) -> Result<Self> {
+        let certs = signer.certs()?;
+        let completed_certs = if _sync {
+            completed_cert_chain(&certs)?
+        } else {
+            completed_cert_chain_async(&certs).await?
+        };
+
+        Ok(Self {
+            size: signer.reserve_size() + added_certs_size(&certs, completed_certs.as_deref()),
+            completed_certs,
+        })
+    }
+
+    /// Resolves the certificate chain of `signer` and reserves exactly `size`
+    /// bytes for the signature.
+    #[async_generic(async_signature(signer: &dyn AsyncSigner, size: usize))]
+    pub(crate) fn with_size(signer: &dyn Signer, size: usize) -> Result<Self> {
+        let reserve = if _sync {
+            Self::new(signer)?
+        } else {
+            Self::new_async(signer).await?
+        };
+
+        Ok(Self { size, ..reserve })
+    }
+
+    /// Returns the number of bytes reserved for the signature box.
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// Returns the space taken in `x5chain` by the certificates of `completed_certs`
+/// that are not in `certs`, including their CBOR byte string headers.
+fn added_certs_size(certs: &[Vec<u8>], completed_certs: Option<&[Vec<u8>]>) -> usize {
+    completed_certs.map_or(0, |completed_certs| {
+        completed_certs
+            .iter()
+            .filter(|cert| !certs.contains(cert))
+            .map(|cert| cert.len() + CBOR_BSTR_HEADER_SIZE)
+            .sum()
+    })
+}
+
+// Largest CBOR header for a byte string shorter than 4 GiB.
+const CBOR_BSTR_HEADER_SIZE: usize = 5;
+
+/// Completes the signer's certificate chain if `builder.certificate_chain_completion`
+/// is set, using the `trust.intermediates` pool and optionally AIA caIssuers URLs.
+///
+/// Returns `None` if completion is disabled or no certificates were added.
+#[async_generic]
+fn completed_cert_chain(certs: &[Vec<u8>]) -> Result<Option<Vec<Vec<u8>>>> {
+    let Ok(Some(completion)) = get_settings_value::<Option<CertificateChainCompletion>>(
+        "builder.certificate_chain_completion",
+    ) else {
+        return Ok(None);
+    };
+
+    let mut intermediates = IntermediateCertificateStore::new();
+    if let Ok(Some(pems)) = get_settings_value::<Option<String>>("trust.intermediates") {
+        intermediates.add_intermediates(pems.as_bytes())?;
+    }
+
+    let fetch_ca_issuers = completion == CertificateChainCompletion::Fetch;
+    let chain = if _sync {
+        complete_certificate_chain(certs, &intermediates, fetch_ca_issuers)
+    } else {
+        complete_certificate_chain_async(certs, &intermediates, fetch_ca_issuers).await
+    };
+
+    Ok((chain.len() > certs.len()).then_some(chain))
+}
+
 fn signing_cert_valid(signing_cert: &[u8]) -> Result<()> {
     // make sure signer certs are valid
     let mut cose_log = StatusTracker::with_error_behavior(ErrorBehavior::StopOnFirstError);
@@ -163,7 +266,7 @@ fn signing_cert_valid(signing_cert: &[u8]) -> Result<()> {
     )?)
 }
 
-struct SignerWrapper<'a>(&'a dyn Signer);
+struct SignerWrapper<'a>(&'a dyn Signer, Option<Vec<Vec<u8>>>);
 
 impl RawSigner for SignerWrapper<'_> {
     fn sign(&self, data: &[u8]) -> std::result::Result<Vec<u8>, RawSignerError> {
@@ -175,11 +278,15 @@ impl RawSigner for SignerWrapper<'_> {
     }
 
     fn cert_chain(&self) -> std::result::Result<Vec<Vec<u8>>, RawSignerError> {
-        Ok(self.0.certs()?)
+        match &self.1 {
+            Some(certs) => Ok(certs.clone()),
+            None => Ok(self.0.certs()?),
+        }
     }
 
     fn reserve_size(&self) -> usize {
-        self.0.reserve_size()
+        let certs = self.0.certs().unwrap_or_default();
+        self.0.reserve_size() + added_certs_size(&certs, self.1.as_deref())
     }
 
     fn ocsp_response(&self) -> Option<Vec<u8>> {
@@ -213,7 +320,7 @@ impl TimeStampProvider for SignerWrapper<'_> {
     }
 }
 
-struct AsyncSignerWrapper<'a>(&'a dyn AsyncSigner);
+struct AsyncSignerWrapper<'a>(&'a dyn AsyncSigner, Option<Vec<Vec<u8>>>);
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
@@ -227,11 +334,15 @@ impl AsyncRawSigner for AsyncSignerWrapper<'_> {
     }
 
     fn cert_chain(&self) -> std::result::Result<Vec<Vec<u8>>, RawSignerError> {
-        Ok(self.0.certs()?)
+        match &self.1 {
+            Some(certs) => Ok(certs.clone()),
+            None => Ok(self.0.certs()?),
+        }
     }
 
     fn reserve_size(&self) -> usize {
-        self.0.reserve_size()
+        let certs = self.0.certs().unwrap_or_default();
+        self.0.reserve_size() + added_certs_size(&certs, self.1.as_deref())
     }
 
     async fn ocsp_response(&self) -> Option<Vec<u8>> {
@@ -275,8 +386,10 @@ mod tests {
     #[cfg(feature = "file_io")]
     use crate::utils::test_signer::async_test_signer;
     use crate::{
-        claim::Claim, crypto::raw_signature::SigningAlg, utils::test_signer::test_signer, Result,
-        Signer,
+        claim::Claim,
+        crypto::{cose::TimeStampStorage, raw_signature::SigningAlg},
+        utils::test_signer::test_signer,
+        Result, Signer,
     };
 
     #[test]
@@ -329,6 +442,74 @@ mod tests {
         assert_eq!(cose_sign1.len(), box_size);
     }
 
+    #[test]
+    fn test_sign_claim_completes_cert_chain() {
+        use crate::crypto::cose::{cert_chain_from_sign1, parse_cose_sign1};
+
+        crate::settings::set_settings_value("verify.verify_trust", false).unwrap();
+
+        let chain = include_bytes!("../tests/fixtures/certs/es256.pub");
+        let end_entity = x509_parser::pem::Pem::iter_from_buffer(chain)
+            .next()
+            .unwrap()
+            .unwrap();
+        let end_entity_pem = pem::encode(&pem::Pem::new("CERTIFICATE", end_entity.contents));
+
+        let signer = crate::create_signer::from_keys(
+            end_entity_pem.as_bytes(),
+            include_bytes!("../tests/fixtures/certs/es256.pem"),
+            SigningAlg::Es256,
+            None,
+        )
+        .unwrap();
+        assert_eq!(signer.certs().unwrap().len(), 1);
+
+        crate::settings::set_settings_value(
+            "trust.intermediates",
+            String::from_utf8_lossy(chain).into_owned(),
+        )
+        .unwrap();
+        crate::settings::set_settings_value("builder.certificate_chain_completion", "local")
+            .unwrap();
+
+        let mut claim = Claim::new("chain_completion_test", Some("contentauth"), 1);
+        claim.build().unwrap();
+        let claim_bytes = claim.data().unwrap();
+
+        // the box has room for the intermediate certificate added to the chain
+        let intermediate_len = x509_parser::pem::Pem::iter_from_buffer(chain)
+            .nth(1)
+            .unwrap()
+            .unwrap()
+            .contents
+            .len();
+        let reserve = super::SignatureReserve::new(signer.as_ref()).unwrap();
+        assert_eq!(
+            reserve.size(),
+            signer.reserve_size() + intermediate_len + super::CBOR_BSTR_HEADER_SIZE
+        );
+
+        // signing uses the chain the reserve was sized for, without completing
+        // it again
+        crate::settings::reset_default_settings().unwrap();
+        crate::settings::set_settings_value("verify.verify_trust", false).unwrap();
+
+        let cose_sign1 = super::cose_sign(
+            signer.as_ref(),
+            &claim_bytes,
+            &reserve,
+            TimeStampStorage::V1_sigTst,
+        )
+        .unwrap();
+        assert_eq!(cose_sign1.len(), reserve.size());
+
+        crate::settings::reset_default_settings().unwrap();
+
+        let mut log = crate::status_tracker::StatusTracker::default();
+        let sign1 = parse_cose_sign1(&cose_sign1, &claim_bytes, &mut log).unwrap();
+        assert_eq!(cert_chain_from_sign1(&sign1).unwrap().len(), 2);
+    }
+
     struct BogusSigner {}
 
     impl BogusSigner {
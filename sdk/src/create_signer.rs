@@ -15,11 +15,22 @@
 
 //! The `create_signer` module provides a way to obtain a [`Signer`]
 //! instance for each signing format supported by this crate.
-#[cfg(feature = "file_io")]
+#[cfg(any(
+    feature = "file_io",
+    all(feature = "pkcs11", not(target_arch = "wasm32"))
+))]
 use std::path::Path;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+    crypto::raw_signature::signer::async_signer_from_raw_signer, signer::AsyncRawSignerWrapper,
+    AsyncSigner,
+};
 use crate::{
-    crypto::raw_signature::{signer_from_cert_chain_and_private_key, SigningAlg},
+    crypto::{
+        raw_signature::{signer_from_cert_chain_and_private_key, SigningAlg},
+        time_stamp::{LocalTimeStampAuthority, LocalTimeStampSigner},
+    },
     error::Result,
     signer::RawSignerWrapper,
     Signer,
@@ -48,6 +59,98 @@ pub fn from_keys(
     )))
 }
 
+/// Creates a [`Signer`] instance using signing certificate and private key
+/// as byte slices, with time stamps issued by a [`LocalTimeStampAuthority`]
+/// instead of a remote time stamp service.
+///
+/// Readers will only trust the resulting time stamps if the time stamp
+/// authority's root certificate is in their trust store.
+///
+/// # Arguments
+///
+/// * `signcert` - Signing certificate
+/// * `pkey` - Private key
+/// * `alg` - Format for signing
+/// * `tsa_cert` - Time stamp authority certificate chain
+/// * `tsa_pkey` - Time stamp authority private key
+/// * `tsa_alg` - Format for signing time stamps
+pub fn from_keys_with_local_tsa(
+    signcert: &[u8],
+    pkey: &[u8],
+    alg: SigningAlg,
+    tsa_cert: &[u8],
+    tsa_pkey: &[u8],
+    tsa_alg: SigningAlg,
+) -> Result<Box<dyn Signer>> {
+    let tsa =
+        LocalTimeStampAuthority::from_cert_chain_and_private_key(tsa_cert, tsa_pkey, tsa_alg)?;
+
+    Ok(Box::new(RawSignerWrapper(Box::new(
+        LocalTimeStampSigner::new(
+            signer_from_cert_chain_and_private_key(signcert, pkey, alg, None)?,
+            tsa,
+        ),
+    ))))
+}
+
+/// Creates an [`AsyncSigner`] instance using signing certificate and private
+/// key as byte slices.
+///
+/// Time stamps are requested from `tsa_url` without blocking the calling
+/// task.
+///
+/// # Arguments
+///
+/// * `signcert` - Signing certificate
+/// * `pkey` - Private key
+/// * `alg` - Format for signing
+/// * `tsa_url` - Optional URL for a timestamp authority
+#[cfg(not(target_arch = "wasm32"))]
+pub fn async_from_keys(
+    signcert: &[u8],
+    pkey: &[u8],
+    alg: SigningAlg,
+    tsa_url: Option<String>,
+) -> Result<Box<dyn AsyncSigner + Send + Sync>> {
+    Ok(Box::new(AsyncRawSignerWrapper(
+        async_signer_from_raw_signer(signer_from_cert_chain_and_private_key(
+            signcert, pkey, alg, tsa_url,
+        )?),
+    )))
+}
+
+/// Creates an [`AsyncSigner`] instance using signing certificate and private
+/// key as byte slices, with time stamps issued by a
+/// [`LocalTimeStampAuthority`].
+///
+/// # Arguments
+///
+/// * `signcert` - Signing certificate
+/// * `pkey` - Private key
+/// * `alg` - Format for signing
+/// * `tsa_cert` - Time stamp authority certificate chain
+/// * `tsa_pkey` - Time stamp authority private key
+/// * `tsa_alg` - Format for signing time stamps
+#[cfg(not(target_arch = "wasm32"))]
+pub fn async_from_keys_with_local_tsa(
+    signcert: &[u8],
+    pkey: &[u8],
+    alg: SigningAlg,
+    tsa_cert: &[u8],
+    tsa_pkey: &[u8],
+    tsa_alg: SigningAlg,
+) -> Result<Box<dyn AsyncSigner + Send + Sync>> {
+    let tsa =
+        LocalTimeStampAuthority::from_cert_chain_and_private_key(tsa_cert, tsa_pkey, tsa_alg)?;
+
+    Ok(Box::new(AsyncRawSignerWrapper(
+        async_signer_from_raw_signer(Box::new(LocalTimeStampSigner::new(
+            signer_from_cert_chain_and_private_key(signcert, pkey, alg, None)?,
+            tsa,
+        ))),
+    )))
+}
+
 /// Creates a [`Signer`] instance using signing certificate and
 /// private key files.
 ///
@@ -69,3 +172,73 @@ pub fn from_files<P: AsRef<Path>>(
 
     from_keys(&cert_chain, &private_key, alg, tsa_url)
 }
+
+/// Creates a [`Signer`] instance whose private key is held on a PKCS#11
+/// token, such as a hardware security module.
+///
+/// The token is selected by its label and the key by its `CKA_ID`. The
+/// certificate with the same ID, plus any issuing certificates found on the
+/// token, form the certificate chain.
+///
+/// # Arguments
+///
+/// * `module_path` - Path to the PKCS#11 module (shared library)
+/// * `token_label` - Label of the token holding the key
+/// * `key_id` - `CKA_ID` of the private key and its certificate
+/// * `pin` - Optional user PIN for the token
+/// * `alg` - Format for signing
+/// * `tsa_url` - Optional URL for a timestamp authority
+#[cfg(all(feature = "pkcs11", not(target_arch = "wasm32")))]
+pub fn from_pkcs11<P: AsRef<Path>>(
+    module_path: P,
+    token_label: &str,
+    key_id: &[u8],
+    pin: Option<&str>,
+    alg: SigningAlg,
+    tsa_url: Option<String>,
+) -> Result<Box<dyn Signer>> {
+    Ok(Box::new(RawSignerWrapper(
+        crate::crypto::raw_signature::signer_from_pkcs11_token(
+            module_path.as_ref(),
+            token_label,
+            key_id,
+            pin,
+            alg,
+            tsa_url,
+        )?,
+    )))
+}
+
+/// Creates an [`AsyncSigner`] instance whose private key is held on a PKCS#11
+/// token.
+///
+/// See [`from_pkcs11`] for how the key and certificate chain are located.
+///
+/// # Arguments
+///
+/// * `module_path` - Path to the PKCS#11 module (shared library)
+/// * `token_label` - Label of the token holding the key
+/// * `key_id` - `CKA_ID` of the private key and its certificate
+/// * `pin` - Optional user PIN for the token
+/// * `alg` - Format for signing
+/// * `tsa_url` - Optional URL for a timestamp authority
+#[cfg(all(feature = "pkcs11", not(target_arch = "wasm32")))]
+pub fn async_from_pkcs11<P: AsRef<Path>>(
+    module_path: P,
+    token_label: &str,
+    key_id: &[u8],
+    pin: Option<&str>,
+    alg: SigningAlg,
+    tsa_url: Option<String>,
+) -> Result<Box<dyn AsyncSigner + Send + Sync>> {
+    Ok(Box::new(AsyncRawSignerWrapper(
+        async_signer_from_raw_signer(crate::crypto::raw_signature::signer_from_pkcs11_token(
+            module_path.as_ref(),
+            token_label,
+            key_id,
+            pin,
+            alg,
+            tsa_url,
+        )?),
+    )))
+}
@@ -0,0 +1,570 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Content Credentials JSON (crJSON) serialization of a manifest store.
+//!
+//! crJSON is a JSON-LD document with one entry per manifest, starting with
+//! the active manifest. Each entry holds the claim, every assertion keyed by
+//! its label (ingredients included, with their relationships), and the
+//! signature. The document also carries the C2PA validation results for the
+//! whole store.
+//!
+//! Use [`Reader::crjson`] to export a store and [`Reader::from_crjson`] to read
+//! the export back. crJSON describes signing certificates by issuer and serial
+//! number only, so certificate chains don't survive the round trip.
+//!
+//! ```
+//! use std::io::Cursor;
+//!
+//! use c2pa::Reader;
+//!
+//! let reader = Reader::from_stream(
+//!     "image/jpeg",
+//!     Cursor::new(include_bytes!("../tests/fixtures/CA.jpg")),
+//! )?;
+//!
+//! let crjson = reader.crjson()?;
+//! assert!(crjson.contains("@context"));
+//!
+//! let restored = Reader::from_crjson(&crjson)?;
+//! assert_eq!(restored.active_label(), reader.active_label());
+//! # Ok::<(), c2pa::Error>(())
+//! ```
+
+use std::collections::HashMap;
+
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::{assertions::labels, Error, Reader, Result, SignatureInfo, ValidationResults};
+
+/// The JSON-LD vocabulary used for crJSON terms.
+pub const CRJSON_VOCABULARY: &str = "https://contentcredentials.org/crjson/vocab/";
+
+// Terms that are not part of the crJSON vocabulary.
+const CRJSON_EXTRAS: &str = "https://contentcredentials.org/crjson/extras/";
+
+// Dublin Core, which claims use for titles and formats.
+const DC_NAMESPACE: &str = "http://purl.org/dc/elements/1.1/";
+
+/// A manifest store serialized as crJSON.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct CrJson {
+    /// The JSON-LD context of the document.
+    #[serde(rename = "@context")]
+    pub context: Value,
+
+    /// The manifests in the store, starting with the active manifest.
+    pub manifests: Vec<CrJsonManifest>,
+
+    /// The C2PA validation results of the store.
+    #[serde(rename = "validationResults", skip_serializing_if = "Option::is_none")]
+    pub validation_results: Option<ValidationResults>,
+}
+
+/// One manifest in a [`CrJson`] document.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct CrJsonManifest {
+    /// The manifest label.
+    pub label: String,
+
+    /// Assertion data keyed by label, with an instance suffix such as `__2`
+    /// for repeated labels. Ingredients are included as ingredient assertions.
+    #[serde(default)]
+    pub assertions: Map<String, Value>,
+
+    /// The claim of a 1.x manifest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim: Option<CrJsonClaim>,
+
+    /// The claim of a 2.x manifest.
+    #[serde(rename = "claim.v2", skip_serializing_if = "Option::is_none")]
+    pub claim_v2: Option<CrJsonClaim>,
+
+    /// Information about the claim signature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<CrJsonSignature>,
+}
+
+/// The claim fields of a [`CrJsonManifest`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct CrJsonClaim {
+    /// The title of the asset.
+    #[serde(rename = "dc:title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// The media type of the asset.
+    #[serde(rename = "dc:format", skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// The XMP instance ID of the asset.
+    #[serde(rename = "instanceID", default)]
+    pub instance_id: String,
+
+    /// The claim generator of a 1.x claim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_generator: Option<String>,
+
+    /// The software that created the claim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_generator_info: Option<Value>,
+
+    /// Metadata about the claim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+
+    /// URIs of assertions this claim redacts from its ingredients.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redacted_assertions: Option<Vec<String>>,
+}
+
+/// The signature fields of a [`CrJsonManifest`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CrJsonSignature {
+    /// The signing algorithm, such as `es256`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<String>,
+
+    /// The signing certificate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_info: Option<CrJsonCertificateInfo>,
+
+    /// The time stamp of the signature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_stamp_info: Option<CrJsonTimeStampInfo>,
+}
+
+/// The signing certificate of a [`CrJsonSignature`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CrJsonCertificateInfo {
+    /// The serial number of the certificate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<String>,
+
+    /// The organization that issued the certificate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+
+    /// Whether the certificate was found to be revoked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revocation_status: Option<bool>,
+}
+
+/// The time stamp of a [`CrJsonSignature`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct CrJsonTimeStampInfo {
+    /// When the signature was time stamped.
+    pub timestamp: String,
+}
+
+impl CrJson {
+    /// Builds the crJSON document for the manifest store read by `reader`.
+    pub fn from_reader(reader: &Reader) -> Result<Self> {
+        let json = reader.to_json_formatted()?;
+
+        let mut labels: Vec<&String> = reader.manifests().keys().collect();
+        labels.sort_by_key(|label| (Some(label.as_str()) != reader.active_label(), *label));
+
+        let manifests = labels
+            .into_iter()
+            .filter_map(|label| {
+                let manifest = json.get("manifests")?.get(label)?;
+                Some(CrJsonManifest::from_manifest_json(label, manifest))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            context: Self::default_context(),
+            manifests,
+            validation_results: reader
+                .validation_results()
+                .cloned()
+                .map(ValidationResults::without_reports),
+        })
+    }
+
+    /// Converts the document back to the JSON accepted by [`Reader::from_json`].
+    pub fn to_reader_json(&self) -> Result<Value> {
+        let mut manifests = Map::new();
+        for manifest in &self.manifests {
+            manifests.insert(manifest.label.clone(), manifest.to_manifest_json()?);
+        }
+
+        let active_manifest = self.manifests.first().map(|m| m.label.clone());
+        let validation_status = self
+            .validation_results
+            .as_ref()
+            .and_then(ValidationResults::validation_errors);
+        let validation_state = self
+            .validation_results
+            .as_ref()
+            .map(ValidationResults::validation_state);
+
+        let mut json = json!({ "manifests": manifests });
+        let fields = [
+            ("active_manifest", to_value(&active_manifest)?),
+            ("validation_status", to_value(&validation_status)?),
+            ("validation_results", to_value(&self.validation_results)?),
+            ("validation_state", to_value(&validation_state)?),
+        ];
+        if let Some(object) = json.as_object_mut() {
+            object.extend(
+                fields
+                    .into_iter()
+                    .filter(|(_, v)| !v.is_null())
+                    .map(|(k, v)| (k.to_owned(), v)),
+            );
+        }
+        Ok(json)
+    }
+
+    /// The JSON-LD context written to new documents.
+    pub fn default_context() -> Value {
+        json!({
+            "@vocab": CRJSON_VOCABULARY,
+            "extras": CRJSON_EXTRAS,
+            "dc": DC_NAMESPACE,
+        })
+    }
+}
+
+impl CrJsonManifest {
+    // maps a manifest in the Reader JSON format to crJSON
+    fn from_manifest_json(label: &str, manifest: &Value) -> Result<Self> {
+        let field = |name: &str| manifest.get(name).cloned();
+
+        let mut assertions = Map::new();
+        for assertion in manifest
+            .get("assertions")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let Some(assertion_label) = assertion.get("label").and_then(Value::as_str) else {
+                continue;
+            };
+            let key = match assertion.get("instance").and_then(Value::as_u64) {
+                Some(instance) if instance > 1 => format!("{assertion_label}__{instance}"),
+                _ => assertion_label.to_owned(),
+            };
+            assertions.insert(key, assertion.get("data").cloned().unwrap_or(Value::Null));
+        }
+
+        // ingredients are assertions in crJSON, keyed by their own label
+        for ingredient in manifest
+            .get("ingredients")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let mut ingredient = ingredient.clone();
+            let ingredient_label = ingredient
+                .as_object_mut()
+                .and_then(|object| object.remove("label"))
+                .and_then(|label| label.as_str().map(str::to_owned))
+                .unwrap_or_else(|| labels::INGREDIENT.to_owned());
+            let key = unique_key(&assertions, ingredient_label);
+            assertions.insert(key, ingredient);
+        }
+
+        let claim = CrJsonClaim {
+            title: from_field(manifest, "title")?,
+            format: from_field(manifest, "format")?,
+            instance_id: from_field(manifest, "instance_id")?.unwrap_or_default(),
+            claim_generator: from_field(manifest, "claim_generator")?,
+            claim_generator_info: field("claim_generator_info"),
+            metadata: field("metadata"),
+            redacted_assertions: from_field(manifest, "redactions")?,
+        };
+
+        let signature = from_field::<SignatureInfo>(manifest, "signature_info")?
+            .map(|info| CrJsonSignature::from_signature_info(&info));
+
+        // only 1.x claims have a claim generator string
+        let (claim, claim_v2) = if claim.claim_generator.is_some() {
+            (Some(claim), None)
+        } else {
+            (None, Some(claim))
+        };
+
+        Ok(Self {
+            label: label.to_owned(),
+            assertions,
+            claim,
+            claim_v2,
+            signature,
+        })
+    }
+
+    // maps this manifest back to the Reader JSON format
+    fn to_manifest_json(&self) -> Result<Value> {
+        let mut assertions = Vec::new();
+        let mut ingredients = Vec::new();
+        for (key, data) in &self.assertions {
+            let (label, instance) = match key
+                .rsplit_once("__")
+                .and_then(|(label, n)| Some((label, n.parse::<usize>().ok()?)))
+            {
+                Some((label, instance)) => (label, Some(instance)),
+                None => (key.as_str(), None),
+            };
+
+            if label.starts_with(labels::INGREDIENT) {
+                let mut ingredient = data.clone();
+                if let Some(object) = ingredient.as_object_mut() {
+                    object.insert("label".to_owned(), json!(key));
+                }
+                ingredients.push(ingredient);
+                continue;
+            }
+
+            let mut assertion = json!({ "label": label, "data": data });
+            if let Some(instance) = instance {
+                assertion["instance"] = json!(instance);
+            }
+            assertions.push(assertion);
+        }
+
+        let claim = self.claim.as_ref().or(self.claim_v2.as_ref());
+        let claim_field = |f: fn(&CrJsonClaim) -> Result<Value>| match claim {
+            Some(claim) => f(claim),
+            None => Ok(Value::Null),
+        };
+        let signature_info = self
+            .signature
+            .as_ref()
+            .map(CrJsonSignature::to_signature_info);
+
+        let fields: HashMap<&str, Value> = HashMap::from([
+            ("label", json!(self.label)),
+            ("title", claim_field(|c| to_value(&c.title))?),
+            ("format", claim_field(|c| to_value(&c.format))?),
+            ("instance_id", claim_field(|c| to_value(&c.instance_id))?),
+            (
+                "claim_generator",
+                claim_field(|c| to_value(&c.claim_generator))?,
+            ),
+            (
+                "claim_generator_info",
+                claim_field(|c| to_value(&c.claim_generator_info))?,
+            ),
+            ("metadata", claim_field(|c| to_value(&c.metadata))?),
+            (
+                "redactions",
+                claim_field(|c| to_value(&c.redacted_assertions))?,
+            ),
+            ("ingredients", json!(ingredients)),
+            ("assertions", json!(assertions)),
+            ("signature_info", to_value(&signature_info)?),
+        ]);
+
+        Ok(Value::Object(
+            fields
+                .into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k.to_owned(), v))
+                .collect(),
+        ))
+    }
+}
+
+impl CrJsonSignature {
+    fn from_signature_info(info: &SignatureInfo) -> Self {
+        let certificate_info = CrJsonCertificateInfo {
+            serial_number: info.cert_serial_number.clone(),
+            issuer: info.issuer.clone(),
+            revocation_status: info.revocation_status,
+        };
+        Self {
+            algorithm: info.alg.map(|alg| alg.to_string()),
+            certificate_info: Some(certificate_info)
+                .filter(|c| *c != CrJsonCertificateInfo::default()),
+            time_stamp_info: info
+                .time
+                .clone()
+                .map(|timestamp| CrJsonTimeStampInfo { timestamp }),
+        }
+    }
+
+    // crJSON only carries a summary of the signing certificate, so a signature
+    // read back from crJSON has no certificate chain.
+    fn to_signature_info(&self) -> SignatureInfo {
+        let certificate_info = self.certificate_info.clone().unwrap_or_default();
+        SignatureInfo {
+            alg: self.algorithm.as_deref().and_then(|alg| alg.parse().ok()),
+            issuer: certificate_info.issuer,
+            cert_serial_number: certificate_info.serial_number,
+            time: self.time_stamp_info.as_ref().map(|t| t.timestamp.clone()),
+            revocation_status: certificate_info.revocation_status,
+            cert_chain: String::new(),
+        }
+    }
+}
+
+// returns `label`, or `label` with the first free instance suffix
+fn unique_key(assertions: &Map<String, Value>, label: String) -> String {
+    let mut key = label.clone();
+    let mut instance = 1;
+    while assertions.contains_key(&key) {
+        instance += 1;
+        key = format!("{label}__{instance}");
+    }
+    key
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value> {
+    serde_json::to_value(value).map_err(Error::JsonError)
+}
+
+fn from_field<T: serde::de::DeserializeOwned>(object: &Value, name: &str) -> Result<Option<T>> {
+    object
+        .get(name)
+        .map(|v| serde_json::from_value(v.clone()).map_err(Error::JsonError))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::Cursor;
+
+    use super::*;
+
+    const CRJSON_SCHEMA: &str = include_str!("../tests/fixtures/crJSON-schema.json");
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn schema_errors(crjson: &Value) -> Vec<String> {
+        let schema: Value = serde_json::from_str(CRJSON_SCHEMA).unwrap();
+        let validator = jsonschema::validator_for(&schema).unwrap();
+        validator
+            .iter_errors(crjson)
+            .map(|e| format!("{}: {e}", e.instance_path))
+            .collect()
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn crjson_matches_schema() {
+        for (format, bytes) in [
+            (
+                "image/jpeg",
+                &include_bytes!("../tests/fixtures/CA.jpg")[..],
+            ),
+            (
+                "image/jpeg",
+                include_bytes!("../tests/fixtures/CACAE-uri-CA.jpg"),
+            ),
+            (
+                "image/jpeg",
+                include_bytes!("../tests/fixtures/C_with_CAWG_data.jpg"),
+            ),
+            ("image/jpeg", include_bytes!("../tests/fixtures/XCA.jpg")),
+            (
+                "image/jpeg",
+                include_bytes!("../tests/fixtures/CIE-sig-CA.jpg"),
+            ),
+        ] {
+            let reader = Reader::from_stream(format, Cursor::new(bytes)).unwrap();
+            let crjson: Value = serde_json::from_str(&reader.crjson().unwrap()).unwrap();
+            let errors = schema_errors(&crjson);
+            assert!(errors.is_empty(), "{errors:#?}");
+        }
+
+        // the schema rejects fields it does not define
+        let reader = Reader::from_stream(
+            "image/jpeg",
+            Cursor::new(include_bytes!("../tests/fixtures/CA.jpg")),
+        )
+        .unwrap();
+        let mut crjson: Value = serde_json::from_str(&reader.crjson().unwrap()).unwrap();
+        crjson["activeManifest"] = json!(reader.active_label());
+        crjson["manifests"][0]["claim.v2"] = json!({ "instanceID": "xmp:iid:1" });
+        crjson["manifests"][0]["claim"] = json!({ "instanceID": "xmp:iid:1" });
+        let errors = schema_errors(&crjson);
+        assert_eq!(errors.len(), 2, "{errors:#?}");
+        assert!(errors[0].starts_with("/manifests/0: ") && errors[0].ends_with("'oneOf' keyword"));
+        assert!(errors[1].contains("'activeManifest' was unexpected"));
+    }
+
+    #[test]
+    fn crjson_round_trip() {
+        let reader = Reader::from_stream(
+            "image/jpeg",
+            Cursor::new(include_bytes!("../tests/fixtures/CACAE-uri-CA.jpg")),
+        )
+        .unwrap();
+
+        let crjson = CrJson::from_reader(&reader).unwrap();
+        assert_eq!(crjson.context["@vocab"], CRJSON_VOCABULARY);
+        assert_eq!(crjson.manifests.len(), reader.manifests().len());
+        assert_eq!(
+            Some(crjson.manifests[0].label.as_str()),
+            reader.active_label(),
+            "the active manifest comes first"
+        );
+
+        let active = reader.active_manifest().unwrap();
+        let exported = &crjson.manifests[0];
+        let claim = exported.claim.as_ref().or(exported.claim_v2.as_ref());
+        assert_eq!(claim.unwrap().title.as_deref(), active.title());
+        assert_eq!(
+            exported.assertions.len(),
+            active.assertions().len() + active.ingredients().len()
+        );
+        let ingredient = exported
+            .assertions
+            .iter()
+            .find(|(label, _)| label.starts_with(labels::INGREDIENT))
+            .unwrap()
+            .1;
+        assert!(ingredient.get("relationship").is_some());
+        assert_eq!(
+            exported.signature.as_ref().unwrap().algorithm.as_deref(),
+            Some("ps256")
+        );
+
+        let json = reader.crjson().unwrap();
+        let restored = Reader::from_crjson(&json).unwrap();
+        assert_eq!(restored.active_label(), reader.active_label());
+        assert_eq!(restored.validation_state(), reader.validation_state());
+        assert_eq!(
+            restored.active_manifest().unwrap().assertions().len(),
+            active.assertions().len()
+        );
+        assert_eq!(
+            restored.active_manifest().unwrap().ingredients().len(),
+            active.ingredients().len()
+        );
+        assert_eq!(
+            restored.active_manifest().unwrap().instance_id(),
+            active.instance_id()
+        );
+
+        // exporting the restored store gives the same manifests
+        assert_eq!(
+            CrJson::from_reader(&restored).unwrap().manifests,
+            crjson.manifests
+        );
+    }
+}
@@ -176,6 +176,14 @@ impl PkiStatusInfo {
             })
         })
     }
+
+    pub fn encode_ref(&self) -> impl Values + '_ {
+        encode::sequence((
+            self.status.encode(),
+            self.status_string.as_ref().map(|s| s.encode_ref()),
+            self.fail_info.map(|fail_info| fail_info.encode()),
+        ))
+    }
 }
 
 /// PKI status.
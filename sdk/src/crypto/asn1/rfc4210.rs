@@ -8,6 +8,7 @@
 
 use bcder::{
     decode::{Constructed, DecodeError, Source},
+    encode::{self, Values},
     Tag, Utf8String,
 };
 
@@ -39,4 +40,8 @@ impl PkiFreeText {
 
         Ok(Self(res))
     }
+
+    pub fn encode_ref(&self) -> impl Values + '_ {
+        encode::sequence(encode::iter(self.0.iter().map(|s| s.encode_ref())))
+    }
 }
@@ -0,0 +1,402 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use async_generic::async_generic;
+use x509_parser::{
+    certificate::X509Certificate,
+    der_parser::{oid, Oid},
+    extensions::{GeneralName, ParsedExtension},
+    pem::Pem,
+    prelude::FromDer,
+};
+
+use crate::crypto::cose::InvalidCertificateError;
+
+/// Longest chain that will be built when completing a certificate chain.
+///
+/// Guards against loops and unreasonably deep hierarchies.
+const MAX_CHAIN_LENGTH: usize = 10;
+
+/// A pool of intermediate CA certificates that can be used to complete
+/// certificate chains that are missing one or more issuers.
+///
+/// Intermediates in this pool are never trusted on their own; they are only
+/// used to connect an end-entity certificate to a configured trust anchor.
+#[derive(Clone, Debug, Default)]
+pub struct IntermediateCertificateStore {
+    /// Intermediate certificates in DER format.
+    ders: Vec<Vec<u8>>,
+}
+
+impl IntermediateCertificateStore {
+    /// Create an empty intermediate certificate store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add zero or more intermediate certificates in PEM format.
+    pub fn add_intermediates(&mut self, pems: &[u8]) -> Result<(), InvalidCertificateError> {
+        for maybe_pem in Pem::iter_from_buffer(pems) {
+            match maybe_pem {
+                Ok(pem) => self.add_intermediate_der(pem.contents),
+                Err(e) => {
+                    return Err(InvalidCertificateError(e.to_string()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add a single intermediate certificate in DER format.
+    pub fn add_intermediate_der(&mut self, der: Vec<u8>) {
+        if !self.ders.contains(&der) {
+            self.ders.push(der);
+        }
+    }
+
+    /// Return `true` if no intermediates have been added.
+    pub fn is_empty(&self) -> bool {
+        self.ders.is_empty()
+    }
+
+    /// Remove all intermediates.
+    pub fn clear(&mut self) {
+        self.ders.clear();
+    }
+
+    /// Extend `chain_der` with issuers found in this store.
+    ///
+    /// `chain_der` may or may not start with `end_entity_cert_der`. Issuers
+    /// are appended in order until a self-issued certificate is reached or no
+    /// issuer can be found. Root certificates are not added.
+    pub fn complete_chain(
+        &self,
+        end_entity_cert_der: &[u8],
+        chain_der: &[Vec<u8>],
+    ) -> Vec<Vec<u8>> {
+        let mut chain = chain_der.to_vec();
+
+        while chain.len() < MAX_CHAIN_LENGTH {
+            let tip = chain
+                .last()
+                .map(|c| c.as_slice())
+                .unwrap_or(end_entity_cert_der);
+            let Some(issuer) = find_issuer(tip, &self.ders, end_entity_cert_der, &chain) else {
+                break;
+            };
+            chain.push(issuer);
+        }
+
+        chain
+    }
+}
+
+/// Complete a signing certificate chain (end-entity certificate first).
+///
+/// Missing issuers are taken from `intermediates` first. If `fetch_ca_issuers`
+/// is `true`, any that remain are retrieved from the `caIssuers` URLs found in
+/// the Authority Information Access (AIA) extension of each certificate.
+///
+/// Returns the chain unchanged if it is empty or can't be extended.
+#[allow(unused_variables)] // `fetch_ca_issuers` is unused on WASM
+#[async_generic]
+pub(crate) fn complete_certificate_chain(
+    certs: &[Vec<u8>],
+    intermediates: &IntermediateCertificateStore,
+    fetch_ca_issuers: bool,
+) -> Vec<Vec<u8>> {
+    let Some(end_entity_cert_der) = certs.first() else {
+        return vec![];
+    };
+
+    let mut chain = intermediates.complete_chain(end_entity_cert_der, &certs[1..]);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    while fetch_ca_issuers && chain.len() + 1 < MAX_CHAIN_LENGTH {
+        let tip = chain.last().unwrap_or(end_entity_cert_der);
+        let candidates = if _sync {
+            fetch_ca_issuers_certs(tip)
+        } else {
+            fetch_ca_issuers_certs_async(tip).await
+        };
+
+        let Some(issuer) = find_issuer(tip, &candidates, end_entity_cert_der, &chain) else {
+            break;
+        };
+        chain.push(issuer);
+
+        // The fetched issuer may itself have issuers in the local pool.
+        chain = intermediates.complete_chain(end_entity_cert_der, &chain);
+    }
+
+    let mut full_chain = Vec::with_capacity(chain.len() + 1);
+    full_chain.push(end_entity_cert_der.clone());
+    full_chain.append(&mut chain);
+    full_chain
+}
+
+/// Find the certificate in `candidates` that issued `cert_der`.
+///
+/// Self-issued candidates (roots) and certificates already in the chain are
+/// skipped. Returns `None` if `cert_der` is itself self-issued.
+fn find_issuer(
+    cert_der: &[u8],
+    candidates: &[Vec<u8>],
+    end_entity_cert_der: &[u8],
+    chain: &[Vec<u8>],
+) -> Option<Vec<u8>> {
+    let (_, cert) = X509Certificate::from_der(cert_der).ok()?;
+    if cert.subject().as_raw() == cert.issuer().as_raw() {
+        return None;
+    }
+
+    let authority_key_id = authority_key_id(&cert);
+
+    candidates
+        .iter()
+        .filter(|der| der.as_slice() != end_entity_cert_der && !chain.contains(der))
+        .find(|der| {
+            let Ok((_, candidate)) = X509Certificate::from_der(der) else {
+                return false;
+            };
+
+            if candidate.subject().as_raw() != cert.issuer().as_raw()
+                || candidate.subject().as_raw() == candidate.issuer().as_raw()
+            {
+                return false;
+            }
+
+            match (authority_key_id, subject_key_id(&candidate)) {
+                (Some(aki), Some(ski)) => aki == ski,
+                _ => true,
+            }
+        })
+        .cloned()
+}
+
+pub(crate) fn authority_key_id<'a>(cert: &'a X509Certificate<'a>) -> Option<&'a [u8]> {
+    cert.extensions()
+        .iter()
+        .find_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::AuthorityKeyIdentifier(aki) => aki.key_identifier.as_ref(),
+            _ => None,
+        })
+        .map(|kid| kid.0)
+}
+
+pub(crate) fn subject_key_id<'a>(cert: &'a X509Certificate<'a>) -> Option<&'a [u8]> {
+    cert.extensions()
+        .iter()
+        .find_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::SubjectKeyIdentifier(kid) => Some(kid.0),
+            _ => None,
+        })
+}
+
+/// Return the access locations in a certificate's Authority Information
+/// Access extension that use the given access method.
+pub(crate) fn aia_access_locations(cert: &X509Certificate, access_method: &Oid) -> Vec<String> {
+    let Ok(em) = cert.extensions_map() else {
+        return vec![];
+    };
+
+    let Some(aia_extension) = em.get(&AUTHORITY_INFO_ACCESS_OID) else {
+        return vec![];
+    };
+
+    let ParsedExtension::AuthorityInfoAccess(aia) = aia_extension.parsed_extension() else {
+        return vec![];
+    };
+
+    aia.accessdescs
+        .iter()
+        .filter(|ad| &ad.access_method == access_method)
+        .filter_map(|ad| match ad.access_location {
+            GeneralName::URI(uri) => Some(uri.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Retrieve the certificates published at the `caIssuers` URLs of a
+/// certificate.
+///
+/// Only HTTP(S) URLs are followed. Failures are ignored so that signing and
+/// validation can proceed with whatever chain is available.
+#[cfg(not(target_arch = "wasm32"))]
+#[async_generic]
+fn fetch_ca_issuers_certs(cert_der: &[u8]) -> Vec<Vec<u8>> {
+    let Ok((_, cert)) = X509Certificate::from_der(cert_der) else {
+        return vec![];
+    };
+
+    let mut certs = vec![];
+    for url in aia_access_locations(&cert, &AD_CA_ISSUERS_OID) {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            continue;
+        }
+
+        let body = if _sync {
+            http_get(&url)
+        } else {
+            http_get_async(&url).await
+        };
+
+        if let Some(body) = body {
+            certs.append(&mut parse_ca_issuers_response(&body));
+        }
+    }
+
+    certs
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_CA_ISSUERS_RESPONSE_LEN: u64 = 1000000;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn http_get(url: &str) -> Option<Vec<u8>> {
+    use std::io::Read;
+
+    let response = ureq::get(url).call().ok()?;
+    let mut bytes = vec![];
+    response
+        .into_body()
+        .into_reader()
+        .take(MAX_CA_ISSUERS_RESPONSE_LEN)
+        .read_to_end(&mut bytes)
+        .ok()?;
+
+    Some(bytes)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn http_get_async(url: &str) -> Option<Vec<u8>> {
+    let mut response = reqwest::get(url).await.ok()?.error_for_status().ok()?;
+
+    let mut bytes = vec![];
+    while let Some(chunk) = response.chunk().await.ok()? {
+        if bytes.len() as u64 + chunk.len() as u64 > MAX_CA_ISSUERS_RESPONSE_LEN {
+            return None;
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+
+    Some(bytes)
+}
+
+/// Parse a `caIssuers` response.
+///
+/// [RFC 5280, §4.2.2.1] allows either a single DER-encoded certificate or a
+/// "certs-only" CMS message. PEM is also accepted since some servers publish
+/// it.
+///
+/// [RFC 5280, §4.2.2.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.2.1
+pub(crate) fn parse_ca_issuers_response(body: &[u8]) -> Vec<Vec<u8>> {
+    if X509Certificate::from_der(body).is_ok() {
+        return vec![body.to_vec()];
+    }
+
+    if let Ok(content_info) = rasn::der::decode::<rasn_cms::ContentInfo>(body) {
+        if content_info.content_type == rasn_cms::CONTENT_SIGNED_DATA {
+            if let Ok(signed_data) =
+                rasn::der::decode::<rasn_cms::SignedData>(content_info.content.as_bytes())
+            {
+                let Some(certs) = signed_data.certificates else {
+                    return vec![];
+                };
+                return certs
+                    .to_vec()
+                    .into_iter()
+                    .filter_map(|choice| match choice {
+                        rasn_cms::CertificateChoices::Certificate(cert) => {
+                            rasn::der::encode(cert.as_ref()).ok()
+                        }
+                        _ => None,
+                    })
+                    .collect();
+            }
+        }
+    }
+
+    Pem::iter_from_buffer(body)
+        .filter_map(|pem| pem.ok())
+        .map(|pem| pem.contents)
+        .collect()
+}
+
+pub(crate) const AUTHORITY_INFO_ACCESS_OID: Oid<'static> = oid!(1.3.6 .1 .5 .5 .7 .1 .1);
+#[allow(dead_code)] // not used on WASM
+pub(crate) const AD_CA_ISSUERS_OID: Oid<'static> = oid!(1.3.6 .1 .5 .5 .7 .48 .2);
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use x509_parser::pem::Pem;
+
+    use super::*;
+
+    fn cert_ders(pems: &[u8]) -> Vec<Vec<u8>> {
+        Pem::iter_from_buffer(pems)
+            .map(|pem| pem.unwrap().contents)
+            .collect()
+    }
+
+    #[test]
+    fn completes_chain_from_store() {
+        let certs = cert_ders(include_bytes!("../../../tests/fixtures/certs/es256.pub"));
+        assert_eq!(certs.len(), 2);
+
+        let mut store = IntermediateCertificateStore::new();
+        store.add_intermediate_der(certs[1].clone());
+
+        let chain = complete_certificate_chain(&certs[..1], &store, false);
+        assert_eq!(chain, certs);
+
+        // The chain in the store is used as is when already complete.
+        let chain = complete_certificate_chain(&certs, &store, false);
+        assert_eq!(chain, certs);
+
+        // Intermediates that didn't issue the certificate are ignored.
+        let other = cert_ders(include_bytes!("../../../tests/fixtures/certs/ps256.pub"));
+        let mut store = IntermediateCertificateStore::new();
+        store.add_intermediate_der(other[1].clone());
+
+        let chain = complete_certificate_chain(&certs[..1], &store, false);
+        assert_eq!(chain, certs[..1]);
+    }
+
+    #[test]
+    fn roots_are_not_added() {
+        let certs = cert_ders(include_bytes!("../../../tests/fixtures/certs/es256.pub"));
+        let mut store = IntermediateCertificateStore::new();
+        store
+            .add_intermediates(include_bytes!(
+                "../../../tests/fixtures/certs/trust/test_cert_root_bundle.pem"
+            ))
+            .unwrap();
+
+        assert_eq!(store.complete_chain(&certs[0], &certs[1..]), certs[1..]);
+    }
+
+    #[test]
+    fn parses_ca_issuers_responses() {
+        let pems = include_bytes!("../../../tests/fixtures/certs/es256.pub");
+        let certs = cert_ders(pems);
+
+        assert_eq!(parse_ca_issuers_response(&certs[1]), certs[1..]);
+        assert_eq!(parse_ca_issuers_response(pems), certs);
+        assert!(parse_ca_issuers_response(b"not a certificate").is_empty());
+    }
+}
@@ -25,7 +25,11 @@ use x509_parser::{
 };
 
 use crate::{
-    crypto::{asn1::rfc3161::TstInfo, cose::CertificateTrustPolicy},
+    crypto::{
+        asn1::rfc3161::TstInfo,
+        cose::CertificateTrustPolicy,
+        raw_signature::{ml_dsa, oids::is_ml_dsa_oid},
+    },
     log_item,
     status_tracker::StatusTracker,
     validation_results::validation_codes::*,
@@ -163,7 +167,8 @@ pub fn check_certificate_profile(
         || *cert_alg == ECDSA_WITH_SHA384_OID
         || *cert_alg == ECDSA_WITH_SHA512_OID
         || *cert_alg == RSASSA_PSS_OID
-        || *cert_alg == ED25519_OID)
+        || *cert_alg == ED25519_OID
+        || is_ml_dsa_oid(cert_alg))
     {
         log_item!(
             "",
@@ -265,10 +270,36 @@ pub fn check_certificate_profile(
         }
     }
 
-    // CHeck curves for SPKI EC algorithms.
+    // ML-DSA signature algorithm identifiers must omit parameters (RFC 9881).
+    if is_ml_dsa_oid(cert_alg) && signcert.signature_algorithm.parameters.is_some() {
+        log_item!(
+            "",
+            "certificate algorithm error",
+            "check_certificate_profile"
+        )
+        .validation_status(SIGNING_CREDENTIAL_INVALID)
+        .failure_no_throw(validation_log, CertificateProfileError::InvalidCertificate);
+
+        return Err(CertificateProfileError::InvalidCertificate);
+    }
+
     let pk = signcert.public_key();
     let skpi_alg = &pk.algorithm;
 
+    // ML-DSA public keys must omit parameters and match the parameter set size.
+    if is_ml_dsa_oid(&skpi_alg.algorithm) && ml_dsa::alg_from_spki(pk.raw).is_none() {
+        log_item!(
+            "",
+            "certificate invalid ML-DSA public key",
+            "check_certificate_profile"
+        )
+        .validation_status(SIGNING_CREDENTIAL_INVALID)
+        .failure_no_throw(validation_log, CertificateProfileError::InvalidCertificate);
+
+        return Err(CertificateProfileError::InvalidCertificate);
+    }
+
+    // CHeck curves for SPKI EC algorithms.
     if skpi_alg.algorithm == EC_PUBLICKEY_OID {
         if let Some(parameters) = &skpi_alg.parameters {
             let named_curve_oid = parameters
@@ -630,6 +661,15 @@ mod tests {
         check_end_entity_certificate_profile(&es384_cert, &ctp, &mut validation_log, None).unwrap();
         check_end_entity_certificate_profile(&es512_cert, &ctp, &mut validation_log, None).unwrap();
         check_end_entity_certificate_profile(&ps256_cert, &ctp, &mut validation_log, None).unwrap();
+
+        for cert_pem in [
+            include_bytes!("../../../tests/fixtures/crypto/raw_signature/mldsa44.pub").as_slice(),
+            include_bytes!("../../../tests/fixtures/crypto/raw_signature/mldsa65.pub").as_slice(),
+            include_bytes!("../../../tests/fixtures/crypto/raw_signature/mldsa87.pub").as_slice(),
+        ] {
+            let cert = x509_der_from_pem(cert_pem);
+            check_end_entity_certificate_profile(&cert, &ctp, &mut validation_log, None).unwrap();
+        }
     }
 
     fn x509_der_from_pem(cert_pem: &[u8]) -> Vec<u8> {
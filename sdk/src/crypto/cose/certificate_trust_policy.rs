@@ -13,14 +13,34 @@
 
 #![allow(clippy::doc_lazy_continuation)] // Clippy and rustfmt aren't agreeing at the moment. :-(
 
-use std::{collections::HashSet, error::Error, fmt, io::BufRead, str::FromStr};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+    io::BufRead,
+    str::FromStr,
+};
 
 use asn1_rs::{oid, Oid};
 use async_generic::async_generic;
 use thiserror::Error;
-use x509_parser::{extensions::ExtendedKeyUsage, pem::Pem};
-
-use crate::crypto::{base64, hash::sha256};
+use x509_parser::{
+    extensions::ExtendedKeyUsage,
+    pem::Pem,
+    prelude::{FromDer, X509Certificate},
+    time::ASN1Time,
+};
+
+use crate::crypto::{
+    base64,
+    cose::{
+        authority_key_id, subject_key_id, CertificateSummary, IntermediateCertificateStore,
+        TrustList, TrustMatch, TrustRejection, TrustReport, TrustSource, TrustWindow,
+    },
+    hash::sha256,
+    internal::time,
+};
 
 /// Enum to describe the type of trust anchor that validated the certificate.
 #[derive(Debug, Eq, PartialEq)]
@@ -38,6 +58,41 @@ pub enum TrustAnchorType {
     NoCheck,
 }
 
+/// Identifies the role a [`CertificateTrustPolicy`] is configured for.
+///
+/// Claim signers, time stamp authorities, and CAWG X.509 identity signers can
+/// each be evaluated against their own trust anchors. The role of the policy
+/// that was applied is reported alongside the resulting validation status.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TrustPolicyRole {
+    /// Policy for the certificate that signed the C2PA claim.
+    #[default]
+    ClaimSigner,
+
+    /// Policy for time stamp authority (TSA) certificates.
+    TimeStamp,
+
+    /// Policy for X.509 certificates used in CAWG identity assertions.
+    CawgIdentity,
+}
+
+impl TrustPolicyRole {
+    /// Returns the name used for this role in validation reports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ClaimSigner => "claim_signer",
+            Self::TimeStamp => "time_stamp",
+            Self::CawgIdentity => "cawg_identity",
+        }
+    }
+}
+
+impl fmt::Display for TrustPolicyRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// A `CertificateTrustPolicy` is configured with information about trust
 /// anchors, privately-accepted end-entity certificates, and allowed EKUs. It
 /// can be used to evaluate a signing certificate against those policies.
@@ -56,8 +111,22 @@ pub struct CertificateTrustPolicy {
     /// Additional extended key usage (EKU) OIDs.
     additional_ekus: HashSet<String>,
 
+    /// Intermediate certificates used to complete chains that are missing
+    /// issuers.
+    intermediates: IntermediateCertificateStore,
+
+    /// Trust windows for anchors loaded from a structured trust list, keyed by
+    /// base-64 encoded SHA-256 hash of the anchor's DER encoding.
+    anchor_windows: HashMap<String, TrustWindow>,
+
     /// passthrough mode
     passthrough: bool,
+
+    /// The role this policy is configured for.
+    role: TrustPolicyRole,
+
+    /// Separate policy for time stamp authority certificates, if configured.
+    time_stamp_policy: Option<Box<CertificateTrustPolicy>>,
 }
 
 impl Default for CertificateTrustPolicy {
@@ -67,7 +136,11 @@ impl Default for CertificateTrustPolicy {
             user_trust_anchor_ders: vec![],
             end_entity_cert_set: HashSet::default(),
             additional_ekus: HashSet::default(),
+            intermediates: IntermediateCertificateStore::default(),
+            anchor_windows: HashMap::default(),
             passthrough: false,
+            role: TrustPolicyRole::default(),
+            time_stamp_policy: None,
         };
 
         this.add_valid_ekus(include_bytes!("./valid_eku_oids.cfg"));
@@ -97,7 +170,11 @@ impl CertificateTrustPolicy {
             user_trust_anchor_ders: vec![],
             end_entity_cert_set: HashSet::default(),
             additional_ekus: HashSet::default(),
+            intermediates: IntermediateCertificateStore::default(),
+            anchor_windows: HashMap::default(),
             passthrough: false,
+            role: TrustPolicyRole::default(),
+            time_stamp_policy: None,
         }
     }
 
@@ -108,10 +185,100 @@ impl CertificateTrustPolicy {
             user_trust_anchor_ders: vec![],
             end_entity_cert_set: HashSet::default(),
             additional_ekus: HashSet::default(),
+            intermediates: IntermediateCertificateStore::default(),
+            anchor_windows: HashMap::default(),
             passthrough: true,
+            role: TrustPolicyRole::default(),
+            time_stamp_policy: None,
         }
     }
 
+    /// Build the policy described by a trust section of the settings (for
+    /// example `timestamp_trust` or `cawg_trust`).
+    ///
+    /// The policy starts with the default set of EKUs and no trust anchors.
+    /// Returns `None` if the section configures no trust anchors or allowed
+    /// certificates, in which case the caller should fall back to its default
+    /// policy. Returns an error if any of the configured certificates or the
+    /// trust list can't be parsed.
+    pub(crate) fn from_settings(
+        section: &str,
+        role: TrustPolicyRole,
+    ) -> Result<Option<Self>, InvalidCertificateError> {
+        let setting = |key: &str| {
+            crate::settings::get_settings_value::<Option<String>>(&format!("{section}.{key}"))
+                .ok()
+                .flatten()
+        };
+
+        let mut ctp = Self::new();
+        ctp.add_valid_ekus(include_bytes!("./valid_eku_oids.cfg"));
+        ctp.role = role;
+
+        if let Some(ta) = setting("trust_anchors") {
+            ctp.add_trust_anchors(ta.as_bytes())?;
+        }
+
+        if let Some(tl) = setting("trust_list") {
+            ctp.add_trust_list(&TrustList::from_json(tl.as_bytes())?);
+        }
+
+        if let Some(pa) = setting("user_anchors") {
+            ctp.add_user_trust_anchors(pa.as_bytes())?;
+        }
+
+        if let Some(tc) = setting("trust_config") {
+            ctp.add_valid_ekus(tc.as_bytes());
+        }
+
+        if let Some(al) = setting("allowed_list") {
+            ctp.add_end_entity_credentials(al.as_bytes())?;
+        }
+
+        if let Some(im) = setting("intermediates") {
+            ctp.add_intermediate_certificates(im.as_bytes())?;
+        }
+
+        if ctp.trust_anchor_ders.is_empty()
+            && ctp.user_trust_anchor_ders.is_empty()
+            && ctp.end_entity_cert_set.is_empty()
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(ctp))
+    }
+
+    /// Returns the role this policy is configured for.
+    pub fn role(&self) -> TrustPolicyRole {
+        self.role
+    }
+
+    /// Set the role this policy is configured for.
+    ///
+    /// The role is reported with validation statuses produced by this policy.
+    pub fn set_role(&mut self, role: TrustPolicyRole) {
+        self.role = role;
+    }
+
+    /// Use a separate policy to evaluate time stamp authority certificates.
+    ///
+    /// Without one, time stamps are evaluated against this policy.
+    pub fn set_time_stamp_policy(&mut self, mut policy: CertificateTrustPolicy) {
+        policy.time_stamp_policy = None;
+        self.time_stamp_policy = Some(Box::new(policy));
+    }
+
+    /// Returns the policy used to evaluate time stamp authority certificates.
+    ///
+    /// This is the policy set via [`set_time_stamp_policy`] if any, otherwise
+    /// this policy.
+    ///
+    /// [`set_time_stamp_policy`]: Self::set_time_stamp_policy
+    pub fn time_stamp_policy(&self) -> &CertificateTrustPolicy {
+        self.time_stamp_policy.as_deref().unwrap_or(self)
+    }
+
     /// Evaluate a certificate against the trust policy described by this
     /// struct.
     ///
@@ -122,13 +289,60 @@ impl CertificateTrustPolicy {
     /// If `signing_time_epoch` is provided, evaluates the signing time (which
     /// must be in Unix seconds since the epoch) against the certificate's
     /// period of validity.
-    #[allow(unused)] // parameters may be unused in some cases
     #[async_generic]
     pub fn check_certificate_trust(
         &self,
         chain_der: &[Vec<u8>],
         end_entity_cert_der: &[u8],
         signing_time_epoch: Option<i64>,
+    ) -> Result<TrustAnchorType, CertificateTrustError> {
+        let chain_der = self.evaluated_chain(chain_der, end_entity_cert_der);
+        self.check_evaluated_chain(&chain_der, end_entity_cert_der, signing_time_epoch)
+    }
+
+    /// Evaluate a certificate as [`check_certificate_trust`] does and also
+    /// return a [`TrustReport`] explaining the outcome.
+    ///
+    /// [`check_certificate_trust`]: Self::check_certificate_trust
+    #[async_generic]
+    pub fn check_certificate_trust_with_report(
+        &self,
+        chain_der: &[Vec<u8>],
+        end_entity_cert_der: &[u8],
+        signing_time_epoch: Option<i64>,
+    ) -> (Result<TrustAnchorType, CertificateTrustError>, TrustReport) {
+        let chain_der = self.evaluated_chain(chain_der, end_entity_cert_der);
+        let result =
+            self.check_evaluated_chain(&chain_der, end_entity_cert_der, signing_time_epoch);
+        let report =
+            self.trust_report(&chain_der, end_entity_cert_der, signing_time_epoch, &result);
+
+        (result, report)
+    }
+
+    // The chain as it is evaluated: any intermediates the signer left out are
+    // filled in from the policy's intermediate store.
+    fn evaluated_chain<'a>(
+        &self,
+        chain_der: &'a [Vec<u8>],
+        end_entity_cert_der: &[u8],
+    ) -> Cow<'a, [Vec<u8>]> {
+        if self.intermediates.is_empty() {
+            Cow::Borrowed(chain_der)
+        } else {
+            Cow::Owned(
+                self.intermediates
+                    .complete_chain(end_entity_cert_der, chain_der),
+            )
+        }
+    }
+
+    #[allow(unused)] // parameters may be unused in some cases
+    fn check_evaluated_chain(
+        &self,
+        chain_der: &[Vec<u8>],
+        end_entity_cert_der: &[u8],
+        signing_time_epoch: Option<i64>,
     ) -> Result<TrustAnchorType, CertificateTrustError> {
         if self.passthrough {
             return Ok(TrustAnchorType::NoCheck);
@@ -166,6 +380,207 @@ impl CertificateTrustPolicy {
         ))
     }
 
+    // Describe the path that was evaluated and why each trust source did or
+    // did not accept it. `result` is the outcome of the actual trust check;
+    // anchors are related to the path by name and key identifier only.
+    fn trust_report(
+        &self,
+        chain_der: &[Vec<u8>],
+        end_entity_cert_der: &[u8],
+        signing_time_epoch: Option<i64>,
+        result: &Result<TrustAnchorType, CertificateTrustError>,
+    ) -> TrustReport {
+        let mut report = TrustReport {
+            trust_policy: self.role.as_str().to_string(),
+            trusted: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.to_string()),
+            ..Default::default()
+        };
+
+        let Ok((_, end_entity_cert)) = X509Certificate::from_der(end_entity_cert_der) else {
+            report.rejections.push(TrustRejection::new(
+                "end-entity certificate",
+                "the certificate could not be parsed",
+            ));
+            return report;
+        };
+
+        // The path as the trust check saw it.
+        let path: Vec<X509Certificate> = std::iter::once(end_entity_cert_der)
+            .chain(
+                chain_der
+                    .iter()
+                    .map(Vec::as_slice)
+                    .filter(|der| *der != end_entity_cert_der),
+            )
+            .filter_map(|der| X509Certificate::from_der(der).ok().map(|(_, cert)| cert))
+            .collect();
+        report.chain = path.iter().map(CertificateSummary::from_cert).collect();
+
+        if self.passthrough {
+            return report;
+        }
+
+        let mut rejections = Vec::new();
+
+        match end_entity_cert.extended_key_usage() {
+            Ok(Some(eku)) => match self.has_allowed_eku(eku.value) {
+                Some(oid) => report.eku = Some(oid.to_id_string()),
+                None => rejections.push(TrustRejection::new(
+                    "extended key usage",
+                    "none of the certificate's EKUs are allowed by the policy",
+                )),
+            },
+            _ => rejections.push(TrustRejection::new(
+                "extended key usage",
+                "the certificate has no extended key usage extension",
+            )),
+        }
+
+        if self
+            .end_entity_cert_set
+            .contains(&base64_sha256_cert_der(end_entity_cert_der))
+        {
+            report.matched = Some(TrustMatch {
+                source: TrustSource::AllowedList,
+                certificate: CertificateSummary::from_cert(&end_entity_cert),
+            });
+        } else if !self.end_entity_cert_set.is_empty() {
+            rejections.push(TrustRejection::new(
+                "allowed list",
+                "the end-entity certificate is not on the allowed list",
+            ));
+        }
+
+        for pair in path.windows(2) {
+            if pair[0].issuer().as_raw() != pair[1].subject().as_raw() {
+                rejections.push(TrustRejection::new(
+                    pair[0].subject().to_string(),
+                    format!(
+                        "the issuer does not match the subject of the next certificate ({})",
+                        pair[1].subject()
+                    ),
+                ));
+            }
+        }
+
+        if let Some(signing_time) =
+            signing_time_epoch.and_then(|t| ASN1Time::from_timestamp(t).ok())
+        {
+            for cert in &path {
+                if !cert.validity().is_valid_at(signing_time) {
+                    rejections.push(TrustRejection::new(
+                        cert.subject().to_string(),
+                        "the certificate was not valid at the signing time",
+                    ));
+                }
+            }
+        }
+
+        if self.trust_anchor_ders.is_empty()
+            && self.user_trust_anchor_ders.is_empty()
+            && self.end_entity_cert_set.is_empty()
+        {
+            rejections.push(TrustRejection::new(
+                "trust anchors",
+                "the policy has no trust anchors",
+            ));
+        }
+
+        let trust_time = signing_time_epoch.unwrap_or_else(|| time::utc_now().timestamp());
+        let matched_source = match result {
+            Ok(TrustAnchorType::System) => Some(TrustSource::System),
+            Ok(TrustAnchorType::User) => Some(TrustSource::User),
+            _ => None,
+        };
+
+        let mut unrelated_anchors = 0;
+        for (source, anchor_ders) in [
+            (TrustSource::System, &self.trust_anchor_ders),
+            (TrustSource::User, &self.user_trust_anchor_ders),
+        ] {
+            for anchor_der in anchor_ders {
+                let Ok((_, anchor)) = X509Certificate::from_der(anchor_der) else {
+                    rejections.push(TrustRejection::new(
+                        "trust anchor",
+                        "the anchor could not be parsed",
+                    ));
+                    continue;
+                };
+
+                // Anchors are matched against the certificate closest to the
+                // root first, since that's where a complete path ends.
+                let Some(issued) = path
+                    .iter()
+                    .rev()
+                    .find(|cert| cert.issuer().as_raw() == anchor.subject().as_raw())
+                else {
+                    unrelated_anchors += 1;
+                    continue;
+                };
+
+                let anchor_name = anchor.subject().to_string();
+
+                if let (Some(aki), Some(ski)) = (authority_key_id(issued), subject_key_id(&anchor))
+                {
+                    if aki != ski {
+                        rejections.push(TrustRejection::new(
+                            anchor_name,
+                            format!(
+                                "the anchor's key identifier does not match the issuer of {}",
+                                issued.subject()
+                            ),
+                        ));
+                        continue;
+                    }
+                }
+
+                if let Some(window) = self.anchor_windows.get(&base64_sha256_cert_der(anchor_der)) {
+                    if !window.contains(trust_time) {
+                        rejections.push(TrustRejection::new(
+                            anchor_name,
+                            "the anchor was not trusted at the signing time",
+                        ));
+                        continue;
+                    }
+                }
+
+                if matched_source == Some(source) && report.matched.is_none() {
+                    let certificate = CertificateSummary::from_cert(&anchor);
+                    if report.chain.last() != Some(&certificate) {
+                        report.chain.push(certificate.clone());
+                    }
+                    report.matched = Some(TrustMatch {
+                        source,
+                        certificate,
+                    });
+                } else if result.is_err() {
+                    rejections.push(TrustRejection::new(
+                        anchor_name,
+                        format!(
+                            "the anchor matches the issuer of {}, but the path was rejected",
+                            issued.subject()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if unrelated_anchors > 0 {
+            rejections.push(TrustRejection::new(
+                "trust anchors",
+                format!("{unrelated_anchors} trust anchors do not match any issuer in the path"),
+            ));
+        }
+
+        // Alternatives don't need explaining once something matched.
+        if report.matched.is_none() {
+            report.rejections = rejections;
+        }
+
+        report
+    }
+
     /// Add trust anchors (root X.509 certificates) that shall be accepted when
     /// verifying COSE signatures.
     ///
@@ -231,6 +646,38 @@ impl CertificateTrustPolicy {
         Ok(())
     }
 
+    /// Add the anchors of a C2PA [`TrustList`] as trust anchors.
+    ///
+    /// Unlike [`add_trust_anchors`], each anchor is only trusted for
+    /// signatures made within its trust window on the list.
+    ///
+    /// [`add_trust_anchors`]: Self::add_trust_anchors
+    pub fn add_trust_list(&mut self, trust_list: &TrustList) {
+        for entry in trust_list.entries() {
+            self.anchor_windows
+                .insert(base64_sha256_cert_der(&entry.certificate_der), entry.window);
+            self.trust_anchor_ders.push(entry.certificate_der.clone());
+        }
+    }
+
+    /// Add intermediate CA certificates that may be used to complete a
+    /// certificate chain during trust evaluation.
+    ///
+    /// Signers are expected to include every certificate between the signing
+    /// certificate and the trust anchor, but some only provide the end-entity
+    /// certificate. When a chain is missing issuers, matching certificates
+    /// from this pool are appended to it before it is evaluated.
+    ///
+    /// This function reads zero or more X.509 certificates in PEM format.
+    /// Intermediates are not trust anchors; a completed chain must still lead
+    /// to one of the configured trust anchors.
+    pub fn add_intermediate_certificates(
+        &mut self,
+        intermediate_pems: &[u8],
+    ) -> Result<(), InvalidCertificateError> {
+        self.intermediates.add_intermediates(intermediate_pems)
+    }
+
     /// Add individual end-entity credentials that shall be accepted when
     /// verifying COSE signatures.
     ///
@@ -333,26 +780,53 @@ impl CertificateTrustPolicy {
         }
     }
 
-    /// Remove all trust anchors, private credentials, and EKUs previously
-    /// configured.
+    /// Remove all trust anchors, private credentials, intermediates, EKUs, and
+    /// any separate time stamp policy previously configured.
     pub fn clear(&mut self) {
         self.trust_anchor_ders.clear();
+        self.anchor_windows.clear();
         self.end_entity_cert_set.clear();
         self.additional_ekus.clear();
+        self.intermediates.clear();
+        self.time_stamp_policy = None;
     }
 
-    /// Return an iterator over the trust anchors.
+    /// Return an iterator over the trust anchors that were trusted at the
+    /// signing time (or now, if there is none).
     ///
-    /// Each anchor will be returned in DER format.
-    pub(crate) fn trust_anchor_ders(&self) -> impl Iterator<Item = &'_ Vec<u8>> {
-        self.trust_anchor_ders.iter()
+    /// Each anchor will be returned in DER format. Anchors from a structured
+    /// trust list only count within their trust window; all other anchors are
+    /// always returned.
+    pub(crate) fn trust_anchor_ders_at(
+        &self,
+        signing_time_epoch: Option<i64>,
+    ) -> impl Iterator<Item = &'_ Vec<u8>> {
+        let trust_time = signing_time_epoch.unwrap_or_else(|| time::utc_now().timestamp());
+        self.trust_anchor_ders
+            .iter()
+            .filter(move |der| self.anchor_trusted_at(der, trust_time))
     }
 
-    /// Return an iterator over the user trust anchors.
+    /// Return an iterator over the user trust anchors that were trusted at
+    /// the signing time (or now, if there is none).
     ///
     /// Each anchor will be returned in DER format.
-    pub(crate) fn user_trust_anchor_ders(&self) -> impl Iterator<Item = &'_ Vec<u8>> {
-        self.user_trust_anchor_ders.iter()
+    pub(crate) fn user_trust_anchor_ders_at(
+        &self,
+        signing_time_epoch: Option<i64>,
+    ) -> impl Iterator<Item = &'_ Vec<u8>> {
+        let trust_time = signing_time_epoch.unwrap_or_else(|| time::utc_now().timestamp());
+        self.user_trust_anchor_ders
+            .iter()
+            .filter(move |der| self.anchor_trusted_at(der, trust_time))
+    }
+
+    fn anchor_trusted_at(&self, anchor_der: &[u8], time_epoch: i64) -> bool {
+        self.anchor_windows.is_empty()
+            || self
+                .anchor_windows
+                .get(&base64_sha256_cert_der(anchor_der))
+                .is_none_or(|window| window.contains(time_epoch))
     }
 
     /// Return `true` if the EKU OID is allowed.
@@ -471,7 +945,8 @@ mod tests {
 
     use crate::crypto::{
         cose::{
-            CertificateTrustError, CertificateTrustPolicy, InvalidCertificateError, TrustAnchorType,
+            CertificateTrustError, CertificateTrustPolicy, InvalidCertificateError,
+            TrustAnchorType, TrustList, TrustPolicyRole, TrustSource,
         },
         raw_signature::{signer::test_signer, SigningAlg},
     };
@@ -773,6 +1248,159 @@ zGxQnM2hCA==
         );
     }
 
+    #[test]
+    fn test_trust_list_windows() {
+        // Every test root is on the list, but withdrawn at the start of 2025.
+        let mut trust_list: serde_json::Value = serde_json::from_slice(include_bytes!(
+            "../../../tests/fixtures/certs/trust/trust_list.json"
+        ))
+        .unwrap();
+        for entry in trust_list["entries"].as_array_mut().unwrap() {
+            entry["withdrawn"] = "2025-01-01T00:00:00Z".into();
+        }
+        let trust_list = TrustList::from_json(trust_list.to_string().as_bytes()).unwrap();
+
+        let mut ctp = CertificateTrustPolicy::new();
+        ctp.add_trust_list(&trust_list);
+
+        let es256_certs = test_signer(SigningAlg::Es256).cert_chain().unwrap();
+
+        // Signed while the anchor was trusted.
+        assert_eq!(
+            ctp.check_certificate_trust(&es256_certs[1..], &es256_certs[0], Some(1719792000)) // 2024-07-01
+                .unwrap(),
+            TrustAnchorType::System
+        );
+
+        // Signed after the anchor was withdrawn.
+        assert!(ctp
+            .check_certificate_trust(&es256_certs[1..], &es256_certs[0], Some(1751328000)) // 2025-07-01
+            .is_err());
+
+        // Without a signing time the current time is used.
+        assert!(ctp
+            .check_certificate_trust(&es256_certs[1..], &es256_certs[0], None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_trust_report() {
+        let es256_certs = test_signer(SigningAlg::Es256).cert_chain().unwrap();
+
+        let mut ctp = CertificateTrustPolicy::new();
+        ctp.add_trust_anchors(include_bytes!(
+            "../../../tests/fixtures/crypto/raw_signature/test_cert_root_bundle.pem"
+        ))
+        .unwrap();
+
+        let (result, report) =
+            ctp.check_certificate_trust_with_report(&es256_certs[1..], &es256_certs[0], None);
+        assert_eq!(result.unwrap(), TrustAnchorType::System);
+        assert!(report.trusted);
+        assert_eq!(report.trust_policy, "claim_signer");
+        assert_eq!(report.chain.len(), 3);
+        assert!(report.eku.is_some());
+        assert!(report.error.is_none());
+        assert!(report.rejections.is_empty());
+
+        let matched = report.matched.unwrap();
+        assert_eq!(matched.source, TrustSource::System);
+        assert_eq!(&matched.certificate, report.chain.last().unwrap());
+
+        // No anchors at all, so the report says why nothing matched.
+        let (result, report) = CertificateTrustPolicy::new().check_certificate_trust_with_report(
+            &es256_certs[1..],
+            &es256_certs[0],
+            None,
+        );
+        assert!(result.is_err());
+        assert!(!report.trusted);
+        assert!(report.matched.is_none());
+        assert!(report.error.is_some());
+        assert!(report
+            .rejections
+            .iter()
+            .any(|r| r.candidate == "trust anchors"));
+    }
+
+    #[test]
+    fn test_policy_from_settings() {
+        // Nothing configured, so callers fall back to their default policy.
+        assert!(CertificateTrustPolicy::from_settings(
+            "timestamp_trust",
+            TrustPolicyRole::TimeStamp
+        )
+        .unwrap()
+        .is_none());
+
+        crate::settings::set_settings_value(
+            "timestamp_trust.trust_anchors",
+            String::from_utf8_lossy(include_bytes!(
+                "../../../tests/fixtures/crypto/raw_signature/test_cert_root_bundle.pem"
+            ))
+            .into_owned(),
+        )
+        .unwrap();
+
+        let tsa_ctp =
+            CertificateTrustPolicy::from_settings("timestamp_trust", TrustPolicyRole::TimeStamp)
+                .unwrap()
+                .unwrap();
+        assert_eq!(tsa_ctp.role(), TrustPolicyRole::TimeStamp);
+        assert!(tsa_ctp.trust_anchor_ders_at(None).count() > 0);
+        assert_eq!(tsa_ctp.user_trust_anchor_ders_at(None).count(), 0);
+
+        crate::settings::reset_default_settings().unwrap();
+
+        let mut ctp = CertificateTrustPolicy::new();
+        assert_eq!(ctp.time_stamp_policy().role(), TrustPolicyRole::ClaimSigner);
+
+        ctp.set_time_stamp_policy(tsa_ctp);
+        assert_eq!(ctp.time_stamp_policy().role(), TrustPolicyRole::TimeStamp);
+
+        ctp.clear();
+        assert_eq!(ctp.time_stamp_policy().role(), TrustPolicyRole::ClaimSigner);
+    }
+
+    #[test]
+    fn test_intermediate_store() {
+        let mut ctp = CertificateTrustPolicy::new();
+        ctp.add_trust_anchors(include_bytes!(
+            "../../../tests/fixtures/crypto/raw_signature/test_cert_root_bundle.pem"
+        ))
+        .unwrap();
+
+        let es256 = test_signer(SigningAlg::Es256);
+        let es256_certs = es256.cert_chain().unwrap();
+
+        // The intermediate is missing, so the chain can't reach the root.
+        assert_eq!(
+            ctp.check_certificate_trust(&[], &es256_certs[0], None),
+            Err(CertificateTrustError::CertificateNotTrusted)
+        );
+
+        ctp.add_intermediate_certificates(include_bytes!(
+            "../../../tests/fixtures/crypto/raw_signature/es256.pub"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            ctp.check_certificate_trust(&[], &es256_certs[0], None),
+            Ok(TrustAnchorType::System)
+        );
+
+        // Intermediates alone are not trust anchors.
+        let mut ctp = CertificateTrustPolicy::new();
+        ctp.add_intermediate_certificates(include_bytes!(
+            "../../../tests/fixtures/crypto/raw_signature/es256.pub"
+        ))
+        .unwrap();
+
+        assert!(ctp
+            .check_certificate_trust(&[], &es256_certs[0], None)
+            .is_err());
+    }
+
     #[test]
     fn test_user_trust_store() {
         let ctp = CertificateTrustPolicy::default();
@@ -15,12 +15,20 @@
 //!
 //! [COSE]: https://datatracker.ietf.org/doc/rfc9052/
 
+mod certificate_chain;
+pub use certificate_chain::IntermediateCertificateStore;
+pub(crate) use certificate_chain::{
+    aia_access_locations, authority_key_id, complete_certificate_chain,
+    complete_certificate_chain_async, subject_key_id,
+};
+
 mod certificate_info;
 pub use certificate_info::CertificateInfo;
 
 mod certificate_trust_policy;
 pub use certificate_trust_policy::{
     CertificateTrustError, CertificateTrustPolicy, InvalidCertificateError, TrustAnchorType,
+    TrustPolicyRole,
 };
 
 mod certificate_profile;
@@ -53,5 +61,11 @@ pub(crate) use sigtst::{
 mod time_stamp_storage;
 pub use time_stamp_storage::TimeStampStorage;
 
+mod trust_list;
+pub use trust_list::{TrustList, TrustListEntry, TrustWindow};
+
+mod trust_report;
+pub use trust_report::{CertificateSummary, TrustMatch, TrustRejection, TrustReport, TrustSource};
+
 mod verifier;
 pub use verifier::Verifier;
@@ -387,20 +387,26 @@ fn build_protected_header(
         SigningAlg::Es384 => HeaderBuilder::new().algorithm(iana::Algorithm::ES384),
         SigningAlg::Es512 => HeaderBuilder::new().algorithm(iana::Algorithm::ES512),
         SigningAlg::Ed25519 => HeaderBuilder::new().algorithm(iana::Algorithm::EdDSA),
+        SigningAlg::MlDsa44 => HeaderBuilder::new().algorithm(iana::Algorithm::ML_DSA_44),
+        SigningAlg::MlDsa65 => HeaderBuilder::new().algorithm(iana::Algorithm::ML_DSA_65),
+        SigningAlg::MlDsa87 => HeaderBuilder::new().algorithm(iana::Algorithm::ML_DSA_87),
     };
 
     let certs = signer.cert_chain()?;
 
-    let sc_der_array_or_bytes = match certs.len() {
-        1 => Value::Bytes(certs[0].clone()),
-        _ => Value::Array(certs.into_iter().map(Value::Bytes).collect()),
-    };
-
-    // Add certs to protected header.
-    protected_h = protected_h.value(
-        iana::HeaderParameter::X5Chain.to_i64(),
-        sc_der_array_or_bytes.clone(),
-    );
+    // Add certs to protected header. Signers whose key is identified some
+    // other way (for example, by a DID) have no certificates to add.
+    if !certs.is_empty() {
+        let sc_der_array_or_bytes = match certs.len() {
+            1 => Value::Bytes(certs[0].clone()),
+            _ => Value::Array(certs.into_iter().map(Value::Bytes).collect()),
+        };
+
+        protected_h = protected_h.value(
+            iana::HeaderParameter::X5Chain.to_i64(),
+            sc_der_array_or_bytes,
+        );
+    }
 
     // Add content type to protected header.
     match content_type {
@@ -0,0 +1,229 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use crate::crypto::{base64, cose::InvalidCertificateError};
+
+/// A structured trust list, such as the C2PA conformance trust list or the
+/// C2PA TSA trust list.
+///
+/// Unlike a flat PEM bundle, each entry carries the period during which the
+/// anchor is trusted. An anchor that has been withdrawn remains trusted for
+/// signatures made before the withdrawal date.
+///
+/// The list is published as JSON:
+///
+/// ```json
+/// {
+///   "name": "C2PA Trust List",
+///   "version": "1.0",
+///   "issued": "2025-01-01T00:00:00Z",
+///   "entries": [
+///     {
+///       "name": "Example Root CA",
+///       "certificate": "MIIC...",
+///       "notBefore": "2024-01-01T00:00:00Z",
+///       "notAfter": "2030-01-01T00:00:00Z",
+///       "withdrawn": "2026-05-01T00:00:00Z"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// `certificate` is the base64-encoded DER of the anchor. All dates are
+/// RFC 3339; `notBefore` and `notAfter` are required and `withdrawn` is only
+/// present for anchors that have been removed from the list.
+#[derive(Clone, Debug, Default)]
+pub struct TrustList {
+    name: Option<String>,
+    version: Option<String>,
+    entries: Vec<TrustListEntry>,
+}
+
+impl TrustList {
+    /// Parse a trust list from its JSON representation.
+    pub fn from_json(json: &[u8]) -> Result<Self, InvalidCertificateError> {
+        let raw: RawTrustList = serde_json::from_slice(json)
+            .map_err(|e| InvalidCertificateError(format!("invalid trust list: {e}")))?;
+
+        let entries = raw
+            .entries
+            .into_iter()
+            .map(TrustListEntry::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            name: raw.name,
+            version: raw.version,
+            entries,
+        })
+    }
+
+    /// Returns the name of this list, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the version of this list, if it has one.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Returns the entries on this list.
+    pub fn entries(&self) -> &[TrustListEntry] {
+        &self.entries
+    }
+}
+
+/// A single trust anchor on a [`TrustList`].
+#[derive(Clone, Debug)]
+pub struct TrustListEntry {
+    /// Display name of the entry, if the list provides one.
+    pub name: Option<String>,
+
+    /// The anchor certificate in DER format.
+    pub certificate_der: Vec<u8>,
+
+    /// The period during which the anchor is trusted.
+    pub window: TrustWindow,
+}
+
+impl TryFrom<RawTrustListEntry> for TrustListEntry {
+    type Error = InvalidCertificateError;
+
+    fn try_from(raw: RawTrustListEntry) -> Result<Self, Self::Error> {
+        let certificate_der =
+            base64::decode(&raw.certificate).map_err(|e| InvalidCertificateError(e.to_string()))?;
+
+        X509Certificate::from_der(&certificate_der)
+            .map_err(|e| InvalidCertificateError(e.to_string()))?;
+
+        // A withdrawal ends the window early, it never extends it.
+        let not_after = match raw.withdrawn {
+            Some(withdrawn) => raw.not_after.min(withdrawn),
+            None => raw.not_after,
+        };
+
+        Ok(Self {
+            name: raw.name,
+            certificate_der,
+            window: TrustWindow {
+                not_before: Some(raw.not_before.timestamp()),
+                not_after: Some(not_after.timestamp()),
+            },
+        })
+    }
+}
+
+/// The period during which a trust anchor is trusted, in Unix seconds.
+///
+/// `None` at either end means the window is open on that side.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TrustWindow {
+    /// Start of the trust period.
+    pub not_before: Option<i64>,
+
+    /// End of the trust period (exclusive). This is the earlier of the
+    /// anchor's expiry on the list and its withdrawal date.
+    pub not_after: Option<i64>,
+}
+
+impl TrustWindow {
+    /// Returns `true` if the anchor is trusted at `time_epoch`.
+    pub fn contains(&self, time_epoch: i64) -> bool {
+        self.not_before.is_none_or(|t| time_epoch >= t)
+            && self.not_after.is_none_or(|t| time_epoch < t)
+    }
+}
+
+#[derive(Deserialize)]
+struct RawTrustList {
+    #[serde(default)]
+    name: Option<String>,
+
+    #[serde(default)]
+    version: Option<String>,
+
+    entries: Vec<RawTrustListEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTrustListEntry {
+    #[serde(default)]
+    name: Option<String>,
+
+    certificate: String,
+
+    not_before: DateTime<Utc>,
+
+    not_after: DateTime<Utc>,
+
+    #[serde(default)]
+    withdrawn: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    const TRUST_LIST: &[u8] = include_bytes!("../../../tests/fixtures/certs/trust/trust_list.json");
+
+    #[test]
+    fn parses_trust_list() {
+        let list = TrustList::from_json(TRUST_LIST).unwrap();
+        assert_eq!(list.name(), Some("C2PA Test Trust List"));
+        assert_eq!(list.version(), Some("1.0"));
+        assert_eq!(list.entries().len(), 10);
+
+        let ed25519 = &list.entries()[0];
+        assert_eq!(ed25519.name.as_deref(), Some("C2PA Test Root CA (ED25519)"));
+        assert!(!ed25519.window.contains(1577836800)); // 2020
+        assert!(ed25519.window.contains(1704067200)); // 2024
+        assert!(!ed25519.window.contains(2019686400)); // 2034
+    }
+
+    #[test]
+    fn withdrawal_ends_window() {
+        let mut json: serde_json::Value = serde_json::from_slice(TRUST_LIST).unwrap();
+        json["entries"][0]["withdrawn"] = "2025-01-01T00:00:00Z".into();
+
+        let list = TrustList::from_json(json.to_string().as_bytes()).unwrap();
+        let withdrawn = &list.entries()[0];
+        assert!(withdrawn.window.contains(1704067200)); // 2024
+        assert!(!withdrawn.window.contains(1767225600)); // 2026
+        assert!(list.entries()[1].window.contains(1767225600));
+    }
+
+    #[test]
+    fn rejects_bad_entries() {
+        let mut json: serde_json::Value = serde_json::from_slice(TRUST_LIST).unwrap();
+        json["entries"][0]["certificate"] = "bm90IGEgY2VydA==".into();
+        assert!(TrustList::from_json(json.to_string().as_bytes()).is_err());
+
+        let mut json: serde_json::Value = serde_json::from_slice(TRUST_LIST).unwrap();
+        json["entries"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("notAfter");
+        assert!(TrustList::from_json(json.to_string().as_bytes()).is_err());
+
+        assert!(TrustList::from_json(b"{}").is_err());
+        assert!(TrustList::from_json(b"-----BEGIN CERTIFICATE-----").is_err());
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use x509_parser::prelude::X509Certificate;
+
+/// Explains the outcome of evaluating a certificate against a
+/// [`CertificateTrustPolicy`].
+///
+/// The decision itself is made by the cryptographic trust check. The report
+/// describes the certificate path that was evaluated, what it was matched
+/// against, and why the alternatives were not accepted.
+///
+/// [`CertificateTrustPolicy`]: crate::crypto::cose::CertificateTrustPolicy
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TrustReport {
+    /// JUMBF URI of the signature that was evaluated, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Name of the trust policy that was applied.
+    pub trust_policy: String,
+
+    /// `true` if the certificate was trusted.
+    pub trusted: bool,
+
+    /// The certificate path as built, starting with the end-entity certificate
+    /// and ending with the matching trust anchor, if any.
+    pub chain: Vec<CertificateSummary>,
+
+    /// The trust anchor or allowed-list entry that the path matched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched: Option<TrustMatch>,
+
+    /// The extended key usage (EKU) OID that satisfied the policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eku: Option<String>,
+
+    /// The error returned by the trust check, if the certificate is untrusted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// The alternatives that were considered and why each was not accepted.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub rejections: Vec<TrustRejection>,
+}
+
+/// Identifying details of one certificate in a path.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateSummary {
+    /// Subject distinguished name.
+    pub subject: String,
+
+    /// Issuer distinguished name.
+    pub issuer: String,
+
+    /// Serial number in hexadecimal.
+    pub serial_number: String,
+
+    /// Start of the certificate's validity period (RFC 3339).
+    pub not_before: String,
+
+    /// End of the certificate's validity period (RFC 3339).
+    pub not_after: String,
+}
+
+impl CertificateSummary {
+    pub(crate) fn from_cert(cert: &X509Certificate) -> Self {
+        let rfc3339 = |t: x509_parser::time::ASN1Time| {
+            chrono::DateTime::from_timestamp(t.timestamp(), 0)
+                .map(|dt| dt.to_rfc3339())
+                .unwrap_or_default()
+        };
+
+        Self {
+            subject: cert.subject().to_string(),
+            issuer: cert.issuer().to_string(),
+            serial_number: cert.raw_serial_as_string(),
+            not_before: rfc3339(cert.validity().not_before),
+            not_after: rfc3339(cert.validity().not_after),
+        }
+    }
+}
+
+/// Where a trusted certificate path ended.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TrustMatch {
+    /// The list the match was found on.
+    pub source: TrustSource,
+
+    /// The matching anchor or allowed end-entity certificate.
+    pub certificate: CertificateSummary,
+}
+
+/// The list a [`TrustMatch`] was found on.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum TrustSource {
+    /// Trust anchors provided by a sanctioned authority.
+    System,
+
+    /// User provided trust anchors.
+    User,
+
+    /// The allowed list of end-entity certificates.
+    AllowedList,
+}
+
+/// An alternative that was considered and not accepted.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct TrustRejection {
+    /// What was considered, such as an anchor's subject name.
+    pub candidate: String,
+
+    /// Why it was not accepted.
+    pub reason: String,
+}
+
+impl TrustRejection {
+    pub(crate) fn new<C: Into<String>, R: Into<String>>(candidate: C, reason: R) -> Self {
+        Self {
+            candidate: candidate.into(),
+            reason: reason.into(),
+        }
+    }
+}
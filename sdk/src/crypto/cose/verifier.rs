@@ -31,6 +31,7 @@ use crate::{
         raw_signature::{validator_for_signing_alg, SigningAlg},
     },
     log_item,
+    settings::get_settings_value,
     status_tracker::StatusTracker,
     validation_results::validation_codes::{
         ALGORITHM_UNSUPPORTED, SIGNING_CREDENTIAL_INVALID, SIGNING_CREDENTIAL_TRUSTED,
@@ -85,20 +86,20 @@ impl Verifier<'_> {
         };
 
         match alg {
-            SigningAlg::Es256 | SigningAlg::Es384 | SigningAlg::Es512 => {
-                if parse_ec_der_sig(&sign1.signature).is_ok() {
-                    // Should have been in P1363 format, not DER.
-                    log_item!(
-                        "Cose_Sign1",
-                        "unsupported signature format (EC signature should be in P1363 r|s format)",
-                        "verify_cose"
-                    )
-                    .validation_status(SIGNING_CREDENTIAL_INVALID)
-                    .failure_no_throw(validation_log, CoseError::InvalidEcdsaSignature);
-
-                    // validation_log.log(log_item, CoseError::InvalidEcdsaSignature)?;
-                    return Err(CoseError::InvalidEcdsaSignature);
-                }
+            SigningAlg::Es256 | SigningAlg::Es384 | SigningAlg::Es512
+                if parse_ec_der_sig(&sign1.signature).is_ok() =>
+            {
+                // Should have been in P1363 format, not DER.
+                log_item!(
+                    "Cose_Sign1",
+                    "unsupported signature format (EC signature should be in P1363 r|s format)",
+                    "verify_cose"
+                )
+                .validation_status(SIGNING_CREDENTIAL_INVALID)
+                .failure_no_throw(validation_log, CoseError::InvalidEcdsaSignature);
+
+                // validation_log.log(log_item, CoseError::InvalidEcdsaSignature)?;
+                return Err(CoseError::InvalidEcdsaSignature);
             }
             _ => (),
         }
@@ -233,33 +234,63 @@ impl Verifier<'_> {
             dt.timestamp()
         });
 
-        let verify_result = if _sync {
-            ctp.check_certificate_trust(chain_der, end_entity_cert_der, signing_time_epoch)
+        let build_trust_report = get_settings_value("verify.trust_reports").unwrap_or(false);
+
+        let verify_result = if !build_trust_report {
+            if _sync {
+                ctp.check_certificate_trust(chain_der, end_entity_cert_der, signing_time_epoch)
+            } else {
+                ctp.check_certificate_trust_async(
+                    chain_der,
+                    end_entity_cert_der,
+                    signing_time_epoch,
+                )
+                .await
+            }
         } else {
-            ctp.check_certificate_trust_async(chain_der, end_entity_cert_der, signing_time_epoch)
+            let (result, trust_report) = if _sync {
+                ctp.check_certificate_trust_with_report(
+                    chain_der,
+                    end_entity_cert_der,
+                    signing_time_epoch,
+                )
+            } else {
+                ctp.check_certificate_trust_with_report_async(
+                    chain_der,
+                    end_entity_cert_der,
+                    signing_time_epoch,
+                )
                 .await
+            };
+            validation_log.add_trust_report(trust_report);
+            result
         };
 
+        let trust_policy = ctp.role().as_str();
+
         match verify_result {
             Ok(tat) => {
                 log_item!(
                     "",
                     format!(
-                        "signing certificate trusted, found in {:?} trust anchors",
-                        tat
+                        "signing certificate trusted, found in {tat:?} trust anchors ({trust_policy} trust policy)"
                     ),
                     "verify_cose"
                 )
                 .validation_status(SIGNING_CREDENTIAL_TRUSTED)
+                .trust_policy(trust_policy)
                 .success(validation_log);
 
                 Ok(tat)
             }
-            Err(e) => Err(
-                log_item!("", "signing certificate untrusted", "verify_cose")
-                    .validation_status(SIGNING_CREDENTIAL_UNTRUSTED)
-                    .failure_as_err(validation_log, e.into()),
-            ),
+            Err(e) => Err(log_item!(
+                "",
+                format!("signing certificate untrusted ({trust_policy} trust policy)"),
+                "verify_cose"
+            )
+            .validation_status(SIGNING_CREDENTIAL_UNTRUSTED)
+            .trust_policy(trust_policy)
+            .failure_as_err(validation_log, e.into())),
         }
     }
 }
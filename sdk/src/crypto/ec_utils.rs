@@ -126,6 +126,59 @@ pub(crate) fn der_to_p1363(data: &[u8], sig_len: usize) -> Result<Vec<u8>, RawSi
         .map_err(|e| RawSignerError::InternalError(format!("invalid signature components {e}")))
 }
 
+/// Convert a P1363 (r | s) ECDSA signature into the ASN.1 DER
+/// `ECDSA-Sig-Value` form used by CMS and X.509.
+#[allow(dead_code)] // Not used in all configurations.
+pub(crate) fn p1363_to_der(data: &[u8]) -> Result<Vec<u8>, RawSignerError> {
+    if data.is_empty() || data.len() % 2 != 0 {
+        return Err(RawSignerError::InternalError(
+            "invalid P1363 signature length".to_string(),
+        ));
+    }
+
+    let (r, s) = data.split_at(data.len() / 2);
+
+    let mut content = der_unsigned_integer(r);
+    content.extend(der_unsigned_integer(s));
+
+    let mut der = vec![0x30];
+    der.extend(der_length(content.len()));
+    der.extend(content);
+
+    Ok(der)
+}
+
+// Encode a big-endian unsigned value as a DER INTEGER.
+fn der_unsigned_integer(value: &[u8]) -> Vec<u8> {
+    let first = value.iter().position(|b| *b != 0).unwrap_or(value.len());
+    let mut content = value[first..].to_vec();
+
+    if content.first().is_none_or(|b| b & 0x80 != 0) {
+        content.insert(0, 0);
+    }
+
+    let mut der = vec![0x02];
+    der.extend(der_length(content.len()));
+    der.extend(content);
+    der
+}
+
+fn der_length(len: usize) -> Vec<u8> {
+    if len < 0x80 {
+        vec![len as u8]
+    } else {
+        let bytes: Vec<u8> = len
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|b| *b == 0)
+            .collect();
+
+        let mut der = vec![0x80 | bytes.len() as u8];
+        der.extend(bytes);
+        der
+    }
+}
+
 // Returns supported EcdsaCurve for given public key.
 #[allow(dead_code)]
 pub(crate) fn ec_curve_from_public_key_der(public_key: &[u8]) -> Option<EcdsaCurve> {
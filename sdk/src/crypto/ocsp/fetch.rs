@@ -17,11 +17,10 @@ use rasn::prelude::*;
 use rasn_pkix::Certificate;
 use x509_parser::{
     der_parser::{oid, Oid},
-    extensions::ParsedExtension,
     prelude::*,
 };
 
-use crate::crypto::base64;
+use crate::crypto::{base64, cose::aia_access_locations};
 
 /// Retrieve an OCSP response if available.
 ///
@@ -128,25 +127,7 @@ pub(crate) fn fetch_ocsp_response(certs: &[Vec<u8>]) -> Option<Vec<u8>> {
 }
 
 fn extract_aia_responders(cert: &x509_parser::certificate::X509Certificate) -> Option<Vec<String>> {
-    let em = cert.extensions_map().ok()?;
-
-    let aia_extension = em.get(&AUTHORITY_INFO_ACCESS_OID)?;
-
-    let ParsedExtension::AuthorityInfoAccess(aia) = aia_extension.parsed_extension() else {
-        return None;
-    };
-
-    let mut output = Vec::new();
-
-    for ad in &aia.accessdescs {
-        if let x509_parser::extensions::GeneralName::URI(uri) = ad.access_location {
-            if ad.access_method == AD_OCSP_OID {
-                output.push(uri.to_string())
-            }
-        }
-    }
-    Some(output)
+    Some(aia_access_locations(cert, &AD_OCSP_OID))
 }
 
 const AD_OCSP_OID: Oid<'static> = oid!(1.3.6 .1 .5 .5 .7 .48 .1);
-const AUTHORITY_INFO_ACCESS_OID: Oid<'static> = oid!(1.3.6 .1 .5 .5 .7 .1 .1);
@@ -0,0 +1,78 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+#![allow(dead_code)] // Usage varies by platform.
+
+//! Helpers shared by the OpenSSL and Rust-native implementations of ML-DSA
+//! (FIPS 204).
+
+use x509_parser::{oid_registry::Oid, prelude::FromDer, x509::SubjectPublicKeyInfo};
+
+use crate::crypto::raw_signature::{oids::*, SigningAlg};
+
+/// Return the ML-DSA parameter set identified by an algorithm OID, if any.
+pub(crate) fn alg_from_oid(oid: &Oid) -> Option<SigningAlg> {
+    if *oid == ML_DSA_44_OID {
+        Some(SigningAlg::MlDsa44)
+    } else if *oid == ML_DSA_65_OID {
+        Some(SigningAlg::MlDsa65)
+    } else if *oid == ML_DSA_87_OID {
+        Some(SigningAlg::MlDsa87)
+    } else {
+        None
+    }
+}
+
+/// Return the ML-DSA parameter set of a DER-encoded `SubjectPublicKeyInfo`.
+///
+/// Returns `None` if the key is not an ML-DSA key or if the algorithm
+/// identifier carries parameters, which RFC 9881 forbids.
+pub(crate) fn alg_from_spki(spki_der: &[u8]) -> Option<SigningAlg> {
+    let (_, spki) = SubjectPublicKeyInfo::from_der(spki_der).ok()?;
+
+    if spki.algorithm.parameters.is_some() {
+        return None;
+    }
+
+    let alg = alg_from_oid(&spki.algorithm.algorithm)?;
+
+    if spki.subject_public_key.data.len() != public_key_len(alg) {
+        return None;
+    }
+
+    Some(alg)
+}
+
+/// Return the size in bytes of an encoded ML-DSA public key.
+///
+/// Returns 0 for any algorithm other than ML-DSA.
+pub(crate) fn public_key_len(alg: SigningAlg) -> usize {
+    match alg {
+        SigningAlg::MlDsa44 => 1312,
+        SigningAlg::MlDsa65 => 1952,
+        SigningAlg::MlDsa87 => 2592,
+        _ => 0,
+    }
+}
+
+/// Return the size in bytes of an encoded ML-DSA signature.
+///
+/// Returns 0 for any algorithm other than ML-DSA.
+pub(crate) fn signature_len(alg: SigningAlg) -> usize {
+    match alg {
+        SigningAlg::MlDsa44 => 2420,
+        SigningAlg::MlDsa65 => 3309,
+        SigningAlg::MlDsa87 => 4627,
+        _ => 0,
+    }
+}
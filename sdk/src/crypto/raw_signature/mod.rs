@@ -13,11 +13,18 @@
 
 //! Tools for working with raw signature algorithms.
 
+pub(crate) mod ml_dsa;
+
 pub(crate) mod oids;
 
 #[cfg(feature = "openssl")]
 pub(crate) mod openssl;
 
+#[cfg(all(feature = "pkcs11", not(target_arch = "wasm32")))]
+mod pkcs11;
+#[cfg(all(feature = "pkcs11", not(target_arch = "wasm32")))]
+pub use pkcs11::signer_from_pkcs11_token;
+
 #[cfg(feature = "rust_native_crypto")]
 pub(crate) mod rust_native;
 
@@ -42,6 +42,15 @@ pub(crate) const PRIME256V1_OID: Oid<'static> = oid!(1.2.840 .10045 .3 .1 .7);
 
 pub(crate) const ED25519_OID: Oid<'static> = oid!(1.3.101 .112);
 
+pub(crate) const ML_DSA_44_OID: Oid<'static> = oid!(2.16.840 .1 .101 .3 .4 .3 .17);
+pub(crate) const ML_DSA_65_OID: Oid<'static> = oid!(2.16.840 .1 .101 .3 .4 .3 .18);
+pub(crate) const ML_DSA_87_OID: Oid<'static> = oid!(2.16.840 .1 .101 .3 .4 .3 .19);
+
+/// Returns true if `oid` identifies one of the ML-DSA parameter sets.
+pub(crate) fn is_ml_dsa_oid(oid: &Oid) -> bool {
+    *oid == ML_DSA_44_OID || *oid == ML_DSA_65_OID || *oid == ML_DSA_87_OID
+}
+
 // utility function to make using Oid between crates easier
 pub(crate) fn ans1_oid_bcder_oid(asn1_oid: &asn1_rs::Oid) -> Option<bcder::Oid> {
     let asn1_oid_str = asn1_oid.to_id_string();
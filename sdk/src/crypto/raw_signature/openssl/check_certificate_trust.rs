@@ -29,7 +29,9 @@ pub(crate) fn check_certificate_trust(
 ) -> Result<TrustAnchorType, CertificateTrustError> {
     let _openssl = OpenSslMutex::acquire()?;
 
-    if ctp.trust_anchor_ders().count() == 0 && ctp.user_trust_anchor_ders().count() == 0 {
+    if ctp.trust_anchor_ders_at(signing_time_epoch).count() == 0
+        && ctp.user_trust_anchor_ders_at(signing_time_epoch).count() == 0
+    {
         return Err(CertificateTrustError::CertificateNotTrusted);
     }
 
@@ -57,7 +59,7 @@ pub(crate) fn check_certificate_trust(
     builder.set_param(&verify_param)?;
 
     // add trust anchors.
-    for der in ctp.trust_anchor_ders() {
+    for der in ctp.trust_anchor_ders_at(signing_time_epoch) {
         let root_cert = X509::from_der(der)?;
         builder.add_cert(root_cert)?;
     }
@@ -76,7 +78,7 @@ pub(crate) fn check_certificate_trust(
         builder.set_param(&verify_param)?;
 
         // add user trust anchors.
-        for der in ctp.user_trust_anchor_ders() {
+        for der in ctp.user_trust_anchor_ders_at(signing_time_epoch) {
             let root_cert = X509::from_der(der)?;
             builder.add_cert(root_cert)?;
         }
@@ -26,3 +26,10 @@ pub(crate) use ffi_mutex::{OpenSslMutex, OpenSslMutexUnavailable};
 pub(crate) mod check_certificate_trust;
 pub(crate) mod signers;
 pub(crate) mod validators;
+
+/// Return `true` if the linked OpenSSL library supports ML-DSA (FIPS 204).
+///
+/// ML-DSA was introduced in OpenSSL 3.5.
+pub(crate) fn ml_dsa_supported() -> bool {
+    ::openssl::version::number() >= 0x3050_0000
+}
@@ -0,0 +1,144 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use openssl::{
+    pkey::{PKey, Private},
+    sign::Signer,
+    x509::X509,
+};
+
+use crate::crypto::{
+    raw_signature::{
+        ml_dsa,
+        openssl::{cert_chain::check_chain_order, ml_dsa_supported, OpenSslMutex},
+        RawSigner, RawSignerError, SigningAlg,
+    },
+    time_stamp::TimeStampProvider,
+};
+
+/// Implements `RawSigner` trait using OpenSSL's implementation of
+/// ML-DSA (FIPS 204).
+///
+/// Requires OpenSSL 3.5 or later.
+pub struct MlDsaSigner {
+    alg: SigningAlg,
+
+    cert_chain: Vec<Vec<u8>>,
+    cert_chain_len: usize,
+
+    private_key: PKey<Private>,
+
+    time_stamp_service_url: Option<String>,
+    time_stamp_size: usize,
+}
+
+impl MlDsaSigner {
+    pub(crate) fn from_cert_chain_and_private_key(
+        cert_chain: &[u8],
+        private_key: &[u8],
+        alg: SigningAlg,
+        time_stamp_service_url: Option<String>,
+    ) -> Result<Self, RawSignerError> {
+        if !matches!(
+            alg,
+            SigningAlg::MlDsa44 | SigningAlg::MlDsa65 | SigningAlg::MlDsa87
+        ) {
+            return Err(RawSignerError::InternalError(
+                "MlDsaSigner should be used only for SigningAlg::MlDsa**".to_string(),
+            ));
+        }
+
+        if !ml_dsa_supported() {
+            return Err(RawSignerError::InternalError(format!(
+                "unsupported signing algorithm {alg} (requires OpenSSL 3.5 or later)"
+            )));
+        }
+
+        let _openssl = OpenSslMutex::acquire()?;
+
+        let cert_chain = X509::stack_from_pem(cert_chain)?;
+
+        if !check_chain_order(&cert_chain) {
+            return Err(RawSignerError::InvalidSigningCredentials(
+                "certificate chain in incorrect order".to_string(),
+            ));
+        }
+
+        // certs in DER format
+        let cert_chain = cert_chain
+            .iter()
+            .map(|cert| {
+                cert.to_der().map_err(|_| {
+                    RawSignerError::CryptoLibraryError(
+                        "could not encode certificate to DER".to_string(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, RawSignerError>>()?;
+
+        // get the actual length of the certificate chain
+        let cert_chain_len = cert_chain.iter().fold(0usize, |sum, c| sum + c.len());
+
+        let private_key = PKey::private_key_from_pem(private_key)?;
+
+        // Make sure the key matches the requested parameter set.
+        let public_key_der = private_key.public_key_to_der()?;
+        if ml_dsa::alg_from_spki(&public_key_der) != Some(alg) {
+            return Err(RawSignerError::InvalidSigningCredentials(format!(
+                "private key is not a valid {alg} key"
+            )));
+        }
+
+        Ok(MlDsaSigner {
+            alg,
+            cert_chain,
+            cert_chain_len,
+            private_key,
+            time_stamp_service_url,
+            time_stamp_size: 10000,
+            // TO DO: Call out to time stamp service to get actual time stamp and use that size?
+        })
+    }
+}
+
+impl RawSigner for MlDsaSigner {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>, RawSignerError> {
+        let _openssl = OpenSslMutex::acquire()?;
+
+        let mut signer = Signer::new_without_digest(&self.private_key)?;
+
+        Ok(signer.sign_oneshot_to_vec(data)?)
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.alg
+    }
+
+    fn reserve_size(&self) -> usize {
+        // Unlike the classical algorithms, ML-DSA signatures are too large to
+        // fit in the default 1024-byte allowance.
+        ml_dsa::signature_len(self.alg) + 1024 + self.cert_chain_len + self.time_stamp_size
+    }
+
+    fn cert_chain(&self) -> Result<Vec<Vec<u8>>, RawSignerError> {
+        let _openssl = OpenSslMutex::acquire()?;
+
+        Ok(self.cert_chain.clone())
+    }
+}
+
+impl TimeStampProvider for MlDsaSigner {
+    fn time_stamp_service_url(&self) -> Option<String> {
+        self.time_stamp_service_url.clone()
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use openssl::{pkey::PKey, sign::Verifier};
+
+use crate::crypto::raw_signature::{
+    ml_dsa, openssl::OpenSslMutex, RawSignatureValidationError, RawSignatureValidator, SigningAlg,
+};
+
+/// An `MlDsaValidator` can validate raw signatures with one of the ML-DSA
+/// (FIPS 204) signature algorithms.
+///
+/// Requires OpenSSL 3.5 or later.
+#[non_exhaustive]
+pub enum MlDsaValidator {
+    /// ML-DSA-44 parameter set
+    MlDsa44,
+
+    /// ML-DSA-65 parameter set
+    MlDsa65,
+
+    /// ML-DSA-87 parameter set
+    MlDsa87,
+}
+
+impl MlDsaValidator {
+    fn alg(&self) -> SigningAlg {
+        match self {
+            Self::MlDsa44 => SigningAlg::MlDsa44,
+            Self::MlDsa65 => SigningAlg::MlDsa65,
+            Self::MlDsa87 => SigningAlg::MlDsa87,
+        }
+    }
+}
+
+impl RawSignatureValidator for MlDsaValidator {
+    fn validate(
+        &self,
+        sig: &[u8],
+        data: &[u8],
+        public_key: &[u8],
+    ) -> Result<(), RawSignatureValidationError> {
+        let alg = self.alg();
+
+        if ml_dsa::alg_from_spki(public_key) != Some(alg) {
+            return Err(RawSignatureValidationError::InvalidPublicKey);
+        }
+
+        if sig.len() != ml_dsa::signature_len(alg) {
+            return Err(RawSignatureValidationError::InvalidSignature);
+        }
+
+        let _openssl = OpenSslMutex::acquire()?;
+
+        let public_key = PKey::public_key_from_der(public_key)?;
+
+        if Verifier::new_without_digest(&public_key)?.verify_oneshot(sig, data)? {
+            Ok(())
+        } else {
+            Err(RawSignatureValidationError::SignatureMismatch)
+        }
+    }
+}
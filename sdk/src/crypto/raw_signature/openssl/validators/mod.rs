@@ -16,7 +16,9 @@
 
 use bcder::Oid;
 
-use crate::crypto::raw_signature::{oids::*, RawSignatureValidator, SigningAlg};
+use crate::crypto::raw_signature::{
+    oids::*, openssl::ml_dsa_supported, RawSignatureValidator, SigningAlg,
+};
 
 mod ecdsa_validator;
 pub use ecdsa_validator::EcdsaValidator;
@@ -24,6 +26,9 @@ pub use ecdsa_validator::EcdsaValidator;
 mod ed25519_validator;
 pub use ed25519_validator::Ed25519Validator;
 
+mod ml_dsa_validator;
+pub use ml_dsa_validator::MlDsaValidator;
+
 mod rsa_legacy_validator;
 pub(crate) use rsa_legacy_validator::RsaLegacyValidator;
 
@@ -40,6 +45,10 @@ pub fn validator_for_signing_alg(alg: SigningAlg) -> Option<Box<dyn RawSignature
         SigningAlg::Ps256 => Some(Box::new(RsaValidator::Ps256)),
         SigningAlg::Ps384 => Some(Box::new(RsaValidator::Ps384)),
         SigningAlg::Ps512 => Some(Box::new(RsaValidator::Ps512)),
+        SigningAlg::MlDsa44 if ml_dsa_supported() => Some(Box::new(MlDsaValidator::MlDsa44)),
+        SigningAlg::MlDsa65 if ml_dsa_supported() => Some(Box::new(MlDsaValidator::MlDsa65)),
+        SigningAlg::MlDsa87 if ml_dsa_supported() => Some(Box::new(MlDsaValidator::MlDsa87)),
+        SigningAlg::MlDsa44 | SigningAlg::MlDsa65 | SigningAlg::MlDsa87 => None,
     }
 }
 
@@ -88,5 +97,16 @@ pub(crate) fn validator_for_sig_and_hash_algs(
         return Some(Box::new(Ed25519Validator {}));
     }
 
+    // Handle ML-DSA. The hash algorithm is intrinsic to the parameter set.
+    if ml_dsa_supported() {
+        if sig_alg.as_ref() == ML_DSA_44_OID.as_bytes() {
+            return Some(Box::new(MlDsaValidator::MlDsa44));
+        } else if sig_alg.as_ref() == ML_DSA_65_OID.as_bytes() {
+            return Some(Box::new(MlDsaValidator::MlDsa65));
+        } else if sig_alg.as_ref() == ML_DSA_87_OID.as_bytes() {
+            return Some(Box::new(MlDsaValidator::MlDsa87));
+        }
+    }
+
     None
 }
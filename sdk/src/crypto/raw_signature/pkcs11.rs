@@ -0,0 +1,583 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Signing with keys held on a PKCS#11 token, such as a hardware security
+//! module (HSM) or smart card.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use cryptoki::{
+    context::{CInitializeArgs, Pkcs11},
+    error::{Error as Pkcs11Error, RvError},
+    mechanism::{
+        eddsa::{EddsaParams, EddsaSignatureScheme},
+        rsa::{PkcsMgfType, PkcsPssParams},
+        Mechanism, MechanismType,
+    },
+    object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle},
+    session::{Session, UserType},
+    types::AuthPin,
+};
+use x509_parser::{certificate::X509Certificate, prelude::FromDer};
+
+use crate::crypto::{
+    raw_signature::{RawSigner, RawSignerError, SigningAlg},
+    time_stamp::TimeStampProvider,
+};
+
+/// Return a [`RawSigner`] that signs with a private key stored on a PKCS#11
+/// token.
+///
+/// The PKCS#11 module at `module_path` is loaded and the token whose label
+/// matches `token_label` is opened. The private key and signing certificate
+/// are located by their `CKA_ID` attribute, which must equal `key_id`. The
+/// rest of the certificate chain is assembled from the other certificates
+/// stored on the token; a self-signed root certificate is never included.
+///
+/// If `pin` is provided, the session is logged in as the normal user before
+/// the key is located.
+///
+/// The signing mechanism is chosen from `alg`:
+///
+/// | `SigningAlg` | PKCS#11 mechanism |
+/// | --- | --- |
+/// | `es256`, `es384`, `es512` | `CKM_ECDSA_SHA256`, `CKM_ECDSA_SHA384`, `CKM_ECDSA_SHA512` |
+/// | `ps256`, `ps384`, `ps512` | `CKM_SHA256_RSA_PKCS_PSS`, `CKM_SHA384_RSA_PKCS_PSS`, `CKM_SHA512_RSA_PKCS_PSS` |
+/// | `ed25519` | `CKM_EDDSA` |
+///
+/// Other algorithms are rejected with `RawSignerError::InternalError`.
+pub fn signer_from_pkcs11_token(
+    module_path: &Path,
+    token_label: &str,
+    key_id: &[u8],
+    pin: Option<&str>,
+    alg: SigningAlg,
+    time_stamp_service_url: Option<String>,
+) -> Result<Box<dyn RawSigner + Send + Sync>, RawSignerError> {
+    Ok(Box::new(Pkcs11Signer::from_token(
+        module_path,
+        token_label,
+        key_id,
+        pin,
+        alg,
+        time_stamp_service_url,
+    )?))
+}
+
+/// Implements `RawSigner` trait using a private key that never leaves a
+/// PKCS#11 token.
+struct Pkcs11Signer {
+    alg: SigningAlg,
+
+    cert_chain: Vec<Vec<u8>>,
+    cert_chain_len: usize,
+
+    // `Session` is `Send` but not `Sync`; PKCS#11 sessions must not be used
+    // concurrently from multiple threads.
+    session: Mutex<Session>,
+    private_key: ObjectHandle,
+
+    time_stamp_service_url: Option<String>,
+    time_stamp_size: usize,
+}
+
+impl Pkcs11Signer {
+    fn from_token(
+        module_path: &Path,
+        token_label: &str,
+        key_id: &[u8],
+        pin: Option<&str>,
+        alg: SigningAlg,
+        time_stamp_service_url: Option<String>,
+    ) -> Result<Self, RawSignerError> {
+        // Fail early rather than after the token has been opened.
+        mechanism_for_alg(alg)?;
+
+        let pkcs11 = pkcs11_context(module_path)?;
+
+        let slot = pkcs11
+            .get_slots_with_token()?
+            .into_iter()
+            .find(|slot| {
+                pkcs11
+                    .get_token_info(*slot)
+                    .is_ok_and(|info| info.label().trim_end() == token_label)
+            })
+            .ok_or_else(|| {
+                RawSignerError::InvalidSigningCredentials(format!(
+                    "no PKCS#11 token with label \"{token_label}\""
+                ))
+            })?;
+
+        let session = pkcs11.open_ro_session(slot)?;
+
+        if let Some(pin) = pin {
+            session.login(UserType::User, Some(&AuthPin::new(pin.to_string())))?;
+        }
+
+        let private_key = find_one(
+            &session,
+            &[
+                Attribute::Class(ObjectClass::PRIVATE_KEY),
+                Attribute::Id(key_id.to_vec()),
+            ],
+            "private key",
+        )?;
+
+        check_key_type(&session, private_key, alg)?;
+
+        let cert_chain = read_cert_chain(&session, key_id)?;
+        let cert_chain_len = cert_chain.iter().fold(0usize, |sum, c| sum + c.len());
+
+        Ok(Pkcs11Signer {
+            alg,
+            cert_chain,
+            cert_chain_len,
+            session: Mutex::new(session),
+            private_key,
+            time_stamp_service_url,
+            time_stamp_size: 10000,
+            // TO DO: Call out to time stamp service to get actual time stamp and use that size?
+        })
+    }
+}
+
+impl RawSigner for Pkcs11Signer {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>, RawSignerError> {
+        let mechanism = mechanism_for_alg(self.alg)?;
+
+        let session = self
+            .session
+            .lock()
+            .map_err(|_| RawSignerError::InternalError("PKCS#11 session poisoned".to_string()))?;
+
+        // The ECDSA mechanisms return the raw `r || s` encoding, which is
+        // exactly what COSE expects, so no conversion is needed.
+        Ok(session.sign(&mechanism, self.private_key, data)?)
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.alg
+    }
+
+    fn reserve_size(&self) -> usize {
+        1024 + self.cert_chain_len + self.time_stamp_size
+    }
+
+    fn cert_chain(&self) -> Result<Vec<Vec<u8>>, RawSignerError> {
+        Ok(self.cert_chain.clone())
+    }
+}
+
+impl TimeStampProvider for Pkcs11Signer {
+    fn time_stamp_service_url(&self) -> Option<String> {
+        self.time_stamp_service_url.clone()
+    }
+}
+
+impl From<Pkcs11Error> for RawSignerError {
+    fn from(err: Pkcs11Error) -> Self {
+        Self::CryptoLibraryError(err.to_string())
+    }
+}
+
+// A PKCS#11 module may only be initialized once per process, and finalizing
+// it closes every session opened through it. Contexts are therefore shared
+// between signers and kept for the lifetime of the process.
+fn pkcs11_context(module_path: &Path) -> Result<Pkcs11, RawSignerError> {
+    static CONTEXTS: OnceLock<Mutex<HashMap<PathBuf, Pkcs11>>> = OnceLock::new();
+
+    let mut contexts = CONTEXTS
+        .get_or_init(Default::default)
+        .lock()
+        .map_err(|_| RawSignerError::InternalError("PKCS#11 context poisoned".to_string()))?;
+
+    if let Some(pkcs11) = contexts.get(module_path) {
+        return Ok(pkcs11.clone());
+    }
+
+    let pkcs11 = Pkcs11::new(module_path).map_err(|e| {
+        RawSignerError::InternalError(format!(
+            "unable to load PKCS#11 module {}: {e}",
+            module_path.display()
+        ))
+    })?;
+
+    // Some other code in this process may already have initialized the module.
+    match pkcs11.initialize(CInitializeArgs::OsThreads) {
+        Ok(()) | Err(Pkcs11Error::Pkcs11(RvError::CryptokiAlreadyInitialized, _)) => (),
+        Err(e) => return Err(e.into()),
+    }
+
+    contexts.insert(module_path.to_path_buf(), pkcs11.clone());
+
+    Ok(pkcs11)
+}
+
+fn mechanism_for_alg(alg: SigningAlg) -> Result<Mechanism<'static>, RawSignerError> {
+    let pss = |hash_alg, mgf, s_len: u64| PkcsPssParams {
+        hash_alg,
+        mgf,
+        s_len: s_len.into(),
+    };
+
+    Ok(match alg {
+        SigningAlg::Es256 => Mechanism::EcdsaSha256,
+        SigningAlg::Es384 => Mechanism::EcdsaSha384,
+        SigningAlg::Es512 => Mechanism::EcdsaSha512,
+        SigningAlg::Ps256 => {
+            Mechanism::Sha256RsaPkcsPss(pss(MechanismType::SHA256, PkcsMgfType::MGF1_SHA256, 32))
+        }
+        SigningAlg::Ps384 => {
+            Mechanism::Sha384RsaPkcsPss(pss(MechanismType::SHA384, PkcsMgfType::MGF1_SHA384, 48))
+        }
+        SigningAlg::Ps512 => {
+            Mechanism::Sha512RsaPkcsPss(pss(MechanismType::SHA512, PkcsMgfType::MGF1_SHA512, 64))
+        }
+        SigningAlg::Ed25519 => Mechanism::Eddsa(EddsaParams::new(EddsaSignatureScheme::Ed25519)),
+        SigningAlg::MlDsa44 | SigningAlg::MlDsa65 | SigningAlg::MlDsa87 => {
+            return Err(unsupported_alg_error(alg))
+        }
+    })
+}
+
+fn unsupported_alg_error(alg: SigningAlg) -> RawSignerError {
+    RawSignerError::InternalError(format!("unsupported signing algorithm for PKCS#11: {alg}"))
+}
+
+fn check_key_type(
+    session: &Session,
+    key: ObjectHandle,
+    alg: SigningAlg,
+) -> Result<(), RawSignerError> {
+    let expected = match alg {
+        SigningAlg::Es256 | SigningAlg::Es384 | SigningAlg::Es512 => KeyType::EC,
+        SigningAlg::Ps256 | SigningAlg::Ps384 | SigningAlg::Ps512 => KeyType::RSA,
+        SigningAlg::Ed25519 => KeyType::EC_EDWARDS,
+        SigningAlg::MlDsa44 | SigningAlg::MlDsa65 | SigningAlg::MlDsa87 => {
+            return Err(unsupported_alg_error(alg))
+        }
+    };
+
+    let key_type = session
+        .get_attributes(key, &[AttributeType::KeyType])?
+        .into_iter()
+        .find_map(|attr| match attr {
+            Attribute::KeyType(key_type) => Some(key_type),
+            _ => None,
+        });
+
+    if key_type != Some(expected) {
+        return Err(RawSignerError::InvalidSigningCredentials(format!(
+            "PKCS#11 private key can not be used for {alg}"
+        )));
+    }
+
+    Ok(())
+}
+
+fn find_one(
+    session: &Session,
+    template: &[Attribute],
+    what: &str,
+) -> Result<ObjectHandle, RawSignerError> {
+    let mut objects = session.find_objects(template)?;
+
+    match objects.len() {
+        1 => Ok(objects.remove(0)),
+        0 => Err(RawSignerError::InvalidSigningCredentials(format!(
+            "no PKCS#11 {what} with the requested ID"
+        ))),
+        _ => Err(RawSignerError::InvalidSigningCredentials(format!(
+            "more than one PKCS#11 {what} with the requested ID"
+        ))),
+    }
+}
+
+fn cert_value(session: &Session, cert: ObjectHandle) -> Result<Vec<u8>, RawSignerError> {
+    session
+        .get_attributes(cert, &[AttributeType::Value])?
+        .into_iter()
+        .find_map(|attr| match attr {
+            Attribute::Value(der) => Some(der),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            RawSignerError::InvalidSigningCredentials(
+                "PKCS#11 certificate has no value".to_string(),
+            )
+        })
+}
+
+// Return the signing certificate followed by its issuers, ending before the
+// self-signed root (if present on the token).
+fn read_cert_chain(session: &Session, key_id: &[u8]) -> Result<Vec<Vec<u8>>, RawSignerError> {
+    let sign_cert = find_one(
+        session,
+        &[
+            Attribute::Class(ObjectClass::CERTIFICATE),
+            Attribute::Id(key_id.to_vec()),
+        ],
+        "certificate",
+    )?;
+
+    let sign_cert = cert_value(session, sign_cert)?;
+
+    let others = session
+        .find_objects(&[Attribute::Class(ObjectClass::CERTIFICATE)])?
+        .into_iter()
+        .map(|cert| cert_value(session, cert))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(build_chain(sign_cert, &others))
+}
+
+fn build_chain(sign_cert: Vec<u8>, candidates: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let mut chain = vec![sign_cert];
+
+    // Bound the walk by the number of candidates so a cycle can't loop forever.
+    for _ in 0..candidates.len() {
+        let Some(current) = chain.last().and_then(|der| parse_cert(der)) else {
+            break;
+        };
+
+        if current.subject().as_raw() == current.issuer().as_raw() {
+            break;
+        }
+
+        let issuer = candidates.iter().find(|der| {
+            !chain.contains(der)
+                && parse_cert(der).is_some_and(|cert| {
+                    cert.subject().as_raw() == current.issuer().as_raw()
+                        && cert.subject().as_raw() != cert.issuer().as_raw()
+                })
+        });
+
+        match issuer {
+            Some(issuer) => chain.push(issuer.clone()),
+            None => break,
+        }
+    }
+
+    chain
+}
+
+fn parse_cert(der: &[u8]) -> Option<X509Certificate<'_>> {
+    X509Certificate::from_der(der).ok().map(|(_, cert)| cert)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::path::Path;
+
+    use asn1_rs::{FromDer, Sequence};
+    use cryptoki::{
+        object::{Attribute, CertificateType, KeyType, ObjectClass},
+        session::UserType,
+        types::AuthPin,
+    };
+    use pkcs8::PrivateKeyInfo;
+    use x509_parser::{certificate::X509Certificate, pem::Pem};
+
+    use super::{build_chain, pkcs11_context, signer_from_pkcs11_token};
+    use crate::crypto::raw_signature::{validator_for_signing_alg, RawSignerError, SigningAlg};
+
+    fn ders(pem: &[u8]) -> Vec<Vec<u8>> {
+        Pem::iter_from_buffer(pem)
+            .map(|pem| pem.unwrap().contents)
+            .collect()
+    }
+
+    #[test]
+    fn chain_from_unordered_certs() {
+        let chain = ders(include_bytes!(
+            "../../../tests/fixtures/crypto/raw_signature/es256.pub"
+        ));
+        let roots = ders(include_bytes!(
+            "../../../tests/fixtures/crypto/raw_signature/test_cert_root_bundle.pem"
+        ));
+
+        // Certificates come back from the token in no particular order and
+        // include the root, which must be left out of the chain.
+        let mut candidates = roots;
+        candidates.extend(chain.iter().rev().cloned());
+
+        assert_eq!(build_chain(chain[0].clone(), &candidates), chain);
+    }
+
+    #[test]
+    fn chain_without_issuers() {
+        let chain = ders(include_bytes!(
+            "../../../tests/fixtures/crypto/raw_signature/es256.pub"
+        ));
+
+        assert_eq!(build_chain(chain[0].clone(), &[]), vec![chain[0].clone()]);
+    }
+
+    /// Signs with the `es256` test credentials imported into a fresh SoftHSM
+    /// token.
+    ///
+    /// Run with `cargo test --features pkcs11 -- --ignored softhsm` after
+    /// setting `SOFTHSM2_MODULE` to the SoftHSM v2 PKCS#11 module (e.g.
+    /// `/usr/lib/softhsm/libsofthsm2.so`) and `SOFTHSM2_CONF` to a
+    /// configuration whose token directory is empty.
+    #[test]
+    #[ignore = "requires SoftHSM; set SOFTHSM2_MODULE and SOFTHSM2_CONF"]
+    fn softhsm_es256() {
+        assert!(
+            std::env::var_os("SOFTHSM2_CONF").is_some(),
+            "SOFTHSM2_CONF must be set"
+        );
+        let module = std::env::var_os("SOFTHSM2_MODULE").unwrap();
+        let module = Path::new(&module);
+
+        let so_pin = AuthPin::new("5678".to_string());
+        let user_pin = AuthPin::new("1234".to_string());
+
+        let pkcs11 = pkcs11_context(module).unwrap();
+        let slot = pkcs11.get_slots_with_token().unwrap()[0];
+        pkcs11.init_token(slot, &so_pin, "c2pa-test").unwrap();
+
+        // SoftHSM moves the initialized token to a new slot.
+        let slot = pkcs11
+            .get_slots_with_token()
+            .unwrap()
+            .into_iter()
+            .find(|slot| pkcs11.get_token_info(*slot).unwrap().label() == "c2pa-test")
+            .unwrap();
+
+        let session = pkcs11.open_rw_session(slot).unwrap();
+        session.login(UserType::So, Some(&so_pin)).unwrap();
+        session.init_pin(&user_pin).unwrap();
+        session.logout().unwrap();
+        session.login(UserType::User, Some(&user_pin)).unwrap();
+
+        let chain = ders(include_bytes!(
+            "../../../tests/fixtures/crypto/raw_signature/es256.pub"
+        ));
+        for (id, der) in chain.iter().enumerate() {
+            let (_, cert) = X509Certificate::from_der(der).unwrap();
+            session
+                .create_object(&[
+                    Attribute::Class(ObjectClass::CERTIFICATE),
+                    Attribute::CertificateType(CertificateType::X_509),
+                    Attribute::Token(true),
+                    Attribute::Id(vec![id as u8 + 1]),
+                    Attribute::Subject(cert.subject().as_raw().to_vec()),
+                    Attribute::Value(der.clone()),
+                ])
+                .unwrap();
+        }
+
+        // Pull the private scalar out of the PKCS#8-wrapped SEC1 key.
+        let key = pem::parse(include_bytes!(
+            "../../../tests/fixtures/crypto/raw_signature/es256.priv"
+        ))
+        .unwrap();
+        let key = PrivateKeyInfo::try_from(key.contents()).unwrap();
+        let (_, sec1) = Sequence::from_der(key.private_key).unwrap();
+        let (_, scalar) = sec1
+            .parse(|i| {
+                let (i, _version) = u32::from_der(i)?;
+                let (i, scalar) = <&[u8]>::from_der(i)?;
+                Ok((i, scalar))
+            })
+            .unwrap();
+
+        session
+            .create_object(&[
+                Attribute::Class(ObjectClass::PRIVATE_KEY),
+                Attribute::KeyType(KeyType::EC),
+                Attribute::Token(true),
+                Attribute::Private(true),
+                Attribute::Sign(true),
+                Attribute::Id(vec![1]),
+                // DER-encoded OID for prime256v1
+                Attribute::EcParams(vec![
+                    0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07,
+                ]),
+                Attribute::Value(scalar.to_vec()),
+            ])
+            .unwrap();
+
+        session.logout().unwrap();
+        drop(session);
+
+        let signer = signer_from_pkcs11_token(
+            module,
+            "c2pa-test",
+            &[1],
+            Some("1234"),
+            SigningAlg::Es256,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(signer.cert_chain().unwrap(), chain);
+
+        let data = b"some sample content to sign";
+        let signature = signer.sign(data).unwrap();
+        assert!(signature.len() <= signer.reserve_size());
+
+        let pub_key = include_bytes!("../../../tests/fixtures/crypto/raw_signature/es256.pub_key");
+
+        let validator = validator_for_signing_alg(SigningAlg::Es256).unwrap();
+        validator.validate(&signature, data, pub_key).unwrap();
+
+        assert!(signer_from_pkcs11_token(
+            module,
+            "c2pa-test",
+            &[1],
+            Some("1234"),
+            SigningAlg::Ps256,
+            None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn missing_module() {
+        assert!(signer_from_pkcs11_token(
+            Path::new("/nonexistent/libpkcs11.so"),
+            "c2pa-test",
+            &[1],
+            None,
+            SigningAlg::Es256,
+            None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn unsupported_alg() {
+        // The algorithm is checked before the module is loaded.
+        let err = signer_from_pkcs11_token(
+            Path::new("/nonexistent/libpkcs11.so"),
+            "c2pa-test",
+            &[1],
+            None,
+            SigningAlg::MlDsa44,
+            None,
+        )
+        .err()
+        .unwrap();
+
+        assert!(matches!(err, RawSignerError::InternalError(msg) if msg.contains("mldsa44")));
+    }
+}
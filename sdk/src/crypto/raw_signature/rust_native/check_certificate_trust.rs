@@ -31,7 +31,9 @@ pub(crate) fn check_certificate_trust(
     cert_der: &[u8],
     signing_time_epoch: Option<i64>,
 ) -> Result<TrustAnchorType, CertificateTrustError> {
-    if ctp.trust_anchor_ders().count() == 0 && ctp.user_trust_anchor_ders().count() == 0 {
+    if ctp.trust_anchor_ders_at(signing_time_epoch).count() == 0
+        && ctp.user_trust_anchor_ders_at(signing_time_epoch).count() == 0
+    {
         return Err(CertificateTrustError::CertificateNotTrusted);
     }
 
@@ -63,7 +65,7 @@ pub(crate) fn check_certificate_trust(
 
     // Build anchors and check against trust anchors.
     let anchors: Vec<(X509Certificate, &Vec<u8>)> = ctp
-        .trust_anchor_ders()
+        .trust_anchor_ders_at(signing_time_epoch)
         .filter_map(|anchor_der| {
             let (_, cert) = X509Certificate::from_der(anchor_der)
                 .map_err(|_e| CertificateTrustError::CertificateNotTrusted)
@@ -74,7 +76,7 @@ pub(crate) fn check_certificate_trust(
 
     // Build anchors and check against user provided trust anchors.
     let user_anchors: Vec<(X509Certificate, &Vec<u8>)> = ctp
-        .user_trust_anchor_ders()
+        .user_trust_anchor_ders_at(signing_time_epoch)
         .filter_map(|anchor_der| {
             let (_, cert) = X509Certificate::from_der(anchor_der)
                 .map_err(|_e| CertificateTrustError::CertificateNotTrusted)
@@ -218,6 +220,22 @@ fn signing_alg_to_sig_and_hash_oid(alg: &str) -> Option<(bcder::Oid, bcder::Oid)
             ans1_oid_bcder_oid(&ED25519_OID)?,
             ans1_oid_bcder_oid(&SHA512_OID)?,
         ))
+    } else if alg == "mldsa44" {
+        // ML-DSA doesn't use a separate hash algorithm; SHA-512 is a placeholder.
+        Some((
+            ans1_oid_bcder_oid(&ML_DSA_44_OID)?,
+            ans1_oid_bcder_oid(&SHA512_OID)?,
+        ))
+    } else if alg == "mldsa65" {
+        Some((
+            ans1_oid_bcder_oid(&ML_DSA_65_OID)?,
+            ans1_oid_bcder_oid(&SHA512_OID)?,
+        ))
+    } else if alg == "mldsa87" {
+        Some((
+            ans1_oid_bcder_oid(&ML_DSA_87_OID)?,
+            ans1_oid_bcder_oid(&SHA512_OID)?,
+        ))
     } else {
         None
     }
@@ -242,6 +260,12 @@ fn cert_signing_alg(cert: &X509Certificate) -> Option<String> {
         signing_alg_from_rsapss_alg(&cert.signature_algorithm)
     } else if *cert_alg == ED25519_OID {
         Some(SigningAlg::Ed25519.to_string())
+    } else if *cert_alg == ML_DSA_44_OID {
+        Some(SigningAlg::MlDsa44.to_string())
+    } else if *cert_alg == ML_DSA_65_OID {
+        Some(SigningAlg::MlDsa65.to_string())
+    } else if *cert_alg == ML_DSA_87_OID {
+        Some(SigningAlg::MlDsa87.to_string())
     } else {
         None
     }
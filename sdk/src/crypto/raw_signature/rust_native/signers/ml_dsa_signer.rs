@@ -0,0 +1,173 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use ::ml_dsa::{MlDsa44, MlDsa65, MlDsa87, Seed, SigningKey};
+use asn1_rs::{Any, Class, FromDer, Sequence, Tag};
+use pkcs8::PrivateKeyInfo;
+use x509_parser::{error::PEMError, pem::Pem};
+
+use crate::crypto::{
+    raw_signature::{ml_dsa, RawSigner, RawSignerError, SigningAlg},
+    time_stamp::TimeStampProvider,
+};
+
+// Signing keys for each ML-DSA parameter set are different types.
+enum MlDsaSigningKey {
+    MlDsa44(Box<SigningKey<MlDsa44>>),
+    MlDsa65(Box<SigningKey<MlDsa65>>),
+    MlDsa87(Box<SigningKey<MlDsa87>>),
+}
+
+/// Implements `RawSigner` trait using the `ml-dsa` crate's implementation of
+/// ML-DSA (FIPS 204).
+pub struct MlDsaSigner {
+    cert_chain: Vec<Vec<u8>>,
+    cert_chain_len: usize,
+
+    signing_key: MlDsaSigningKey,
+
+    time_stamp_service_url: Option<String>,
+    time_stamp_size: usize,
+}
+
+impl MlDsaSigner {
+    pub(crate) fn from_cert_chain_and_private_key(
+        cert_chain: &[u8],
+        private_key: &[u8],
+        alg: SigningAlg,
+        time_stamp_service_url: Option<String>,
+    ) -> Result<Self, RawSignerError> {
+        let cert_chain = Pem::iter_from_buffer(cert_chain)
+            .map(|r| match r {
+                Ok(pem) => Ok(pem.contents),
+                Err(e) => Err(e),
+            })
+            .collect::<Result<Vec<Vec<u8>>, PEMError>>()
+            .map_err(|e| RawSignerError::InvalidSigningCredentials(e.to_string()))?;
+
+        let cert_chain_len = cert_chain.iter().fold(0usize, |sum, c| sum + c.len());
+
+        let seed = seed_from_pkcs8_pem(private_key, alg)?;
+
+        let signing_key = match alg {
+            SigningAlg::MlDsa44 => MlDsaSigningKey::MlDsa44(Box::new(SigningKey::from_seed(&seed))),
+            SigningAlg::MlDsa65 => MlDsaSigningKey::MlDsa65(Box::new(SigningKey::from_seed(&seed))),
+            SigningAlg::MlDsa87 => MlDsaSigningKey::MlDsa87(Box::new(SigningKey::from_seed(&seed))),
+            _ => {
+                return Err(RawSignerError::InvalidSigningCredentials(
+                    "Unsupported algorithm".to_string(),
+                ))
+            }
+        };
+
+        Ok(MlDsaSigner {
+            cert_chain,
+            cert_chain_len,
+            signing_key,
+            time_stamp_service_url,
+            time_stamp_size: 10000,
+        })
+    }
+}
+
+impl RawSigner for MlDsaSigner {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>, RawSignerError> {
+        let sig = match self.signing_key {
+            MlDsaSigningKey::MlDsa44(ref key) => key
+                .expanded_key()
+                .sign_deterministic(data, &[])
+                .map(|sig| sig.encode().to_vec()),
+            MlDsaSigningKey::MlDsa65(ref key) => key
+                .expanded_key()
+                .sign_deterministic(data, &[])
+                .map(|sig| sig.encode().to_vec()),
+            MlDsaSigningKey::MlDsa87(ref key) => key
+                .expanded_key()
+                .sign_deterministic(data, &[])
+                .map(|sig| sig.encode().to_vec()),
+        };
+
+        sig.map_err(|e| RawSignerError::InternalError(format!("signature error: {e}")))
+    }
+
+    fn alg(&self) -> SigningAlg {
+        match self.signing_key {
+            MlDsaSigningKey::MlDsa44(_) => SigningAlg::MlDsa44,
+            MlDsaSigningKey::MlDsa65(_) => SigningAlg::MlDsa65,
+            MlDsaSigningKey::MlDsa87(_) => SigningAlg::MlDsa87,
+        }
+    }
+
+    fn reserve_size(&self) -> usize {
+        // Unlike the classical algorithms, ML-DSA signatures are too large to
+        // fit in the default 1024-byte allowance.
+        ml_dsa::signature_len(self.alg()) + 1024 + self.cert_chain_len + self.time_stamp_size
+    }
+
+    fn cert_chain(&self) -> Result<Vec<Vec<u8>>, RawSignerError> {
+        Ok(self.cert_chain.clone())
+    }
+}
+
+impl TimeStampProvider for MlDsaSigner {
+    fn time_stamp_service_url(&self) -> Option<String> {
+        self.time_stamp_service_url.clone()
+    }
+}
+
+/// Extract the 32-byte seed from an ML-DSA private key in PKCS#8 PEM format.
+///
+/// Both the `seed` and `both` encodings of the `ML-DSA-PrivateKey` CHOICE
+/// (RFC 9881) are accepted. The `expandedKey`-only form can't be used because
+/// it doesn't carry the seed.
+fn seed_from_pkcs8_pem(private_key: &[u8], alg: SigningAlg) -> Result<Seed, RawSignerError> {
+    let pem = pem::parse(private_key).map_err(|e| {
+        RawSignerError::InvalidSigningCredentials(format!("invalid {alg} private key PEM: {e}"))
+    })?;
+
+    let pk_info = PrivateKeyInfo::try_from(pem.contents()).map_err(|e| {
+        RawSignerError::InvalidSigningCredentials(format!("invalid {alg} PKCS#8 structure: {e}"))
+    })?;
+
+    let key_oid = x509_parser::oid_registry::Oid::new(pk_info.algorithm.oid.as_bytes().into());
+    if ml_dsa::alg_from_oid(&key_oid) != Some(alg) || pk_info.algorithm.parameters.is_some() {
+        return Err(RawSignerError::InvalidSigningCredentials(format!(
+            "private key is not a valid {alg} key"
+        )));
+    }
+
+    let invalid_key =
+        || RawSignerError::InvalidSigningCredentials(format!("invalid {alg} private key encoding"));
+
+    let (_, key) = Any::from_der(pk_info.private_key).map_err(|_| invalid_key())?;
+
+    if key.class() == Class::ContextSpecific && key.tag() == Tag(0) {
+        // seed [0] IMPLICIT OCTET STRING (SIZE (32))
+        Seed::try_from(key.data).map_err(|_| invalid_key())
+    } else if key.tag() == Tag::Sequence {
+        // both SEQUENCE { seed OCTET STRING (SIZE (32)), expandedKey OCTET STRING }
+        let (_, seq) = Sequence::from_der(pk_info.private_key).map_err(|_| invalid_key())?;
+        let (_, seed) = seq
+            .parse(|i| {
+                let (i, seed) = <&[u8]>::from_der(i)?;
+                let (i, _expanded_key) = <&[u8]>::from_der(i)?;
+                Ok((i, seed))
+            })
+            .map_err(|_| invalid_key())?;
+        Seed::try_from(seed).map_err(|_| invalid_key())
+    } else {
+        Err(RawSignerError::InvalidSigningCredentials(format!(
+            "{alg} private key must include the seed"
+        )))
+    }
+}
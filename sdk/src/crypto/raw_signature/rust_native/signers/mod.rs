@@ -18,6 +18,7 @@ use crate::crypto::raw_signature::{RawSigner, RawSignerError, SigningAlg};
 
 mod ecdsa_signer;
 mod ed25519_signer;
+mod ml_dsa_signer;
 mod rsa_signer;
 
 /// Return a built-in [`RawSigner`] instance using the provided signing
@@ -61,9 +62,14 @@ pub(crate) fn signer_from_cert_chain_and_private_key(
             )?,
         )),
 
-        _ => Err(RawSignerError::InternalError(format!(
-            "unsupported signing algorithm {alg}"
-        ))),
+        SigningAlg::MlDsa44 | SigningAlg::MlDsa65 | SigningAlg::MlDsa87 => Ok(Box::new(
+            ml_dsa_signer::MlDsaSigner::from_cert_chain_and_private_key(
+                cert_chain,
+                private_key,
+                alg,
+                time_stamp_service_url,
+            )?,
+        )),
     }
 }
 
@@ -0,0 +1,84 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use ::ml_dsa::{
+    EncodedVerifyingKey, MlDsa44, MlDsa65, MlDsa87, MlDsaParams, Signature, VerifyingKey,
+};
+use x509_parser::{prelude::FromDer, x509::SubjectPublicKeyInfo};
+
+use crate::crypto::raw_signature::{
+    ml_dsa, RawSignatureValidationError, RawSignatureValidator, SigningAlg,
+};
+
+/// An `MlDsaValidator` can validate raw signatures with one of the ML-DSA
+/// (FIPS 204) signature algorithms.
+pub enum MlDsaValidator {
+    /// ML-DSA-44 parameter set
+    MlDsa44,
+
+    /// ML-DSA-65 parameter set
+    MlDsa65,
+
+    /// ML-DSA-87 parameter set
+    MlDsa87,
+}
+
+impl RawSignatureValidator for MlDsaValidator {
+    fn validate(
+        &self,
+        sig: &[u8],
+        data: &[u8],
+        public_key: &[u8],
+    ) -> Result<(), RawSignatureValidationError> {
+        let alg = match self {
+            Self::MlDsa44 => SigningAlg::MlDsa44,
+            Self::MlDsa65 => SigningAlg::MlDsa65,
+            Self::MlDsa87 => SigningAlg::MlDsa87,
+        };
+
+        if ml_dsa::alg_from_spki(public_key) != Some(alg) {
+            return Err(RawSignatureValidationError::InvalidPublicKey);
+        }
+
+        let (_, spki) = SubjectPublicKeyInfo::from_der(public_key)
+            .map_err(|_| RawSignatureValidationError::InvalidPublicKey)?;
+
+        let public_key = spki.subject_public_key.data.as_ref();
+
+        match self {
+            Self::MlDsa44 => verify::<MlDsa44>(sig, data, public_key),
+            Self::MlDsa65 => verify::<MlDsa65>(sig, data, public_key),
+            Self::MlDsa87 => verify::<MlDsa87>(sig, data, public_key),
+        }
+    }
+}
+
+fn verify<P: MlDsaParams>(
+    sig: &[u8],
+    data: &[u8],
+    public_key: &[u8],
+) -> Result<(), RawSignatureValidationError> {
+    let encoded_key = EncodedVerifyingKey::<P>::try_from(public_key)
+        .map_err(|_| RawSignatureValidationError::InvalidPublicKey)?;
+
+    let vk = VerifyingKey::<P>::decode(&encoded_key);
+
+    let sig =
+        Signature::<P>::try_from(sig).map_err(|_| RawSignatureValidationError::InvalidSignature)?;
+
+    if vk.verify_with_context(data, &[], &sig) {
+        Ok(())
+    } else {
+        Err(RawSignatureValidationError::SignatureMismatch)
+    }
+}
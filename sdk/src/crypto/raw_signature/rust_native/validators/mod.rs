@@ -28,6 +28,9 @@ pub(crate) use ecdsa_validator::EcdsaValidator;
 mod ed25519_validator;
 pub(crate) use ed25519_validator::Ed25519Validator;
 
+mod ml_dsa_validator;
+pub(crate) use ml_dsa_validator::MlDsaValidator;
+
 mod rsa_legacy_validator;
 pub(crate) use rsa_legacy_validator::RsaLegacyValidator;
 
@@ -86,7 +89,9 @@ pub fn validator_for_signing_alg(alg: SigningAlg) -> Option<Box<dyn RawSignature
         SigningAlg::Es256 => Some(Box::new(EcdsaValidator::Es256)),
         SigningAlg::Es384 => Some(Box::new(EcdsaValidator::Es384)),
         SigningAlg::Es512 => Some(Box::new(EcdsaValidator::Es512)),
-        _ => None,
+        SigningAlg::MlDsa44 => Some(Box::new(MlDsaValidator::MlDsa44)),
+        SigningAlg::MlDsa65 => Some(Box::new(MlDsaValidator::MlDsa65)),
+        SigningAlg::MlDsa87 => Some(Box::new(MlDsaValidator::MlDsa87)),
     }
 }
 
@@ -133,6 +138,15 @@ pub(crate) fn validator_for_sig_and_hash_algs(
         return Some(Box::new(Ed25519Validator {}));
     }
 
+    // Handle ML-DSA. The hash algorithm is intrinsic to the parameter set.
+    if sig_alg.as_ref() == ML_DSA_44_OID.as_bytes() {
+        return Some(Box::new(MlDsaValidator::MlDsa44));
+    } else if sig_alg.as_ref() == ML_DSA_65_OID.as_bytes() {
+        return Some(Box::new(MlDsaValidator::MlDsa65));
+    } else if sig_alg.as_ref() == ML_DSA_87_OID.as_bytes() {
+        return Some(Box::new(MlDsaValidator::MlDsa87));
+    }
+
     None
 }
 
@@ -238,7 +238,18 @@ pub fn async_signer_from_cert_chain_and_private_key(
         time_stamp_service_url,
     )?;
 
-    Ok(Box::new(AsyncRawSignerWrapper(sync_signer)))
+    Ok(async_signer_from_raw_signer(sync_signer))
+}
+
+/// Adapt a synchronous [`RawSigner`] so it can be used where an
+/// [`AsyncRawSigner`] is expected.
+///
+/// Time stamps requested from a time stamp service URL go through the
+/// non-blocking HTTP client.
+pub(crate) fn async_signer_from_raw_signer(
+    signer: Box<dyn RawSigner + Send + Sync>,
+) -> Box<dyn AsyncRawSigner + Send + Sync> {
+    Box::new(AsyncRawSignerWrapper(signer))
 }
 
 struct AsyncRawSignerWrapper(Box<dyn RawSigner + Send + Sync>);
@@ -286,6 +297,25 @@ impl AsyncTimeStampProvider for AsyncRawSignerWrapper {
         &self,
         message: &[u8],
     ) -> Option<Result<Vec<u8>, TimeStampError>> {
+        // Use the non-blocking HTTP client when talking to a time stamp
+        // service. Signers that issue time stamps some other way (e.g. a
+        // local TSA) keep their own implementation.
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(url) = self.0.time_stamp_service_url() {
+            return match self.0.time_stamp_request_body(message) {
+                Ok(body) => Some(
+                    crate::crypto::time_stamp::default_rfc3161_request_async(
+                        &url,
+                        self.0.time_stamp_request_headers(),
+                        &body,
+                        message,
+                    )
+                    .await,
+                ),
+                Err(err) => Some(Err(err)),
+            };
+        }
+
         self.0.send_time_stamp_request(message)
     }
 }
@@ -322,6 +352,18 @@ pub(crate) fn test_signer(alg: SigningAlg) -> Box<dyn RawSigner> {
             include_bytes!("../../../tests/fixtures/crypto/raw_signature/ps512.pub").as_slice(),
             include_bytes!("../../../tests/fixtures/crypto/raw_signature/ps512.priv").as_slice(),
         ),
+        SigningAlg::MlDsa44 => (
+            include_bytes!("../../../tests/fixtures/crypto/raw_signature/mldsa44.pub").as_slice(),
+            include_bytes!("../../../tests/fixtures/crypto/raw_signature/mldsa44.priv").as_slice(),
+        ),
+        SigningAlg::MlDsa65 => (
+            include_bytes!("../../../tests/fixtures/crypto/raw_signature/mldsa65.pub").as_slice(),
+            include_bytes!("../../../tests/fixtures/crypto/raw_signature/mldsa65.priv").as_slice(),
+        ),
+        SigningAlg::MlDsa87 => (
+            include_bytes!("../../../tests/fixtures/crypto/raw_signature/mldsa87.pub").as_slice(),
+            include_bytes!("../../../tests/fixtures/crypto/raw_signature/mldsa87.priv").as_slice(),
+        ),
     };
 
     signer_from_cert_chain_and_private_key(cert_chain, private_key, alg, None).unwrap()
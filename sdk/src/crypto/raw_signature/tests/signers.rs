@@ -116,6 +116,103 @@ fn ed25519() {
     validator.validate(&signature, data, pub_key).unwrap();
 }
 
+#[test]
+#[cfg_attr(
+    all(target_arch = "wasm32", not(target_os = "wasi")),
+    wasm_bindgen_test
+)]
+fn mldsa44() {
+    let cert_chain = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa44.pub");
+    let private_key =
+        include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa44.priv");
+
+    let signer =
+        signer_from_cert_chain_and_private_key(cert_chain, private_key, SigningAlg::MlDsa44, None)
+            .unwrap();
+
+    let data = b"some sample content to sign";
+    let signature = signer.sign(data).unwrap();
+
+    println!("signature len = {}", signature.len());
+    assert!(signature.len() <= signer.reserve_size());
+
+    let pub_key = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa44.pub_key");
+
+    let validator = validator_for_signing_alg(SigningAlg::MlDsa44).unwrap();
+    validator.validate(&signature, data, pub_key).unwrap();
+}
+
+#[test]
+#[cfg_attr(
+    all(target_arch = "wasm32", not(target_os = "wasi")),
+    wasm_bindgen_test
+)]
+fn mldsa65() {
+    let cert_chain = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.pub");
+    let private_key =
+        include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.priv");
+
+    let signer =
+        signer_from_cert_chain_and_private_key(cert_chain, private_key, SigningAlg::MlDsa65, None)
+            .unwrap();
+
+    let data = b"some sample content to sign";
+    let signature = signer.sign(data).unwrap();
+
+    println!("signature len = {}", signature.len());
+    assert!(signature.len() <= signer.reserve_size());
+
+    let pub_key = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.pub_key");
+
+    let validator = validator_for_signing_alg(SigningAlg::MlDsa65).unwrap();
+    validator.validate(&signature, data, pub_key).unwrap();
+}
+
+#[test]
+#[cfg_attr(
+    all(target_arch = "wasm32", not(target_os = "wasi")),
+    wasm_bindgen_test
+)]
+fn mldsa87() {
+    let cert_chain = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa87.pub");
+    let private_key =
+        include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa87.priv");
+
+    let signer =
+        signer_from_cert_chain_and_private_key(cert_chain, private_key, SigningAlg::MlDsa87, None)
+            .unwrap();
+
+    let data = b"some sample content to sign";
+    let signature = signer.sign(data).unwrap();
+
+    println!("signature len = {}", signature.len());
+    assert!(signature.len() <= signer.reserve_size());
+
+    let pub_key = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa87.pub_key");
+
+    let validator = validator_for_signing_alg(SigningAlg::MlDsa87).unwrap();
+    validator.validate(&signature, data, pub_key).unwrap();
+}
+
+#[test]
+#[cfg_attr(
+    all(target_arch = "wasm32", not(target_os = "wasi")),
+    wasm_bindgen_test
+)]
+fn mldsa_wrong_parameter_set() {
+    let cert_chain = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.pub");
+    let private_key =
+        include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.priv");
+
+    assert!(signer_from_cert_chain_and_private_key(
+        cert_chain,
+        private_key,
+        SigningAlg::MlDsa44,
+        None
+    )
+    .is_err());
+}
+
 #[test]
 #[cfg_attr(
     all(target_arch = "wasm32", not(target_os = "wasi")),
@@ -144,6 +144,93 @@ fn ed25519_bad_data() {
     );
 }
 
+#[test]
+#[cfg_attr(
+    all(target_arch = "wasm32", not(target_os = "wasi")),
+    wasm_bindgen_test
+)]
+fn mldsa44() {
+    let signature =
+        include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa44.raw_sig");
+    let pub_key = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa44.pub_key");
+
+    let validator = validator_for_signing_alg(SigningAlg::MlDsa44).unwrap();
+
+    validator.validate(signature, SAMPLE_DATA, pub_key).unwrap();
+}
+
+#[test]
+#[cfg_attr(
+    all(target_arch = "wasm32", not(target_os = "wasi")),
+    wasm_bindgen_test
+)]
+fn mldsa65() {
+    let signature =
+        include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.raw_sig");
+    let pub_key = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.pub_key");
+
+    let validator = validator_for_signing_alg(SigningAlg::MlDsa65).unwrap();
+
+    validator.validate(signature, SAMPLE_DATA, pub_key).unwrap();
+}
+
+#[test]
+#[cfg_attr(
+    all(target_arch = "wasm32", not(target_os = "wasi")),
+    wasm_bindgen_test
+)]
+fn mldsa87() {
+    let signature =
+        include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa87.raw_sig");
+    let pub_key = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa87.pub_key");
+
+    let validator = validator_for_signing_alg(SigningAlg::MlDsa87).unwrap();
+
+    validator.validate(signature, SAMPLE_DATA, pub_key).unwrap();
+}
+
+#[test]
+#[cfg_attr(
+    all(target_arch = "wasm32", not(target_os = "wasi")),
+    wasm_bindgen_test
+)]
+fn mldsa65_bad_data() {
+    let signature =
+        include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.raw_sig");
+    let pub_key = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.pub_key");
+
+    let mut data = SAMPLE_DATA.to_vec();
+    data[5] = 10;
+    data[6] = 11;
+
+    let validator = validator_for_signing_alg(SigningAlg::MlDsa65).unwrap();
+
+    assert_eq!(
+        validator.validate(signature, &data, pub_key).unwrap_err(),
+        RawSignatureValidationError::SignatureMismatch
+    );
+}
+
+#[test]
+#[cfg_attr(
+    all(target_arch = "wasm32", not(target_os = "wasi")),
+    wasm_bindgen_test
+)]
+fn mldsa44_wrong_key() {
+    let signature =
+        include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa44.raw_sig");
+    let pub_key = include_bytes!("../../../../tests/fixtures/crypto/raw_signature/mldsa65.pub_key");
+
+    let validator = validator_for_signing_alg(SigningAlg::MlDsa44).unwrap();
+
+    assert_eq!(
+        validator
+            .validate(signature, SAMPLE_DATA, pub_key)
+            .unwrap_err(),
+        RawSignatureValidationError::InvalidPublicKey
+    );
+}
+
 #[test]
 #[cfg_attr(
     all(target_arch = "wasm32", not(target_os = "wasi")),
@@ -373,6 +460,9 @@ fn test_get_by_sig_and_alg() {
     let rsa_pss_oid = ans1_oid_bcder_oid(&RSA_PSS_OID).unwrap();
     let ec_pubic_key_oid = ans1_oid_bcder_oid(&EC_PUBLICKEY_OID).unwrap();
     let ed25519_oid = ans1_oid_bcder_oid(&ED25519_OID).unwrap();
+    let ml_dsa_44_oid = ans1_oid_bcder_oid(&ML_DSA_44_OID).unwrap();
+    let ml_dsa_65_oid = ans1_oid_bcder_oid(&ML_DSA_65_OID).unwrap();
+    let ml_dsa_87_oid = ans1_oid_bcder_oid(&ML_DSA_87_OID).unwrap();
     let sha256 = ans1_oid_bcder_oid(&SHA256_OID).unwrap();
     let sha384 = ans1_oid_bcder_oid(&SHA384_OID).unwrap();
     let sha512 = ans1_oid_bcder_oid(&SHA512_OID).unwrap();
@@ -391,6 +481,10 @@ fn test_get_by_sig_and_alg() {
 
     assert!(validator_for_sig_and_hash_algs(&ed25519_oid, &sha512).is_some());
 
+    assert!(validator_for_sig_and_hash_algs(&ml_dsa_44_oid, &sha512).is_some());
+    assert!(validator_for_sig_and_hash_algs(&ml_dsa_65_oid, &sha512).is_some());
+    assert!(validator_for_sig_and_hash_algs(&ml_dsa_87_oid, &sha512).is_some());
+
     // test negative case
     const TEST_FAIL: Oid = bcder::Oid(OctetString::from_static(&[0, 0, 0, 0]));
     assert!(validator_for_sig_and_hash_algs(&TEST_FAIL, &sha512).is_none());
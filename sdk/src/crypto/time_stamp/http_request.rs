@@ -50,7 +50,11 @@ pub fn default_rfc3161_request(
         TimeStampError::InternalError("failure to decode Constructed TimeStampReq".to_string())
     })?;
 
-    let ts = time_stamp_request_http(url, headers, &request)?;
+    let ts = if _sync {
+        time_stamp_request_http(url, headers, &request)?
+    } else {
+        time_stamp_request_http_async(url, headers, &request).await?
+    };
 
     let mut local_log = StatusTracker::default();
     let ctp = CertificateTrustPolicy::passthrough();
@@ -65,6 +69,7 @@ pub fn default_rfc3161_request(
     Ok(ts)
 }
 
+#[async_generic]
 fn time_stamp_request_http(
     url: &str,
     headers: Option<Vec<(String, String)>>,
@@ -72,16 +77,47 @@ fn time_stamp_request_http(
 ) -> Result<Vec<u8>, TimeStampError> {
     // This function exists to work around a bug in serialization of
     // TimeStampResp so we just return the data directly.
-    use std::io::Read;
-
-    const HTTP_CONTENT_TYPE_REQUEST: &str = "application/timestamp-query";
-    const HTTP_CONTENT_TYPE_RESPONSE: &str = "application/timestamp-reply";
-
     let mut body = Vec::<u8>::new();
     request
         .encode_ref()
         .write_encoded(bcder::Mode::Der, &mut body)?;
 
+    let response_bytes = if _sync {
+        post_time_stamp_query(url, headers, body)?
+    } else {
+        post_time_stamp_query_async(url, headers, body).await?
+    };
+
+    let res = TimeStampResponse(
+        Constructed::decode(response_bytes.as_ref(), bcder::Mode::Der, |cons| {
+            TimeStampResp::take_from(cons)
+        })
+        .map_err(|e| TimeStampError::DecodeError(e.to_string()))?,
+    );
+
+    // Verify nonce was reflected, if present.
+    if res.is_success() {
+        if let Some(tst_info) = res.tst_info()? {
+            if tst_info.nonce != request.nonce {
+                return Err(TimeStampError::NonceMismatch);
+            }
+        }
+    }
+
+    Ok(response_bytes)
+}
+
+const HTTP_CONTENT_TYPE_REQUEST: &str = "application/timestamp-query";
+const HTTP_CONTENT_TYPE_RESPONSE: &str = "application/timestamp-reply";
+const MAX_RESPONSE_LEN: u64 = 1000000;
+
+fn post_time_stamp_query(
+    url: &str,
+    headers: Option<Vec<(String, String)>>,
+    body: Vec<u8>,
+) -> Result<Vec<u8>, TimeStampError> {
+    use std::io::Read;
+
     let mut req = ureq::post(url);
 
     if let Some(headers) = headers {
@@ -107,30 +143,59 @@ fn time_stamp_request_http(
         let mut response_bytes: Vec<u8> = Vec::with_capacity(len);
 
         body.into_reader()
-            .take(1000000)
+            .take(MAX_RESPONSE_LEN)
             .read_to_end(&mut response_bytes)?;
 
-        let res = TimeStampResponse(
-            Constructed::decode(response_bytes.as_ref(), bcder::Mode::Der, |cons| {
-                TimeStampResp::take_from(cons)
-            })
-            .map_err(|e| TimeStampError::DecodeError(e.to_string()))?,
-        );
-
-        // Verify nonce was reflected, if present.
-        if res.is_success() {
-            if let Some(tst_info) = res.tst_info()? {
-                if tst_info.nonce != request.nonce {
-                    return Err(TimeStampError::NonceMismatch);
-                }
+        Ok(response_bytes)
+    } else {
+        Err(TimeStampError::HttpErrorResponse(
+            response.status().as_u16(),
+            content_type.map(|content_type| content_type.to_owned()),
+        ))
+    }
+}
+
+async fn post_time_stamp_query_async(
+    url: &str,
+    headers: Option<Vec<(String, String)>>,
+    body: Vec<u8>,
+) -> Result<Vec<u8>, TimeStampError> {
+    let mut req = reqwest::Client::new().post(url);
+
+    if let Some(headers) = headers {
+        for (name, value) in headers {
+            req = req.header(name, value);
+        }
+    }
+
+    let mut response = req
+        .header(header::CONTENT_TYPE, HTTP_CONTENT_TYPE_REQUEST)
+        .body(body)
+        .send()
+        .await?;
+
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|header| header.to_str().ok())
+        .map(|content_type| content_type.to_owned());
+    if status == 200 && content_type.as_deref() == Some(HTTP_CONTENT_TYPE_RESPONSE) {
+        let mut response_bytes: Vec<u8> = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if response_bytes.len() as u64 + chunk.len() as u64 > MAX_RESPONSE_LEN {
+                return Err(TimeStampError::DecodeError(
+                    "time stamp response is too large".to_string(),
+                ));
             }
+            response_bytes.extend_from_slice(&chunk);
         }
 
         Ok(response_bytes)
     } else {
         Err(TimeStampError::HttpErrorResponse(
-            response.status().as_u16(),
-            content_type.map(|content_type| content_type.to_owned()),
+            status.as_u16(),
+            content_type,
         ))
     }
 }
@@ -143,3 +208,9 @@ impl From<ureq::Error> for TimeStampError {
         Self::HttpConnectionError(err.to_string())
     }
 }
+
+impl From<reqwest::Error> for TimeStampError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::HttpConnectionError(err.to_string())
+    }
+}
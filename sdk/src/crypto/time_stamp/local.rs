@@ -0,0 +1,633 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use async_trait::async_trait;
+use bcder::{
+    decode::Constructed,
+    encode::{self, Values},
+    ConstOid, Mode,
+};
+use chrono::{Timelike, Utc};
+use rand::{thread_rng, Rng};
+use rasn::{
+    prelude::*,
+    types::{Any, ObjectIdentifier, SetOf},
+};
+use rasn_cms::{
+    AlgorithmIdentifier, Attribute, Certificate, CertificateChoices, EncapsulatedContentInfo,
+    IssuerAndSerialNumber, SignerIdentifier, SignerInfo,
+};
+use x509_certificate::{asn1time::GeneralizedTime, DigestAlgorithm};
+
+use crate::crypto::{
+    asn1::rfc3161::{PkiStatus, PkiStatusInfo, TimeStampReq, TstInfo},
+    ec_utils::p1363_to_der,
+    raw_signature::{
+        signer_from_cert_chain_and_private_key, RawSigner, RawSignerError, SigningAlg,
+    },
+    time_stamp::{
+        default_rfc3161_message, AsyncTimeStampProvider, TimeStampError, TimeStampProvider,
+    },
+};
+
+/// Policy reported in issued tokens when the request does not ask for one.
+///
+/// This is the X.509 `anyPolicy` identifier (2.5.29.32.0); a local authority
+/// makes no assertions beyond those of its own certificate.
+const DEFAULT_POLICY: ConstOid = bcder::Oid(&[85, 29, 32, 0]);
+
+/// A `LocalTimeStampAuthority` issues [RFC 3161] time stamp tokens in-process
+/// using a configured certificate and private key rather than contacting a
+/// remote time stamp service.
+///
+/// This is intended for offline and test signing workflows. Tokens issued
+/// this way validate like any other time stamp, which means a reader will
+/// only report them as trusted if the authority's root certificate is in
+/// its trust store.
+///
+/// [RFC 3161]: https://datatracker.ietf.org/doc/html/rfc3161
+pub struct LocalTimeStampAuthority {
+    signer: Box<dyn RawSigner + Send + Sync>,
+}
+
+impl LocalTimeStampAuthority {
+    /// Create a time stamp authority that signs tokens with the provided
+    /// signer.
+    ///
+    /// The signer's certificate chain must start with the authority's own
+    /// certificate. Its time stamp settings, if any, are ignored.
+    pub fn new(signer: Box<dyn RawSigner + Send + Sync>) -> Self {
+        Self { signer }
+    }
+
+    /// Create a time stamp authority from a PEM-encoded certificate chain and
+    /// private key.
+    pub fn from_cert_chain_and_private_key(
+        cert_chain: &[u8],
+        private_key: &[u8],
+        alg: SigningAlg,
+    ) -> Result<Self, RawSignerError> {
+        Ok(Self::new(signer_from_cert_chain_and_private_key(
+            cert_chain,
+            private_key,
+            alg,
+            None,
+        )?))
+    }
+
+    /// Return the algorithm used to sign issued tokens.
+    pub fn alg(&self) -> SigningAlg {
+        self.signer.alg()
+    }
+
+    /// Return the size in bytes of the largest token this authority is
+    /// expected to issue.
+    pub fn reserve_size(&self) -> usize {
+        let cert_chain_len = self
+            .signer
+            .cert_chain()
+            .map(|certs| certs.iter().map(|c| c.len()).sum::<usize>())
+            .unwrap_or_default();
+
+        // Signed attributes, TSTInfo, and encoding overhead sit comfortably
+        // within 2 KiB; the signature and certificates are the variable part.
+        2048 + self.signer.reserve_size() + cert_chain_len
+    }
+
+    /// Process a DER-encoded `TimeStampReq` and return the DER-encoded
+    /// `TimeStampResp`.
+    pub fn respond(&self, request: &[u8]) -> Result<Vec<u8>, TimeStampError> {
+        let request = Constructed::decode(request, Mode::Der, TimeStampReq::take_from)
+            .map_err(|e| TimeStampError::DecodeError(e.to_string()))?;
+
+        let token = self.time_stamp_token(&request)?;
+
+        let token = Constructed::decode(token.as_slice(), Mode::Der, |cons| cons.capture_one())
+            .map_err(|e| TimeStampError::InternalError(e.to_string()))?;
+
+        let status = PkiStatusInfo {
+            status: PkiStatus::Granted,
+            status_string: None,
+            fail_info: None,
+        };
+
+        let mut response = Vec::new();
+        encode::sequence((status.encode_ref(), &token)).write_encoded(Mode::Der, &mut response)?;
+
+        Ok(response)
+    }
+
+    // Build the DER-encoded `TimeStampToken` (a CMS `ContentInfo` wrapping
+    // `SignedData`) for a request.
+    fn time_stamp_token(&self, request: &TimeStampReq) -> Result<Vec<u8>, TimeStampError> {
+        let alg = self.signer.alg();
+        let digest_algorithm = digest_algorithm_for(alg);
+
+        let cert_chain = self.signer.cert_chain().map_err(internal_error)?;
+        let tsa_cert_der = cert_chain.first().ok_or_else(|| {
+            TimeStampError::InternalError("time stamp authority has no certificate".to_string())
+        })?;
+        let tsa_cert: Certificate = rasn::der::decode(tsa_cert_der).map_err(internal_error)?;
+
+        // RFC 3161 only allows whole seconds unless accuracy is specified.
+        let now = Utc::now().with_nanosecond(0).unwrap_or_else(Utc::now);
+
+        let mut serial = [0u8; 8];
+        thread_rng().try_fill(&mut serial).map_err(|_| {
+            TimeStampError::InternalError("Unable to generate random number".to_string())
+        })?;
+
+        let tst_info = TstInfo {
+            version: bcder::Integer::from(1_u8),
+            policy: request
+                .req_policy
+                .clone()
+                .unwrap_or_else(|| bcder::Oid(bytes::Bytes::from_static(DEFAULT_POLICY.0))),
+            message_imprint: request.message_imprint.clone(),
+            serial_number: bcder::Integer::from(u64::from_be_bytes(serial) >> 1),
+            gen_time: GeneralizedTime::from(now),
+            accuracy: None,
+            ordering: None,
+            nonce: request.nonce.clone(),
+            tsa: None,
+            extensions: None,
+        };
+
+        let mut tst_info_der = Vec::new();
+        tst_info
+            .encode_ref()
+            .write_encoded(Mode::Der, &mut tst_info_der)?;
+
+        let mut h = digest_algorithm.digester();
+        h.update(&tst_info_der);
+        let tst_info_digest = h.finish();
+
+        let mut h = DigestAlgorithm::Sha256.digester();
+        h.update(tsa_cert_der);
+        let tsa_cert_hash = h.finish();
+
+        let signing_certificate = SigningCertificateV2 {
+            certs: vec![EssCertIdV2 {
+                cert_hash: OctetString::copy_from_slice(tsa_cert_hash.as_ref()),
+            }],
+        };
+
+        let signed_attrs: SetOf<Attribute> = SetOf::from(vec![
+            attribute(
+                Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_CONTENT_TYPE,
+                &Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_CT_TSTINFO.to_owned(),
+            )?,
+            attribute(
+                Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_SIGNING_TIME,
+                &rasn_pkix::Time::Utc(now),
+            )?,
+            attribute(
+                Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_MESSAGE_DIGEST,
+                &OctetString::copy_from_slice(tst_info_digest.as_ref()),
+            )?,
+            attribute(
+                Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_AA_SIGNING_CERTIFICATE_V2,
+                &signing_certificate,
+            )?,
+        ]);
+
+        // The signature covers the DER encoding of the signed attributes as a
+        // SET OF, not the implicitly-tagged form carried in `SignerInfo`.
+        let tbs = rasn::der::encode(&signed_attrs).map_err(internal_error)?;
+        let signature = self.signer.sign(&tbs).map_err(internal_error)?;
+
+        // CMS carries ECDSA signatures in DER form rather than COSE's r | s.
+        let signature = match alg {
+            SigningAlg::Es256 | SigningAlg::Es384 | SigningAlg::Es512 => {
+                p1363_to_der(&signature).map_err(internal_error)?
+            }
+            _ => signature,
+        };
+
+        let digest_algorithm_id = hash_algorithm_identifier(alg);
+
+        let signer_info = SignerInfo {
+            version: 1.into(),
+            sid: SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+                issuer: tsa_cert.tbs_certificate.issuer.clone(),
+                serial_number: tsa_cert.tbs_certificate.serial_number.clone(),
+            }),
+            digest_algorithm: digest_algorithm_id.clone(),
+            signed_attrs: Some(signed_attrs),
+            signature_algorithm: signature_algorithm_identifier(alg)?,
+            signature: OctetString::from(signature),
+            unsigned_attrs: None,
+        };
+
+        let certificates = if request.cert_req.unwrap_or(false) {
+            let certs = cert_chain
+                .iter()
+                .map(|der| {
+                    rasn::der::decode::<Certificate>(der)
+                        .map(|c| CertificateChoices::Certificate(Box::new(c)))
+                        .map_err(internal_error)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Some(certs)
+        } else {
+            None
+        };
+
+        let signed_data = OrderedSignedData {
+            version: 3.into(),
+            digest_algorithms: SetOf::from(vec![digest_algorithm_id]),
+            encap_content_info: EncapsulatedContentInfo {
+                content_type: Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_CT_TSTINFO.to_owned(),
+                content: Some(OctetString::from(tst_info_der)),
+            },
+            certificates,
+            signer_infos: SetOf::from(vec![signer_info]),
+        };
+
+        let content_info = rasn_cms::ContentInfo {
+            content_type: rasn_cms::CONTENT_SIGNED_DATA.to_owned(),
+            content: Any::new(rasn::der::encode(&signed_data).map_err(internal_error)?),
+        };
+
+        rasn::der::encode(&content_info).map_err(internal_error)
+    }
+}
+
+impl TimeStampProvider for LocalTimeStampAuthority {
+    fn send_time_stamp_request(&self, message: &[u8]) -> Option<Result<Vec<u8>, TimeStampError>> {
+        Some(default_rfc3161_message(message).and_then(|request| self.respond(&request)))
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl AsyncTimeStampProvider for LocalTimeStampAuthority {
+    async fn send_time_stamp_request(
+        &self,
+        message: &[u8],
+    ) -> Option<Result<Vec<u8>, TimeStampError>> {
+        Some(default_rfc3161_message(message).and_then(|request| self.respond(&request)))
+    }
+}
+
+/// A [`RawSigner`] that delegates signing to another signer and obtains its
+/// time stamps from a [`LocalTimeStampAuthority`].
+pub(crate) struct LocalTimeStampSigner {
+    signer: Box<dyn RawSigner + Send + Sync>,
+    tsa: LocalTimeStampAuthority,
+}
+
+impl LocalTimeStampSigner {
+    pub(crate) fn new(
+        signer: Box<dyn RawSigner + Send + Sync>,
+        tsa: LocalTimeStampAuthority,
+    ) -> Self {
+        Self { signer, tsa }
+    }
+}
+
+impl RawSigner for LocalTimeStampSigner {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>, RawSignerError> {
+        self.signer.sign(data)
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.signer.alg()
+    }
+
+    fn cert_chain(&self) -> Result<Vec<Vec<u8>>, RawSignerError> {
+        self.signer.cert_chain()
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.signer.reserve_size() + self.tsa.reserve_size()
+    }
+
+    fn ocsp_response(&self) -> Option<Vec<u8>> {
+        self.signer.ocsp_response()
+    }
+}
+
+impl TimeStampProvider for LocalTimeStampSigner {
+    fn send_time_stamp_request(&self, message: &[u8]) -> Option<Result<Vec<u8>, TimeStampError>> {
+        TimeStampProvider::send_time_stamp_request(&self.tsa, message)
+    }
+}
+
+/// CMS `SignedData` whose certificate set is written in the order given.
+///
+/// DER would sort the `SET OF` by encoding, but time stamp verifiers
+/// (including [`verify_time_stamp`]) expect the authority's certificate to
+/// come first, followed by its issuers. The implicit `[0]` tag makes a
+/// `SEQUENCE OF` encode identically apart from the ordering.
+///
+/// [`verify_time_stamp`]: crate::crypto::time_stamp::verify_time_stamp
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq)]
+struct OrderedSignedData {
+    version: Integer,
+    digest_algorithms: SetOf<AlgorithmIdentifier>,
+    encap_content_info: EncapsulatedContentInfo,
+    #[rasn(tag(0))]
+    certificates: Option<SequenceOf<CertificateChoices>>,
+    signer_infos: SetOf<SignerInfo>,
+}
+
+/// ```ASN.1
+/// SigningCertificateV2 ::= SEQUENCE {
+///     certs        SEQUENCE OF ESSCertIDv2,
+///     policies     SEQUENCE OF PolicyInformation OPTIONAL }
+/// ```
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq)]
+struct SigningCertificateV2 {
+    certs: SequenceOf<EssCertIdV2>,
+}
+
+/// ```ASN.1
+/// ESSCertIDv2 ::= SEQUENCE {
+///     hashAlgorithm    AlgorithmIdentifier DEFAULT {algorithm id-sha256},
+///     certHash         Hash,
+///     issuerSerial     IssuerSerial OPTIONAL }
+/// ```
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq)]
+struct EssCertIdV2 {
+    cert_hash: OctetString,
+}
+
+/// ```ASN.1
+/// RSASSA-PSS-params ::= SEQUENCE {
+///     hashAlgorithm      [0] HashAlgorithm DEFAULT sha1,
+///     maskGenAlgorithm   [1] MaskGenAlgorithm DEFAULT mgf1SHA1,
+///     saltLength         [2] INTEGER DEFAULT 20,
+///     trailerField       [3] TrailerField DEFAULT trailerFieldBC }
+/// ```
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq)]
+struct RsassaPssParams {
+    #[rasn(tag(explicit(0)))]
+    hash_algorithm: AlgorithmIdentifier,
+    #[rasn(tag(explicit(1)))]
+    mask_gen_algorithm: AlgorithmIdentifier,
+    #[rasn(tag(explicit(2)))]
+    salt_length: Integer,
+}
+
+fn digest_algorithm_for(alg: SigningAlg) -> DigestAlgorithm {
+    match alg {
+        SigningAlg::Es256 | SigningAlg::Ps256 => DigestAlgorithm::Sha256,
+        SigningAlg::Es384 | SigningAlg::Ps384 => DigestAlgorithm::Sha384,
+        SigningAlg::Es512
+        | SigningAlg::Ps512
+        | SigningAlg::Ed25519
+        | SigningAlg::MlDsa44
+        | SigningAlg::MlDsa65
+        | SigningAlg::MlDsa87 => DigestAlgorithm::Sha512,
+    }
+}
+
+fn hash_algorithm_identifier(alg: SigningAlg) -> AlgorithmIdentifier {
+    let algorithm = match digest_algorithm_for(alg) {
+        DigestAlgorithm::Sha256 => {
+            Oid::JOINT_ISO_ITU_T_COUNTRY_US_ORGANIZATION_GOV_CSOR_NIST_ALGORITHMS_HASH_SHA256
+        }
+        DigestAlgorithm::Sha384 => {
+            Oid::JOINT_ISO_ITU_T_COUNTRY_US_ORGANIZATION_GOV_CSOR_NIST_ALGORITHMS_HASH_SHA384
+        }
+        _ => Oid::JOINT_ISO_ITU_T_COUNTRY_US_ORGANIZATION_GOV_CSOR_NIST_ALGORITHMS_HASH_SHA512,
+    };
+
+    AlgorithmIdentifier {
+        algorithm: algorithm.to_owned(),
+        parameters: None,
+    }
+}
+
+fn signature_algorithm_identifier(alg: SigningAlg) -> Result<AlgorithmIdentifier, TimeStampError> {
+    let oid = |arcs: &'static [u32]| ObjectIdentifier::new_unchecked(arcs.into());
+
+    let (algorithm, parameters) = match alg {
+        SigningAlg::Es256 => (oid(&[1, 2, 840, 10045, 4, 3, 2]), None),
+        SigningAlg::Es384 => (oid(&[1, 2, 840, 10045, 4, 3, 3]), None),
+        SigningAlg::Es512 => (oid(&[1, 2, 840, 10045, 4, 3, 4]), None),
+        SigningAlg::Ps256 | SigningAlg::Ps384 | SigningAlg::Ps512 => {
+            let hash_algorithm = hash_algorithm_identifier(alg);
+            let salt_length = match digest_algorithm_for(alg) {
+                DigestAlgorithm::Sha256 => 32,
+                DigestAlgorithm::Sha384 => 48,
+                _ => 64,
+            };
+
+            let params = RsassaPssParams {
+                mask_gen_algorithm: AlgorithmIdentifier {
+                    algorithm: Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_MGF1.to_owned(),
+                    parameters: Some(Any::new(
+                        rasn::der::encode(&hash_algorithm).map_err(internal_error)?,
+                    )),
+                },
+                hash_algorithm,
+                salt_length: salt_length.into(),
+            };
+
+            (
+                oid(&[1, 2, 840, 113549, 1, 1, 10]),
+                Some(Any::new(
+                    rasn::der::encode(&params).map_err(internal_error)?,
+                )),
+            )
+        }
+        SigningAlg::Ed25519 => (oid(&[1, 3, 101, 112]), None),
+        SigningAlg::MlDsa44 => (oid(&[2, 16, 840, 1, 101, 3, 4, 3, 17]), None),
+        SigningAlg::MlDsa65 => (oid(&[2, 16, 840, 1, 101, 3, 4, 3, 18]), None),
+        SigningAlg::MlDsa87 => (oid(&[2, 16, 840, 1, 101, 3, 4, 3, 19]), None),
+    };
+
+    Ok(AlgorithmIdentifier {
+        algorithm,
+        parameters,
+    })
+}
+
+fn attribute<T: Encode>(oid: &Oid, value: &T) -> Result<Attribute, TimeStampError> {
+    Ok(Attribute {
+        r#type: oid.to_owned(),
+        values: SetOf::from(vec![Any::new(
+            rasn::der::encode(value).map_err(internal_error)?,
+        )]),
+    })
+}
+
+fn internal_error<E: ToString>(err: E) -> TimeStampError {
+    TimeStampError::InternalError(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::panic)]
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{
+        crypto::{
+            cose::{CertificateTrustPolicy, TrustPolicyRole},
+            time_stamp::verify_time_stamp,
+        },
+        status_tracker::StatusTracker,
+        utils::{test::test_certificate_acceptance_policy, test_signer},
+    };
+
+    fn local_tsa(alg: SigningAlg) -> LocalTimeStampAuthority {
+        let (cert_chain, private_key) = test_signer::cert_chain_and_private_key_for_alg(alg);
+        LocalTimeStampAuthority::from_cert_chain_and_private_key(cert_chain, private_key, alg)
+            .unwrap()
+    }
+
+    #[test]
+    fn issued_tokens_verify() {
+        // Trust checks on time stamps are off by default in test builds.
+        crate::settings::set_settings_value("verify.verify_timestamp_trust", true).unwrap();
+
+        let message = b"some sample content to time stamp";
+        let ctp = test_certificate_acceptance_policy();
+
+        for alg in [
+            SigningAlg::Es256,
+            SigningAlg::Es384,
+            SigningAlg::Es512,
+            SigningAlg::Ps256,
+            SigningAlg::Ps384,
+            SigningAlg::Ps512,
+            SigningAlg::Ed25519,
+        ] {
+            let tsa = local_tsa(alg);
+            let response = TimeStampProvider::send_time_stamp_request(&tsa, message)
+                .unwrap()
+                .unwrap();
+
+            let mut log = StatusTracker::default();
+            let tst_info = verify_time_stamp(&response, message, &ctp, &mut log)
+                .unwrap_or_else(|e| panic!("{alg} time stamp failed to verify: {e}"));
+
+            assert_eq!(tst_info.version, bcder::Integer::from(1_u8));
+            assert!(tst_info.nonce.is_some());
+        }
+    }
+
+    #[test]
+    fn response_echoes_request() {
+        let tsa = local_tsa(SigningAlg::Es256);
+        let request = default_rfc3161_message(b"abc").unwrap();
+        let response = tsa.respond(&request).unwrap();
+
+        let request =
+            Constructed::decode(request.as_slice(), Mode::Der, TimeStampReq::take_from).unwrap();
+        let signed_data = super::super::response::signed_data_from_time_stamp_response(&response)
+            .unwrap()
+            .unwrap();
+        let tst_info = super::super::response::tst_info_from_signed_data(&signed_data)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(tst_info.message_imprint, request.message_imprint);
+        assert_eq!(tst_info.nonce, request.nonce);
+        assert_eq!(tst_info.policy, DEFAULT_POLICY);
+
+        // The authority's own certificate must come first so that it is the
+        // one checked against the trust list.
+        let certs = signed_data.certificates.unwrap();
+        let CertificateChoices::Certificate(first) = certs.to_vec()[0] else {
+            panic!("expected an X.509 certificate");
+        };
+        let tsa_cert = &tsa.signer.cert_chain().unwrap()[0];
+        assert_eq!(&rasn::der::encode(first.as_ref()).unwrap(), tsa_cert);
+    }
+
+    #[test]
+    fn wrong_data() {
+        let tsa = local_tsa(SigningAlg::Es256);
+        let response = TimeStampProvider::send_time_stamp_request(&tsa, b"abc")
+            .unwrap()
+            .unwrap();
+
+        let mut log = StatusTracker::default();
+        assert!(matches!(
+            verify_time_stamp(
+                &response,
+                b"xyz",
+                &test_certificate_acceptance_policy(),
+                &mut log
+            ),
+            Err(TimeStampError::InvalidData)
+        ));
+    }
+
+    #[test]
+    fn untrusted_root() {
+        crate::settings::set_settings_value("verify.verify_timestamp_trust", true).unwrap();
+
+        let tsa = local_tsa(SigningAlg::Es256);
+        let response = TimeStampProvider::send_time_stamp_request(&tsa, b"abc")
+            .unwrap()
+            .unwrap();
+
+        let mut log = StatusTracker::default();
+        assert!(matches!(
+            verify_time_stamp(&response, b"abc", &CertificateTrustPolicy::new(), &mut log),
+            Err(TimeStampError::Untrusted)
+        ));
+    }
+
+    #[test]
+    fn separate_time_stamp_policy() {
+        crate::settings::set_settings_value("verify.verify_timestamp_trust", true).unwrap();
+
+        let tsa = local_tsa(SigningAlg::Es256);
+        let response = TimeStampProvider::send_time_stamp_request(&tsa, b"abc")
+            .unwrap()
+            .unwrap();
+
+        // The TSA is trusted only by the time stamp policy, not the claim signer policy.
+        let mut tsa_ctp = test_certificate_acceptance_policy();
+        tsa_ctp.set_role(TrustPolicyRole::TimeStamp);
+
+        let mut ctp = CertificateTrustPolicy::new();
+        ctp.set_time_stamp_policy(tsa_ctp);
+
+        let mut log = StatusTracker::default();
+        verify_time_stamp(&response, b"abc", &ctp, &mut log).unwrap();
+
+        let trusted = log
+            .logged_items()
+            .iter()
+            .find(|item| item.validation_status.as_deref() == Some("timeStamp.trusted"))
+            .unwrap();
+        assert_eq!(trusted.trust_policy.as_deref(), Some("time_stamp"));
+
+        // And the other way around.
+        let mut ctp = test_certificate_acceptance_policy();
+        ctp.set_time_stamp_policy(CertificateTrustPolicy::new());
+
+        let mut log = StatusTracker::default();
+        assert!(matches!(
+            verify_time_stamp(&response, b"abc", &ctp, &mut log),
+            Err(TimeStampError::Untrusted)
+        ));
+    }
+
+    #[test]
+    fn bad_request() {
+        let tsa = local_tsa(SigningAlg::Es256);
+        assert!(matches!(
+            tsa.respond(&[1, 2, 3]),
+            Err(TimeStampError::DecodeError(_))
+        ));
+    }
+}
@@ -23,6 +23,10 @@ mod http_request;
 #[cfg(not(target_arch = "wasm32"))]
 pub use http_request::{default_rfc3161_request, default_rfc3161_request_async};
 
+mod local;
+pub use local::LocalTimeStampAuthority;
+pub(crate) use local::LocalTimeStampSigner;
+
 mod provider;
 pub use provider::{default_rfc3161_message, AsyncTimeStampProvider, TimeStampProvider};
 
@@ -113,8 +113,6 @@ pub trait AsyncTimeStampProvider: Sync {
         &self,
         message: &[u8],
     ) -> Option<Result<Vec<u8>, TimeStampError>> {
-        // NOTE: This is currently synchronous, but may become
-        // async in the future.
         #[cfg(not(target_arch = "wasm32"))]
         if let Some(url) = self.time_stamp_service_url() {
             if let Ok(body) = self.time_stamp_request_body(message) {
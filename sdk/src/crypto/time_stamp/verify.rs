@@ -66,6 +66,10 @@ pub fn verify_time_stamp(
     ctp: &CertificateTrustPolicy,
     validation_log: &mut StatusTracker,
 ) -> Result<TstInfo, TimeStampError> {
+    // TSA certificates are evaluated against the time stamp policy when one is configured.
+    let ctp = ctp.time_stamp_policy();
+    let trust_policy = ctp.role().as_str();
+
     // Get the signed data frorm the timestamp data
     let Ok(Some(sd)) = signed_data_from_time_stamp_response(ts) else {
         log_item!("", "count not parse timestamp data", "verify_time_stamp")
@@ -532,30 +536,56 @@ pub fn verify_time_stamp(
         // the certificate must be on the trust list to be considered valid
         let verify_trust = get_settings_value("verify.verify_timestamp_trust").unwrap_or(true);
 
-        if verify_trust
-            && ctp
-                .check_certificate_trust(&cert_ders[0..], &cert_ders[0], Some(signing_time))
-                .is_err()
-        {
-            log_item!(
+        let build_trust_report = get_settings_value("verify.trust_reports").unwrap_or(false);
+
+        let trust_check = verify_trust.then(|| {
+            if build_trust_report {
+                let (result, trust_report) = ctp.check_certificate_trust_with_report(
+                    &cert_ders[0..],
+                    &cert_ders[0],
+                    Some(signing_time),
+                );
+                current_validation_log.add_trust_report(trust_report);
+                result
+            } else {
+                ctp.check_certificate_trust(&cert_ders[0..], &cert_ders[0], Some(signing_time))
+            }
+        });
+
+        let log = match trust_check {
+            Some(Err(_)) => {
+                log_item!(
+                    "",
+                    format!(
+                        "timestamp cert untrusted: {} ({trust_policy} trust policy)",
+                        &common_name
+                    ),
+                    "verify_time_stamp"
+                )
+                .validation_status(TIMESTAMP_UNTRUSTED)
+                .trust_policy(trust_policy)
+                .informational(&mut current_validation_log);
+
+                last_err = TimeStampError::Untrusted;
+                continue;
+            }
+            Some(Ok(_)) => log_item!(
                 "",
-                format!("timestamp cert untrusted: {}", &common_name),
+                format!(
+                    "timestamp cert trusted: {} ({trust_policy} trust policy)",
+                    &common_name
+                ),
                 "verify_time_stamp"
             )
-            .validation_status(TIMESTAMP_UNTRUSTED)
-            .informational(&mut current_validation_log);
-
-            last_err = TimeStampError::Untrusted;
-            continue;
-        }
-
-        log_item!(
-            "",
-            format!("timestamp cert trusted: {}", &common_name),
-            "verify_time_stamp"
-        )
-        .validation_status(TIMESTAMP_TRUSTED)
-        .success(&mut current_validation_log);
+            .trust_policy(trust_policy),
+            None => log_item!(
+                "",
+                format!("timestamp cert trusted: {}", &common_name),
+                "verify_time_stamp"
+            ),
+        };
+        log.validation_status(TIMESTAMP_TRUSTED)
+            .success(&mut current_validation_log);
 
         // If we find a valid value, we're done.
         validation_log.append(&current_validation_log);
@@ -190,4 +190,12 @@ impl PartialClaim {
     pub(crate) fn add_assertion(&mut self, assertion: &HashedUri) {
         self.assertion_uris.push(assertion.clone());
     }
+
+    pub(crate) fn update_assertion(&mut self, assertion: &HashedUri) {
+        for existing in &mut self.assertion_uris {
+            if existing.url() == assertion.url() {
+                *existing = assertion.clone();
+            }
+        }
+    }
 }
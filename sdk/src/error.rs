@@ -198,6 +198,10 @@ pub enum Error {
     #[error("thread receive error")]
     ThreadReceiveError,
 
+    /// The operation was cancelled by a [`ProgressScope`](crate::progress::ProgressScope) callback.
+    #[error("operation cancelled")]
+    OperationCancelled,
+
     #[error("no JUMBF data found")]
     JumbfNotFound,
 
@@ -38,6 +38,21 @@ pub trait CredentialHolder {
     /// [`sign`]: Self::sign
     fn reserve_size(&self) -> usize;
 
+    /// Returns the maximum expected size in bytes of the `signature`
+    /// field when signing a [`SignerPayload`] no larger than
+    /// `signer_payload`.
+    ///
+    /// Identity assertion builders call this with a placeholder payload
+    /// that has one entry for each assertion they may reference. Implement
+    /// it when the signature embeds the signer payload, so that its size
+    /// depends on the referenced assertions and roles. The default returns
+    /// [`reserve_size`].
+    ///
+    /// [`reserve_size`]: Self::reserve_size
+    fn reserve_size_for(&self, _signer_payload: &SignerPayload) -> usize {
+        self.reserve_size()
+    }
+
     /// Signs the [`SignerPayload`] data structure on behalf of the credential
     /// holder.
     ///
@@ -73,6 +88,21 @@ pub trait AsyncCredentialHolder: Send + Sync {
     /// [`sign`]: Self::sign
     fn reserve_size(&self) -> usize;
 
+    /// Returns the maximum expected size in bytes of the `signature`
+    /// field when signing a [`SignerPayload`] no larger than
+    /// `signer_payload`.
+    ///
+    /// Identity assertion builders call this with a placeholder payload
+    /// that has one entry for each assertion they may reference. Implement
+    /// it when the signature embeds the signer payload, so that its size
+    /// depends on the referenced assertions and roles. The default returns
+    /// [`reserve_size`].
+    ///
+    /// [`reserve_size`]: Self::reserve_size
+    fn reserve_size_for(&self, _signer_payload: &SignerPayload) -> usize {
+        self.reserve_size()
+    }
+
     /// Signs the [`SignerPayload`] data structure on behalf of the credential
     /// holder.
     ///
@@ -108,6 +138,21 @@ pub trait AsyncCredentialHolder {
     /// [`sign`]: Self::sign
     fn reserve_size(&self) -> usize;
 
+    /// Returns the maximum expected size in bytes of the `signature`
+    /// field when signing a [`SignerPayload`] no larger than
+    /// `signer_payload`.
+    ///
+    /// Identity assertion builders call this with a placeholder payload
+    /// that has one entry for each assertion they may reference. Implement
+    /// it when the signature embeds the signer payload, so that its size
+    /// depends on the referenced assertions and roles. The default returns
+    /// [`reserve_size`].
+    ///
+    /// [`reserve_size`]: Self::reserve_size
+    fn reserve_size_for(&self, _signer_payload: &SignerPayload) -> usize {
+        self.reserve_size()
+    }
+
     /// Signs the [`SignerPayload`] data structure on behalf of the credential
     /// holder.
     ///
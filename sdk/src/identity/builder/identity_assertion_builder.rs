@@ -18,10 +18,13 @@ use serde_bytes::ByteBuf;
 
 use super::{CredentialHolder, IdentityBuilderError};
 use crate::{
+    claim::Claim,
     dynamic_assertion::{
         AsyncDynamicAssertion, DynamicAssertion, DynamicAssertionContent, PartialClaim,
     },
     identity::{builder::AsyncCredentialHolder, IdentityAssertion, SignerPayload},
+    jumbf::labels::{to_assertion_uri, to_relative_uri},
+    HashedUri,
 };
 
 /// An `IdentityAssertionBuilder` gathers together the necessary components
@@ -60,6 +63,10 @@ impl IdentityAssertionBuilder {
     /// If any of these labels match assertions that are present in the partial
     /// claim submitted during signing, they will be added to the
     /// `referenced_assertions` list for this identity assertion.
+    ///
+    /// When a manifest has several identity assertions, each one may
+    /// reference those added to the signer before it (`cawg.identity`,
+    /// `cawg.identity__1`, and so on), but not those added after it.
     pub fn add_referenced_assertions(&mut self, labels: &[&str]) {
         for label in labels {
             self.referenced_assertions.insert(label.to_string());
@@ -84,37 +91,24 @@ impl DynamicAssertion for IdentityAssertionBuilder {
     }
 
     fn reserve_size(&self) -> crate::Result<usize> {
-        Ok(self.credential_holder.reserve_size())
-        // TO DO: Credential holder will state reserve size for signature.
-        // Add additional size for CBOR wrapper outside signature.
+        let placeholder = placeholder_signer_payload(
+            self.credential_holder.sig_type(),
+            &self.referenced_assertions,
+            &self.roles,
+        );
+
+        Ok(self.credential_holder.reserve_size_for(&placeholder)
+            + signer_payload_reserve_size(&self.referenced_assertions, &self.roles))
     }
 
     fn content(
         &self,
-        _label: &str,
+        label: &str,
         size: Option<usize>,
         claim: &PartialClaim,
     ) -> crate::Result<DynamicAssertionContent> {
-        // TO DO: Update to respond correctly when identity assertions refer to each
-        // other.
-        let referenced_assertions = claim
-            .assertions()
-            .filter(|a| {
-                // Always accept the hard binding assertion.
-                if a.url().contains("c2pa.assertions/c2pa.hash.") {
-                    return true;
-                }
-
-                let label = if let Some((_, label)) = a.url().rsplit_once('/') {
-                    label.to_string()
-                } else {
-                    a.url()
-                };
-
-                self.referenced_assertions.contains(&label)
-            })
-            .cloned()
-            .collect();
+        let referenced_assertions =
+            referenced_assertions(claim, label, &self.referenced_assertions);
 
         let signer_payload = SignerPayload {
             referenced_assertions,
@@ -165,6 +159,10 @@ impl AsyncIdentityAssertionBuilder {
     /// If any of these labels match assertions that are present in the partial
     /// claim submitted during signing, they will be added to the
     /// `referenced_assertions` list for this identity assertion.
+    ///
+    /// When a manifest has several identity assertions, each one may
+    /// reference those added to the signer before it (`cawg.identity`,
+    /// `cawg.identity__1`, and so on), but not those added after it.
     pub fn add_referenced_assertions(&mut self, labels: &[&str]) {
         for label in labels {
             self.referenced_assertions.insert(label.to_string());
@@ -191,37 +189,24 @@ impl AsyncDynamicAssertion for AsyncIdentityAssertionBuilder {
     }
 
     fn reserve_size(&self) -> crate::Result<usize> {
-        Ok(self.credential_holder.reserve_size())
-        // TO DO: Credential holder will state reserve size for signature.
-        // Add additional size for CBOR wrapper outside signature.
+        let placeholder = placeholder_signer_payload(
+            self.credential_holder.sig_type(),
+            &self.referenced_assertions,
+            &self.roles,
+        );
+
+        Ok(self.credential_holder.reserve_size_for(&placeholder)
+            + signer_payload_reserve_size(&self.referenced_assertions, &self.roles))
     }
 
     async fn content(
         &self,
-        _label: &str,
+        label: &str,
         size: Option<usize>,
         claim: &PartialClaim,
     ) -> crate::Result<DynamicAssertionContent> {
-        // TO DO: Update to respond correctly when identity assertions refer to each
-        // other.
-        let referenced_assertions = claim
-            .assertions()
-            .filter(|a| {
-                // Always accept the hard binding assertion.
-                if a.url().contains("c2pa.assertions/c2pa.hash.") {
-                    return true;
-                }
-
-                let label = if let Some((_, label)) = a.url().rsplit_once('/') {
-                    label.to_string()
-                } else {
-                    a.url()
-                };
-
-                self.referenced_assertions.contains(&label)
-            })
-            .cloned()
-            .collect();
+        let referenced_assertions =
+            referenced_assertions(claim, label, &self.referenced_assertions);
 
         let signer_payload = SignerPayload {
             referenced_assertions,
@@ -235,6 +220,95 @@ impl AsyncDynamicAssertion for AsyncIdentityAssertionBuilder {
     }
 }
 
+/// Builds a signer payload at least as large as any that `content` can
+/// produce for these referenced assertions and roles, so that credential
+/// holders which embed the payload in their signature can size it.
+fn placeholder_signer_payload(
+    sig_type: &str,
+    referenced_assertions: &HashSet<String>,
+    roles: &[String],
+) -> SignerPayload {
+    // Large enough for a SHA-512 hash.
+    const HASH_SIZE: usize = 64;
+
+    // The claim refers to its assertions with relative URIs, so the manifest
+    // label doesn't matter.
+    let referenced_assertions = std::iter::once("c2pa.hash.boxes")
+        .chain(referenced_assertions.iter().map(String::as_str))
+        .map(|label| {
+            HashedUri::new(
+                to_relative_uri(&to_assertion_uri("placeholder", label)),
+                Some("sha512".to_owned()),
+                &[0; HASH_SIZE],
+            )
+        })
+        .collect();
+
+    SignerPayload {
+        referenced_assertions,
+        sig_type: sig_type.to_owned(),
+        roles: roles.to_vec(),
+    }
+}
+
+/// Selects the hard binding and the assertions named in `labels` from the
+/// partial claim.
+///
+/// Identity assertions are written in the order they were added, so only the
+/// ones that precede this identity assertion (named `label`) have their final
+/// hashes. This one and those after it are skipped.
+fn referenced_assertions(
+    claim: &PartialClaim,
+    label: &str,
+    labels: &HashSet<String>,
+) -> Vec<HashedUri> {
+    let (_, own_instance) = Claim::assertion_label_from_link(label);
+
+    claim
+        .assertions()
+        .filter(|a| {
+            // Always accept the hard binding assertion.
+            if a.url().contains("c2pa.assertions/c2pa.hash.") {
+                return true;
+            }
+
+            let (a_label, instance) = Claim::assertion_label_from_link(&a.url());
+            if a_label == "cawg.identity" && instance >= own_instance {
+                return false;
+            }
+
+            let a_label = match a.url().rsplit_once('/') {
+                Some((_, a_label)) => a_label.to_owned(),
+                None => a.url(),
+            };
+            labels.contains(&a_label)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Estimates the space needed for the signer payload that is stored alongside
+/// the credential holder's signature: one hashed URI for the hard binding and
+/// for each referenced assertion, plus the roles.
+///
+/// Each identity assertion reserves its own space this way, so any number of
+/// them can be added to a manifest without affecting each other's layout.
+fn signer_payload_reserve_size(referenced_assertions: &HashSet<String>, roles: &[String]) -> usize {
+    // JUMBF URI prefix, a hash of up to 512 bits, and CBOR framing.
+    const HASHED_URI_SIZE: usize = 160;
+    const HARD_BINDING_LABEL_SIZE: usize = "c2pa.hash.boxes".len();
+    const CBOR_WRAPPER_SIZE: usize = 64;
+
+    let hashed_uris: usize = referenced_assertions
+        .iter()
+        .map(|label| label.len() + HASHED_URI_SIZE)
+        .sum();
+
+    let roles: usize = roles.iter().map(|role| role.len() + 4).sum();
+
+    HARD_BINDING_LABEL_SIZE + HASHED_URI_SIZE + hashed_uris + roles + CBOR_WRAPPER_SIZE
+}
+
 fn finalize_identity_assertion(
     signer_payload: SignerPayload,
     size: Option<usize>,
@@ -299,19 +373,22 @@ mod tests {
     use wasm_bindgen_test::wasm_bindgen_test;
 
     use crate::{
+        crypto::raw_signature,
         identity::{
             builder::{
                 AsyncIdentityAssertionBuilder, AsyncIdentityAssertionSigner,
                 IdentityAssertionBuilder, IdentityAssertionSigner,
             },
             tests::fixtures::{
-                manifest_json, parent_json, NaiveAsyncCredentialHolder, NaiveCredentialHolder,
-                NaiveSignatureVerifier,
+                cert_chain_and_private_key_for_alg, manifest_json, parent_json,
+                NaiveAsyncCredentialHolder, NaiveCredentialHolder, NaiveSignatureVerifier,
             },
+            validator::CawgValidator,
+            x509::{AsyncX509CredentialHolder, X509CredentialHolder, X509SignatureVerifier},
             IdentityAssertion, ToCredentialSummary,
         },
         status_tracker::StatusTracker,
-        Builder, Reader, SigningAlg,
+        Builder, Reader, SigningAlg, ValidationState,
     };
 
     const TEST_IMAGE: &[u8] = include_bytes!("../../../tests/fixtures/CA.jpg");
@@ -422,4 +499,197 @@ mod tests {
         let nc_json = serde_json::to_string(&nc_summary).unwrap();
         assert_eq!(nc_json, "{}");
     }
+
+    fn x509_holder(alg: SigningAlg) -> X509CredentialHolder {
+        let (cert_chain, private_key) = cert_chain_and_private_key_for_alg(alg);
+        X509CredentialHolder::from_raw_signer(
+            raw_signature::signer_from_cert_chain_and_private_key(
+                &cert_chain,
+                &private_key,
+                alg,
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn referenced_labels(ia: &IdentityAssertion) -> Vec<String> {
+        ia.signer_payload()
+            .referenced_assertions
+            .iter()
+            .filter_map(|r| r.url().rsplit_once('/').map(|(_, label)| label.to_owned()))
+            .collect()
+    }
+
+    #[c2pa_test_async]
+    async fn multiple_identity_assertions() {
+        let format = "image/jpeg";
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+
+        let mut builder = Builder::from_json(&manifest_json()).unwrap();
+        builder
+            .add_ingredient_from_stream(parent_json(), format, &mut source)
+            .unwrap();
+
+        builder
+            .add_resource("thumbnail.jpg", Cursor::new(TEST_THUMBNAIL))
+            .unwrap();
+
+        let mut signer = IdentityAssertionSigner::from_test_credentials(SigningAlg::Ps256);
+
+        // The photographer signs over the actions ...
+        let mut photographer =
+            IdentityAssertionBuilder::for_credential_holder(x509_holder(SigningAlg::Ed25519));
+        photographer.add_referenced_assertions(&["c2pa.actions.v2"]);
+        photographer.add_roles(&["cawg.creator"]);
+        signer.add_identity_assertion(photographer);
+
+        // ... and the publisher over its own assertion and the photographer's
+        // identity assertion.
+        let mut publisher =
+            IdentityAssertionBuilder::for_credential_holder(x509_holder(SigningAlg::Es256));
+        publisher.add_referenced_assertions(&["org.test.assertion", "cawg.identity"]);
+        publisher.add_roles(&["cawg.publisher"]);
+        signer.add_identity_assertion(publisher);
+
+        builder
+            .sign(&signer, format, &mut source, &mut dest)
+            .unwrap();
+
+        // Read back the Manifest that was generated.
+        dest.rewind().unwrap();
+
+        let mut reader = Reader::from_stream(format, &mut dest).unwrap();
+        assert_eq!(reader.validation_status(), None);
+
+        let manifest = reader.active_manifest().unwrap();
+        let mut st = StatusTracker::default();
+        let ias: Vec<IdentityAssertion> = IdentityAssertion::from_manifest(manifest, &mut st)
+            .map(|ia| ia.unwrap())
+            .collect();
+        assert_eq!(ias.len(), 2);
+
+        let photographer_refs = referenced_labels(&ias[0]);
+        assert!(photographer_refs.contains(&"c2pa.actions.v2".to_owned()));
+        assert!(!photographer_refs.contains(&"org.test.assertion".to_owned()));
+        assert_eq!(ias[0].signer_payload().roles, vec!["cawg.creator"]);
+
+        let publisher_refs = referenced_labels(&ias[1]);
+        assert!(publisher_refs.contains(&"org.test.assertion".to_owned()));
+        assert!(publisher_refs.contains(&"cawg.identity".to_owned()));
+        assert!(!publisher_refs.contains(&"c2pa.actions.v2".to_owned()));
+        assert_eq!(ias[1].signer_payload().roles, vec!["cawg.publisher"]);
+
+        for ia in &ias {
+            ia.validate(manifest, &mut st, &X509SignatureVerifier {})
+                .await
+                .unwrap();
+        }
+
+        // Each identity assertion is reported on its own.
+        reader.post_validate_async(&CawgValidator {}).await.unwrap();
+        let well_formed: Vec<String> = reader
+            .validation_results()
+            .unwrap()
+            .active_manifest()
+            .unwrap()
+            .success()
+            .iter()
+            .filter(|s| s.code() == "cawg.identity.well-formed")
+            .map(|s| s.url().unwrap().to_owned())
+            .collect();
+        assert_eq!(well_formed.len(), 2);
+        assert!(well_formed[0].ends_with("/cawg.identity"));
+        assert!(well_formed[1].ends_with("/cawg.identity__1"));
+        assert_ne!(reader.validation_state(), ValidationState::Invalid);
+    }
+
+    #[c2pa_test_async]
+    async fn multiple_identity_assertions_async() {
+        let format = "image/jpeg";
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+
+        let mut builder = Builder::from_json(&manifest_json()).unwrap();
+        builder
+            .add_ingredient_from_stream(parent_json(), format, &mut source)
+            .unwrap();
+
+        builder
+            .add_resource("thumbnail.jpg", Cursor::new(TEST_THUMBNAIL))
+            .unwrap();
+
+        let mut signer = AsyncIdentityAssertionSigner::from_test_credentials(SigningAlg::Ps256);
+
+        let (cert_chain, private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Ed25519);
+        let raw_signer = raw_signature::async_signer_from_cert_chain_and_private_key(
+            &cert_chain,
+            &private_key,
+            SigningAlg::Ed25519,
+            None,
+        )
+        .unwrap();
+
+        let mut x509_iab = AsyncIdentityAssertionBuilder::for_credential_holder(
+            AsyncX509CredentialHolder::from_async_raw_signer(raw_signer),
+        );
+        x509_iab.add_referenced_assertions(&["c2pa.actions.v2"]);
+        signer.add_identity_assertion(x509_iab);
+
+        let mut naive_iab =
+            AsyncIdentityAssertionBuilder::for_credential_holder(NaiveAsyncCredentialHolder {});
+        naive_iab.add_referenced_assertions(&["org.test.assertion"]);
+        signer.add_identity_assertion(naive_iab);
+
+        builder
+            .sign_async(&signer, format, &mut source, &mut dest)
+            .await
+            .unwrap();
+
+        // Read back the Manifest that was generated.
+        dest.rewind().unwrap();
+
+        let mut reader = Reader::from_stream(format, &mut dest).unwrap();
+        assert_eq!(reader.validation_status(), None);
+
+        let manifest = reader.active_manifest().unwrap();
+        let mut st = StatusTracker::default();
+        let ias: Vec<IdentityAssertion> = IdentityAssertion::from_manifest(manifest, &mut st)
+            .map(|ia| ia.unwrap())
+            .collect();
+        assert_eq!(ias.len(), 2);
+
+        ias[0]
+            .validate(manifest, &mut st, &X509SignatureVerifier {})
+            .await
+            .unwrap();
+        ias[1]
+            .validate(manifest, &mut st, &NaiveSignatureVerifier {})
+            .await
+            .unwrap();
+
+        // The CAWG validator doesn't understand the naive signature, but that
+        // must not hide the result for the X.509 identity assertion.
+        reader.post_validate_async(&CawgValidator {}).await.unwrap();
+        let results = reader
+            .validation_results()
+            .unwrap()
+            .active_manifest()
+            .unwrap();
+
+        let success = results
+            .success()
+            .iter()
+            .find(|s| s.code() == "cawg.identity.well-formed")
+            .unwrap();
+        assert!(success.url().unwrap().ends_with("/cawg.identity"));
+
+        let failure = results
+            .failure()
+            .iter()
+            .find(|s| s.code() == "cawg.identity.sig_type.unknown")
+            .unwrap();
+        assert!(failure.url().unwrap().ends_with("/cawg.identity__1"));
+    }
 }
@@ -0,0 +1,223 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use coset::ContentType;
+
+use super::ica_credential_holder::{IcaIssuer, VC_CONTENT_TYPE};
+use crate::{
+    crypto::{
+        cose::{sign_v2_embedded_async, CosePayload, TimeStampStorage},
+        raw_signature::AsyncRawSigner,
+    },
+    identity::{
+        builder::{AsyncCredentialHolder, IdentityBuilderError},
+        claim_aggregation::VerifiedIdentity,
+        SignerPayload,
+    },
+};
+
+/// An implementation of [`AsyncCredentialHolder`] that issues identity claims
+/// aggregation credentials as specified in [§8.1, Identity claims
+/// aggregation].
+///
+/// This is the asynchronous counterpart of [`IcaCredentialHolder`]; use it
+/// when the issuer key is held by a remote service.
+///
+/// [`AsyncCredentialHolder`]: crate::identity::builder::AsyncCredentialHolder
+/// [`IcaCredentialHolder`]: super::IcaCredentialHolder
+/// [§8.1, Identity claims aggregation]: https://cawg.io/identity/1.1-draft/#_identity_claims_aggregation
+pub struct AsyncIcaCredentialHolder {
+    issuer: IcaIssuer,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    signer: Box<dyn AsyncRawSigner + Send + Sync + 'static>,
+
+    #[cfg(target_arch = "wasm32")]
+    signer: Box<dyn AsyncRawSigner + 'static>,
+}
+
+impl AsyncIcaCredentialHolder {
+    /// Create an `AsyncIcaCredentialHolder` that issues credentials from the
+    /// `issuer` DID for the given verified identities.
+    ///
+    /// The [`AsyncRawSigner`] must hold (or have access to) the private key
+    /// for the `assertionMethod` of the issuer DID. If the signer has a time
+    /// stamp service URL, the credential signature is time-stamped.
+    ///
+    /// Returns an error if `issuer` is not a valid DID or if there are no
+    /// verified identities.
+    ///
+    /// [`AsyncRawSigner`]: crate::crypto::raw_signature::AsyncRawSigner
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_async_raw_signer<I>(
+        issuer: &str,
+        verified_identities: I,
+        signer: Box<dyn AsyncRawSigner + Send + Sync + 'static>,
+    ) -> Result<Self, IdentityBuilderError>
+    where
+        I: IntoIterator<Item = VerifiedIdentity>,
+    {
+        Ok(Self {
+            issuer: IcaIssuer::new(issuer, verified_identities)?,
+            signer,
+        })
+    }
+
+    /// Create an `AsyncIcaCredentialHolder` that issues credentials from the
+    /// `issuer` DID for the given verified identities.
+    ///
+    /// The [`AsyncRawSigner`] must hold (or have access to) the private key
+    /// for the `assertionMethod` of the issuer DID. If the signer has a time
+    /// stamp service URL, the credential signature is time-stamped.
+    ///
+    /// Returns an error if `issuer` is not a valid DID or if there are no
+    /// verified identities.
+    ///
+    /// [`AsyncRawSigner`]: crate::crypto::raw_signature::AsyncRawSigner
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_async_raw_signer<I>(
+        issuer: &str,
+        verified_identities: I,
+        signer: Box<dyn AsyncRawSigner + 'static>,
+    ) -> Result<Self, IdentityBuilderError>
+    where
+        I: IntoIterator<Item = VerifiedIdentity>,
+    {
+        Ok(Self {
+            issuer: IcaIssuer::new(issuer, verified_identities)?,
+            signer,
+        })
+    }
+
+    /// Sets the `validUntil` date of the credentials issued by this holder.
+    pub fn set_valid_until(mut self, valid_until: DateTime<FixedOffset>) -> Self {
+        self.issuer.valid_until = Some(valid_until);
+        self
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl AsyncCredentialHolder for AsyncIcaCredentialHolder {
+    fn sig_type(&self) -> &'static str {
+        super::CAWG_ICA_SIG_TYPE
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.issuer.reserve_size(None, self.signer.reserve_size())
+    }
+
+    fn reserve_size_for(&self, signer_payload: &SignerPayload) -> usize {
+        self.issuer
+            .reserve_size(Some(signer_payload), self.signer.reserve_size())
+    }
+
+    async fn sign(&self, signer_payload: &SignerPayload) -> Result<Vec<u8>, IdentityBuilderError> {
+        let vc = self.issuer.credential_json(signer_payload)?;
+
+        sign_v2_embedded_async(
+            self.signer.as_ref(),
+            &vc,
+            None,
+            CosePayload::Embedded,
+            Some(ContentType::Text(VC_CONTENT_TYPE.to_owned())),
+            TimeStampStorage::V2_sigTst2_CTT,
+        )
+        .await
+        .map_err(|e| IdentityBuilderError::SignerError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::{Cursor, Seek};
+
+    use c2pa_macros::c2pa_test_async;
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::{
+        identity::{
+            builder::{AsyncIdentityAssertionBuilder, AsyncIdentityAssertionSigner},
+            claim_aggregation::{AsyncIcaCredentialHolder, IcaSignatureVerifier},
+            tests::fixtures::{
+                claim_aggregation::{
+                    ica_credential_example::ica_example_identities,
+                    ica_issuer::ed25519_did_jwk_async_signer,
+                },
+                manifest_json, parent_json,
+            },
+            IdentityAssertion,
+        },
+        status_tracker::StatusTracker,
+        Builder, Reader, SigningAlg,
+    };
+
+    const TEST_IMAGE: &[u8] = include_bytes!("../../../tests/fixtures/CA.jpg");
+    const TEST_THUMBNAIL: &[u8] = include_bytes!("../../../tests/fixtures/thumbnail.jpg");
+
+    #[c2pa_test_async]
+    async fn issues_valid_credential_async() {
+        let format = "image/jpeg";
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+
+        let mut builder = Builder::from_json(&manifest_json()).unwrap();
+        builder
+            .add_ingredient_from_stream(parent_json(), format, &mut source)
+            .unwrap();
+        builder
+            .add_resource("thumbnail.jpg", Cursor::new(TEST_THUMBNAIL))
+            .unwrap();
+
+        let (issuer, raw_signer) = ed25519_did_jwk_async_signer();
+        let ica_holder = AsyncIcaCredentialHolder::from_async_raw_signer(
+            &issuer,
+            ica_example_identities(),
+            raw_signer,
+        )
+        .unwrap();
+
+        let mut c2pa_signer =
+            AsyncIdentityAssertionSigner::from_test_credentials(SigningAlg::Ps256);
+        c2pa_signer.add_identity_assertion(AsyncIdentityAssertionBuilder::for_credential_holder(
+            ica_holder,
+        ));
+
+        builder
+            .sign_async(&c2pa_signer, format, &mut source, &mut dest)
+            .await
+            .unwrap();
+
+        dest.rewind().unwrap();
+        let reader = Reader::from_stream(format, &mut dest).unwrap();
+        let manifest = reader.active_manifest().unwrap();
+
+        let mut st = StatusTracker::default();
+        let mut ia_iter = IdentityAssertion::from_manifest(manifest, &mut st);
+        let ia = ia_iter.next().unwrap().unwrap();
+        drop(ia_iter);
+
+        let ica = ia
+            .validate(manifest, &mut st, &IcaSignatureVerifier {})
+            .await
+            .unwrap();
+
+        assert_eq!(ica.issuer.as_str(), issuer);
+        assert!(st.has_status("cawg.ica.credential_valid"));
+    }
+}
@@ -0,0 +1,302 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use async_trait::async_trait;
+
+use super::w3c_vc::{
+    did::Did,
+    did_doc::{DidDocument, DidVerificationMethod, ValueOrReference},
+    did_jwk, did_key, did_web,
+};
+use crate::identity::{claim_aggregation::IcaValidationError, ValidationError};
+
+/// A `DidResolver` resolves the DIDs of one [DID method] to DID documents.
+///
+/// The resolver receives the DID without any fragment.
+///
+/// [DID method]: https://www.w3.org/TR/did-core/#methods
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+pub trait DidResolver: Send + Sync {
+    /// Returns the DID document for `did`.
+    async fn resolve(&self, did: &str) -> Result<DidDocument, ValidationError<IcaValidationError>>;
+}
+
+/// A `DidResolver` resolves the DIDs of one [DID method] to DID documents.
+///
+/// The resolver receives the DID without any fragment.
+///
+/// [DID method]: https://www.w3.org/TR/did-core/#methods
+#[cfg(target_arch = "wasm32")]
+#[async_trait(?Send)]
+pub trait DidResolver {
+    /// Returns the DID document for `did`.
+    async fn resolve(&self, did: &str) -> Result<DidDocument, ValidationError<IcaValidationError>>;
+}
+
+/// Resolves [`did:key`] DIDs for Ed25519 and P-256 keys without any network
+/// access.
+///
+/// [`did:key`]: https://w3c-ccg.github.io/did-key-spec/
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DidKeyResolver;
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl DidResolver for DidKeyResolver {
+    async fn resolve(&self, did: &str) -> Result<DidDocument, ValidationError<IcaValidationError>> {
+        did_key::resolve(&Did::new(did)?).map_err(|e| {
+            ValidationError::SignatureError(IcaValidationError::InvalidDidDocument(e.to_string()))
+        })
+    }
+}
+
+/// Resolves [`did:jwk`] DIDs without any network access.
+///
+/// [`did:jwk`]: https://github.com/quartzjer/did-jwk/blob/main/spec.md
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DidJwkResolver;
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl DidResolver for DidJwkResolver {
+    async fn resolve(&self, did: &str) -> Result<DidDocument, ValidationError<IcaValidationError>> {
+        did_jwk::resolve(&Did::new(did)?).map_err(|e| {
+            ValidationError::SignatureError(IcaValidationError::InvalidDidDocument(e.to_string()))
+        })
+    }
+}
+
+/// Resolves [`did:web`] DIDs by fetching the DID document over HTTPS.
+///
+/// [`did:web`]: https://w3c-ccg.github.io/did-method-web/
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DidWebResolver;
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl DidResolver for DidWebResolver {
+    async fn resolve(&self, did: &str) -> Result<DidDocument, ValidationError<IcaValidationError>> {
+        Ok(did_web::resolve(&Did::new(did)?).await?)
+    }
+}
+
+/// The set of [`DidResolver`]s used to find the public key of an identity
+/// claims aggregation credential issuer, keyed by DID method name.
+///
+/// The default set resolves `did:key`, `did:jwk` and `did:web`. Use
+/// [`register`] to add other methods or to replace a built-in one.
+///
+/// [`register`]: Self::register
+#[derive(Clone)]
+pub struct DidResolvers {
+    resolvers: HashMap<String, Arc<dyn DidResolver>>,
+}
+
+impl DidResolvers {
+    /// Creates a set with no DID methods.
+    pub fn empty() -> Self {
+        Self {
+            resolvers: HashMap::new(),
+        }
+    }
+
+    /// Registers `resolver` for DIDs whose method name is `method`, such as
+    /// `"key"` for `did:key`. Replaces any resolver already registered for
+    /// that method.
+    pub fn register<R: DidResolver + 'static>(&mut self, method: &str, resolver: R) -> &mut Self {
+        self.resolvers.insert(method.to_owned(), Arc::new(resolver));
+        self
+    }
+
+    /// Returns `true` if a resolver is registered for `method`.
+    pub fn supports(&self, method: &str) -> bool {
+        self.resolvers.contains_key(method)
+    }
+
+    /// Resolves a DID, ignoring any fragment, to its DID document.
+    pub async fn resolve(
+        &self,
+        did: &str,
+    ) -> Result<DidDocument, ValidationError<IcaValidationError>> {
+        let (primary_did, _fragment) = Did::new(did)?.split_fragment();
+
+        let Some(resolver) = self.resolvers.get(primary_did.method_name()) else {
+            return Err(ValidationError::SignatureError(
+                IcaValidationError::UnsupportedIssuerDid(format!(
+                    "unsupported DID method {}",
+                    primary_did.method_name()
+                )),
+            ));
+        };
+
+        resolver.resolve(&primary_did).await
+    }
+
+    /// Resolves a DID URL to the `assertionMethod` verification method it
+    /// names.
+    ///
+    /// If the DID URL has a fragment, the verification method with that
+    /// fragment is returned. Otherwise, the first `assertionMethod` of the
+    /// DID document is returned.
+    pub async fn resolve_assertion_method(
+        &self,
+        did_url: &str,
+    ) -> Result<DidVerificationMethod, ValidationError<IcaValidationError>> {
+        let (_primary_did, fragment) = Did::new(did_url)?.split_fragment();
+        let did_doc = self.resolve(did_url).await?;
+
+        let mut assertion_methods = did_doc
+            .verification_relationships
+            .assertion_method
+            .iter()
+            .filter_map(|vm| match vm {
+                ValueOrReference::Value(vm) => Some(vm),
+                ValueOrReference::Reference(id) => {
+                    did_doc.verification_method.iter().find(|vm| vm.id == *id)
+                }
+            });
+
+        let vm = match fragment {
+            Some(fragment) => assertion_methods.find(|vm| {
+                vm.id
+                    .as_str()
+                    .split_once('#')
+                    .is_some_and(|(_, f)| f == fragment)
+            }),
+            None => assertion_methods.next(),
+        };
+
+        vm.cloned().ok_or_else(|| {
+            let msg = match fragment {
+                Some(fragment) => {
+                    format!("DID document doesn't contain an assertionMethod entry #{fragment}")
+                }
+                None => "DID document doesn't contain an assertionMethod entry".to_owned(),
+            };
+            ValidationError::SignatureError(IcaValidationError::InvalidDidDocument(msg))
+        })
+    }
+}
+
+impl Default for DidResolvers {
+    fn default() -> Self {
+        let mut resolvers = Self::empty();
+        resolvers
+            .register("key", DidKeyResolver)
+            .register("jwk", DidJwkResolver)
+            .register("web", DidWebResolver);
+        resolvers
+    }
+}
+
+impl fmt::Debug for DidResolvers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut methods: Vec<&String> = self.resolvers.keys().collect();
+        methods.sort();
+        f.debug_struct("DidResolvers")
+            .field("methods", &methods)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use c2pa_macros::c2pa_test_async;
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    const EXAMPLE_DID_DOC: &str = r#"{
+        "id": "did:example:issuer",
+        "verificationMethod": [
+            {
+                "id": "did:example:issuer#key-1",
+                "type": "Multikey",
+                "controller": "did:example:issuer",
+                "publicKeyMultibase": "z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+            },
+            {
+                "id": "did:example:issuer#key-2",
+                "type": "Multikey",
+                "controller": "did:example:issuer",
+                "publicKeyMultibase": "zDnaerDaTF5BXEavCrfRZEk316dpbLsfPDZ3WJ5hRTPFU2169"
+            }
+        ],
+        "assertionMethod": ["did:example:issuer#key-1", "did:example:issuer#key-2"]
+    }"#;
+
+    struct ExampleResolver;
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl DidResolver for ExampleResolver {
+        async fn resolve(
+            &self,
+            did: &str,
+        ) -> Result<DidDocument, ValidationError<IcaValidationError>> {
+            assert_eq!(did, "did:example:issuer");
+            Ok(DidDocument::from_json(EXAMPLE_DID_DOC)?)
+        }
+    }
+
+    #[c2pa_test_async]
+    async fn selects_by_fragment() {
+        let mut resolvers = DidResolvers::default();
+        assert!(!resolvers.supports("example"));
+        resolvers.register("example", ExampleResolver);
+
+        let vm = resolvers
+            .resolve_assertion_method("did:example:issuer#key-2")
+            .await
+            .unwrap();
+        assert_eq!(vm.id, "did:example:issuer#key-2");
+
+        let vm = resolvers
+            .resolve_assertion_method("did:example:issuer")
+            .await
+            .unwrap();
+        assert_eq!(vm.id, "did:example:issuer#key-1");
+
+        let err = resolvers
+            .resolve_assertion_method("did:example:issuer#key-3")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::SignatureError(IcaValidationError::InvalidDidDocument(_))
+        ));
+    }
+
+    #[c2pa_test_async]
+    async fn unsupported_method() {
+        let err = DidResolvers::default()
+            .resolve("did:example:issuer")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::SignatureError(IcaValidationError::UnsupportedIssuerDid(_))
+        ));
+
+        assert!(DidResolvers::empty()
+            .resolve("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK")
+            .await
+            .is_err());
+    }
+}
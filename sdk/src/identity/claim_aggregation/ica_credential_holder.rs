@@ -0,0 +1,406 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use chrono::{DateTime, FixedOffset, Utc};
+use coset::ContentType;
+use iref::UriBuf;
+use nonempty_collections::NEVec;
+use serde_json::Value;
+
+use crate::{
+    crypto::{
+        base64,
+        cose::{sign_v2_embedded, CosePayload, TimeStampStorage},
+        raw_signature::RawSigner,
+    },
+    identity::{
+        builder::{CredentialHolder, IdentityBuilderError},
+        claim_aggregation::{
+            w3c_vc::did::DidBuf, IcaCredential, IdentityClaimsAggregationVc, VerifiedIdentity,
+        },
+        SignerPayload,
+    },
+};
+
+/// An implementation of [`CredentialHolder`] that issues identity claims
+/// aggregation credentials as specified in [§8.1, Identity claims
+/// aggregation].
+///
+/// The credential holder acts as the _identity claims aggregator:_ it binds
+/// a set of [`VerifiedIdentity`] records to the [`SignerPayload`] presented for
+/// signature and signs the resulting verifiable credential as COSE on behalf
+/// of the issuer DID.
+///
+/// [§8.1, Identity claims aggregation]: https://cawg.io/identity/1.1-draft/#_identity_claims_aggregation
+pub struct IcaCredentialHolder {
+    issuer: IcaIssuer,
+    signer: Box<dyn RawSigner + Sync + Send + 'static>,
+}
+
+impl IcaCredentialHolder {
+    /// Create an `IcaCredentialHolder` that issues credentials from the
+    /// `issuer` DID for the given verified identities.
+    ///
+    /// The [`RawSigner`] must hold the private key for the `assertionMethod`
+    /// of the issuer DID. If the signer has a time stamp service URL, the
+    /// credential signature is time-stamped.
+    ///
+    /// Returns an error if `issuer` is not a valid DID or if there are no
+    /// verified identities.
+    ///
+    /// [`RawSigner`]: crate::crypto::raw_signature::RawSigner
+    pub fn from_raw_signer<I>(
+        issuer: &str,
+        verified_identities: I,
+        signer: Box<dyn RawSigner + Sync + Send + 'static>,
+    ) -> Result<Self, IdentityBuilderError>
+    where
+        I: IntoIterator<Item = VerifiedIdentity>,
+    {
+        Ok(Self {
+            issuer: IcaIssuer::new(issuer, verified_identities)?,
+            signer,
+        })
+    }
+
+    /// Sets the `validUntil` date of the credentials issued by this holder.
+    pub fn set_valid_until(mut self, valid_until: DateTime<FixedOffset>) -> Self {
+        self.issuer.valid_until = Some(valid_until);
+        self
+    }
+}
+
+impl CredentialHolder for IcaCredentialHolder {
+    fn sig_type(&self) -> &'static str {
+        super::CAWG_ICA_SIG_TYPE
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.issuer.reserve_size(None, self.signer.reserve_size())
+    }
+
+    fn reserve_size_for(&self, signer_payload: &SignerPayload) -> usize {
+        self.issuer
+            .reserve_size(Some(signer_payload), self.signer.reserve_size())
+    }
+
+    fn sign(&self, signer_payload: &SignerPayload) -> Result<Vec<u8>, IdentityBuilderError> {
+        let vc = self.issuer.credential_json(signer_payload)?;
+
+        sign_v2_embedded(
+            self.signer.as_ref(),
+            &vc,
+            None,
+            CosePayload::Embedded,
+            Some(ContentType::Text(VC_CONTENT_TYPE.to_owned())),
+            TimeStampStorage::V2_sigTst2_CTT,
+        )
+        .map_err(|e| IdentityBuilderError::SignerError(e.to_string()))
+    }
+}
+
+/// The COSE content type of a verifiable credential.
+pub(super) const VC_CONTENT_TYPE: &str = "application/vc";
+
+/// The credential contents shared by [`IcaCredentialHolder`] and
+/// [`AsyncIcaCredentialHolder`].
+///
+/// [`AsyncIcaCredentialHolder`]: super::AsyncIcaCredentialHolder
+pub(super) struct IcaIssuer {
+    issuer: UriBuf,
+    verified_identities: NEVec<VerifiedIdentity>,
+    pub(super) valid_until: Option<DateTime<FixedOffset>>,
+}
+
+impl IcaIssuer {
+    pub(super) fn new<I>(issuer: &str, verified_identities: I) -> Result<Self, IdentityBuilderError>
+    where
+        I: IntoIterator<Item = VerifiedIdentity>,
+    {
+        let issuer = DidBuf::new(issuer.to_owned())
+            .map_err(|e| IdentityBuilderError::CredentialError(e.to_string()))?
+            .into_uri();
+
+        let verified_identities = NEVec::try_from_vec(verified_identities.into_iter().collect())
+            .ok_or_else(|| {
+                IdentityBuilderError::CredentialError(
+                    "at least one verified identity is required".to_owned(),
+                )
+            })?;
+
+        Ok(Self {
+            issuer,
+            verified_identities,
+            valid_until: None,
+        })
+    }
+
+    /// Returns the space needed for a credential bound to `signer_payload`,
+    /// or to a payload that references no assertions if it is `None`, and
+    /// signed with a signature of `signature_size` bytes.
+    pub(super) fn reserve_size(
+        &self,
+        signer_payload: Option<&SignerPayload>,
+        signature_size: usize,
+    ) -> usize {
+        let empty_payload = SignerPayload {
+            referenced_assertions: vec![],
+            sig_type: super::CAWG_ICA_SIG_TYPE.to_owned(),
+            roles: vec![],
+        };
+
+        let vc_size = self
+            .credential_json(signer_payload.unwrap_or(&empty_payload))
+            .map(|vc| vc.len())
+            .unwrap_or_default();
+
+        vc_size + signature_size
+    }
+
+    /// Builds the JSON serialization of the credential bound to
+    /// `signer_payload`, valid from now.
+    pub(super) fn credential_json(
+        &self,
+        signer_payload: &SignerPayload,
+    ) -> Result<Vec<u8>, IdentityBuilderError> {
+        let subject = IdentityClaimsAggregationVc {
+            verified_identities: self.verified_identities.clone(),
+            c2pa_asset: signer_payload.clone(),
+            time_stamp: None,
+        };
+
+        let mut credential = IcaCredential::new(None, self.issuer.clone(), NEVec::new(subject));
+        credential.valid_from = Some(Utc::now().fixed_offset());
+        credential.valid_until = self.valid_until;
+
+        let mut json = serde_json::to_value(&credential)
+            .map_err(|e| IdentityBuilderError::InternalError(e.to_string()))?;
+
+        // `c2paAsset` carries assertion hashes as base 64 strings rather than
+        // the byte strings used in CBOR.
+        if let Some(assertions) = json
+            .pointer_mut("/credentialSubject/c2paAsset/referenced_assertions")
+            .and_then(Value::as_array_mut)
+        {
+            for (assertion, hashed_uri) in assertions
+                .iter_mut()
+                .zip(&signer_payload.referenced_assertions)
+            {
+                assertion["hash"] = Value::String(base64::encode(&hashed_uri.hash()));
+            }
+        }
+
+        serde_json::to_vec(&json).map_err(|e| IdentityBuilderError::InternalError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::{Cursor, Seek};
+
+    use c2pa_macros::c2pa_test_async;
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::{
+        crypto::raw_signature::RawSigner,
+        identity::{
+            builder::{CredentialHolder, IdentityAssertionBuilder, IdentityAssertionSigner},
+            claim_aggregation::{
+                DidJwkResolver, DidResolvers, IcaCredential, IcaCredentialHolder,
+                IcaSignatureVerifier, IcaValidationError,
+            },
+            tests::fixtures::{
+                claim_aggregation::{
+                    ica_credential_example::ica_example_identities,
+                    ica_issuer::{
+                        did_jwk_signer_for_alg, ed25519_did_jwk_signer, ed25519_did_key,
+                        p256_did_key,
+                    },
+                },
+                manifest_json, parent_json,
+            },
+            IdentityAssertion, SignatureVerifier, SignerPayload,
+        },
+        status_tracker::StatusTracker,
+        Builder, HashedUri, Reader, SigningAlg,
+    };
+
+    const TEST_IMAGE: &[u8] = include_bytes!("../../../tests/fixtures/CA.jpg");
+    const TEST_THUMBNAIL: &[u8] = include_bytes!("../../../tests/fixtures/thumbnail.jpg");
+
+    /// Signs the test image with an ICA credential from `issuer`, then reads
+    /// the identity assertion back and validates it with `verifier`.
+    async fn issue_and_validate<SV>(
+        issuer: &str,
+        raw_signer: Box<dyn RawSigner + Send + Sync>,
+        verifier: &SV,
+    ) -> (IcaCredential, StatusTracker)
+    where
+        SV: SignatureVerifier<Output = IcaCredential, Error = IcaValidationError>,
+    {
+        let format = "image/jpeg";
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+
+        let mut builder = Builder::from_json(&manifest_json()).unwrap();
+        builder
+            .add_ingredient_from_stream(parent_json(), format, &mut source)
+            .unwrap();
+        builder
+            .add_resource("thumbnail.jpg", Cursor::new(TEST_THUMBNAIL))
+            .unwrap();
+
+        let ica_holder =
+            IcaCredentialHolder::from_raw_signer(issuer, ica_example_identities(), raw_signer)
+                .unwrap();
+
+        let mut c2pa_signer = IdentityAssertionSigner::from_test_credentials(SigningAlg::Ps256);
+        c2pa_signer
+            .add_identity_assertion(IdentityAssertionBuilder::for_credential_holder(ica_holder));
+
+        builder
+            .sign(&c2pa_signer, format, &mut source, &mut dest)
+            .unwrap();
+
+        dest.rewind().unwrap();
+        let reader = Reader::from_stream(format, &mut dest).unwrap();
+        let manifest = reader.active_manifest().unwrap();
+
+        let mut st = StatusTracker::default();
+        let mut ia_iter = IdentityAssertion::from_manifest(manifest, &mut st);
+        let ia = ia_iter.next().unwrap().unwrap();
+        drop(ia_iter);
+
+        let ica = ia.validate(manifest, &mut st, verifier).await.unwrap();
+        (ica, st)
+    }
+
+    #[c2pa_test_async]
+    async fn issues_valid_credential() {
+        let (issuer, raw_signer) = ed25519_did_jwk_signer();
+        let (ica, st) = issue_and_validate(&issuer, raw_signer, &IcaSignatureVerifier {}).await;
+
+        assert_eq!(ica.issuer.as_str(), issuer);
+        assert!(ica.valid_from.is_some());
+        assert_eq!(
+            ica.credential_subjects.first().verified_identities,
+            ica_example_identities()
+        );
+        assert!(st.has_status("cawg.ica.credential_valid"));
+    }
+
+    #[c2pa_test_async]
+    async fn issues_credential_for_did_key() {
+        // The same key as a did:key, naming its verification method.
+        let did_key = ed25519_did_key();
+        let msid = did_key.strip_prefix("did:key:").unwrap();
+        let issuer = format!("{did_key}#{msid}");
+
+        let (_issuer, raw_signer) = ed25519_did_jwk_signer();
+        let (ica, st) = issue_and_validate(&issuer, raw_signer, &IcaSignatureVerifier {}).await;
+        assert_eq!(ica.issuer.as_str(), issuer);
+        assert!(st.has_status("cawg.ica.credential_valid"));
+
+        // Without a did:key resolver, the issuer can't be resolved.
+        let mut verifier = IcaSignatureVerifier::with_resolvers(DidResolvers::empty());
+        verifier.did_resolvers_mut().register("jwk", DidJwkResolver);
+
+        let (_issuer, raw_signer) = ed25519_did_jwk_signer();
+        let (_ica, st) = issue_and_validate(&issuer, raw_signer, &verifier).await;
+        assert!(st.has_status("cawg.ica.invalid_issuer"));
+    }
+
+    #[c2pa_test_async]
+    async fn issues_ec_and_rsa_credentials() {
+        for alg in [
+            SigningAlg::Es256,
+            SigningAlg::Es384,
+            SigningAlg::Es512,
+            SigningAlg::Ps256,
+        ] {
+            let (issuer, raw_signer) = did_jwk_signer_for_alg(alg);
+            let (ica, st) = issue_and_validate(&issuer, raw_signer, &IcaSignatureVerifier {}).await;
+
+            assert_eq!(ica.issuer.as_str(), issuer);
+            assert!(
+                st.has_status("cawg.ica.credential_valid"),
+                "{alg} {:#?}",
+                st.logged_items()
+            );
+        }
+    }
+
+    #[c2pa_test_async]
+    async fn issues_credential_for_p256_did_key() {
+        let (_issuer, raw_signer) = did_jwk_signer_for_alg(SigningAlg::Es256);
+        let (_ica, st) =
+            issue_and_validate(&p256_did_key(), raw_signer, &IcaSignatureVerifier {}).await;
+        assert!(st.has_status("cawg.ica.credential_valid"));
+    }
+
+    #[c2pa_test_async]
+    async fn rejects_key_for_other_algorithm() {
+        // An ES256 signature from an issuer whose key is Ed25519.
+        let (_issuer, raw_signer) = did_jwk_signer_for_alg(SigningAlg::Es256);
+        let (issuer, _raw_signer) = ed25519_did_jwk_signer();
+
+        let (_ica, st) = issue_and_validate(&issuer, raw_signer, &IcaSignatureVerifier {}).await;
+        assert!(st.has_status("cawg.ica.signature_mismatch"));
+        assert!(!st.has_status("cawg.ica.credential_valid"));
+    }
+
+    #[test]
+    fn reserves_space_for_signer_payload() {
+        let (issuer, raw_signer) = ed25519_did_jwk_signer();
+        let ica_holder =
+            IcaCredentialHolder::from_raw_signer(&issuer, ica_example_identities(), raw_signer)
+                .unwrap();
+
+        // The credential carries a copy of the signer payload, so a payload
+        // that references many assertions needs tens of kilobytes.
+        let signer_payload = SignerPayload {
+            referenced_assertions: (0..256)
+                .map(|n| {
+                    HashedUri::new(
+                        format!("self#jumbf=c2pa.assertions/org.example.assertion.{n}"),
+                        None,
+                        &[0; 32],
+                    )
+                })
+                .collect(),
+            sig_type: ica_holder.sig_type().to_owned(),
+            roles: vec!["cawg.creator".to_owned()],
+        };
+
+        let signature = ica_holder.sign(&signer_payload).unwrap();
+        assert!(signature.len() > ica_holder.reserve_size());
+        assert!(signature.len() <= ica_holder.reserve_size_for(&signer_payload));
+    }
+
+    #[test]
+    fn rejects_invalid_issuer() {
+        let (_issuer, raw_signer) = ed25519_did_jwk_signer();
+        assert!(IcaCredentialHolder::from_raw_signer(
+            "https://example.com",
+            ica_example_identities(),
+            raw_signer
+        )
+        .is_err());
+
+        let (issuer, raw_signer) = ed25519_did_jwk_signer();
+        assert!(IcaCredentialHolder::from_raw_signer(&issuer, vec![], raw_signer).is_err());
+    }
+}
@@ -12,7 +12,6 @@
 // each license.
 
 use async_trait::async_trait;
-use base64::{prelude::BASE64_URL_SAFE, Engine};
 use chrono::{DateTime, Utc};
 use coset::{CoseSign1, RegisteredLabelWithPrivate, TaggedCborSerializable};
 
@@ -20,15 +19,16 @@ use crate::{
     crypto::{
         asn1::rfc3161::TstInfo,
         cose::{validate_cose_tst_info_async, CertificateTrustPolicy},
+        raw_signature::validator_for_signing_alg,
     },
     identity::{
         claim_aggregation::{
             w3c_vc::{
-                did::Did,
-                did_web,
-                jwk::{Algorithm, Jwk, JwkError, Params},
+                did_doc::DidVerificationMethod,
+                did_key::Multikey,
+                jwk::{Algorithm, Jwk},
             },
-            IcaCredential, IcaValidationError,
+            DidResolvers, IcaCredential, IcaValidationError, StatusListFetcher, StatusLists,
         },
         SignatureVerifier, SignerPayload, ValidationError,
     },
@@ -49,16 +49,62 @@ use crate::{
 /// [`SignatureVerifier`]: crate::identity::SignatureVerifier
 /// [§8.1, Identity claims aggregation]: https://creator-assertions.github.io/identity/1.1-draft/#_identity_claims_aggregation
 /// [§3.3.1 Securing JSON-LD Verifiable Credentials with COSE]: https://w3c.github.io/vc-jose-cose/#securing-vcs-with-cose
+///
+/// Issuer public keys are found with the default [`DidResolvers`]. Use
+/// [`with_resolvers`] to configure the DID resolvers or status list fetcher.
+///
+/// [`with_resolvers`]: Self::with_resolvers
 pub struct IcaSignatureVerifier {
     // TO DO (CAI-7980): Add option to configure trusted ICA issuers.
 }
 
+impl IcaSignatureVerifier {
+    /// Create a [`ConfiguredIcaSignatureVerifier`] that finds issuer public
+    /// keys using the given DID resolvers.
+    pub fn with_resolvers(did_resolvers: DidResolvers) -> ConfiguredIcaSignatureVerifier {
+        ConfiguredIcaSignatureVerifier {
+            did_resolvers,
+            status_lists: StatusLists::default(),
+        }
+    }
+}
+
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl SignatureVerifier for IcaSignatureVerifier {
     type Error = IcaValidationError;
     type Output = IcaCredential;
 
+    async fn check_signature(
+        &self,
+        signer_payload: &SignerPayload,
+        signature: &[u8],
+        status_tracker: &mut StatusTracker,
+    ) -> Result<Self::Output, ValidationError<Self::Error>> {
+        ConfiguredIcaSignatureVerifier::default()
+            .check_signature(signer_payload, signature, status_tracker)
+            .await
+    }
+}
+
+/// An [`IcaSignatureVerifier`] with its own DID resolvers and status list
+/// cache, created by [`IcaSignatureVerifier::with_resolvers`].
+///
+/// The default supports `did:key`, `did:jwk` and `did:web` issuers and fetches
+/// status lists over HTTPS. Status lists are cached for as long as the
+/// verifier is kept.
+#[derive(Debug, Default)]
+pub struct ConfiguredIcaSignatureVerifier {
+    did_resolvers: DidResolvers,
+    status_lists: StatusLists,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl SignatureVerifier for ConfiguredIcaSignatureVerifier {
+    type Error = IcaValidationError;
+    type Output = IcaCredential;
+
     async fn check_signature(
         &self,
         signer_payload: &SignerPayload,
@@ -68,7 +114,7 @@ impl SignatureVerifier for IcaSignatureVerifier {
         self.check_sig_type(signer_payload, status_tracker)?;
 
         let sign1 = self.decode_cose_sign1(signature, status_tracker)?;
-        let _ssi_alg = self.decode_signing_alg(&sign1, status_tracker)?;
+        let alg = self.decode_signing_alg(&sign1, status_tracker)?;
 
         // From this point forward, most errors are recoverable. We can only issue a
         // "credential valid" status if no errors are detected, so we use the `ok`
@@ -83,7 +129,7 @@ impl SignatureVerifier for IcaSignatureVerifier {
         // TO DO (CAI-7970): Add support for VC version 1.
         let mut ica_credential = self.parse_ica_vc_v2(payload_bytes, status_tracker)?;
 
-        self.check_issuer_signature(&sign1, &ica_credential)
+        self.check_issuer_signature(&sign1, alg, &ica_credential)
             .await
             .or_else(|err| {
                 ok = false;
@@ -128,7 +174,12 @@ impl SignatureVerifier for IcaSignatureVerifier {
                 self.handle_non_fatal_error(err, status_tracker)
             })?;
 
-        // TO DO (CAI-7993): CAWG SDK should check ICA issuer revocation status.
+        self.check_credential_status(&ica_credential, status_tracker)
+            .await
+            .or_else(|err| {
+                ok = false;
+                self.handle_non_fatal_error(err, status_tracker)
+            })?;
 
         self.cross_check_signer_payload(&ica_credential, signer_payload, status_tracker, &mut ok)?;
 
@@ -147,7 +198,26 @@ impl SignatureVerifier for IcaSignatureVerifier {
     }
 }
 
-impl IcaSignatureVerifier {
+impl ConfiguredIcaSignatureVerifier {
+    /// Returns the DID resolvers used to find issuer public keys.
+    pub fn did_resolvers_mut(&mut self) -> &mut DidResolvers {
+        &mut self.did_resolvers
+    }
+
+    /// Use `fetcher` to retrieve the status lists named in the
+    /// `credentialStatus` of credentials.
+    ///
+    /// By default, status lists are fetched over HTTPS.
+    pub fn set_status_list_fetcher<F: StatusListFetcher + 'static>(&mut self, fetcher: F) {
+        self.status_lists = StatusLists::new(fetcher);
+    }
+
+    /// Returns the cache of status lists used to check the
+    /// `credentialStatus` of credentials.
+    pub fn status_lists(&self) -> &StatusLists {
+        &self.status_lists
+    }
+
     /// Signal an error if the `sig_type` value is not
     /// `cawg.identity_claims_aggregation`.
     fn check_sig_type(
@@ -199,12 +269,9 @@ impl IcaSignatureVerifier {
         status_tracker: &mut StatusTracker,
     ) -> Result<Algorithm, ValidationError<IcaValidationError>> {
         if let Some(ref alg) = sign1.protected.header.alg {
-            match alg {
-                // TO DO (CAI-7965): Support algorithms other than EdDSA.
-                RegisteredLabelWithPrivate::Assigned(coset::iana::Algorithm::EdDSA) => {
-                    Ok(Algorithm::EdDsa)
-                }
-                _ => {
+            match cose_algorithm(alg) {
+                Some(alg) => Ok(alg),
+                None => {
                     let err = ValidationError::SignatureError(
                         IcaValidationError::UnsupportedSignatureType(format!("{alg:?}")),
                     );
@@ -357,107 +424,12 @@ impl IcaSignatureVerifier {
     async fn check_issuer_signature(
         &self,
         sign1: &CoseSign1,
+        alg: Algorithm,
         ica_credential: &IcaCredential,
     ) -> Result<(), ValidationError<IcaValidationError>> {
-        // Discover public key for issuer DID and validate signature.
-        // TEMPORARY version supports did:jwk and did:web only.
-
         // TO DO (CAI-7976): Accept issuer DID in either `issuer` or `issuer.id` field.
         // Currently only `issuer` field is supported.
-        let issuer_id = Did::new(&ica_credential.issuer)?;
-        let (primary_did, _fragment) = issuer_id.split_fragment();
-
-        let jwk = match primary_did.method_name() {
-            "jwk" => {
-                let jwk = primary_did.method_specific_id();
-
-                let jwk = BASE64_URL_SAFE.decode(jwk).map_err(|e| {
-                    ValidationError::SignatureError(IcaValidationError::InvalidDidDocument(
-                        e.to_string(),
-                    ))
-                })?;
-
-                let jwk: Jwk = serde_json::from_slice(&jwk).map_err(|e| {
-                    ValidationError::SignatureError(IcaValidationError::InvalidDidDocument(
-                        e.to_string(),
-                    ))
-                })?;
-
-                jwk
-            }
-
-            "web" => {
-                let did_doc = did_web::resolve(&primary_did).await?;
-
-                let Some(vm1) = did_doc.verification_relationships.assertion_method.first() else {
-                    return Err(ValidationError::SignatureError(
-                        IcaValidationError::InvalidDidDocument(
-                            "DID document doesn't contain an assertionMethod entry".to_string(),
-                        ),
-                    ));
-                };
-
-                let super::w3c_vc::did_doc::ValueOrReference::Value(vm1) = vm1 else {
-                    return Err(ValidationError::SignatureError(
-                        IcaValidationError::InvalidDidDocument(
-                            "DID document's assertionMethod is not a value".to_string(),
-                        ),
-                    ));
-                };
-
-                let Some(jwk_prop) = vm1.properties.get("publicKeyJwk") else {
-                    return Err(ValidationError::SignatureError(
-                        IcaValidationError::InvalidDidDocument(
-                            "DID document's assertionMethod doesn't contain a publicKeyJwk entry"
-                                .to_string(),
-                        ),
-                    ));
-                };
-
-                // OMG SO HACKY!
-                let Ok(jwk_json) = serde_json::to_string_pretty(jwk_prop) else {
-                    return Err(ValidationError::InternalError(
-                        "couldn't re-serialize JWK".to_string(),
-                    ));
-                };
-
-                let Ok(jwk) = serde_json::from_str(&jwk_json) else {
-                    return Err(ValidationError::InternalError(
-                        "couldn't re-serialize JWK".to_string(),
-                    ));
-                };
-
-                jwk
-            }
-
-            x => {
-                return Err(ValidationError::SignatureError(
-                    IcaValidationError::UnsupportedIssuerDid(format!("unsupported DID method {x}")),
-                ));
-            }
-        };
-
-        // TEMPORARY: only support ED25519.
-        let Params::Okp(ref okp) = jwk.params;
-        if okp.curve != "Ed25519" {
-            return Err(ValidationError::SignatureError(
-                IcaValidationError::InvalidDidDocument(format!(
-                    "unsupported OKP curve {}",
-                    okp.curve
-                )),
-            ));
-        }
-
-        // Check the signature, which needs to have the same `aad` provided, by
-        // providing a closure that can do the verify operation.
-        sign1
-            .verify_signature(b"", |sig, data| {
-                use ed25519_dalek::Verifier;
-                let public_key = ed25519_dalek::VerifyingKey::try_from(okp)?;
-                let signature: ed25519_dalek::Signature = sig.try_into().map_err(JwkError::from)?;
-                public_key.verify(data, &signature).map_err(JwkError::from)
-            })
-            .map_err(|_e| ValidationError::SignatureMismatch)?;
+        verify_issuer_signature(&self.did_resolvers, &ica_credential.issuer, sign1, alg).await?;
 
         // TO DO: Enforce signer_payload matches what was stated outside the signature.
 
@@ -698,6 +670,41 @@ impl IcaSignatureVerifier {
         Ok(())
     }
 
+    /// Signal an error if the credential's `credentialStatus` says it has
+    /// been revoked or suspended.
+    ///
+    /// A revocation or suspension reported by a status list credential whose
+    /// proof can't be verified is only logged as informational.
+    async fn check_credential_status(
+        &self,
+        ica_credential: &IcaCredential,
+        status_tracker: &mut StatusTracker,
+    ) -> Result<(), (IcaValidationError, &'static str)> {
+        match self
+            .status_lists
+            .check(ica_credential, &self.did_resolvers)
+            .await
+        {
+            Ok(()) => Ok(()),
+
+            Err(err @ IcaValidationError::CredentialStatusUnverified(_)) => {
+                log_current_item!(err.to_string(), "IcaSignatureVerifier::check_signature")
+                    .validation_status("cawg.ica.credential_status_unverified")
+                    .informational(status_tracker);
+                Ok(())
+            }
+
+            Err(err) => {
+                let status = match err {
+                    IcaValidationError::CredentialRevoked(_) => "cawg.ica.credential_revoked",
+                    IcaValidationError::CredentialSuspended(_) => "cawg.ica.credential_suspended",
+                    _ => "cawg.ica.credential_status_unavailable",
+                };
+                Err((err, status))
+            }
+        }
+    }
+
     /// Verify that `signer_payload` is the same as credential issuer signed.
     fn cross_check_signer_payload(
         &self,
@@ -746,3 +753,98 @@ impl IcaSignatureVerifier {
         Ok(())
     }
 }
+
+/// Converts a COSE `alg` header to the corresponding [`Algorithm`], if it is
+/// one that credentials can be signed with.
+pub(super) fn cose_algorithm(alg: &coset::Algorithm) -> Option<Algorithm> {
+    match alg {
+        RegisteredLabelWithPrivate::Assigned(coset::iana::Algorithm::EdDSA) => {
+            Some(Algorithm::EdDsa)
+        }
+        RegisteredLabelWithPrivate::Assigned(coset::iana::Algorithm::ES256) => {
+            Some(Algorithm::Es256)
+        }
+        RegisteredLabelWithPrivate::Assigned(coset::iana::Algorithm::ES384) => {
+            Some(Algorithm::Es384)
+        }
+        RegisteredLabelWithPrivate::Assigned(coset::iana::Algorithm::ES512) => {
+            Some(Algorithm::Es512)
+        }
+        RegisteredLabelWithPrivate::Assigned(coset::iana::Algorithm::PS256) => {
+            Some(Algorithm::Ps256)
+        }
+        _ => None,
+    }
+}
+
+/// Checks that `sign1` was signed with `alg` by the key that the `issuer` DID
+/// lists as an assertion method.
+pub(super) async fn verify_issuer_signature(
+    did_resolvers: &DidResolvers,
+    issuer: &str,
+    sign1: &CoseSign1,
+    alg: Algorithm,
+) -> Result<(), ValidationError<IcaValidationError>> {
+    let vm = did_resolvers.resolve_assertion_method(issuer).await?;
+
+    let (key_alg, public_key) = verification_method_key(&vm)?;
+
+    // The COSE algorithm must be the one the issuer's key is meant for.
+    if key_alg != alg {
+        return Err(ValidationError::SignatureMismatch);
+    }
+
+    let validator = alg
+        .signing_alg()
+        .and_then(validator_for_signing_alg)
+        .ok_or_else(|| {
+            ValidationError::SignatureError(IcaValidationError::UnsupportedSignatureType(format!(
+                "{alg:?}"
+            )))
+        })?;
+
+    // Check the signature, which needs to have the same `aad` provided, by
+    // providing a closure that can do the verify operation.
+    sign1
+        .verify_signature(b"", |sig, data| validator.validate(sig, data, &public_key))
+        .map_err(|_e| ValidationError::SignatureMismatch)
+}
+
+/// Reads the public key of a verification method, returning the signature
+/// algorithm it is used with and its DER-encoded `SubjectPublicKeyInfo`.
+///
+/// Keys can be given as `publicKeyJwk` or, as `did:key` documents do, as
+/// `publicKeyMultibase`.
+fn verification_method_key(
+    vm: &DidVerificationMethod,
+) -> Result<(Algorithm, Vec<u8>), ValidationError<IcaValidationError>> {
+    let invalid =
+        |msg: String| ValidationError::SignatureError(IcaValidationError::InvalidDidDocument(msg));
+
+    if let Some(jwk) = vm.properties.get("publicKeyJwk") {
+        let jwk = Jwk::try_from(jwk.clone()).map_err(|e| invalid(e.to_string()))?;
+
+        let alg = jwk
+            .get_algorithm()
+            .ok_or_else(|| invalid(format!("unsupported key type {:?}", jwk.params)))?;
+
+        let public_key = jwk
+            .to_public_key_der()
+            .map_err(|e| invalid(e.to_string()))?;
+
+        return Ok((alg, public_key));
+    }
+
+    if let Some(multibase) = vm.properties.get("publicKeyMultibase") {
+        let multibase = multibase
+            .as_str()
+            .ok_or_else(|| invalid("publicKeyMultibase is not a string".to_owned()))?;
+
+        let key = Multikey::decode(multibase).map_err(|e| invalid(e.to_string()))?;
+        return Ok((key.algorithm(), key.to_public_key_der()));
+    }
+
+    Err(invalid(
+        "DID document's assertionMethod doesn't contain a publicKeyJwk entry".to_owned(),
+    ))
+}
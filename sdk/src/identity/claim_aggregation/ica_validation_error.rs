@@ -90,6 +90,27 @@ pub enum IcaValidationError {
     /// `c2paAsset` does not match `signer_payload`
     #[error("c2paAsset does not match signer_payload")]
     SignerPayloadMismatch,
+
+    /// The credential's status list marks it as revoked. The value names the
+    /// status list and index.
+    #[error("credential has been revoked ({0})")]
+    CredentialRevoked(String),
+
+    /// The credential's status list marks it as suspended. The value names
+    /// the status list and index.
+    #[error("credential has been suspended ({0})")]
+    CredentialSuspended(String),
+
+    /// The credential's status could not be determined (status list could
+    /// not be fetched or was invalid, etc.).
+    #[error("credential status could not be checked ({0})")]
+    CredentialStatusUnavailable(String),
+
+    /// A status list credential whose proof could not be verified marks the
+    /// credential as revoked or suspended. The value names the status purpose,
+    /// status list and index. This is informational only.
+    #[error("unverified status list reports {0}")]
+    CredentialStatusUnverified(String),
 }
 
 impl From<coset::CoseError> for ValidationError<IcaValidationError> {
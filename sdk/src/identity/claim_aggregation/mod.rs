@@ -11,26 +11,43 @@
 // specific language governing permissions and limitations under
 // each license.
 
-//! Contains implementations of [`AsyncCredentialHolder`] and
-//! [`SignatureVerifier`] for the identity claim aggregation credential type
+//! Contains implementations of [`CredentialHolder`], [`AsyncCredentialHolder`]
+//! and [`SignatureVerifier`] for the identity claim aggregation credential type
 //! described as specified in [§8.1, Identity claims aggregation].
 //!
+//! [`CredentialHolder`]: crate::identity::builder::CredentialHolder
 //! [`AsyncCredentialHolder`]: crate::identity::builder::AsyncCredentialHolder
 //! [`SignatureVerifier`]: crate::identity::SignatureVerifier
 //! [§8.1, Identity claims aggregation]: https://creator-assertions.github.io/identity/1.1-draft/#_identity_claims_aggregation
 
+mod async_ica_credential_holder;
+pub use async_ica_credential_holder::AsyncIcaCredentialHolder;
+
+mod did_resolver;
+pub use did_resolver::{DidJwkResolver, DidKeyResolver, DidResolver, DidResolvers, DidWebResolver};
+pub use w3c_vc::{
+    did::DidBuf,
+    did_doc::{DidDocument, DidVerificationMethod, ValueOrReference, VerificationRelationships},
+};
+
 mod ica_credential;
 pub use ica_credential::{
     IcaCredential, IcaCredentialSummary, IdentityClaimsAggregationVc, IdentityProvider,
     VerifiedIdentity,
 };
 
+mod ica_credential_holder;
+pub use ica_credential_holder::IcaCredentialHolder;
+
 mod ica_signature_verifier;
-pub use ica_signature_verifier::IcaSignatureVerifier;
+pub use ica_signature_verifier::{ConfiguredIcaSignatureVerifier, IcaSignatureVerifier};
 
 mod ica_validation_error;
 pub use ica_validation_error::IcaValidationError;
 
+mod status_list;
+pub use status_list::{HttpStatusListFetcher, StatusListFetcher, StatusLists};
+
 pub(crate) mod w3c_vc;
 
 pub(crate) const CAWG_ICA_SIG_TYPE: &str = "cawg.identity_claims_aggregation";
@@ -0,0 +1,754 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io::Read,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use base64::{
+    engine::{general_purpose::GeneralPurpose, DecodePaddingMode, GeneralPurposeConfig},
+    Engine,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use coset::{CoseSign1, TaggedCborSerializable};
+use flate2::read::GzDecoder;
+use serde_json::Value;
+
+use super::{
+    ica_signature_verifier::{cose_algorithm, verify_issuer_signature},
+    w3c_vc::did_web,
+    DidResolvers, IcaCredential, IcaValidationError,
+};
+
+/// A `StatusListFetcher` retrieves the status list credential named by the
+/// `statusListCredential` property of a [`BitstringStatusListEntry`].
+///
+/// [`BitstringStatusListEntry`]: https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistentry
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+pub trait StatusListFetcher: Send + Sync {
+    /// Returns the status list credential at `url`, either as its JSON
+    /// serialization or secured with COSE (`application/vc+cose`).
+    async fn fetch(&self, url: &str) -> Result<Vec<u8>, IcaValidationError>;
+}
+
+/// A `StatusListFetcher` retrieves the status list credential named by the
+/// `statusListCredential` property of a [`BitstringStatusListEntry`].
+///
+/// [`BitstringStatusListEntry`]: https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistentry
+#[cfg(target_arch = "wasm32")]
+#[async_trait(?Send)]
+pub trait StatusListFetcher {
+    /// Returns the status list credential at `url`, either as its JSON
+    /// serialization or secured with COSE (`application/vc+cose`).
+    async fn fetch(&self, url: &str) -> Result<Vec<u8>, IcaValidationError>;
+}
+
+/// Fetches status list credentials over HTTPS.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HttpStatusListFetcher;
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl StatusListFetcher for HttpStatusListFetcher {
+    async fn fetch(&self, url: &str) -> Result<Vec<u8>, IcaValidationError> {
+        did_web::get(
+            url,
+            "application/vc+cose, application/vc+ld+json, application/json",
+        )
+        .await
+        .map_err(|e| IcaValidationError::CredentialStatusUnavailable(e.to_string()))
+    }
+}
+
+/// Checks the [Bitstring Status List] entries of identity claims aggregation
+/// credentials.
+///
+/// Status list credentials are fetched with a [`StatusListFetcher`] and
+/// cached by URL until their `ttl` (five minutes by default) passes or they
+/// reach their `validUntil` date, whichever is sooner, or until
+/// [`clear_cache`] is called.
+///
+/// A status list credential secured with COSE, as identity claims aggregation
+/// credentials are, is only used once its issuer's signature has been
+/// verified with the configured [`DidResolvers`]. The proofs of other status
+/// list credentials can't be verified, so a revocation or suspension they
+/// report is informational only.
+///
+/// [Bitstring Status List]: https://www.w3.org/TR/vc-bitstring-status-list/
+/// [`clear_cache`]: Self::clear_cache
+pub struct StatusLists {
+    fetcher: Arc<dyn StatusListFetcher>,
+    cache: Mutex<HashMap<String, Arc<BitstringStatusList>>>,
+}
+
+// How long a status list is cached if its credential doesn't give a `ttl`,
+// in milliseconds, as in the Bitstring Status List specification.
+const DEFAULT_TTL_MS: u64 = 300_000;
+
+// The largest decompressed status list accepted. The specification's minimum
+// is 16 KB, so this leaves plenty of room while stopping a small
+// `encodedList` from expanding without limit.
+const MAX_STATUS_LIST_BYTES: u64 = 16 * 1024 * 1024;
+
+impl StatusLists {
+    /// Creates a `StatusLists` that fetches status list credentials with
+    /// `fetcher`.
+    pub fn new<F: StatusListFetcher + 'static>(fetcher: F) -> Self {
+        Self {
+            fetcher: Arc::new(fetcher),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Forgets all fetched status lists.
+    pub fn clear_cache(&self) {
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.clear();
+        }
+    }
+
+    /// Checks each `BitstringStatusListEntry` in the `credentialStatus` of
+    /// `credential`, verifying COSE-secured status list credentials with
+    /// `did_resolvers`.
+    ///
+    /// Returns [`IcaValidationError::CredentialRevoked`] or
+    /// [`IcaValidationError::CredentialSuspended`] if the status bit is set
+    /// for a `revocation` or `suspension` entry in a verified status list, or
+    /// [`IcaValidationError::CredentialStatusUnverified`] if it is only set in
+    /// an unverified one. Entries of other types or purposes are ignored.
+    pub async fn check(
+        &self,
+        credential: &IcaCredential,
+        did_resolvers: &DidResolvers,
+    ) -> Result<(), IcaValidationError> {
+        let entries = match credential.extra_properties.get("credentialStatus") {
+            None => return Ok(()),
+            Some(Value::Array(entries)) => entries.iter().collect(),
+            Some(entry) => vec![entry],
+        };
+
+        let mut unverified = None;
+        for entry in entries {
+            if entry.get("type").and_then(Value::as_str) != Some("BitstringStatusListEntry") {
+                continue;
+            }
+
+            let entry = StatusListEntry::from_json(entry)?;
+            if entry.purpose != "revocation" && entry.purpose != "suspension" {
+                continue;
+            }
+
+            let list = self.status_list(&entry.list_url, did_resolvers).await?;
+            if list.purpose != entry.purpose {
+                return Err(unavailable(format!(
+                    "status list {} has purpose {}, expected {}",
+                    entry.list_url, list.purpose, entry.purpose
+                )));
+            }
+
+            if list.is_set(entry.index)? {
+                let id = format!("{}#{}", entry.list_url, entry.index);
+                if !list.verified {
+                    unverified.get_or_insert_with(|| {
+                        IcaValidationError::CredentialStatusUnverified(format!(
+                            "{} ({id})",
+                            entry.purpose
+                        ))
+                    });
+                    continue;
+                }
+
+                return Err(if entry.purpose == "revocation" {
+                    IcaValidationError::CredentialRevoked(id)
+                } else {
+                    IcaValidationError::CredentialSuspended(id)
+                });
+            }
+        }
+
+        unverified.map_or(Ok(()), Err)
+    }
+
+    async fn status_list(
+        &self,
+        url: &str,
+        did_resolvers: &DidResolvers,
+    ) -> Result<Arc<BitstringStatusList>, IcaValidationError> {
+        if let Some(list) = self.cache.lock().ok().and_then(|c| c.get(url).cloned()) {
+            if list.expires > Utc::now() {
+                return Ok(list);
+            }
+        }
+
+        let credential = self.fetcher.fetch(url).await?;
+
+        let list = match CoseSign1::from_tagged_slice(&credential) {
+            Ok(sign1) => {
+                let json = verify_status_list_credential(&sign1, did_resolvers).await?;
+                BitstringStatusList::from_credential_json(json, true)?
+            }
+            Err(_) => BitstringStatusList::from_credential_json(&credential, false)?,
+        };
+        let list = Arc::new(list);
+
+        if let Ok(mut cache) = self.cache.lock() {
+            if list.expires > Utc::now() {
+                cache.insert(url.to_owned(), list.clone());
+            } else {
+                cache.remove(url);
+            }
+        }
+
+        Ok(list)
+    }
+}
+
+impl Default for StatusLists {
+    fn default() -> Self {
+        Self::new(HttpStatusListFetcher)
+    }
+}
+
+impl fmt::Debug for StatusLists {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cached = self.cache.lock().map(|c| c.len()).unwrap_or_default();
+        f.debug_struct("StatusLists")
+            .field("cached", &cached)
+            .finish_non_exhaustive()
+    }
+}
+
+// The parts of a `BitstringStatusListEntry` needed to check it.
+struct StatusListEntry {
+    purpose: String,
+    index: usize,
+    list_url: String,
+}
+
+impl StatusListEntry {
+    fn from_json(entry: &Value) -> Result<Self, IcaValidationError> {
+        let field = |name: &str| {
+            entry
+                .get(name)
+                .ok_or_else(|| unavailable(format!("credentialStatus entry has no {name}")))
+        };
+
+        // The index is a string in the specification, but some issuers write
+        // a number.
+        let index = match field("statusListIndex")? {
+            Value::String(index) => index.parse().ok(),
+            Value::Number(index) => index.as_u64().and_then(|i| usize::try_from(i).ok()),
+            _ => None,
+        }
+        .ok_or_else(|| unavailable("invalid statusListIndex".to_owned()))?;
+
+        let string = |name: &str| {
+            field(name)?
+                .as_str()
+                .map(str::to_owned)
+                .ok_or_else(|| unavailable(format!("{name} is not a string")))
+        };
+
+        Ok(Self {
+            purpose: string("statusPurpose")?,
+            index,
+            list_url: string("statusListCredential")?,
+        })
+    }
+}
+
+// Checks the issuer's signature on a COSE-secured status list credential,
+// returning the credential's JSON serialization.
+async fn verify_status_list_credential<'a>(
+    sign1: &'a CoseSign1,
+    did_resolvers: &DidResolvers,
+) -> Result<&'a [u8], IcaValidationError> {
+    let payload = sign1
+        .payload
+        .as_deref()
+        .ok_or_else(|| unavailable("status list credential has no payload".to_owned()))?;
+
+    let alg = sign1
+        .protected
+        .header
+        .alg
+        .as_ref()
+        .and_then(cose_algorithm)
+        .ok_or_else(|| unavailable("unsupported status list signature algorithm".to_owned()))?;
+
+    let credential: Value = serde_json::from_slice(payload)
+        .map_err(|e| unavailable(format!("status list credential is not JSON: {e}")))?;
+
+    let issuer = match credential.get("issuer") {
+        Some(Value::Object(issuer)) => issuer.get("id"),
+        issuer => issuer,
+    }
+    .and_then(Value::as_str)
+    .ok_or_else(|| unavailable("status list credential has no issuer".to_owned()))?;
+
+    verify_issuer_signature(did_resolvers, issuer, sign1, alg)
+        .await
+        .map_err(|e| unavailable(format!("invalid status list credential signature: {e}")))?;
+
+    Ok(payload)
+}
+
+/// The decoded bitstring of a status list credential.
+struct BitstringStatusList {
+    purpose: String,
+    bits: Vec<u8>,
+    expires: DateTime<Utc>,
+
+    // Whether the issuer's signature on the status list credential was verified.
+    verified: bool,
+}
+
+// `encodedList` is a multibase base64url value, which may or may not be padded.
+const BASE64_URL_SAFE_INDIFFERENT_PAD: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+impl BitstringStatusList {
+    fn from_credential_json(credential: &[u8], verified: bool) -> Result<Self, IcaValidationError> {
+        let credential: Value = serde_json::from_slice(credential)
+            .map_err(|e| unavailable(format!("status list credential is not JSON: {e}")))?;
+
+        let subject = match credential.get("credentialSubject") {
+            Some(Value::Array(subjects)) => subjects.first(),
+            subject => subject,
+        }
+        .ok_or_else(|| unavailable("status list credential has no subject".to_owned()))?;
+
+        let string = |name: &str| {
+            subject
+                .get(name)
+                .and_then(Value::as_str)
+                .ok_or_else(|| unavailable(format!("status list has no {name}")))
+        };
+
+        if string("type")? != "BitstringStatusList" {
+            return Err(unavailable("not a BitstringStatusList".to_owned()));
+        }
+
+        let encoded_list = string("encodedList")?;
+        let encoded_list = encoded_list.strip_prefix('u').unwrap_or(encoded_list);
+
+        let compressed = BASE64_URL_SAFE_INDIFFERENT_PAD
+            .decode(encoded_list)
+            .map_err(|e| unavailable(format!("invalid encodedList: {e}")))?;
+
+        let mut bits = Vec::new();
+        GzDecoder::new(compressed.as_slice())
+            .take(MAX_STATUS_LIST_BYTES + 1)
+            .read_to_end(&mut bits)
+            .map_err(|e| unavailable(format!("invalid encodedList: {e}")))?;
+
+        if bits.len() as u64 > MAX_STATUS_LIST_BYTES {
+            return Err(unavailable(format!(
+                "encodedList is larger than {MAX_STATUS_LIST_BYTES} bytes"
+            )));
+        }
+
+        Ok(Self {
+            purpose: string("statusPurpose")?.to_owned(),
+            bits,
+            expires: Self::expires(&credential, subject),
+            verified,
+        })
+    }
+
+    // When to stop using the cached list: after its `ttl` or at the
+    // credential's `validUntil` date, whichever is sooner.
+    fn expires(credential: &Value, subject: &Value) -> DateTime<Utc> {
+        let ttl = subject
+            .get("ttl")
+            .and_then(Value::as_u64)
+            .unwrap_or(DEFAULT_TTL_MS);
+
+        let expires = i64::try_from(ttl)
+            .ok()
+            .and_then(TimeDelta::try_milliseconds)
+            .and_then(|ttl| Utc::now().checked_add_signed(ttl))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+
+        let valid_until = credential
+            .get("validUntil")
+            .and_then(Value::as_str)
+            .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+            .map(|date| date.with_timezone(&Utc));
+
+        match valid_until {
+            Some(valid_until) => expires.min(valid_until),
+            None => expires,
+        }
+    }
+
+    // Index 0 is the most significant bit of the first byte.
+    fn is_set(&self, index: usize) -> Result<bool, IcaValidationError> {
+        let byte = self.bits.get(index / 8).ok_or_else(|| {
+            unavailable(format!(
+                "statusListIndex {index} is outside the status list"
+            ))
+        })?;
+
+        Ok(byte & (0x80 >> (index % 8)) != 0)
+    }
+}
+
+fn unavailable(msg: String) -> IcaValidationError {
+    IcaValidationError::CredentialStatusUnavailable(msg)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{
+        io::Write,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use c2pa_macros::c2pa_test_async;
+    use coset::ContentType;
+    use flate2::{write::GzEncoder, Compression};
+    use iref::UriBuf;
+    use nonempty_collections::NEVec;
+    use serde_json::json;
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    use crate::{
+        crypto::cose::{sign_v2_embedded, CosePayload, TimeStampStorage},
+        identity::{
+            claim_aggregation::IdentityClaimsAggregationVc,
+            tests::fixtures::claim_aggregation::{
+                ica_credential_example::ica_example_identities,
+                ica_issuer::{did_jwk_for_alg, ed25519_did_jwk_signer},
+            },
+            SignerPayload,
+        },
+        SigningAlg,
+    };
+
+    const LIST_URL: &str = "https://example.com/status/3";
+
+    /// Serves one status list with bits 3 and 20 000 set.
+    struct ExampleFetcher {
+        purpose: &'static str,
+        fetches: Arc<AtomicUsize>,
+        list_len: usize,
+        ttl: Option<u64>,
+        valid_until: Option<&'static str>,
+
+        // Secure the credential with COSE, signed by the Ed25519 test key.
+        signed: bool,
+
+        // The issuer named in the credential, if not the signer.
+        issuer: Option<String>,
+    }
+
+    impl ExampleFetcher {
+        fn new(purpose: &'static str) -> Self {
+            Self {
+                purpose,
+                fetches: Arc::new(AtomicUsize::new(0)),
+                // The specification requires at least 16 KB of bits.
+                list_len: 16 * 1024,
+                ttl: None,
+                valid_until: None,
+                signed: true,
+                issuer: None,
+            }
+        }
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl StatusListFetcher for ExampleFetcher {
+        async fn fetch(&self, url: &str) -> Result<Vec<u8>, IcaValidationError> {
+            assert_eq!(url, LIST_URL);
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+
+            let mut bits = vec![0u8; self.list_len];
+            bits[0] = 0b0001_0000;
+            bits[2500] = 0b1000_0000;
+
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&bits).unwrap();
+            let encoded_list = format!(
+                "u{}",
+                base64::prelude::BASE64_URL_SAFE_NO_PAD.encode(encoder.finish().unwrap())
+            );
+
+            let mut credential = json!({
+                "@context": ["https://www.w3.org/ns/credentials/v2"],
+                "type": ["VerifiableCredential", "BitstringStatusListCredential"],
+                "issuer": "did:example:issuer",
+                "credentialSubject": {
+                    "id": format!("{LIST_URL}#list"),
+                    "type": "BitstringStatusList",
+                    "statusPurpose": self.purpose,
+                    "encodedList": encoded_list,
+                },
+            });
+
+            if let Some(ttl) = self.ttl {
+                credential["credentialSubject"]["ttl"] = json!(ttl);
+            }
+            if let Some(valid_until) = self.valid_until {
+                credential["validUntil"] = json!(valid_until);
+            }
+
+            if !self.signed {
+                return Ok(credential.to_string().into_bytes());
+            }
+
+            let (signer_did, signer) = ed25519_did_jwk_signer();
+            credential["issuer"] = json!(self.issuer.clone().unwrap_or(signer_did));
+
+            Ok(sign_v2_embedded(
+                signer.as_ref(),
+                credential.to_string().as_bytes(),
+                None,
+                CosePayload::Embedded,
+                Some(ContentType::Text("application/vc".to_owned())),
+                TimeStampStorage::V2_sigTst2_CTT,
+            )
+            .unwrap())
+        }
+    }
+
+    fn credential_with_status(purpose: &str, index: &str) -> IcaCredential {
+        let subject = IdentityClaimsAggregationVc {
+            verified_identities: ica_example_identities(),
+            c2pa_asset: SignerPayload {
+                referenced_assertions: vec![],
+                sig_type: "cawg.identity_claims_aggregation".to_owned(),
+                roles: vec![],
+            },
+            time_stamp: None,
+        };
+
+        let mut credential = IcaCredential::new(
+            None,
+            UriBuf::new(b"did:example:issuer".to_vec()).unwrap(),
+            NEVec::new(subject),
+        );
+
+        credential.extra_properties.insert(
+            "credentialStatus".to_owned(),
+            json!({
+                "id": format!("{LIST_URL}#{index}"),
+                "type": "BitstringStatusListEntry",
+                "statusPurpose": purpose,
+                "statusListIndex": index,
+                "statusListCredential": LIST_URL,
+            }),
+        );
+
+        credential
+    }
+
+    fn status_lists(purpose: &'static str) -> (StatusLists, Arc<AtomicUsize>) {
+        status_lists_from(ExampleFetcher::new(purpose))
+    }
+
+    fn status_lists_from(fetcher: ExampleFetcher) -> (StatusLists, Arc<AtomicUsize>) {
+        let fetches = fetcher.fetches.clone();
+        (StatusLists::new(fetcher), fetches)
+    }
+
+    #[c2pa_test_async]
+    async fn revocation() {
+        let resolvers = DidResolvers::default();
+        let (status_lists, fetches) = status_lists("revocation");
+
+        status_lists
+            .check(&credential_with_status("revocation", "4"), &resolvers)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            status_lists
+                .check(&credential_with_status("revocation", "3"), &resolvers)
+                .await
+                .unwrap_err(),
+            IcaValidationError::CredentialRevoked(format!("{LIST_URL}#3"))
+        );
+
+        assert!(matches!(
+            status_lists
+                .check(&credential_with_status("revocation", "20000"), &resolvers)
+                .await,
+            Err(IcaValidationError::CredentialRevoked(_))
+        ));
+
+        // The list is fetched once and then cached.
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        status_lists.clear_cache();
+        status_lists
+            .check(&credential_with_status("revocation", "4"), &resolvers)
+            .await
+            .unwrap();
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[c2pa_test_async]
+    async fn suspension() {
+        let resolvers = DidResolvers::default();
+        let (status_lists, _fetches) = status_lists("suspension");
+
+        assert!(matches!(
+            status_lists
+                .check(&credential_with_status("suspension", "3"), &resolvers)
+                .await,
+            Err(IcaValidationError::CredentialSuspended(_))
+        ));
+
+        // The entry's purpose must match the list's.
+        assert!(matches!(
+            status_lists
+                .check(&credential_with_status("revocation", "3"), &resolvers)
+                .await,
+            Err(IcaValidationError::CredentialStatusUnavailable(_))
+        ));
+
+        // Messages aren't a revocation or suspension.
+        status_lists
+            .check(&credential_with_status("message", "3"), &resolvers)
+            .await
+            .unwrap();
+    }
+
+    #[c2pa_test_async]
+    async fn invalid_entries() {
+        let resolvers = DidResolvers::default();
+        let (status_lists, fetches) = status_lists("revocation");
+
+        assert!(matches!(
+            status_lists
+                .check(&credential_with_status("revocation", "131072"), &resolvers)
+                .await,
+            Err(IcaValidationError::CredentialStatusUnavailable(_))
+        ));
+
+        assert!(matches!(
+            status_lists
+                .check(&credential_with_status("revocation", "three"), &resolvers)
+                .await,
+            Err(IcaValidationError::CredentialStatusUnavailable(_))
+        ));
+
+        // Credentials without a status aren't checked.
+        let mut credential = credential_with_status("revocation", "3");
+        credential.extra_properties.clear();
+        status_lists.check(&credential, &resolvers).await.unwrap();
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[c2pa_test_async]
+    async fn cache_expiry() {
+        let resolvers = DidResolvers::default();
+        // A `ttl` of zero isn't cached.
+        let (status_lists, fetches) = status_lists_from(ExampleFetcher {
+            ttl: Some(0),
+            ..ExampleFetcher::new("revocation")
+        });
+
+        for _ in 0..2 {
+            status_lists
+                .check(&credential_with_status("revocation", "4"), &resolvers)
+                .await
+                .unwrap();
+        }
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+
+        // Nor is a status list credential past its `validUntil` date.
+        let (status_lists, fetches) = status_lists_from(ExampleFetcher {
+            valid_until: Some("2020-01-01T00:00:00Z"),
+            ..ExampleFetcher::new("revocation")
+        });
+
+        for _ in 0..2 {
+            status_lists
+                .check(&credential_with_status("revocation", "4"), &resolvers)
+                .await
+                .unwrap();
+        }
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[c2pa_test_async]
+    async fn unverified_list_is_informational() {
+        let resolvers = DidResolvers::default();
+        let (status_lists, _fetches) = status_lists_from(ExampleFetcher {
+            signed: false,
+            ..ExampleFetcher::new("revocation")
+        });
+
+        assert_eq!(
+            status_lists
+                .check(&credential_with_status("revocation", "3"), &resolvers)
+                .await
+                .unwrap_err(),
+            IcaValidationError::CredentialStatusUnverified(format!("revocation ({LIST_URL}#3)"))
+        );
+
+        status_lists
+            .check(&credential_with_status("revocation", "4"), &resolvers)
+            .await
+            .unwrap();
+    }
+
+    #[c2pa_test_async]
+    async fn signature_from_another_issuer() {
+        let resolvers = DidResolvers::default();
+        let (status_lists, _fetches) = status_lists_from(ExampleFetcher {
+            issuer: Some(did_jwk_for_alg(SigningAlg::Es256)),
+            ..ExampleFetcher::new("revocation")
+        });
+
+        assert!(matches!(
+            status_lists
+                .check(&credential_with_status("revocation", "4"), &resolvers)
+                .await,
+            Err(IcaValidationError::CredentialStatusUnavailable(_))
+        ));
+    }
+
+    #[c2pa_test_async]
+    async fn oversized_list() {
+        let resolvers = DidResolvers::default();
+        // About 16 KB of gzip that decompresses to more than the limit.
+        let (status_lists, _fetches) = status_lists_from(ExampleFetcher {
+            list_len: MAX_STATUS_LIST_BYTES as usize + 1,
+            ..ExampleFetcher::new("revocation")
+        });
+
+        assert!(matches!(
+            status_lists
+                .check(&credential_with_status("revocation", "3"), &resolvers)
+                .await,
+            Err(IcaValidationError::CredentialStatusUnavailable(_))
+        ));
+    }
+}
@@ -133,7 +133,6 @@ impl DidBuf {
         self.0.as_str()
     }
 
-    #[cfg(test)] // currently only used in test code
     pub fn into_uri(self) -> iref::UriBuf {
         unsafe { iref::UriBuf::new_unchecked(self.0.into_bytes()) }
     }
@@ -0,0 +1,128 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Resolution of [`did:jwk`] DIDs.
+//!
+//! [`did:jwk`]: https://github.com/quartzjer/did-jwk/blob/main/spec.md
+
+use std::collections::BTreeMap;
+
+use base64::{
+    alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+
+use super::{
+    did::{Did, DidBuf},
+    did_doc::{DidDocument, DidVerificationMethod, ValueOrReference, VerificationRelationships},
+};
+
+// The spec calls for unpadded base64url, but some issuers pad it.
+const BASE64_URL_SAFE_ANY_PAD: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+#[derive(Debug, thiserror::Error)]
+pub enum DidJwkError {
+    #[error("the method-specific ID is not base64url: {0}")]
+    NotBase64(String),
+
+    #[error("the method-specific ID is not a JWK: {0}")]
+    NotJwk(String),
+
+    #[error("invalid JWK DID: {0}")]
+    MalformedDid(String),
+}
+
+/// Resolves a `did:jwk` DID to its DID document.
+///
+/// The document has a single `JsonWebKey2020` verification method with the
+/// fragment `0`, which is also the document's only `assertionMethod`.
+pub(crate) fn resolve(did: &Did<'_>) -> Result<DidDocument, DidJwkError> {
+    let jwk = BASE64_URL_SAFE_ANY_PAD
+        .decode(did.method_specific_id())
+        .map_err(|e| DidJwkError::NotBase64(e.to_string()))?;
+
+    let jwk: serde_json::Value =
+        serde_json::from_slice(&jwk).map_err(|e| DidJwkError::NotJwk(e.to_string()))?;
+
+    if !jwk.is_object() {
+        return Err(DidJwkError::NotJwk("expected a JSON object".to_owned()));
+    }
+
+    let id = DidBuf::new(did.to_string()).map_err(|e| DidJwkError::MalformedDid(e.to_string()))?;
+    let vm_id =
+        DidBuf::new(format!("{did}#0")).map_err(|e| DidJwkError::MalformedDid(e.to_string()))?;
+
+    let verification_method = DidVerificationMethod {
+        id: vm_id.clone(),
+        type_: "JsonWebKey2020".to_owned(),
+        controller: id.clone(),
+        properties: BTreeMap::from([("publicKeyJwk".to_owned(), jwk)]),
+    };
+
+    Ok(DidDocument {
+        id,
+        verification_relationships: VerificationRelationships {
+            assertion_method: vec![ValueOrReference::Reference(vm_id)],
+            ..Default::default()
+        },
+        verification_method: vec![verification_method],
+        property_set: BTreeMap::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    // Example from the did:jwk specification.
+    const DID: &str = "did:jwk:eyJjcnYiOiJQLTI1NiIsImt0eSI6IkVDIiwieCI6ImFjYklRaXVNczNpOF91c3pFakoydHBUdFJNNEVVM3l6OTFQSDZDZEgyVjAiLCJ5IjoiX0tjeUxqOXZXTXB0bm1LdG00NkdxRHo4d2Y3NEk1TEtncmwyR3pIM25TRSJ9";
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn resolve_did_jwk() {
+        let doc = resolve(&Did::new(DID).unwrap()).unwrap();
+        assert_eq!(doc.id, DID);
+
+        let vm = &doc.verification_method[0];
+        assert_eq!(vm.id, format!("{DID}#0").as_str());
+        assert_eq!(vm.properties["publicKeyJwk"]["crv"], "P-256");
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn rejects_non_jwk() {
+        assert!(matches!(
+            resolve(&Did::new("did:jwk:%%%%").unwrap()),
+            Err(DidJwkError::NotBase64(_))
+        ));
+        assert!(matches!(
+            resolve(&Did::new("did:jwk:WzFd").unwrap()),
+            Err(DidJwkError::NotJwk(_))
+        ));
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Resolution of [`did:key`] DIDs.
+//!
+//! [`did:key`]: https://w3c-ccg.github.io/did-key-spec/
+
+use std::collections::BTreeMap;
+
+use super::{
+    did::{Did, DidBuf},
+    did_doc::{DidDocument, DidVerificationMethod, ValueOrReference, VerificationRelationships},
+    jwk::{self, Algorithm},
+};
+
+/// Multicodec prefix of an Ed25519 public key.
+const ED25519_PUB: [u8; 2] = [0xed, 0x01];
+
+/// Multicodec prefix of a compressed P-256 public key.
+const P256_PUB: [u8; 2] = [0x80, 0x24];
+
+#[derive(Debug, thiserror::Error)]
+pub enum DidKeyError {
+    #[error("invalid multibase key: {0}")]
+    InvalidMultibase(String),
+
+    #[error("unsupported multicodec key type: {0}")]
+    UnsupportedKeyType(String),
+
+    #[error("invalid key DID: {0}")]
+    InvalidKeyDid(String),
+}
+
+/// A public key decoded from a multibase `Multikey` value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Multikey {
+    /// A 32-byte Ed25519 public key.
+    Ed25519(Vec<u8>),
+
+    /// A 33-byte compressed P-256 point.
+    P256(Vec<u8>),
+}
+
+impl Multikey {
+    /// Decodes a base58btc multibase value (starting with `z`) holding a
+    /// multicodec-prefixed public key.
+    pub(crate) fn decode(multibase: &str) -> Result<Self, DidKeyError> {
+        let Some(encoded) = multibase.strip_prefix('z') else {
+            return Err(DidKeyError::InvalidMultibase(
+                "only base58btc (z) multibase values are supported".to_owned(),
+            ));
+        };
+
+        let bytes = bs58::decode(encoded)
+            .into_vec()
+            .map_err(|e| DidKeyError::InvalidMultibase(e.to_string()))?;
+
+        match bytes.split_at_checked(2) {
+            Some((codec, key)) if codec == ED25519_PUB && key.len() == 32 => {
+                Ok(Self::Ed25519(key.to_vec()))
+            }
+            Some((codec, key)) if codec == P256_PUB && key.len() == 33 => {
+                Ok(Self::P256(key.to_vec()))
+            }
+            Some((codec, _)) => Err(DidKeyError::UnsupportedKeyType(format!(
+                "0x{}",
+                hex::encode(codec)
+            ))),
+            None => Err(DidKeyError::InvalidMultibase("value too short".to_owned())),
+        }
+    }
+
+    /// Returns the signature algorithm for this key.
+    pub(crate) fn algorithm(&self) -> Algorithm {
+        match self {
+            Self::Ed25519(_) => Algorithm::EdDsa,
+            Self::P256(_) => Algorithm::Es256,
+        }
+    }
+
+    /// Returns the public key as a DER-encoded `SubjectPublicKeyInfo`.
+    pub(crate) fn to_public_key_der(&self) -> Vec<u8> {
+        match self {
+            Self::Ed25519(key) => jwk::ed25519_public_key_der(key),
+            Self::P256(point) => jwk::ec_public_key_der(jwk::OID_PRIME256V1, point),
+        }
+    }
+}
+
+/// Resolves a `did:key` DID to its DID document.
+///
+/// The document has a single `Multikey` verification method, identified by
+/// the method-specific ID as its fragment, which is also the document's only
+/// `assertionMethod`.
+pub(crate) fn resolve(did: &Did<'_>) -> Result<DidDocument, DidKeyError> {
+    let multibase = did.method_specific_id();
+    Multikey::decode(multibase)?;
+
+    let id = DidBuf::new(did.to_string()).map_err(|e| DidKeyError::InvalidKeyDid(e.to_string()))?;
+    let vm_id = DidBuf::new(format!("{did}#{multibase}"))
+        .map_err(|e| DidKeyError::InvalidKeyDid(e.to_string()))?;
+
+    let verification_method = DidVerificationMethod {
+        id: vm_id.clone(),
+        type_: "Multikey".to_owned(),
+        controller: id.clone(),
+        properties: BTreeMap::from([(
+            "publicKeyMultibase".to_owned(),
+            serde_json::Value::String(multibase.to_owned()),
+        )]),
+    };
+
+    Ok(DidDocument {
+        id,
+        verification_relationships: VerificationRelationships {
+            assertion_method: vec![ValueOrReference::Reference(vm_id)],
+            ..Default::default()
+        },
+        verification_method: vec![verification_method],
+        property_set: BTreeMap::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    // Test vectors from the did:key specification.
+    const ED25519_DID: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+    const P256_DID: &str = "did:key:zDnaerDaTF5BXEavCrfRZEk316dpbLsfPDZ3WJ5hRTPFU2169";
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn resolve_ed25519() {
+        let doc = resolve(&Did::new(ED25519_DID).unwrap()).unwrap();
+        assert_eq!(doc.id, ED25519_DID);
+
+        let vm = &doc.verification_method[0];
+        assert_eq!(
+            vm.id,
+            format!("{ED25519_DID}#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK").as_str()
+        );
+        assert_eq!(
+            doc.verification_relationships.assertion_method,
+            vec![ValueOrReference::Reference(vm.id.clone())]
+        );
+
+        let multibase = vm.properties["publicKeyMultibase"].as_str().unwrap();
+        let Multikey::Ed25519(key) = Multikey::decode(multibase).unwrap() else {
+            unreachable!("expected an Ed25519 key");
+        };
+        assert_eq!(
+            base64::Engine::encode(&base64::prelude::BASE64_URL_SAFE_NO_PAD, key),
+            "Lm_M42cB3HkUiODQsXRcweM6TByfzEHGO9ND274JcOY"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn resolve_p256() {
+        let doc = resolve(&Did::new(P256_DID).unwrap()).unwrap();
+        let multibase = doc.verification_method[0].properties["publicKeyMultibase"]
+            .as_str()
+            .unwrap();
+        assert!(matches!(Multikey::decode(multibase), Ok(Multikey::P256(key)) if key.len() == 33));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn rejects_bad_keys() {
+        assert!(matches!(
+            resolve(&Did::new("did:key:mAAAA").unwrap()),
+            Err(DidKeyError::InvalidMultibase(_))
+        ));
+
+        // secp256k1 (0xe7) keys are not supported
+        assert!(matches!(
+            resolve(
+                &Did::new("did:key:zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme").unwrap()
+            ),
+            Err(DidKeyError::UnsupportedKeyType(_))
+        ));
+    }
+}
@@ -71,14 +71,15 @@ pub(crate) async fn resolve(did: &Did<'_>) -> Result<DidDocument, DidWebError> {
     let url = to_url(method_specific_id)?;
     // TODO: https://w3c-ccg.github.io/did-method-web/#in-transit-security
 
-    let did_doc = get_did_doc(&url).await?;
+    let did_doc = get(&url, "application/did+json").await?;
 
     let json = String::from_utf8(did_doc).map_err(|_| DidWebError::InvalidData(url.clone()))?;
 
     DidDocument::from_json(&json).map_err(|_| DidWebError::InvalidData(url))
 }
 
-async fn get_did_doc(url: &str) -> Result<Vec<u8>, DidWebError> {
+/// Fetches `url` over HTTP(S), asking for the `accept` media type.
+pub(crate) async fn get(url: &str, accept: &'static str) -> Result<Vec<u8>, DidWebError> {
     #[cfg(not(target_os = "wasi"))]
     {
         use reqwest::header;
@@ -97,7 +98,7 @@ async fn get_did_doc(url: &str) -> Result<Vec<u8>, DidWebError> {
 
         let resp = client
             .get(url)
-            .header(header::ACCEPT, "application/did+json")
+            .header(header::ACCEPT, accept)
             .send()
             .await
             .map_err(|e: reqwest::Error| DidWebError::Request(url.to_owned(), e))?;
@@ -120,10 +121,7 @@ async fn get_did_doc(url: &str) -> Result<Vec<u8>, DidWebError> {
 
         let request = http::Request::get(url)
             .header("User-Agent", http::HeaderValue::from_static(USER_AGENT))
-            .header(
-                "Accept",
-                http::HeaderValue::from_static("application/did+json"),
-            )
+            .header("Accept", http::HeaderValue::from_static(accept))
             .body(io::empty())
             .map_err(|e| DidWebError::Request(url.to_owned(), e.to_string()))?;
         let resp = http::Client::new()
@@ -23,10 +23,16 @@ use std::{
 };
 
 use base64::{DecodeError as Base64Error, Engine};
+use bcder::{
+    encode::{self, PrimitiveContent, Values},
+    BitString, ConstOid, Mode, Oid, Unsigned,
+};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use zeroize::Zeroize;
 
+use crate::crypto::raw_signature::SigningAlg;
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Hash, Eq)]
 pub(crate) struct Jwk {
     #[serde(rename = "use")]
@@ -116,9 +122,10 @@ impl From<Params> for Jwk {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Hash, Eq, Zeroize)]
 #[serde(tag = "kty")]
 pub enum Params {
-    // TEMPORARY: Only supporting Ed25519 for now
-    // EC(ECParams),
-    // RSA(RSAParams),
+    #[serde(rename = "EC")]
+    Ec(EcParams),
+    #[serde(rename = "RSA")]
+    Rsa(RsaParams),
     // #[serde(rename = "oct")]
     // Symmetric(SymmetricParams),
     #[serde(rename = "OKP")]
@@ -130,8 +137,8 @@ impl Params {
     #[cfg(test)] // So far, only used in test code
     pub fn to_public(&self) -> Self {
         match self {
-            // Self::EC(params) => Self::EC(params.to_public()),
-            // Self::RSA(params) => Self::RSA(params.to_public()),
+            Self::Ec(params) => Self::Ec(params.to_public()),
+            Self::Rsa(params) => Self::Rsa(params.clone()),
             // Self::Symmetric(params) => Self::Symmetric(params.to_public()),
             Self::Okp(params) => Self::Okp(params.to_public()),
         }
@@ -147,6 +154,65 @@ impl Drop for OctetParams {
     }
 }
 
+impl Drop for EcParams {
+    fn drop(&mut self) {
+        // Zeroize private key
+        if let Some(ref mut d) = self.ecc_private_key {
+            d.zeroize();
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Hash, Eq, Zeroize)]
+pub struct EcParams {
+    // Parameters for Elliptic Curve Public Keys
+    #[serde(rename = "crv")]
+    pub curve: String,
+
+    #[serde(rename = "x")]
+    pub x_coordinate: Base64urlUInt,
+
+    #[serde(rename = "y")]
+    pub y_coordinate: Base64urlUInt,
+
+    // Parameters for Elliptic Curve Private Keys
+    #[serde(rename = "d")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ecc_private_key: Option<Base64urlUInt>,
+}
+
+impl EcParams {
+    pub fn to_public(&self) -> Self {
+        Self {
+            curve: self.curve.clone(),
+            x_coordinate: self.x_coordinate.clone(),
+            y_coordinate: self.y_coordinate.clone(),
+            ecc_private_key: None,
+        }
+    }
+
+    /// Returns the curve's OID and the length of each coordinate.
+    fn curve_info(&self) -> Result<(ConstOid, usize), JwkError> {
+        match self.curve.as_str() {
+            "P-256" => Ok((OID_PRIME256V1, 32)),
+            "P-384" => Ok((OID_SECP384R1, 48)),
+            "P-521" => Ok((OID_SECP521R1, 66)),
+            curve => Err(JwkError::CurveNotImplemented(curve.to_string())),
+        }
+    }
+}
+
+// Only the public key parameters are read; the private key parameters of an
+// RSA JWK are ignored.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Hash, Eq, Zeroize)]
+pub struct RsaParams {
+    #[serde(rename = "n")]
+    pub modulus: Base64urlUInt,
+
+    #[serde(rename = "e")]
+    pub exponent: Base64urlUInt,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Hash, Eq, Zeroize)]
 pub struct OctetParams {
     // Parameters for Octet Key Pair Public Keys
@@ -191,18 +257,67 @@ impl Jwk {
         })))
     }
 
-    #[cfg(test)]
+    /// Returns the signature algorithm for this key: the `alg` parameter if
+    /// present, otherwise the algorithm implied by the key type and curve.
     pub fn get_algorithm(&self) -> Option<Algorithm> {
         if let Some(algorithm) = self.algorithm {
             return Some(algorithm);
         }
         match &self.params {
-            Params::Okp(okp_params) if okp_params.curve == "Ed25519" => {
-                return Some(Algorithm::EdDsa);
+            Params::Okp(okp_params) if okp_params.curve == "Ed25519" => Some(Algorithm::EdDsa),
+            Params::Ec(ec_params) => match ec_params.curve.as_str() {
+                "P-256" => Some(Algorithm::Es256),
+                "P-384" => Some(Algorithm::Es384),
+                "P-521" => Some(Algorithm::Es512),
+                _ => None,
+            },
+            Params::Rsa(_) => Some(Algorithm::Ps256),
+            _ => None,
+        }
+    }
+
+    /// Returns the public key as a DER-encoded `SubjectPublicKeyInfo`, the
+    /// form expected by the [`RawSignatureValidator`] implementations.
+    ///
+    /// [`RawSignatureValidator`]: crate::crypto::raw_signature::RawSignatureValidator
+    pub fn to_public_key_der(&self) -> Result<Vec<u8>, JwkError> {
+        match &self.params {
+            Params::Okp(okp) => {
+                if okp.curve != "Ed25519" {
+                    return Err(JwkError::CurveNotImplemented(okp.curve.to_string()));
+                }
+                Ok(ed25519_public_key_der(&okp.public_key.0))
+            }
+
+            Params::Ec(ec) => {
+                let (curve, len) = ec.curve_info()?;
+                let (x, y) = (&ec.x_coordinate.0, &ec.y_coordinate.0);
+                if x.len() != len || y.len() != len {
+                    return Err(JwkError::InvalidCoordinates);
+                }
+
+                let mut point = Vec::with_capacity(1 + 2 * len);
+                point.push(0x04);
+                point.extend_from_slice(x);
+                point.extend_from_slice(y);
+                Ok(ec_public_key_der(curve, &point))
+            }
+
+            Params::Rsa(rsa) => {
+                let modulus =
+                    Unsigned::from_slice(&rsa.modulus.0).map_err(|_| JwkError::MissingKeyValue)?;
+                let exponent =
+                    Unsigned::from_slice(&rsa.exponent.0).map_err(|_| JwkError::MissingKeyValue)?;
+
+                let rsa_public_key = encode::sequence(((&modulus).encode(), (&exponent).encode()))
+                    .to_captured(Mode::Der);
+
+                Ok(public_key_der(
+                    (OID_RSA_ENCRYPTION.encode(), ().encode()),
+                    rsa_public_key.as_slice(),
+                ))
             }
-            _ => {}
-        };
-        None
+        }
     }
 
     /// Strip private key material
@@ -279,17 +394,71 @@ impl From<Base64urlUInt> for Base64urlUIntString {
     }
 }
 
+const OID_ED25519: ConstOid = Oid(&[43, 101, 112]);
+const OID_EC_PUBLIC_KEY: ConstOid = Oid(&[42, 134, 72, 206, 61, 2, 1]);
+pub(crate) const OID_PRIME256V1: ConstOid = Oid(&[42, 134, 72, 206, 61, 3, 1, 7]);
+const OID_SECP384R1: ConstOid = Oid(&[43, 129, 4, 0, 34]);
+const OID_SECP521R1: ConstOid = Oid(&[43, 129, 4, 0, 35]);
+const OID_RSA_ENCRYPTION: ConstOid = Oid(&[42, 134, 72, 134, 247, 13, 1, 1, 1]);
+
+/// Encodes an Ed25519 public key as a DER `SubjectPublicKeyInfo`.
+pub(crate) fn ed25519_public_key_der(public_key: &[u8]) -> Vec<u8> {
+    public_key_der(OID_ED25519.encode(), public_key)
+}
+
+/// Encodes an elliptic curve point, compressed or not, as a DER
+/// `SubjectPublicKeyInfo`.
+pub(crate) fn ec_public_key_der(curve: ConstOid, point: &[u8]) -> Vec<u8> {
+    public_key_der((OID_EC_PUBLIC_KEY.encode(), curve.encode()), point)
+}
+
+fn public_key_der<V: Values>(algorithm: V, public_key: &[u8]) -> Vec<u8> {
+    encode::sequence((
+        encode::sequence(algorithm),
+        BitString::encode_slice(public_key, 0),
+    ))
+    .to_captured(Mode::Der)
+    .into_bytes()
+    .to_vec()
+}
+
 /// Signature algorithm.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Hash, Eq)]
 pub enum Algorithm {
-    // TEMPORARY: Only supporting Ed25519 for now.
     #[serde(rename = "EdDSA")]
     EdDsa,
 
+    #[serde(rename = "ES256")]
+    Es256,
+
+    #[serde(rename = "ES384")]
+    Es384,
+
+    #[serde(rename = "ES512")]
+    Es512,
+
+    #[serde(rename = "PS256")]
+    Ps256,
+
     #[serde(alias = "None")]
     None,
 }
 
+impl Algorithm {
+    /// Returns the C2PA signing algorithm that validates signatures of this
+    /// type.
+    pub fn signing_alg(self) -> Option<SigningAlg> {
+        match self {
+            Self::EdDsa => Some(SigningAlg::Ed25519),
+            Self::Es256 => Some(SigningAlg::Es256),
+            Self::Es384 => Some(SigningAlg::Es384),
+            Self::Es512 => Some(SigningAlg::Es512),
+            Self::Ps256 => Some(SigningAlg::Ps256),
+            Self::None => None,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum JwkError {
@@ -390,4 +559,42 @@ mod tests {
     fn generate_ed25519() {
         let _key = Jwk::generate_ed25519().unwrap();
     }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn ec_and_rsa_from_str() {
+        // From RFC 7517, Appendix A.1.
+        let ec: Jwk = serde_json::from_str(r#"{"kty":"EC","crv":"P-256","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM","use":"enc","kid":"1"}"#).unwrap();
+        assert_eq!(ec.get_algorithm(), Some(Algorithm::Es256));
+        assert_eq!(ec.to_public_key_der().unwrap().len(), 91);
+
+        let rsa: Jwk = serde_json::from_str(r#"{"kty":"RSA","n":"0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw","e":"AQAB","kid":"2011-04-29"}"#).unwrap();
+        assert_eq!(rsa.get_algorithm(), Some(Algorithm::Ps256));
+        assert_eq!(Algorithm::Ps256.signing_alg(), Some(SigningAlg::Ps256));
+        assert!(rsa.to_public_key_der().is_ok());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn rejects_bad_ec_keys() {
+        let unknown_curve: Jwk = serde_json::from_str(r#"{"kty":"EC","crv":"secp256k1","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"}"#).unwrap();
+        assert_eq!(unknown_curve.get_algorithm(), None);
+        assert!(matches!(
+            unknown_curve.to_public_key_der(),
+            Err(JwkError::CurveNotImplemented(_))
+        ));
+
+        let short_point: Jwk =
+            serde_json::from_str(r#"{"kty":"EC","crv":"P-384","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"}"#).unwrap();
+        assert!(matches!(
+            short_point.to_public_key_der(),
+            Err(JwkError::InvalidCoordinates)
+        ));
+    }
 }
@@ -18,6 +18,8 @@
 pub(crate) mod credential;
 pub(crate) mod did;
 pub(crate) mod did_doc;
+pub(crate) mod did_jwk;
+pub(crate) mod did_key;
 pub(crate) mod did_web;
 pub(crate) mod jwk;
 pub(super) mod serialization;
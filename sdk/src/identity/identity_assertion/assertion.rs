@@ -22,7 +22,7 @@ use serde_bytes::ByteBuf;
 use crate::{
     dynamic_assertion::PartialClaim,
     identity::{
-        claim_aggregation::IcaSignatureVerifier,
+        claim_aggregation::{IcaCredential, IcaSignatureVerifier, IcaValidationError},
         identity_assertion::{
             report::{
                 IdentityAssertionReport, IdentityAssertionsForManifest,
@@ -317,6 +317,28 @@ impl IdentityAssertion {
         partial_claim: &PartialClaim,
         status_tracker: &mut StatusTracker,
     ) -> Result<serde_json::Value, ValidationError<String>> {
+        self.validate_partial_claim_with_ica_verifier(
+            partial_claim,
+            status_tracker,
+            &IcaSignatureVerifier {},
+        )
+        .await
+    }
+
+    /// Like [`validate_partial_claim`], but checks identity claims
+    /// aggregation credentials with `ica_verifier` rather than a default
+    /// [`IcaSignatureVerifier`].
+    ///
+    /// [`validate_partial_claim`]: Self::validate_partial_claim
+    pub async fn validate_partial_claim_with_ica_verifier<SV>(
+        &self,
+        partial_claim: &PartialClaim,
+        status_tracker: &mut StatusTracker,
+        ica_verifier: &SV,
+    ) -> Result<serde_json::Value, ValidationError<String>>
+    where
+        SV: SignatureVerifier<Output = IcaCredential, Error = IcaValidationError>,
+    {
         self.check_padding(status_tracker)?;
 
         self.signer_payload
@@ -345,9 +367,7 @@ impl IdentityAssertion {
             serde_json::to_value(result)
                 .map_err(|e| ValidationError::UnknownSignatureType(e.to_string()))
         } else if sig_type == "cawg.identity_claims_aggregation" {
-            let verifier = IcaSignatureVerifier {};
-
-            let result = verifier
+            let result = ica_verifier
                 .check_signature(&self.signer_payload, &self.signature, status_tracker)
                 .await
                 .map(|v| v.to_summary())
@@ -67,8 +67,14 @@ impl SignerPayload {
                 url == ref_assertion.url()
             }) {
                 if claim_assertion.hash() != ref_assertion.hash() {
-                    return Err(ValidationError::AssertionMismatch(
-                        ref_assertion.url().to_owned(),
+                    return Err(log_current_item!(
+                        "referenced assertion hash does not match claim",
+                        "SignerPayload::check_against_partial_claim"
+                    )
+                    .validation_status("cawg.identity.assertion.mismatch")
+                    .failure_as_err(
+                        status_tracker,
+                        ValidationError::AssertionMismatch(ref_assertion.url().to_owned()),
                     ));
                 }
             } else {
@@ -147,8 +153,14 @@ impl SignerPayload {
                 url == ref_assertion.url()
             }) {
                 if claim_assertion.hash() != ref_assertion.hash() {
-                    return Err(ValidationError::AssertionMismatch(
-                        ref_assertion.url().to_owned(),
+                    return Err(log_current_item!(
+                        "referenced assertion hash does not match claim",
+                        "SignerPayload::check_against_manifest"
+                    )
+                    .validation_status("cawg.identity.assertion.mismatch")
+                    .failure_as_err(
+                        status_tracker,
+                        ValidationError::AssertionMismatch(ref_assertion.url().to_owned()),
                     ));
                 }
 
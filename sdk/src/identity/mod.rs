@@ -21,6 +21,7 @@
 
 pub mod builder;
 pub mod claim_aggregation;
+pub mod policy;
 pub mod validator;
 
 mod identity_assertion;
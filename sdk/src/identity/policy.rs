@@ -0,0 +1,548 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Declarative policies for accepting CAWG identity assertions.
+//!
+//! [`IdentityAssertion::validate`] reports whether an identity assertion is
+//! well-formed and its signature is valid. An [`IdentityPolicy`] goes further
+//! and describes which identity assertions a particular application is
+//! willing to accept, for example “a `cawg.creator` whose identity was
+//! verified by one of these identity providers and who signed over
+//! `c2pa.actions`.”
+//!
+//! [`IdentityAssertion::validate`]: crate::identity::IdentityAssertion::validate
+
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    assertions::labels,
+    claim::Claim,
+    dynamic_assertion::{AsyncPostValidator, PartialClaim},
+    identity::{
+        claim_aggregation::{ConfiguredIcaSignatureVerifier, VerifiedIdentity},
+        validator::{CawgValidator, ConfiguredCawgValidator},
+        IdentityAssertion,
+    },
+    jumbf, log_current_item,
+    status_tracker::StatusTracker,
+    ManifestAssertion,
+};
+
+/// Status code for an identity assertion that satisfies an [`IdentityPolicy`].
+pub const POLICY_MATCH: &str = "cawg.identity.policy.match";
+
+/// Status code for an identity assertion that doesn't satisfy an
+/// [`IdentityPolicy`]. The explanation lists the reasons.
+pub const POLICY_MISMATCH: &str = "cawg.identity.policy.mismatch";
+
+/// Status code for a manifest in which no identity assertion satisfies an
+/// [`IdentityPolicy`].
+pub const POLICY_UNSATISFIED: &str = "cawg.identity.policy.unsatisfied";
+
+/// Describes the identity assertions that are acceptable to an application.
+///
+/// A manifest satisfies the policy if at least one of its identity assertions
+/// is valid and meets every requirement. Requirements that are left empty
+/// aren't checked.
+///
+/// The policy can be loaded from JSON or any other format supported by
+/// `serde`:
+///
+/// ```
+/// use c2pa::identity::policy::IdentityPolicy;
+///
+/// let policy: IdentityPolicy = serde_json::from_str(
+///     r#"{
+///         "required_roles": ["cawg.creator"],
+///         "trusted_identity_providers": ["https://instagram.com"],
+///         "required_referenced_assertions": ["c2pa.actions"]
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(policy.required_roles, vec!["cawg.creator"]);
+/// ```
+///
+/// Use the [`IdentityPolicyValidator`] returned by [`validator`] to evaluate
+/// the policy when validating a manifest with [`Reader::post_validate_async`].
+///
+/// [`validator`]: Self::validator
+/// [`Reader::post_validate_async`]: crate::Reader::post_validate_async
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IdentityPolicy {
+    /// Roles that the named actor must have been given, such as
+    /// `cawg.creator`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_roles: Vec<String>,
+
+    /// IDs (`verifiedIdentities[?].provider.id`) of identity providers, one
+    /// of which must have verified the named actor's identity.
+    ///
+    /// Only identity claims aggregation credentials contain verified
+    /// identities, so X.509 identity assertions never satisfy this
+    /// requirement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trusted_identity_providers: Vec<String>,
+
+    /// Labels of assertions that the identity assertion must reference.
+    ///
+    /// A label without a version, such as `c2pa.actions`, also matches
+    /// versioned labels such as `c2pa.actions.v2`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_referenced_assertions: Vec<String>,
+}
+
+impl IdentityPolicy {
+    /// Returns a post-validator that evaluates this policy.
+    ///
+    /// The validator remembers which manifests it has evaluated, so use a new
+    /// one for each [`Reader`](crate::Reader).
+    pub fn validator(&self) -> IdentityPolicyValidator<'_> {
+        IdentityPolicyValidator {
+            policy: self,
+            cawg_validator: ConfiguredCawgValidator::default(),
+            progress: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the reasons why an identity assertion doesn't satisfy this
+    /// policy, or an empty list if it does.
+    ///
+    /// `credential` is the credential summary produced when the identity
+    /// assertion was validated.
+    fn mismatches(&self, ia: &IdentityAssertion, credential: &Value) -> Vec<String> {
+        let mut reasons = vec![];
+        let signer_payload = ia.signer_payload();
+
+        for role in &self.required_roles {
+            if !signer_payload.roles.contains(role) {
+                reasons.push(format!("role {role:?} is missing"));
+            }
+        }
+
+        if !self.trusted_identity_providers.is_empty() {
+            let verified_identities: Vec<VerifiedIdentity> = credential
+                .get("verifiedIdentities")
+                .and_then(|vi| serde_json::from_value(vi.clone()).ok())
+                .unwrap_or_default();
+
+            if !verified_identities.iter().any(|vi| {
+                self.trusted_identity_providers
+                    .iter()
+                    .any(|id| id == vi.provider.id.as_str())
+            }) {
+                reasons.push("no identity was verified by a trusted identity provider".to_owned());
+            }
+        }
+
+        for required in &self.required_referenced_assertions {
+            if !signer_payload
+                .referenced_assertions
+                .iter()
+                .any(|ra| label_matches(&ra.url(), required))
+            {
+                reasons.push(format!("assertion {required:?} is not referenced"));
+            }
+        }
+
+        reasons
+    }
+}
+
+// Returns true if the assertion at `uri` has the label `required`, ignoring
+// its instance and, unless `required` has one, its version.
+fn label_matches(uri: &str, required: &str) -> bool {
+    let (label, _instance) = Claim::assertion_label_from_link(uri);
+    if label == required {
+        return true;
+    }
+
+    labels::version(&label).is_some()
+        && labels::version(required).is_none()
+        && label
+            .rsplit_once('.')
+            .is_some_and(|(base, _version)| base == required)
+}
+
+fn is_identity_assertion(label: &str) -> bool {
+    label == "cawg.identity" || label.starts_with("cawg.identity__")
+}
+
+/// Evaluates an [`IdentityPolicy`] against the active manifest while it is
+/// post-validated.
+///
+/// Each identity assertion is validated as [`CawgValidator`] would, and then
+/// logged as a [`POLICY_MATCH`] success or a [`POLICY_MISMATCH`]
+/// informational status explaining what is missing. If none of the active
+/// manifest's identity assertions satisfy the policy, a [`POLICY_UNSATISFIED`]
+/// failure is logged and the manifest is no longer considered valid.
+///
+/// ```no_run
+/// # async fn example(mut reader: c2pa::Reader) -> c2pa::Result<()> {
+/// use c2pa::identity::policy::IdentityPolicy;
+///
+/// let policy = IdentityPolicy {
+///     required_roles: vec!["cawg.creator".to_owned()],
+///     ..Default::default()
+/// };
+///
+/// reader.post_validate_async(&policy.validator()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct IdentityPolicyValidator<'a> {
+    policy: &'a IdentityPolicy,
+    cawg_validator: ConfiguredCawgValidator,
+
+    // Progress through the identity assertions of each manifest, keyed by
+    // manifest label, since the policy applies to the manifest as a whole but
+    // assertions are validated one at a time.
+    progress: Mutex<HashMap<String, PolicyProgress>>,
+}
+
+#[derive(Default)]
+struct PolicyProgress {
+    checked: usize,
+    satisfied: bool,
+    reported: bool,
+}
+
+impl IdentityPolicyValidator<'_> {
+    /// Returns the policy evaluated by this validator.
+    pub fn policy(&self) -> &IdentityPolicy {
+        self.policy
+    }
+
+    /// Check identity claims aggregation credentials with `ica_verifier`
+    /// rather than a default [`IcaSignatureVerifier`].
+    pub fn with_ica_verifier(mut self, ica_verifier: ConfiguredIcaSignatureVerifier) -> Self {
+        self.cawg_validator = CawgValidator::with_ica_verifier(ica_verifier);
+        self
+    }
+
+    // Records the result for one identity assertion (if any), returning the
+    // overall result once every identity assertion in the manifest has been
+    // checked. The overall result is only returned once per manifest.
+    fn record(
+        &self,
+        manifest_label: &str,
+        identity_assertions: usize,
+        satisfied: Option<bool>,
+    ) -> Option<bool> {
+        let mut progress = self.progress.lock().ok()?;
+        let entry = progress.entry(manifest_label.to_owned()).or_default();
+
+        if let Some(satisfied) = satisfied {
+            entry.checked += 1;
+            entry.satisfied |= satisfied;
+        }
+
+        if entry.reported || entry.checked < identity_assertions {
+            return None;
+        }
+
+        entry.reported = true;
+        Some(entry.satisfied)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl AsyncPostValidator for IdentityPolicyValidator<'_> {
+    async fn validate(
+        &self,
+        label: &str,
+        assertion: &ManifestAssertion,
+        uri: &str,
+        partial_claim: &PartialClaim,
+        tracker: &mut StatusTracker,
+    ) -> crate::Result<Option<Value>> {
+        let credential = self
+            .cawg_validator
+            .validate(label, assertion, uri, partial_claim, tracker)
+            .await?;
+
+        // Policy only applies to the manifest being read, not its ingredients.
+        if tracker.ingredient_uri().is_some() {
+            return Ok(credential);
+        }
+
+        let identity_assertions = partial_claim
+            .assertions()
+            .filter(|a| {
+                jumbf::labels::assertion_label_from_uri(&a.url())
+                    .is_some_and(|label| is_identity_assertion(&label))
+            })
+            .count();
+
+        let manifest_label = jumbf::labels::manifest_label_from_uri(uri).unwrap_or_default();
+
+        tracker.push_current_uri(uri);
+
+        let satisfied = if is_identity_assertion(label) {
+            let reasons = match &credential {
+                Some(credential) => {
+                    let ia: IdentityAssertion = assertion.to_assertion()?;
+                    self.policy.mismatches(&ia, credential)
+                }
+                None => vec!["identity assertion is not valid".to_owned()],
+            };
+
+            if reasons.is_empty() {
+                log_current_item!(
+                    "identity assertion satisfies policy",
+                    "IdentityPolicyValidator::validate"
+                )
+                .validation_status(POLICY_MATCH)
+                .success(tracker);
+            } else {
+                log_current_item!(
+                    format!(
+                        "identity assertion does not satisfy policy: {}",
+                        reasons.join("; ")
+                    ),
+                    "IdentityPolicyValidator::validate"
+                )
+                .validation_status(POLICY_MISMATCH)
+                .informational(tracker);
+            }
+
+            self.record(
+                &manifest_label,
+                identity_assertions,
+                Some(reasons.is_empty()),
+            )
+        } else {
+            // Only reports a manifest that has no identity assertions at all.
+            self.record(&manifest_label, identity_assertions, None)
+        };
+
+        if satisfied == Some(false) {
+            log_current_item!(
+                "no identity assertion satisfies policy",
+                "IdentityPolicyValidator::validate"
+            )
+            .validation_status(POLICY_UNSATISFIED)
+            .failure_no_throw(tracker, POLICY_UNSATISFIED);
+        }
+
+        tracker.pop_current_uri();
+        Ok(credential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::{Cursor, Seek};
+
+    use c2pa_macros::c2pa_test_async;
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    use crate::{
+        create_signer,
+        crypto::raw_signature,
+        identity::{
+            builder::{IdentityAssertionBuilder, IdentityAssertionSigner},
+            claim_aggregation::IcaCredentialHolder,
+            tests::fixtures::{
+                cert_chain_and_private_key_for_alg,
+                claim_aggregation::{
+                    ica_credential_example::ica_example_identities,
+                    ica_issuer::ed25519_did_jwk_signer,
+                },
+                manifest_json, parent_json,
+            },
+            x509::X509CredentialHolder,
+        },
+        Builder, Reader, SigningAlg, ValidationState,
+    };
+
+    const TEST_IMAGE: &[u8] = include_bytes!("../../tests/fixtures/CA.jpg");
+    const TEST_THUMBNAIL: &[u8] = include_bytes!("../../tests/fixtures/thumbnail.jpg");
+
+    fn builder(source: &mut Cursor<&[u8]>) -> Builder {
+        let mut builder = Builder::from_json(&manifest_json()).unwrap();
+        builder
+            .add_ingredient_from_stream(parent_json(), "image/jpeg", source)
+            .unwrap();
+        builder
+            .add_resource("thumbnail.jpg", Cursor::new(TEST_THUMBNAIL))
+            .unwrap();
+        builder
+    }
+
+    // Signs the test image with an X.509 identity assertion that has no roles,
+    // followed by an ICA identity assertion for a `cawg.creator` that
+    // references the actions.
+    fn signed_with_identities() -> Reader {
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+
+        let mut signer = IdentityAssertionSigner::from_test_credentials(SigningAlg::Ps256);
+
+        let (cert_chain, private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Ed25519);
+        let x509_holder = X509CredentialHolder::from_raw_signer(
+            raw_signature::signer_from_cert_chain_and_private_key(
+                &cert_chain,
+                &private_key,
+                SigningAlg::Ed25519,
+                None,
+            )
+            .unwrap(),
+        );
+        signer.add_identity_assertion(IdentityAssertionBuilder::for_credential_holder(x509_holder));
+
+        let (issuer, raw_signer) = ed25519_did_jwk_signer();
+        let ica_holder =
+            IcaCredentialHolder::from_raw_signer(&issuer, ica_example_identities(), raw_signer)
+                .unwrap();
+        let mut iab = IdentityAssertionBuilder::for_credential_holder(ica_holder);
+        iab.add_referenced_assertions(&["c2pa.actions.v2"]);
+        iab.add_roles(&["cawg.creator"]);
+        signer.add_identity_assertion(iab);
+
+        builder(&mut source)
+            .sign(&signer, "image/jpeg", &mut source, &mut dest)
+            .unwrap();
+
+        dest.rewind().unwrap();
+        Reader::from_stream("image/jpeg", &mut dest).unwrap()
+    }
+
+    fn policy(trusted_identity_provider: &str) -> IdentityPolicy {
+        IdentityPolicy {
+            required_roles: vec!["cawg.creator".to_owned()],
+            trusted_identity_providers: vec![trusted_identity_provider.to_owned()],
+            required_referenced_assertions: vec!["c2pa.actions".to_owned()],
+        }
+    }
+
+    fn statuses<'a>(
+        statuses: &'a [crate::validation_status::ValidationStatus],
+        code: &str,
+    ) -> Vec<&'a crate::validation_status::ValidationStatus> {
+        statuses.iter().filter(|s| s.code() == code).collect()
+    }
+
+    #[c2pa_test_async]
+    async fn satisfied_by_one_identity() {
+        let mut reader = signed_with_identities();
+        let policy = policy("https://example-social-network.com");
+        reader
+            .post_validate_async(&policy.validator())
+            .await
+            .unwrap();
+
+        let results = reader
+            .validation_results()
+            .unwrap()
+            .active_manifest()
+            .unwrap();
+
+        let matches = statuses(results.success(), POLICY_MATCH);
+        assert_eq!(matches.len(), 1);
+        assert!(matches[0].url().unwrap().ends_with("/cawg.identity__1"));
+
+        // The X.509 identity assertion doesn't satisfy the policy, but that
+        // only matters if no other identity assertion does.
+        let mismatches = statuses(results.informational(), POLICY_MISMATCH);
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].url().unwrap().ends_with("/cawg.identity"));
+        let explanation = mismatches[0].explanation().unwrap();
+        assert!(explanation.contains("role \"cawg.creator\" is missing"));
+        assert!(explanation.contains("trusted identity provider"));
+        assert!(explanation.contains("assertion \"c2pa.actions\" is not referenced"));
+
+        assert!(statuses(results.failure(), POLICY_UNSATISFIED).is_empty());
+        assert_ne!(reader.validation_state(), ValidationState::Invalid);
+
+        // The identity assertions are summarized as with `CawgValidator`.
+        assert!(reader.to_string().contains("verifiedIdentities"));
+    }
+
+    #[c2pa_test_async]
+    async fn unsatisfied() {
+        let mut reader = signed_with_identities();
+        let policy = policy("https://untrusted-id-verifier.com");
+        reader
+            .post_validate_async(&policy.validator())
+            .await
+            .unwrap();
+
+        let results = reader
+            .validation_results()
+            .unwrap()
+            .active_manifest()
+            .unwrap();
+        assert!(statuses(results.success(), POLICY_MATCH).is_empty());
+        assert_eq!(statuses(results.informational(), POLICY_MISMATCH).len(), 2);
+        assert_eq!(statuses(results.failure(), POLICY_UNSATISFIED).len(), 1);
+        assert_eq!(reader.validation_state(), ValidationState::Invalid);
+    }
+
+    #[c2pa_test_async]
+    async fn unsatisfied_without_identity_assertions() {
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+
+        let (cert_chain, private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Ps256);
+        let signer =
+            create_signer::from_keys(&cert_chain, &private_key, SigningAlg::Ps256, None).unwrap();
+        builder(&mut source)
+            .sign(signer.as_ref(), "image/jpeg", &mut source, &mut dest)
+            .unwrap();
+
+        dest.rewind().unwrap();
+        let mut reader = Reader::from_stream("image/jpeg", &mut dest).unwrap();
+
+        // An empty policy accepts any valid identity assertion, but there must
+        // be at least one.
+        let policy = IdentityPolicy::default();
+        reader
+            .post_validate_async(&policy.validator())
+            .await
+            .unwrap();
+
+        let results = reader
+            .validation_results()
+            .unwrap()
+            .active_manifest()
+            .unwrap();
+        assert_eq!(statuses(results.failure(), POLICY_UNSATISFIED).len(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn matches_labels() {
+        let uri = "self#jumbf=c2pa.assertions/c2pa.actions.v2";
+        assert!(label_matches(uri, "c2pa.actions"));
+        assert!(label_matches(uri, "c2pa.actions.v2"));
+        assert!(!label_matches(uri, "c2pa.actions.v3"));
+        assert!(!label_matches(uri, "c2pa"));
+
+        let uri = "self#jumbf=/c2pa/urn:c2pa:123/c2pa.assertions/cawg.identity__1";
+        assert!(label_matches(uri, "cawg.identity"));
+        assert!(!label_matches(uri, "cawg.identity__1"));
+    }
+}
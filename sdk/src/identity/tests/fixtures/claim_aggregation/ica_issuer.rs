@@ -0,0 +1,144 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Signers for an identity claims aggregator whose issuer DID is derived from
+//! the test credentials.
+
+use base64::{
+    prelude::{BASE64_URL_SAFE, BASE64_URL_SAFE_NO_PAD},
+    Engine,
+};
+use ed25519_dalek::{pkcs8::DecodePrivateKey, SigningKey};
+use serde_json::json;
+use x509_parser::{der_parser::parse_der, pem::parse_x509_pem, public_key::PublicKey};
+
+use crate::{
+    crypto::raw_signature::{self, AsyncRawSigner, RawSigner},
+    identity::tests::fixtures::cert_chain_and_private_key_for_alg,
+    SigningAlg,
+};
+
+fn ed25519_public_key() -> [u8; 32] {
+    let (_cert_chain, private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Ed25519);
+    let private_key = std::str::from_utf8(&private_key).unwrap();
+    let signing_key = SigningKey::from_pkcs8_pem(private_key).unwrap();
+    signing_key.verifying_key().to_bytes()
+}
+
+/// Returns a `did:jwk` DID for the Ed25519 test key.
+pub(crate) fn ed25519_did_jwk() -> String {
+    did_jwk_for_alg(SigningAlg::Ed25519)
+}
+
+/// Returns a `did:jwk` DID for the test key of the given algorithm.
+pub(crate) fn did_jwk_for_alg(alg: SigningAlg) -> String {
+    let (cert_chain, _private_key) = cert_chain_and_private_key_for_alg(alg);
+    let (_, pem) = parse_x509_pem(&cert_chain).unwrap();
+    let cert = pem.parse_x509().unwrap();
+    let b64 = |bytes: &[u8]| BASE64_URL_SAFE_NO_PAD.encode(bytes);
+
+    let spki = cert.public_key();
+
+    let jwk = match alg {
+        SigningAlg::Ps256 | SigningAlg::Ps384 | SigningAlg::Ps512 => {
+            // The test certificates use the RSASSA-PSS key type, which
+            // `x509_parser` leaves unparsed.
+            let (_, rsa_key) = parse_der(&spki.subject_public_key.data).unwrap();
+            let rsa_key = rsa_key.as_sequence().unwrap();
+            json!({
+                "kty": "RSA",
+                "n": b64(rsa_key[0].as_slice().unwrap()),
+                "e": b64(rsa_key[1].as_slice().unwrap()),
+            })
+        }
+
+        SigningAlg::Es256 | SigningAlg::Es384 | SigningAlg::Es512 => {
+            let PublicKey::EC(point) = spki.parsed().unwrap() else {
+                unreachable!("expected an EC key");
+            };
+            let coordinates = &point.data()[1..];
+            let (x, y) = coordinates.split_at(coordinates.len() / 2);
+            let crv = match alg {
+                SigningAlg::Es256 => "P-256",
+                SigningAlg::Es384 => "P-384",
+                _ => "P-521",
+            };
+            json!({ "kty": "EC", "crv": crv, "x": b64(x), "y": b64(y) })
+        }
+
+        _ => json!({
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "x": b64(&ed25519_public_key()),
+        }),
+    };
+
+    format!("did:jwk:{}", BASE64_URL_SAFE.encode(jwk.to_string()))
+}
+
+/// Returns a `did:key` DID for the Ed25519 test key.
+pub(crate) fn ed25519_did_key() -> String {
+    let mut multikey = vec![0xed, 0x01];
+    multikey.extend_from_slice(&ed25519_public_key());
+
+    format!("did:key:z{}", bs58::encode(multikey).into_string())
+}
+
+/// Returns a `did:key` DID for the P-256 test key.
+pub(crate) fn p256_did_key() -> String {
+    let (cert_chain, _private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Es256);
+    let (_, pem) = parse_x509_pem(&cert_chain).unwrap();
+    let cert = pem.parse_x509().unwrap();
+    let PublicKey::EC(point) = cert.public_key().parsed().unwrap() else {
+        unreachable!("expected an EC key");
+    };
+
+    // Multikey values hold the compressed point.
+    let (x, y) = point.data()[1..].split_at(32);
+    let mut multikey = vec![0x80, 0x24, 0x02 | (y[31] & 1)];
+    multikey.extend_from_slice(x);
+
+    format!("did:key:z{}", bs58::encode(multikey).into_string())
+}
+
+/// Returns the issuer DID and a [`RawSigner`] for the Ed25519 test key.
+pub(crate) fn ed25519_did_jwk_signer() -> (String, Box<dyn RawSigner + Send + Sync>) {
+    did_jwk_signer_for_alg(SigningAlg::Ed25519)
+}
+
+/// Returns the issuer DID and a [`RawSigner`] for the test key of the given
+/// algorithm.
+pub(crate) fn did_jwk_signer_for_alg(
+    alg: SigningAlg,
+) -> (String, Box<dyn RawSigner + Send + Sync>) {
+    let (cert_chain, private_key) = cert_chain_and_private_key_for_alg(alg);
+    let signer =
+        raw_signature::signer_from_cert_chain_and_private_key(&cert_chain, &private_key, alg, None)
+            .unwrap();
+
+    (did_jwk_for_alg(alg), signer)
+}
+
+/// Returns the issuer DID and an [`AsyncRawSigner`] for the Ed25519 test key.
+pub(crate) fn ed25519_did_jwk_async_signer() -> (String, Box<dyn AsyncRawSigner + Send + Sync>) {
+    let (cert_chain, private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Ed25519);
+    let signer = raw_signature::async_signer_from_cert_chain_and_private_key(
+        &cert_chain,
+        &private_key,
+        SigningAlg::Ed25519,
+        None,
+    )
+    .unwrap();
+
+    (ed25519_did_jwk(), signer)
+}
@@ -12,3 +12,4 @@
 // each license.
 
 pub(crate) mod ica_credential_example;
+pub(crate) mod ica_issuer;
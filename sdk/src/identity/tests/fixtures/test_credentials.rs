@@ -49,5 +49,20 @@ pub(crate) fn cert_chain_and_private_key_for_alg(alg: SigningAlg) -> (Vec<u8>, V
             include_bytes!("../../../../tests/fixtures/certs/ed25519.pub").to_vec(),
             include_bytes!("../../../../tests/fixtures/certs/ed25519.pem").to_vec(),
         ),
+
+        SigningAlg::MlDsa44 => (
+            include_bytes!("../../../../tests/fixtures/certs/mldsa44.pub").to_vec(),
+            include_bytes!("../../../../tests/fixtures/certs/mldsa44.pem").to_vec(),
+        ),
+
+        SigningAlg::MlDsa65 => (
+            include_bytes!("../../../../tests/fixtures/certs/mldsa65.pub").to_vec(),
+            include_bytes!("../../../../tests/fixtures/certs/mldsa65.pem").to_vec(),
+        ),
+
+        SigningAlg::MlDsa87 => (
+            include_bytes!("../../../../tests/fixtures/certs/mldsa87.pub").to_vec(),
+            include_bytes!("../../../../tests/fixtures/certs/mldsa87.pem").to_vec(),
+        ),
     }
 }
@@ -19,13 +19,36 @@ use serde_json::Value;
 
 use crate::{
     dynamic_assertion::{AsyncPostValidator, PartialClaim},
-    identity::IdentityAssertion,
-    status_tracker::StatusTracker,
+    identity::{
+        claim_aggregation::{
+            ConfiguredIcaSignatureVerifier, IcaCredential, IcaSignatureVerifier, IcaValidationError,
+        },
+        IdentityAssertion, SignatureVerifier, ValidationError,
+    },
+    log_current_item,
+    status_tracker::{ErrorBehavior, StatusTracker},
     ManifestAssertion,
 };
 
 /// Validates a CAWG identity assertion.
+///
+/// Identity claims aggregation credentials are checked with the default
+/// [`IcaSignatureVerifier`]. Use [`with_ica_verifier`] to configure its DID
+/// resolvers or status list fetcher.
+///
+/// [`with_ica_verifier`]: Self::with_ica_verifier
 pub struct CawgValidator;
+
+impl CawgValidator {
+    /// Create a [`ConfiguredCawgValidator`] that checks identity claims
+    /// aggregation credentials with `ica_verifier`.
+    pub fn with_ica_verifier(
+        ica_verifier: ConfiguredIcaSignatureVerifier,
+    ) -> ConfiguredCawgValidator {
+        ConfiguredCawgValidator { ica_verifier }
+    }
+}
+
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AsyncPostValidator for CawgValidator {
@@ -37,20 +60,112 @@ impl AsyncPostValidator for CawgValidator {
         partial_claim: &PartialClaim,
         tracker: &mut StatusTracker,
     ) -> crate::Result<Option<Value>> {
-        if label == "cawg.identity" || label.starts_with("cawg.identity__") {
-            let identity_assertion: IdentityAssertion = assertion.to_assertion()?;
-            tracker.push_current_uri(uri);
-
-            let result = identity_assertion
-                .validate_partial_claim(partial_claim, tracker)
-                .await
-                .map(Some)
-                .map_err(|e| crate::Error::ClaimVerification(e.to_string()));
-
-            tracker.pop_current_uri();
-            return result;
+        validate_identity(
+            label,
+            assertion,
+            uri,
+            partial_claim,
+            tracker,
+            &IcaSignatureVerifier {},
+        )
+        .await
+    }
+}
+
+/// A [`CawgValidator`] that checks identity claims aggregation credentials
+/// with a [`ConfiguredIcaSignatureVerifier`], created by
+/// [`CawgValidator::with_ica_verifier`].
+#[derive(Debug, Default)]
+pub struct ConfiguredCawgValidator {
+    ica_verifier: ConfiguredIcaSignatureVerifier,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl AsyncPostValidator for ConfiguredCawgValidator {
+    async fn validate(
+        &self,
+        label: &str,
+        assertion: &ManifestAssertion,
+        uri: &str,
+        partial_claim: &PartialClaim,
+        tracker: &mut StatusTracker,
+    ) -> crate::Result<Option<Value>> {
+        validate_identity(
+            label,
+            assertion,
+            uri,
+            partial_claim,
+            tracker,
+            &self.ica_verifier,
+        )
+        .await
+    }
+}
+
+// Validates `assertion` if it is an identity assertion, checking identity
+// claims aggregation credentials with `ica_verifier`.
+async fn validate_identity<SV>(
+    label: &str,
+    assertion: &ManifestAssertion,
+    uri: &str,
+    partial_claim: &PartialClaim,
+    tracker: &mut StatusTracker,
+    ica_verifier: &SV,
+) -> crate::Result<Option<Value>>
+where
+    SV: SignatureVerifier<Output = IcaCredential, Error = IcaValidationError>,
+{
+    if label == "cawg.identity" || label.starts_with("cawg.identity__") {
+        let identity_assertion: IdentityAssertion = assertion.to_assertion()?;
+        tracker.push_current_uri(uri);
+
+        // Each identity assertion is reported on its own, so a failure here
+        // is logged against this assertion's URI. Validation of the others
+        // only continues if the tracker is set to continue when possible.
+        let failures = tracker.filter_errors().count();
+        let result = match identity_assertion
+            .validate_partial_claim_with_ica_verifier(partial_claim, tracker, ica_verifier)
+            .await
+        {
+            Ok(value) => Ok(Some(value)),
+            Err(err) => {
+                let err = if tracker.filter_errors().count() == failures {
+                    log_current_item!(err.to_string(), "CawgValidator::validate")
+                        .validation_status(validation_status(&err))
+                        .failure_as_err(tracker, err)
+                } else {
+                    err
+                };
+
+                match tracker.error_behavior() {
+                    ErrorBehavior::ContinueWhenPossible => Ok(None),
+                    ErrorBehavior::StopOnFirstError => {
+                        Err(crate::Error::ClaimVerification(err.to_string()))
+                    }
+                }
+            }
         };
-        Ok(None)
+
+        tracker.pop_current_uri();
+        return result;
+    };
+    Ok(None)
+}
+
+// Status code for a validation error that wasn't already logged.
+fn validation_status(err: &ValidationError<String>) -> &'static str {
+    match err {
+        ValidationError::AssertionNotInClaim(_) | ValidationError::AssertionMismatch(_) => {
+            "cawg.identity.assertion.mismatch"
+        }
+        ValidationError::DuplicateAssertionReference(_) => "cawg.identity.assertion.duplicate",
+        ValidationError::NoHardBindingAssertion => "cawg.identity.hard_binding_missing",
+        ValidationError::InvalidPadding => "cawg.identity.pad.invalid",
+        ValidationError::UnknownSignatureType(_) => "cawg.identity.sig_type.unknown",
+        ValidationError::SignatureMismatch
+        | ValidationError::SignatureError(_)
+        | ValidationError::InternalError(_) => "cawg.identity.signature.mismatch",
     }
 }
 
@@ -65,7 +180,10 @@ mod tests {
     use wasm_bindgen_test::wasm_bindgen_test;
 
     use super::*;
-    use crate::{Reader, ValidationState};
+    use crate::{
+        identity::claim_aggregation::DidResolvers, jumbf::labels::to_assertion_uri, store::Store,
+        Reader, ValidationState,
+    };
 
     const CONNECTED_IDENTITIES_VALID: &[u8] =
         include_bytes!("tests/fixtures/claim_aggregation/adobe_connected_identities.jpg");
@@ -118,6 +236,91 @@ mod tests {
         assert_eq!(reader.validation_state(), ValidationState::Valid);
     }
 
+    #[c2pa_test_async]
+    async fn test_configured_ica_verifier() {
+        crate::settings::set_settings_value("verify.verify_trust", false).unwrap();
+
+        // Without any DID resolvers, the credential's issuer can't be resolved.
+        let validator = CawgValidator::with_ica_verifier(IcaSignatureVerifier::with_resolvers(
+            DidResolvers::empty(),
+        ));
+
+        let mut stream = Cursor::new(CONNECTED_IDENTITIES_VALID);
+        let mut reader = Reader::from_stream("image/jpeg", &mut stream).unwrap();
+        reader.post_validate_async(&validator).await.unwrap();
+
+        let failures = reader
+            .validation_results()
+            .unwrap()
+            .active_manifest()
+            .unwrap()
+            .failure();
+        assert!(failures
+            .iter()
+            .any(|status| status.code() == "cawg.ica.invalid_issuer"));
+    }
+
+    // Validates the identity assertion in the no hard binding fixture directly,
+    // with a status tracker set to `error_behavior`.
+    async fn validate_no_hard_binding(
+        error_behavior: ErrorBehavior,
+    ) -> (crate::Result<Option<Value>>, StatusTracker) {
+        let mut stream = Cursor::new(NO_HARD_BINDING);
+        let reader = Reader::from_stream("image/jpeg", &mut stream).unwrap();
+        let label = reader.active_label().unwrap();
+
+        stream.set_position(0);
+        let store = Store::from_stream(
+            "image/jpeg",
+            &mut stream,
+            false,
+            &mut StatusTracker::default(),
+        )
+        .unwrap();
+        let mut partial_claim = PartialClaim::default();
+        for assertion in store.get_claim(label).unwrap().assertions() {
+            partial_claim.add_assertion(assertion);
+        }
+
+        let assertion = reader
+            .active_manifest()
+            .unwrap()
+            .assertions()
+            .iter()
+            .find(|a| a.label().starts_with("cawg.identity"))
+            .unwrap();
+        let uri = to_assertion_uri(label, assertion.label());
+
+        let mut tracker = StatusTracker::with_error_behavior(error_behavior);
+        let result = CawgValidator {}
+            .validate(
+                assertion.label(),
+                assertion,
+                &uri,
+                &partial_claim,
+                &mut tracker,
+            )
+            .await;
+        (result, tracker)
+    }
+
+    #[c2pa_test_async]
+    async fn test_continue_when_possible_logs_failure() {
+        let (result, tracker) = validate_no_hard_binding(ErrorBehavior::ContinueWhenPossible).await;
+
+        // The failure is logged and validation carries on.
+        assert!(result.is_ok());
+        assert!(tracker.has_status("cawg.identity.hard_binding_missing"));
+    }
+
+    #[c2pa_test_async]
+    async fn test_stop_on_first_error_returns_failure() {
+        let (result, tracker) = validate_no_hard_binding(ErrorBehavior::StopOnFirstError).await;
+
+        assert!(matches!(result, Err(crate::Error::ClaimVerification(_))));
+        assert!(tracker.has_status("cawg.identity.hard_binding_missing"));
+    }
+
     #[c2pa_test_async]
     async fn test_post_validate_with_hard_binding_missing() {
         let mut stream = Cursor::new(NO_HARD_BINDING);
@@ -25,7 +25,18 @@ pub use async_x509_credential_holder::AsyncX509CredentialHolder;
 mod x509_credential_holder;
 pub use x509_credential_holder::X509CredentialHolder;
 
+mod x509_identity_signer;
+pub use x509_identity_signer::X509IdentitySigner;
+
 mod x509_signature_verifier;
 pub use x509_signature_verifier::{X509SignatureInfo, X509SignatureReport, X509SignatureVerifier};
 
 pub(crate) const CAWG_X509_SIG_TYPE: &str = "cawg.x509.cose";
+
+/// Status code for an X.509 signing credential that is trusted by the
+/// configured `cawg_trust` policy.
+pub const SIGNING_CREDENTIAL_TRUSTED: &str = "cawg.identity.signing_credential.trusted";
+
+/// Status code for an X.509 signing credential that is not trusted by the
+/// configured `cawg_trust` policy.
+pub const SIGNING_CREDENTIAL_UNTRUSTED: &str = "cawg.identity.signing_credential.untrusted";
@@ -0,0 +1,294 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use zeroize::Zeroizing;
+
+use crate::{
+    crypto::raw_signature::{self, signer_from_cert_chain_and_private_key, SigningAlg},
+    dynamic_assertion::DynamicAssertion,
+    identity::{builder::IdentityAssertionBuilder, x509::X509CredentialHolder},
+    Result, Signer,
+};
+
+/// An `X509IdentitySigner` wraps a C2PA [`Signer`] and adds an identity
+/// assertion backed by an X.509 credential to every [`Manifest`] it signs.
+///
+/// Unlike [`IdentityAssertionSigner`], which hands out its identity assertions
+/// once, this signer keeps the credential holder's certificate chain and
+/// private key so the same instance can be used for any number of signing
+/// operations. This makes it suitable for language bindings and command-line
+/// tools where the signer is configured once and reused.
+///
+/// [`Manifest`]: crate::Manifest
+/// [`IdentityAssertionSigner`]: crate::identity::builder::IdentityAssertionSigner
+pub struct X509IdentitySigner {
+    signer: Box<dyn Signer>,
+    cert_chain: Vec<u8>,
+    private_key: Zeroizing<Vec<u8>>,
+    alg: SigningAlg,
+    tsa_url: Option<String>,
+    referenced_assertions: Vec<String>,
+    roles: Vec<String>,
+}
+
+impl X509IdentitySigner {
+    /// Create an `X509IdentitySigner` that signs the C2PA claim with `signer`
+    /// and the identity assertion with the X.509 credential described by
+    /// `cert_chain` and `private_key` (both in PEM format).
+    ///
+    /// An error is returned if the credential can not be used to create a
+    /// signer for `alg`.
+    pub fn from_keys(
+        signer: Box<dyn Signer>,
+        cert_chain: &[u8],
+        private_key: &[u8],
+        alg: SigningAlg,
+        tsa_url: Option<String>,
+    ) -> Result<Self> {
+        // Fail early if the credential is unusable rather than silently
+        // omitting the identity assertion at signing time.
+        signer_from_cert_chain_and_private_key(cert_chain, private_key, alg, tsa_url.clone())?;
+
+        Ok(Self {
+            signer,
+            cert_chain: cert_chain.to_vec(),
+            private_key: Zeroizing::new(private_key.to_vec()),
+            alg,
+            tsa_url,
+            referenced_assertions: vec![],
+            roles: vec![],
+        })
+    }
+
+    /// Labels of assertions that should be referenced by the identity
+    /// assertion, if they are present in the claim being signed.
+    ///
+    /// See [`IdentityAssertionBuilder::add_referenced_assertions`].
+    pub fn add_referenced_assertions(&mut self, labels: &[&str]) {
+        for label in labels {
+            if !self.referenced_assertions.iter().any(|l| l == label) {
+                self.referenced_assertions.push(label.to_string());
+            }
+        }
+    }
+
+    /// Add roles to attach to the named actor for the identity assertion.
+    ///
+    /// See [`IdentityAssertionBuilder::add_roles`].
+    pub fn add_roles(&mut self, roles: &[&str]) {
+        for role in roles {
+            self.roles.push(role.to_string());
+        }
+    }
+
+    fn identity_assertion_builder(&self) -> Result<IdentityAssertionBuilder> {
+        let raw_signer = signer_from_cert_chain_and_private_key(
+            &self.cert_chain,
+            &self.private_key,
+            self.alg,
+            self.tsa_url.clone(),
+        )?;
+
+        let mut iab = IdentityAssertionBuilder::for_credential_holder(
+            X509CredentialHolder::from_raw_signer(raw_signer),
+        );
+
+        let referenced_assertions: Vec<&str> = self
+            .referenced_assertions
+            .iter()
+            .map(String::as_str)
+            .collect();
+        iab.add_referenced_assertions(&referenced_assertions);
+
+        let roles: Vec<&str> = self.roles.iter().map(String::as_str).collect();
+        iab.add_roles(&roles);
+
+        Ok(iab)
+    }
+}
+
+impl Signer for X509IdentitySigner {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        self.signer.sign(data)
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.signer.alg()
+    }
+
+    fn certs(&self) -> Result<Vec<Vec<u8>>> {
+        self.signer.certs()
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.signer.reserve_size()
+    }
+
+    fn ocsp_val(&self) -> Option<Vec<u8>> {
+        self.signer.ocsp_val()
+    }
+
+    fn time_authority_url(&self) -> Option<String> {
+        self.signer.time_authority_url()
+    }
+
+    fn timestamp_request_headers(&self) -> Option<Vec<(String, String)>> {
+        self.signer.timestamp_request_headers()
+    }
+
+    fn timestamp_request_body(&self, message: &[u8]) -> Result<Vec<u8>> {
+        self.signer.timestamp_request_body(message)
+    }
+
+    fn send_timestamp_request(&self, message: &[u8]) -> Option<Result<Vec<u8>>> {
+        self.signer.send_timestamp_request(message)
+    }
+
+    fn direct_cose_handling(&self) -> bool {
+        self.signer.direct_cose_handling()
+    }
+
+    fn raw_signer(&self) -> Option<Box<&dyn raw_signature::RawSigner>> {
+        self.signer.raw_signer()
+    }
+
+    fn dynamic_assertions(&self) -> Vec<Box<dyn DynamicAssertion>> {
+        let mut dynamic_assertions = self.signer.dynamic_assertions();
+
+        // The credential was checked in `from_keys`, so this can only fail if
+        // the crypto backend changes its mind about the same inputs.
+        if let Ok(iab) = self.identity_assertion_builder() {
+            dynamic_assertions.push(Box::new(iab));
+        }
+
+        dynamic_assertions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::{Cursor, Seek};
+
+    use c2pa_macros::c2pa_test_async;
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    use crate::{
+        create_signer,
+        identity::{
+            tests::fixtures::{cert_chain_and_private_key_for_alg, manifest_json, parent_json},
+            x509::X509SignatureVerifier,
+            IdentityAssertion,
+        },
+        status_tracker::StatusTracker,
+        Builder, Reader,
+    };
+
+    const TEST_IMAGE: &[u8] = include_bytes!("../../../tests/fixtures/CA.jpg");
+    const TEST_THUMBNAIL: &[u8] = include_bytes!("../../../tests/fixtures/thumbnail.jpg");
+
+    fn identity_signer() -> X509IdentitySigner {
+        let (cert_chain, private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Ps256);
+        let c2pa_signer =
+            create_signer::from_keys(&cert_chain, &private_key, SigningAlg::Ps256, None).unwrap();
+
+        let (cawg_cert_chain, cawg_private_key) =
+            cert_chain_and_private_key_for_alg(SigningAlg::Ed25519);
+
+        let mut signer = X509IdentitySigner::from_keys(
+            c2pa_signer,
+            &cawg_cert_chain,
+            &cawg_private_key,
+            SigningAlg::Ed25519,
+            None,
+        )
+        .unwrap();
+
+        signer.add_referenced_assertions(&["org.test.assertion", "org.test.assertion"]);
+        signer.add_roles(&["cawg.creator"]);
+        signer
+    }
+
+    #[c2pa_test_async]
+    async fn signs_repeatedly() {
+        let signer = identity_signer();
+        assert_eq!(signer.alg(), SigningAlg::Ps256);
+
+        // The identity assertion must be added every time, not just the first.
+        for _ in 0..2 {
+            let format = "image/jpeg";
+            let mut source = Cursor::new(TEST_IMAGE);
+            let mut dest = Cursor::new(Vec::new());
+
+            let mut builder = Builder::from_json(&manifest_json()).unwrap();
+            builder
+                .add_ingredient_from_stream(parent_json(), format, &mut source)
+                .unwrap();
+            builder
+                .add_resource("thumbnail.jpg", Cursor::new(TEST_THUMBNAIL))
+                .unwrap();
+            builder
+                .sign(&signer, format, &mut source, &mut dest)
+                .unwrap();
+
+            dest.rewind().unwrap();
+            let reader = Reader::from_stream(format, &mut dest).unwrap();
+            let manifest = reader.active_manifest().unwrap();
+
+            let mut st = StatusTracker::default();
+            let mut ia_iter = IdentityAssertion::from_manifest(manifest, &mut st);
+
+            let ia = ia_iter.next().unwrap().unwrap();
+            assert!(ia_iter.next().is_none());
+            drop(ia_iter);
+
+            let sp = ia.signer_payload();
+            assert_eq!(sp.roles, vec!["cawg.creator".to_owned()]);
+            assert!(sp
+                .referenced_assertions
+                .iter()
+                .any(|a| a.url().ends_with("org.test.assertion")));
+
+            let x509_verifier = X509SignatureVerifier {};
+            ia.validate(manifest, &mut st, &x509_verifier)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn rejects_unusable_credential() {
+        let (cert_chain, private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Ps256);
+        let c2pa_signer =
+            create_signer::from_keys(&cert_chain, &private_key, SigningAlg::Ps256, None).unwrap();
+
+        let (cawg_cert_chain, cawg_private_key) =
+            cert_chain_and_private_key_for_alg(SigningAlg::Ed25519);
+
+        assert!(X509IdentitySigner::from_keys(
+            c2pa_signer,
+            &cawg_cert_chain,
+            &cawg_private_key,
+            SigningAlg::Es256,
+            None,
+        )
+        .is_err());
+    }
+}
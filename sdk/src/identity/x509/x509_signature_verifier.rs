@@ -11,18 +11,24 @@
 // specific language governing permissions and limitations under
 // each license.
 
+use std::borrow::Cow;
+
 use async_trait::async_trait;
 use coset::CoseSign1;
 use serde::Serialize;
 
 use crate::{
     crypto::{
-        cose::{parse_cose_sign1, CertificateInfo, CoseError, Verifier},
+        cose::{
+            parse_cose_sign1, CertificateInfo, CertificateTrustPolicy, CoseError, TrustPolicyRole,
+            Verifier,
+        },
         raw_signature::RawSignatureValidationError,
     },
     identity::{
-        identity_assertion::signature_verifier::ToCredentialSummary, SignatureVerifier,
-        SignerPayload, ValidationError,
+        identity_assertion::signature_verifier::ToCredentialSummary,
+        x509::{SIGNING_CREDENTIAL_TRUSTED, SIGNING_CREDENTIAL_UNTRUSTED},
+        SignatureVerifier, SignerPayload, ValidationError,
     },
     log_current_item,
     status_tracker::StatusTracker,
@@ -32,11 +38,13 @@ use crate::{
 /// generated from X.509 credentials as specified in [§8.2, X.509 certificates
 /// and COSE signatures].
 ///
+/// The signing certificate is only checked against a trust list if the
+/// `cawg_trust` settings configure one. That list is independent of the
+/// `trust` settings used for C2PA claim signers.
+///
 /// [`SignatureVerifier`]: crate::identity::SignatureVerifier
 /// [§8.2, X.509 certificates and COSE signatures]: https://cawg.io/identity/1.1-draft/#_x_509_certificates_and_cose_signatures
-pub struct X509SignatureVerifier {
-    // TO DO (CAI-7980): Add option to configure trust roots and trusted signers.
-}
+pub struct X509SignatureVerifier {}
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
@@ -70,15 +78,22 @@ impl SignatureVerifier for X509SignatureVerifier {
         ciborium::into_writer(signer_payload, &mut signer_payload_cbor)
             .map_err(|_| ValidationError::InternalError("CBOR serialization error".to_string()))?;
 
-        // TO DO: Add options for trust list and certificate policy config.
-        let verifier = Verifier::IgnoreProfileAndTrustPolicy;
+        let ctp =
+            CertificateTrustPolicy::from_settings("cawg_trust", TrustPolicyRole::CawgIdentity)
+                .map_err(|e| {
+                    ValidationError::InternalError(format!("invalid cawg_trust settings: {e}"))
+                })?;
+        let verifier = match &ctp {
+            Some(ctp) => Verifier::VerifyTrustPolicy(Cow::Borrowed(ctp)),
+            None => Verifier::IgnoreProfileAndTrustPolicy,
+        };
 
         // TO DO: Figure out how to provide a validation log.
         let mut validation_log = StatusTracker::default();
 
         let cose_sign1 = parse_cose_sign1(signature, &signer_payload_cbor, &mut validation_log)?;
 
-        let cert_info = verifier
+        let result = verifier
             .verify_signature_async(
                 signature,
                 &signer_payload_cbor,
@@ -86,14 +101,40 @@ impl SignatureVerifier for X509SignatureVerifier {
                 None,
                 &mut validation_log,
             )
-            .await
-            .map_err(|e| match e {
-                CoseError::RawSignatureValidationError(
-                    RawSignatureValidationError::SignatureMismatch,
-                ) => ValidationError::SignatureMismatch,
-
-                e => ValidationError::SignatureError(e),
-            })?;
+            .await;
+
+        if let Some(ctp) = &ctp {
+            let trust_policy = ctp.role().as_str();
+            match &result {
+                Ok(_) => {
+                    log_current_item!(
+                        format!("signing certificate trusted ({trust_policy} trust policy)"),
+                        "X509SignatureVerifier::check_signature"
+                    )
+                    .validation_status(SIGNING_CREDENTIAL_TRUSTED)
+                    .trust_policy(trust_policy)
+                    .success(status_tracker);
+                }
+                Err(CoseError::CertificateTrustError(e)) => {
+                    log_current_item!(
+                        format!("signing certificate untrusted ({trust_policy} trust policy)"),
+                        "X509SignatureVerifier::check_signature"
+                    )
+                    .validation_status(SIGNING_CREDENTIAL_UNTRUSTED)
+                    .trust_policy(trust_policy)
+                    .failure_no_throw(status_tracker, e);
+                }
+                Err(_) => (),
+            }
+        }
+
+        let cert_info = result.map_err(|e| match e {
+            CoseError::RawSignatureValidationError(
+                RawSignatureValidationError::SignatureMismatch,
+            ) => ValidationError::SignatureMismatch,
+
+            e => ValidationError::SignatureError(e),
+        })?;
 
         Ok(X509SignatureInfo {
             signer_payload: signer_payload.clone(),
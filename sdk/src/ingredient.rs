@@ -1340,7 +1340,10 @@ impl Ingredient {
             2 => {
                 ingredient_assertion.active_manifest = active_manifest;
                 ingredient_assertion.claim_signature = claim_signature;
-                ingredient_assertion.validation_results = self.validation_results.clone();
+                ingredient_assertion.validation_results = self
+                    .validation_results
+                    .clone()
+                    .map(ValidationResults::without_reports);
             }
             _ => {}
         }
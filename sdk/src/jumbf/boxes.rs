@@ -26,7 +26,7 @@ use std::{
     any::Any,
     ffi::CString,
     fmt,
-    io::{Read, Result as IoResult, Seek, SeekFrom, Write},
+    io::{Cursor, Read, Result as IoResult, Seek, SeekFrom, Write},
 };
 
 use byteorder::{BigEndian, ReadBytesExt};
@@ -94,6 +94,12 @@ pub enum JumbfParseError {
 
     #[error("invalid JUMD box")]
     InvalidDescriptionBox,
+
+    #[error("invalid compressed box")]
+    InvalidCompressedBox,
+
+    #[error("compressed box exceeds the decompressed size limit")]
+    CompressedBoxTooLarge,
 }
 
 /// A specialized `JumbfParseResult` type for JUMBF parsing operations.
@@ -1779,6 +1785,119 @@ impl JUMBFEmbeddedFileDescriptionBox {
     }
 }
 
+// ANCHOR Brotli Compressed box
+/// Brotli compressed box (ISO/IEC 18181-2, 4.3)
+///
+/// Holds the contents of another box, without its header, compressed with
+/// Brotli. C2PA uses it to store manifest and assertion store superboxes in
+/// less space.
+#[derive(Debug)]
+pub struct BrotliCompressedBox {
+    original_type: [u8; 4], // type of the box that was compressed
+    data: Vec<u8>,          // Brotli compressed payload of that box
+}
+
+impl BMFFBox for BrotliCompressedBox {
+    fn box_type(&self) -> &'static [u8; 4] {
+        b"brob"
+    }
+
+    fn box_uuid(&self) -> &'static str {
+        "" // base boxes don't have any...
+    }
+
+    fn box_payload_size(&self) -> IoResult<u32> {
+        let size = self.original_type.len() + self.data.len();
+        Ok(size as u32)
+    }
+
+    fn write_box_payload(&self, writer: &mut dyn Write) -> IoResult<()> {
+        write_all!(writer, &self.original_type);
+        write_all!(writer, &self.data);
+        Ok(())
+    }
+
+    // Necessary method to enable conversion between types...
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl BrotliCompressedBox {
+    // the compressed box takes ownership of the data!
+    pub fn new(original_type: [u8; 4], data: Vec<u8>) -> Self {
+        BrotliCompressedBox {
+            original_type,
+            data,
+        }
+    }
+
+    /// Compresses the payload of `original`.
+    pub fn compress(original: &dyn BMFFBox) -> IoResult<Self> {
+        // write the whole box since wrappers like `CAIStore` only know the
+        // type of the superbox they hold once it is written
+        let mut original_box = Vec::new();
+        original.write_box(&mut original_box)?;
+        if original_box.len() < HEADER_SIZE as usize {
+            return Err(std::io::ErrorKind::InvalidInput.into());
+        }
+        let (header, payload) = original_box.split_at(HEADER_SIZE as usize);
+        let mut original_type = [0u8; 4];
+        original_type.copy_from_slice(&header[4..]);
+
+        let params = brotli::enc::BrotliEncoderParams {
+            quality: BROTLI_QUALITY,
+            lgwin: BROTLI_WINDOW,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        brotli::BrotliCompress(&mut &payload[..], &mut data, &params)?;
+
+        Ok(BrotliCompressedBox::new(original_type, data))
+    }
+
+    // getters
+    pub fn original_type(&self) -> &[u8; 4] {
+        &self.original_type
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the original box, header included.
+    ///
+    /// Decompression stops with [`JumbfParseError::CompressedBoxTooLarge`]
+    /// as soon as the payload grows past `max_size` bytes.
+    pub fn decompress(&self, max_size: u64) -> JumbfParseResult<Vec<u8>> {
+        let mut payload = Vec::new();
+        brotli::Decompressor::new(self.data.as_slice(), 4096)
+            .take(max_size.saturating_add(1))
+            .read_to_end(&mut payload)
+            .map_err(|_| JumbfParseError::InvalidCompressedBox)?;
+
+        if payload.len() as u64 > max_size {
+            return Err(JumbfParseError::CompressedBoxTooLarge);
+        }
+
+        let size = u32::try_from(payload.len() as u64 + HEADER_SIZE)
+            .map_err(|_| JumbfParseError::CompressedBoxTooLarge)?;
+
+        let mut original = Vec::with_capacity(size as usize);
+        original.extend_from_slice(&size.to_be_bytes());
+        original.extend_from_slice(&self.original_type);
+        original.extend_from_slice(&payload);
+
+        Ok(original)
+    }
+}
+
+// Brotli settings used when compressing boxes. The window is the largest
+// a decoder must support by default.
+const BROTLI_QUALITY: i32 = 5;
+const BROTLI_WINDOW: i32 = 22;
+
 // !SECTION
 
 //---------------
@@ -1788,6 +1907,17 @@ impl JUMBFEmbeddedFileDescriptionBox {
 const HEADER_SIZE: u64 = 8;
 const TOGGLE_SIZE: u64 = 1;
 
+/// Largest box a Brotli compressed box may expand to.
+pub const MAX_DECOMPRESSED_BOX_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Largest ratio between the decompressed and compressed size of a Brotli
+/// compressed box. Real manifests stay far below this, decompression bombs do
+/// not.
+pub const MAX_COMPRESSION_RATIO: u64 = 1024;
+
+// lets small boxes of mostly padding through the ratio check
+const MIN_DECOMPRESSED_BOX_LIMIT: u64 = 64 * 1024;
+
 /// method for getting the current position
 pub fn current_pos<R: Seek>(seeker: &mut R) -> JumbfParseResult<u64> {
     Ok(seeker.stream_position()?)
@@ -1856,7 +1986,8 @@ boxtype! {
     Jp2c => 0x6A70_3263,
     Cbor => 0x6362_6F72,
     EmbedMediaDesc => 0x6266_6462,
-    EmbedContent => 0x6269_6462
+    EmbedContent => 0x6269_6462,
+    Brob => 0x6272_6F62
 }
 
 // ANCHOR BlockHeader
@@ -2205,7 +2336,69 @@ impl BoxReader {
         Ok(JUMBFEmbeddedFileContentBox::new(buf))
     }
 
+    pub fn read_brob_box<R: Read + Seek>(
+        reader: &mut R,
+        size: u64,
+    ) -> JumbfParseResult<BrotliCompressedBox> {
+        let header =
+            BoxReader::read_header(reader).map_err(|_| JumbfParseError::InvalidBoxHeader)?;
+        if header.size == 0 {
+            // bad read, nothing to decompress...
+            return Err(JumbfParseError::InvalidCompressedBox);
+        } else if header.size != size {
+            // this means that we started w/o the header...
+            unread_bytes(reader, HEADER_SIZE)?;
+        }
+
+        if size < HEADER_SIZE + 4 {
+            return Err(JumbfParseError::InvalidCompressedBox);
+        }
+
+        let mut original_type = [0u8; 4];
+        reader.read_exact(&mut original_type)?;
+
+        let data_len = size - HEADER_SIZE - 4;
+        let data = reader
+            .read_to_vec(data_len)
+            .map_err(|_| JumbfParseError::InvalidBoxHeader)?;
+
+        Ok(BrotliCompressedBox::new(original_type, data))
+    }
+
+    // reads a brob box holding a compressed superbox and returns the superbox
+    fn read_compressed_super_box<R: Read + Seek>(
+        reader: &mut R,
+        size: u64,
+    ) -> JumbfParseResult<JUMBFSuperBox> {
+        let brob = BoxReader::read_brob_box(reader, size)?;
+        if brob.original_type() != b"jumb" {
+            return Err(JumbfParseError::InvalidCompressedBox);
+        }
+
+        let max_size = (brob.data().len() as u64)
+            .saturating_mul(MAX_COMPRESSION_RATIO)
+            .clamp(MIN_DECOMPRESSED_BOX_LIMIT, MAX_DECOMPRESSED_BOX_SIZE);
+        let original = brob.decompress(max_size)?;
+        let original_len = original.len() as u64;
+
+        // compressed boxes may not nest, so a bomb can only expand once
+        let mut original_reader = Cursor::new(original);
+        let sbox = BoxReader::read_super_box_impl(&mut original_reader, false)?;
+        if current_pos(&mut original_reader)? != original_len {
+            return Err(JumbfParseError::InvalidCompressedBox);
+        }
+
+        Ok(sbox)
+    }
+
     pub fn read_super_box<R: Read + Seek>(reader: &mut R) -> JumbfParseResult<JUMBFSuperBox> {
+        BoxReader::read_super_box_impl(reader, true)
+    }
+
+    fn read_super_box_impl<R: Read + Seek>(
+        reader: &mut R,
+        allow_compressed: bool,
+    ) -> JumbfParseResult<JUMBFSuperBox> {
         // find out where we're starting...
         let start_pos = current_pos(reader).map_err(|_| JumbfParseError::InvalidBoxRange)?;
 
@@ -2252,8 +2445,12 @@ impl BoxReader {
                 unread_bytes(reader, HEADER_SIZE)?; // seek back to the beginning of the box
                 let next_box: Box<dyn BMFFBox> = match box_header.name {
                     BoxType::Jumb => Box::new(
-                        BoxReader::read_super_box(reader)?, //.map_err(|_| JumbfParseError::InvalidJumbBox)?,
+                        BoxReader::read_super_box_impl(reader, allow_compressed)?, //.map_err(|_| JumbfParseError::InvalidJumbBox)?,
                     ),
+                    BoxType::Brob if allow_compressed => Box::new(
+                        BoxReader::read_compressed_super_box(reader, box_header.size)?,
+                    ),
+                    BoxType::Brob => return Err(JumbfParseError::InvalidCompressedBox),
                     BoxType::Json => Box::new(
                         BoxReader::read_json_box(reader, box_header.size)
                             .map_err(|_| JumbfParseError::InvalidJsonBox)?,
@@ -2779,6 +2976,103 @@ pub mod tests {
         assert!(BoxReader::read_super_box(&mut buf_reader).is_err());
     }
 
+    // ANCHOR: Brotli Compressed Box Reader
+    #[test]
+    fn compressed_box_reader() {
+        fn assertion_store() -> CAIAssertionStore {
+            let mut a_store = CAIAssertionStore::new();
+            let location = String::from("{ \"location\": \"Margate City, NJ\"}");
+            let mut lb = CAIJSONAssertionBox::new("c2pa.location.broad");
+            lb.add_json(location.into_bytes());
+            a_store.add_assertion(Box::new(lb));
+            a_store
+        }
+
+        let mut cai_block = Cai::new();
+
+        // a manifest with a compressed assertion store
+        let mut cai_store = CAIStore::new("cb.adobe_1", false);
+        let compressed = BrotliCompressedBox::compress(&assertion_store()).unwrap();
+        assert_eq!(compressed.box_type(), b"brob");
+        assert_eq!(compressed.original_type(), b"jumb");
+        cai_store.add_box(Box::new(compressed));
+        cai_block.add_box(Box::new(cai_store));
+
+        // a compressed manifest
+        let mut cai_store = CAIStore::new("cb.adobe_2", false);
+        cai_store.add_box(Box::new(assertion_store()));
+        cai_block.add_box(Box::new(BrotliCompressedBox::compress(&cai_store).unwrap()));
+
+        let mut mem_box: Vec<u8> = Vec::new();
+        cai_block.write_box(&mut mem_box).unwrap();
+
+        let mut buf_reader = Cursor::new(mem_box);
+        let super_box = BoxReader::read_super_box(&mut buf_reader).unwrap();
+        let cai_block = Cai::from(super_box);
+
+        // the compressed boxes are read back as the superboxes they replaced
+        assert_eq!(cai_block.data_box_count(), 2);
+        for (idx, label) in ["cb.adobe_1", "cb.adobe_2"].iter().enumerate() {
+            check_one_box(cai_block.super_box(), idx, 1, label, CAI_STORE_UUID);
+            let cai_store_box = cai_block.super_box().data_box_as_superbox(idx).unwrap();
+            check_one_box(
+                cai_store_box,
+                0,
+                1,
+                "c2pa.assertions",
+                CAI_ASSERTION_STORE_UUID,
+            );
+
+            let assertion_store_box = cai_store_box.data_box_as_superbox(0).unwrap();
+            let assertion_box = assertion_store_box.data_box_as_superbox(0).unwrap();
+            assert_eq!(assertion_box.desc_box().label(), "c2pa.location.broad");
+            assert_eq!(
+                assertion_box.data_box_as_json_box(0).unwrap().json().len(),
+                33
+            );
+        }
+    }
+
+    #[test]
+    fn compressed_box_too_large() {
+        // a tiny box that claims to expand to several megabytes of zeros
+        let padding =
+            BrotliCompressedBox::compress(&JUMBFPaddingContentBox::new(2_000_000)).unwrap();
+        let bomb = BrotliCompressedBox::new(*b"jumb", padding.data().to_vec());
+
+        let mut sbox = JUMBFSuperBox::new(labels::MANIFEST_STORE, Some(CAI_BLOCK_UUID));
+        sbox.add_data_box(Box::new(bomb));
+
+        let mut mem_box: Vec<u8> = Vec::new();
+        sbox.write_box(&mut mem_box).unwrap();
+
+        let mut buf_reader = Cursor::new(mem_box);
+        assert!(matches!(
+            BoxReader::read_super_box(&mut buf_reader),
+            Err(JumbfParseError::CompressedBoxTooLarge)
+        ));
+    }
+
+    #[test]
+    fn nested_compressed_box() {
+        let inner = JUMBFSuperBox::new("c2pa.assertions", Some(CAI_ASSERTION_STORE_UUID));
+
+        let mut middle = JUMBFSuperBox::new("cb.adobe_1", Some(CAI_STORE_UUID));
+        middle.add_data_box(Box::new(BrotliCompressedBox::compress(&inner).unwrap()));
+
+        let mut sbox = JUMBFSuperBox::new(labels::MANIFEST_STORE, Some(CAI_BLOCK_UUID));
+        sbox.add_data_box(Box::new(BrotliCompressedBox::compress(&middle).unwrap()));
+
+        let mut mem_box: Vec<u8> = Vec::new();
+        sbox.write_box(&mut mem_box).unwrap();
+
+        let mut buf_reader = Cursor::new(mem_box);
+        assert!(matches!(
+            BoxReader::read_super_box(&mut buf_reader),
+            Err(JumbfParseError::InvalidCompressedBox)
+        ));
+    }
+
     #[allow(dead_code)]
     fn check_one_box(
         parent_box: &JUMBFSuperBox,
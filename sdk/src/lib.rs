@@ -103,6 +103,9 @@ pub mod cose_sign;
 /// The create_signer module contains the definitions for the signers that are part of the C2PA specification.
 pub mod create_signer;
 
+/// The crjson module serializes manifest stores as Content Credentials JSON-LD.
+pub mod crjson;
+
 /// Cryptography primitives.
 #[doc(hidden)]
 pub mod crypto;
@@ -118,6 +121,15 @@ pub mod identity;
 /// The jumbf_io module contains the definitions for the JUMBF data in assets.
 pub mod jumbf_io;
 
+/// The manifest_diff module compares two manifest stores.
+pub mod manifest_diff;
+
+/// The progress module reports progress of, and cancels, long-running operations.
+pub mod progress;
+
+/// The provenance_graph module follows ingredients to the assets they came from.
+pub mod provenance_graph;
+
 /// The settings module provides a way to configure the C2PA SDK.
 pub mod settings;
 
@@ -137,7 +149,8 @@ pub use assertions::DigitalSourceType;
 #[doc(inline)]
 pub use assertions::Relationship;
 #[cfg(feature = "v1_api")]
-pub use asset_io::{CAIRead, CAIReadWrite};
+pub use asset_io::CAIReadWrite;
+pub use asset_io::{CAIRead, StreamFactory};
 pub use builder::{Builder, ManifestDefinition};
 pub use callback_signer::{CallbackFunc, CallbackSigner};
 pub use claim_generator_info::ClaimGeneratorInfo;
@@ -159,7 +172,7 @@ pub use manifest_assertion::{ManifestAssertion, ManifestAssertionKind};
 pub use manifest_store::ManifestStore;
 #[cfg(feature = "v1_api")]
 pub use manifest_store_report::ManifestStoreReport;
-pub use reader::Reader;
+pub use reader::{Reader, Redaction};
 #[doc(inline)]
 pub use resource_store::{ResourceRef, ResourceStore};
 #[cfg(feature = "v1_api")]
@@ -167,7 +180,9 @@ pub use signer::RemoteSigner;
 pub use signer::{AsyncSigner, Signer};
 pub use utils::mime::format_from_path;
 #[doc(inline)]
-pub use validation_results::{ValidationResults, ValidationState};
+pub use validation_results::{
+    ValidationCheck, ValidationLevel, ValidationResults, ValidationState,
+};
 
 // Internal modules
 pub(crate) mod assertion;
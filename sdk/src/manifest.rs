@@ -507,6 +507,11 @@ impl Manifest {
         Ok(self)
     }
 
+    /// Returns the URIs of the assertions this manifest redacted from its ingredients.
+    pub fn redactions(&self) -> Option<&[String]> {
+        self.redactions.as_deref()
+    }
+
     /// Returns the name of the signature issuer
     pub fn issuer(&self) -> Option<String> {
         self.signature_info.to_owned().and_then(|sig| sig.issuer)
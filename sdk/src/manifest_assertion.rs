@@ -93,6 +93,12 @@ impl ManifestAssertion {
         self.instance.unwrap_or(1)
     }
 
+    // The instance number the claim gives this assertion, which is 0 for the
+    // first use of a label, unlike `instance`.
+    pub(crate) fn claim_instance(&self) -> usize {
+        self.instance.unwrap_or_default()
+    }
+
     /// The ManifestAssertionKind for this assertion
     /// This refers to how the format of the assertion inside a C2PA manifest
     /// The default is ManifestAssertionKind::Cbor
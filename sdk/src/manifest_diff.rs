@@ -0,0 +1,743 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Compares two manifest stores.
+//!
+//! Manifests are matched by label. For each manifest that was added, removed
+//! or changed, the diff lists the claim fields, assertions, ingredients and
+//! actions that differ, along with any change to the signature.
+//!
+//! Assertions are matched by label and instance. When both sides carry the
+//! hashed URI of an assertion, the hashes are compared; otherwise the
+//! assertion data is compared.
+//!
+//! ```
+//! use std::io::Cursor;
+//!
+//! use c2pa::Reader;
+//!
+//! let reader = Reader::from_stream(
+//!     "image/jpeg",
+//!     Cursor::new(include_bytes!("../tests/fixtures/CA.jpg")),
+//! )?;
+//!
+//! let diff = reader.diff(&reader)?;
+//! assert!(diff.is_empty());
+//! println!("{}", diff.json());
+//! # Ok::<(), c2pa::Error>(())
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    assertions::{labels, Actions},
+    claim::Claim,
+    crypto::base64,
+    Error, Manifest, Reader, Result,
+};
+
+/// How an item differs between the two sides of a diff.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    /// The item only exists in the second store.
+    Added,
+    /// The item only exists in the first store.
+    Removed,
+    /// The item exists in both stores with different content.
+    Changed,
+}
+
+/// The values of a field on either side of a diff.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct ValueChange {
+    /// The value in the first store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+
+    /// The value in the second store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+}
+
+/// The differences between two manifest stores.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct ManifestStoreDiff {
+    /// The change of active manifest label, if it changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_manifest: Option<ValueChange>,
+
+    /// The manifests that differ, ordered by label.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub manifests: Vec<ManifestDiff>,
+}
+
+/// The differences in one manifest.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct ManifestDiff {
+    /// The manifest label.
+    pub label: String,
+
+    /// Whether the manifest was added, removed or changed.
+    pub kind: ChangeKind,
+
+    /// Claim fields that differ, such as `title` or `claim_generator_info`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, ValueChange>,
+
+    /// Assertions that differ.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assertions: Vec<AssertionDiff>,
+
+    /// Ingredients that differ.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ingredients: Vec<IngredientDiff>,
+
+    /// Actions that appear on only one side.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<ActionDiff>,
+
+    /// The signature information, if it differs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<ValueChange>,
+}
+
+/// An assertion that differs between two manifests.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct AssertionDiff {
+    /// The assertion label, with an instance suffix such as `__1` if needed.
+    pub label: String,
+
+    /// Whether the assertion was added, removed or changed.
+    pub kind: ChangeKind,
+
+    /// The base64 hash of the assertion in the first manifest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_hash: Option<String>,
+
+    /// The base64 hash of the assertion in the second manifest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_hash: Option<String>,
+}
+
+/// An ingredient that differs between two manifests.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct IngredientDiff {
+    /// The key the ingredients were matched by: the instance ID, or the title
+    /// if there is no instance ID.
+    pub key: String,
+
+    /// The title of the ingredient.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Whether the ingredient was added, removed or changed.
+    pub kind: ChangeKind,
+}
+
+/// An action that appears in only one of two manifests.
+///
+/// An actions assertion that cannot be decoded is reported as a single
+/// [`ChangeKind::Changed`] entry named by the assertion label, with the raw
+/// assertion data, when it differs between the manifests.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct ActionDiff {
+    /// The action name, such as `c2pa.opened`.
+    pub action: String,
+
+    /// Whether the action was added or removed, or for an undecodable actions
+    /// assertion, changed.
+    pub kind: ChangeKind,
+
+    /// The full action, or the raw data of an undecodable actions assertion.
+    pub data: Value,
+}
+
+impl ManifestStoreDiff {
+    /// Compares the manifest stores read by `before` and `after`.
+    pub fn new(before: &Reader, after: &Reader) -> Result<Self> {
+        let active_manifest =
+            (before.active_label() != after.active_label()).then(|| ValueChange {
+                before: before.active_label().map(Value::from),
+                after: after.active_label().map(Value::from),
+            });
+
+        let labels: BTreeSet<&String> = before
+            .manifests()
+            .keys()
+            .chain(after.manifests().keys())
+            .collect();
+
+        let mut manifests = Vec::new();
+        for label in labels {
+            let diff = ManifestDiff::new(
+                label,
+                before.manifests().get(label),
+                after.manifests().get(label),
+            )?;
+            if let Some(diff) = diff {
+                manifests.push(diff);
+            }
+        }
+
+        Ok(Self {
+            active_manifest,
+            manifests,
+        })
+    }
+
+    /// Returns `true` if the two stores have no differences.
+    pub fn is_empty(&self) -> bool {
+        self.active_manifest.is_none() && self.manifests.is_empty()
+    }
+
+    /// Returns the diff as a pretty-printed JSON string.
+    pub fn json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+impl ManifestDiff {
+    /// Compares two versions of the manifest with the given label.
+    ///
+    /// Pass `None` for a side where the manifest does not exist. Returns `None`
+    /// if the manifests have no differences.
+    pub fn new(
+        label: &str,
+        before: Option<&Manifest>,
+        after: Option<&Manifest>,
+    ) -> Result<Option<Self>> {
+        let kind = match (before, after) {
+            (None, None) => return Ok(None),
+            (None, Some(_)) => ChangeKind::Added,
+            (Some(_), None) => ChangeKind::Removed,
+            (Some(_), Some(_)) => ChangeKind::Changed,
+        };
+
+        let mut fields = BTreeMap::new();
+        for name in [
+            "title",
+            "format",
+            "instance_id",
+            "claim_generator",
+            "claim_generator_info",
+            "redactions",
+        ] {
+            let before = before.map(|m| field(m, name)).transpose()?.flatten();
+            let after = after.map(|m| field(m, name)).transpose()?.flatten();
+            if let Some(change) = value_change(before, after) {
+                fields.insert(name.to_owned(), change);
+            }
+        }
+
+        let signature = value_change(
+            before
+                .and_then(Manifest::signature_info)
+                .map(to_value)
+                .transpose()?,
+            after
+                .and_then(Manifest::signature_info)
+                .map(to_value)
+                .transpose()?,
+        );
+
+        let diff = Self {
+            label: label.to_owned(),
+            kind,
+            fields,
+            assertions: diff_assertions(before, after)?,
+            ingredients: diff_ingredients(before, after)?,
+            actions: diff_actions(before, after)?,
+            signature,
+        };
+
+        let unchanged = diff.kind == ChangeKind::Changed
+            && diff.fields.is_empty()
+            && diff.assertions.is_empty()
+            && diff.ingredients.is_empty()
+            && diff.actions.is_empty()
+            && diff.signature.is_none();
+        Ok((!unchanged).then_some(diff))
+    }
+}
+
+impl Reader {
+    /// Compares this manifest store with `other`.
+    ///
+    /// See the [`manifest_diff`](crate::manifest_diff) module for details.
+    pub fn diff(&self, other: &Reader) -> Result<ManifestStoreDiff> {
+        ManifestStoreDiff::new(self, other)
+    }
+}
+
+// what we know about one assertion when comparing
+#[derive(Default)]
+struct AssertionEntry {
+    hash: Option<String>,
+    data: Option<Value>,
+}
+
+fn assertion_entries(manifest: Option<&Manifest>) -> Result<BTreeMap<String, AssertionEntry>> {
+    let mut entries: BTreeMap<String, AssertionEntry> = BTreeMap::new();
+    let Some(manifest) = manifest else {
+        return Ok(entries);
+    };
+
+    // the hashed references include assertions, such as hard bindings, that
+    // are not listed in the manifest assertions. Both sides are keyed by the
+    // label the claim gives each instance, such as `c2pa.actions.v2__1`
+    for reference in manifest.assertion_references() {
+        let (label, instance) = Claim::assertion_label_from_link(&reference.url());
+        entries
+            .entry(Claim::label_with_instance(&label, instance))
+            .or_default()
+            .hash = Some(base64::encode(&reference.hash()));
+    }
+
+    for assertion in manifest.assertions() {
+        let data = match assertion.value() {
+            Ok(value) => value.clone(),
+            Err(_) => to_value(assertion)?,
+        };
+        // repeated labels may already carry their instance suffix
+        let (label, _) = Claim::assertion_label_from_link(assertion.label());
+        entries
+            .entry(Claim::label_with_instance(
+                &label,
+                assertion.claim_instance(),
+            ))
+            .or_default()
+            .data = Some(data);
+    }
+    Ok(entries)
+}
+
+fn diff_assertions(
+    before: Option<&Manifest>,
+    after: Option<&Manifest>,
+) -> Result<Vec<AssertionDiff>> {
+    let mut before = assertion_entries(before)?;
+    let mut after = assertion_entries(after)?;
+    let labels: BTreeSet<String> = before.keys().chain(after.keys()).cloned().collect();
+
+    Ok(labels
+        .into_iter()
+        .filter_map(|label| {
+            let old = before.remove(&label);
+            let new = after.remove(&label);
+            let kind = match (&old, &new) {
+                (Some(_), None) => ChangeKind::Removed,
+                (None, Some(_)) => ChangeKind::Added,
+                (Some(old), Some(new)) => {
+                    let changed = match (&old.hash, &new.hash) {
+                        (Some(a), Some(b)) => a != b,
+                        _ => old.data != new.data,
+                    };
+                    if !changed {
+                        return None;
+                    }
+                    ChangeKind::Changed
+                }
+                (None, None) => return None,
+            };
+            Some(AssertionDiff {
+                label,
+                kind,
+                before_hash: old.and_then(|e| e.hash),
+                after_hash: new.and_then(|e| e.hash),
+            })
+        })
+        .collect())
+}
+
+fn ingredient_entries(
+    manifest: Option<&Manifest>,
+) -> Result<BTreeMap<String, (Value, Option<String>)>> {
+    let mut entries = BTreeMap::new();
+    for ingredient in manifest.map(Manifest::ingredients).unwrap_or_default() {
+        // instance_id() reports a missing ID as "None"
+        let base = match (ingredient.instance_id(), ingredient.title()) {
+            ("None", Some(title)) => title.to_owned(),
+            ("None", None) => "ingredient".to_owned(),
+            (id, _) => id.to_owned(),
+        };
+        let mut key = base.clone();
+        let mut instance = 1;
+        while entries.contains_key(&key) {
+            instance += 1;
+            key = format!("{base}__{instance}");
+        }
+        entries.insert(
+            key,
+            (to_value(ingredient)?, ingredient.title().map(str::to_owned)),
+        );
+    }
+    Ok(entries)
+}
+
+fn diff_ingredients(
+    before: Option<&Manifest>,
+    after: Option<&Manifest>,
+) -> Result<Vec<IngredientDiff>> {
+    let mut before = ingredient_entries(before)?;
+    let mut after = ingredient_entries(after)?;
+    let keys: BTreeSet<String> = before.keys().chain(after.keys()).cloned().collect();
+
+    Ok(keys
+        .into_iter()
+        .filter_map(|key| {
+            let (kind, title) = match (before.remove(&key), after.remove(&key)) {
+                (Some((_, title)), None) => (ChangeKind::Removed, title),
+                (None, Some((_, title))) => (ChangeKind::Added, title),
+                (Some((old, _)), Some((new, title))) if old != new => (ChangeKind::Changed, title),
+                _ => return None,
+            };
+            Some(IngredientDiff { key, title, kind })
+        })
+        .collect())
+}
+
+// the decoded actions of a manifest, and the raw data of any actions
+// assertions that could not be decoded, by label
+fn action_values(manifest: Option<&Manifest>) -> Result<(Vec<Value>, BTreeMap<String, Value>)> {
+    let mut values = Vec::new();
+    let mut undecodable = BTreeMap::new();
+    for assertion in manifest.map(Manifest::assertions).unwrap_or_default() {
+        // the label carries a version suffix for v2 actions
+        if !assertion.label().starts_with(labels::ACTIONS) {
+            continue;
+        }
+        match assertion.to_assertion::<Actions>() {
+            Ok(actions) => {
+                for action in actions.actions() {
+                    values.push(to_value(action)?);
+                }
+            }
+            Err(_) => {
+                undecodable.insert(
+                    assertion.label_with_instance(),
+                    assertion.value().cloned().unwrap_or_default(),
+                );
+            }
+        }
+    }
+    Ok((values, undecodable))
+}
+
+fn diff_actions(before: Option<&Manifest>, after: Option<&Manifest>) -> Result<Vec<ActionDiff>> {
+    let (before, undecodable_before) = action_values(before)?;
+    let (mut after, undecodable_after) = action_values(after)?;
+
+    // actions assertions that cannot be decoded are compared by their raw data
+    let labels: BTreeSet<String> = undecodable_before
+        .keys()
+        .chain(undecodable_after.keys())
+        .cloned()
+        .collect();
+    let changed: Vec<ActionDiff> = labels
+        .into_iter()
+        .filter_map(|label| {
+            let old = undecodable_before.get(&label);
+            let new = undecodable_after.get(&label);
+            (old != new).then(|| ActionDiff {
+                data: new.or(old).cloned().unwrap_or_default(),
+                action: label,
+                kind: ChangeKind::Changed,
+            })
+        })
+        .collect();
+
+    // match actions one to one so that repeated actions are counted
+    let mut removed = Vec::new();
+    for action in before {
+        match after.iter().position(|a| *a == action) {
+            Some(index) => {
+                after.remove(index);
+            }
+            None => removed.push(action),
+        }
+    }
+
+    let action_diff = |kind, data: Value| ActionDiff {
+        action: data
+            .get("action")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned(),
+        kind,
+        data,
+    };
+    Ok(removed
+        .into_iter()
+        .map(|data| action_diff(ChangeKind::Removed, data))
+        .chain(
+            after
+                .into_iter()
+                .map(|data| action_diff(ChangeKind::Added, data)),
+        )
+        .chain(changed)
+        .collect())
+}
+
+// reads a top level field of the manifest's JSON form
+fn field(manifest: &Manifest, name: &str) -> Result<Option<Value>> {
+    let value = match name {
+        "title" => manifest.title().map(Value::from),
+        "format" => manifest.format().map(Value::from),
+        "instance_id" => Some(Value::from(manifest.instance_id())),
+        "claim_generator" => manifest.claim_generator().map(Value::from),
+        "claim_generator_info" => manifest
+            .claim_generator_info
+            .as_ref()
+            .map(to_value)
+            .transpose()?,
+        "redactions" => manifest.redactions().map(|r| to_value(&r)).transpose()?,
+        _ => None,
+    };
+    Ok(value)
+}
+
+fn value_change(before: Option<Value>, after: Option<Value>) -> Option<ValueChange> {
+    (before != after).then_some(ValueChange { before, after })
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value> {
+    serde_json::to_value(value).map_err(Error::JsonError)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::Cursor;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{utils::test_signer::test_signer, Builder, SigningAlg};
+
+    const CA_JPG: &[u8] = include_bytes!("../tests/fixtures/CA.jpg");
+
+    #[test]
+    fn diff_same_store_is_empty() {
+        let reader = Reader::from_stream("image/jpeg", Cursor::new(CA_JPG)).unwrap();
+        let diff = reader.diff(&reader).unwrap();
+        assert!(diff.is_empty());
+        assert_eq!(diff.json(), "{}");
+    }
+
+    #[test]
+    fn diff_reports_added_manifest() {
+        let before = Reader::from_stream("image/jpeg", Cursor::new(CA_JPG)).unwrap();
+
+        let mut builder = Builder::from_json(
+            &json!({
+                "title": "edited.jpg",
+                "assertions": [{
+                    "label": "c2pa.actions",
+                    "data": { "actions": [{ "action": "c2pa.edited" }] }
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+        builder
+            .add_ingredient_from_stream(
+                json!({ "title": "CA.jpg", "relationship": "parentOf" }).to_string(),
+                "image/jpeg",
+                &mut Cursor::new(CA_JPG),
+            )
+            .unwrap();
+        let mut dest = Cursor::new(Vec::new());
+        builder
+            .sign(
+                &test_signer(SigningAlg::Ps256),
+                "image/jpeg",
+                &mut Cursor::new(CA_JPG),
+                &mut dest,
+            )
+            .unwrap();
+        dest.set_position(0);
+        let after = Reader::from_stream("image/jpeg", dest).unwrap();
+
+        let diff = before.diff(&after).unwrap();
+        let active = after.active_label().unwrap();
+        assert_eq!(
+            diff.active_manifest.as_ref().unwrap().after,
+            Some(json!(active))
+        );
+
+        // the original manifest is carried over unchanged
+        assert_eq!(diff.manifests.len(), 1);
+        let added = &diff.manifests[0];
+        assert_eq!(added.label, active);
+        assert_eq!(added.kind, ChangeKind::Added);
+        assert_eq!(added.ingredients.len(), 1);
+        assert_eq!(added.ingredients[0].title.as_deref(), Some("CA.jpg"));
+        assert!(added
+            .actions
+            .iter()
+            .any(|a| a.action == "c2pa.edited" && a.kind == ChangeKind::Added));
+        assert!(added.assertions.iter().all(|a| a.after_hash.is_some()));
+        assert!(added.signature.is_some());
+
+        let json: Value = serde_json::from_str(&diff.json()).unwrap();
+        assert_eq!(json["manifests"][0]["kind"], "added");
+    }
+
+    #[test]
+    fn diff_reports_changed_fields_and_assertions() {
+        let reader = Reader::from_stream("image/jpeg", Cursor::new(CA_JPG)).unwrap();
+        let label = reader.active_label().unwrap().to_owned();
+
+        let mut json: Value = serde_json::from_str(&reader.json()).unwrap();
+        let manifest = &mut json["manifests"][&label];
+        manifest["title"] = json!("renamed.jpg");
+        manifest["assertions"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({ "label": "org.example.test", "data": { "value": 1 } }));
+        let changed = Reader::from_json(&json.to_string()).unwrap();
+
+        // readers built from JSON have no hashes, so the data is compared
+        let original = Reader::from_json(&reader.json()).unwrap();
+        let diff = original.diff(&changed).unwrap();
+        assert!(diff.active_manifest.is_none());
+        assert_eq!(diff.manifests.len(), 1);
+
+        let manifest = &diff.manifests[0];
+        assert_eq!(manifest.kind, ChangeKind::Changed);
+        assert_eq!(manifest.fields["title"].after, Some(json!("renamed.jpg")));
+        assert_eq!(manifest.assertions.len(), 1);
+        assert_eq!(manifest.assertions[0].label, "org.example.test");
+        assert_eq!(manifest.assertions[0].kind, ChangeKind::Added);
+        assert!(manifest.ingredients.is_empty());
+        assert!(manifest.actions.is_empty());
+        assert!(manifest.signature.is_none());
+    }
+
+    #[test]
+    fn diff_reports_undecodable_actions() {
+        let reader = Reader::from_stream("image/jpeg", Cursor::new(CA_JPG)).unwrap();
+        let label = reader.active_label().unwrap().to_owned();
+
+        let mut json: Value = serde_json::from_str(&reader.json()).unwrap();
+        let actions = json["manifests"][&label]["assertions"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|a| a["label"].as_str().unwrap().starts_with(labels::ACTIONS))
+            .unwrap();
+        let actions_label = actions["label"].as_str().unwrap().to_owned();
+        actions["data"] = json!({ "actions": "not a list" });
+        let changed = Reader::from_json(&json.to_string()).unwrap();
+
+        let original = Reader::from_json(&reader.json()).unwrap();
+        let diff = original.diff(&changed).unwrap();
+        let manifest = &diff.manifests[0];
+        assert_eq!(manifest.assertions.len(), 1);
+
+        // the original actions are gone and the undecodable assertion is reported as changed
+        let undecodable: Vec<_> = manifest
+            .actions
+            .iter()
+            .filter(|a| a.kind == ChangeKind::Changed)
+            .collect();
+        assert_eq!(undecodable.len(), 1);
+        assert_eq!(undecodable[0].action, actions_label);
+        assert_eq!(undecodable[0].data, json!({ "actions": "not a list" }));
+        assert!(manifest
+            .actions
+            .iter()
+            .any(|a| a.kind == ChangeKind::Removed));
+
+        // the same undecodable data on both sides is not a change
+        assert!(changed.diff(&changed).unwrap().manifests.is_empty());
+    }
+
+    #[test]
+    fn diff_keys_repeated_assertions_by_instance() {
+        let mut builder = Builder::from_json(
+            &json!({
+                "title": "repeated.jpg",
+                "assertions": [
+                    { "label": "org.example.test", "data": { "value": 1 } },
+                    { "label": "org.example.test", "data": { "value": 2 } },
+                    { "label": "org.example.test", "data": { "value": 3 } }
+                ]
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let mut dest = Cursor::new(Vec::new());
+        builder
+            .sign(
+                &test_signer(SigningAlg::Ps256),
+                "image/jpeg",
+                &mut Cursor::new(CA_JPG),
+                &mut dest,
+            )
+            .unwrap();
+        dest.set_position(0);
+        let reader = Reader::from_stream("image/jpeg", dest).unwrap();
+
+        // every listed assertion lines up with its hashed reference
+        let entries = assertion_entries(reader.active_manifest()).unwrap();
+        assert_eq!(
+            entries["org.example.test"].data,
+            Some(json!({ "value": 1 }))
+        );
+        assert_eq!(
+            entries["org.example.test__1"].data,
+            Some(json!({ "value": 2 }))
+        );
+        assert_eq!(
+            entries["org.example.test__2"].data,
+            Some(json!({ "value": 3 }))
+        );
+        assert!(entries.values().all(|e| e.hash.is_some()));
+
+        // a change to the third instance is reported against that instance
+        let label = reader.active_label().unwrap().to_owned();
+        let mut json: Value = serde_json::from_str(&reader.json()).unwrap();
+        for assertion in json["manifests"][&label]["assertions"]
+            .as_array_mut()
+            .unwrap()
+        {
+            if assertion["data"] == json!({ "value": 3 }) {
+                assertion["data"] = json!({ "value": 4 });
+            }
+        }
+        let changed = Reader::from_json(&json.to_string()).unwrap();
+
+        // readers built from JSON have no hashes, so compare like with like
+        let reader = Reader::from_json(&reader.json()).unwrap();
+        let diff = reader.diff(&changed).unwrap();
+        let assertions = &diff.manifests[0].assertions;
+        assert_eq!(assertions.len(), 1, "{assertions:?}");
+        assert_eq!(assertions[0].label, "org.example.test__2");
+        assert_eq!(assertions[0].kind, ChangeKind::Changed);
+    }
+}
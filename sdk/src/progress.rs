@@ -0,0 +1,460 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Progress reporting and cancellation for long-running operations.
+//!
+//! Hashing and copying asset data are the slow parts of signing and validating
+//! large assets. While a [`ProgressScope`] is alive, those steps report the
+//! bytes they have processed to its callback. If the callback returns `false`,
+//! the operation stops at the next chunk and returns
+//! [`Error::OperationCancelled`].
+//!
+//! Like [`settings`](crate::settings), the scope applies to the current thread.
+//! Async operations can move between threads, so use [`with_progress`] for
+//! those instead.
+//!
+//! ```
+//! use std::io::Cursor;
+//!
+//! use c2pa::{progress::ProgressScope, Error, Reader};
+//!
+//! let _scope = ProgressScope::new(|progress| {
+//!     println!("{:?}: {} bytes", progress.phase, progress.bytes_processed);
+//!     false // cancel at the first report
+//! });
+//!
+//! let result = Reader::from_stream(
+//!     "image/jpeg",
+//!     Cursor::new(include_bytes!("../tests/fixtures/CA.jpg")),
+//! );
+//! assert!(matches!(result, Err(Error::OperationCancelled)));
+//! ```
+
+use std::{
+    cell::RefCell,
+    future::Future,
+    io::{Read, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use serde::Serialize;
+
+use crate::{Error, Result};
+
+// Smaller chunks than the hashing code would otherwise use, so that
+// reports arrive often enough to drive a progress bar.
+const PROGRESS_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// A callback that receives [`Progress`] reports.
+///
+/// Return `true` to continue or `false` to cancel the operation.
+pub type ProgressCallback = dyn Fn(&Progress) -> bool + Send + Sync;
+
+/// The kind of work an operation is doing when it reports progress.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressPhase {
+    /// Hashing asset data to create a hard binding while signing.
+    Hashing,
+
+    /// Hashing asset data to check a hard binding while validating.
+    Validating,
+
+    /// Copying asset data to the output while embedding a manifest.
+    Writing,
+}
+
+/// A progress report for one step of an operation.
+///
+/// Signing or validating an asset can take several steps in the same phase,
+/// for example one per hash binding, so `bytes_processed` starts from zero at
+/// each step.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct Progress {
+    /// What the operation is doing.
+    pub phase: ProgressPhase,
+
+    /// Bytes processed so far in this step.
+    pub bytes_processed: u64,
+
+    /// Total bytes this step will process, when known.
+    pub total_bytes: Option<u64>,
+}
+
+struct ScopeState {
+    id: u64,
+    callback: Arc<ProgressCallback>,
+    phase: Mutex<Option<ProgressPhase>>,
+    cancelled: AtomicBool,
+}
+
+impl ScopeState {
+    fn new(callback: Arc<ProgressCallback>) -> Arc<Self> {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Arc::new(Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            callback,
+            phase: Mutex::new(None),
+            cancelled: AtomicBool::new(false),
+        })
+    }
+
+    fn phase(&self) -> Option<ProgressPhase> {
+        *self.phase.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn set_phase(&self, phase: Option<ProgressPhase>) -> Option<ProgressPhase> {
+        std::mem::replace(
+            &mut self.phase.lock().unwrap_or_else(|e| e.into_inner()),
+            phase,
+        )
+    }
+}
+
+thread_local! {
+    static SCOPES: RefCell<Vec<Arc<ScopeState>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn enter(state: Arc<ScopeState>) -> u64 {
+    let id = state.id;
+    SCOPES.with_borrow_mut(|scopes| scopes.push(state));
+    id
+}
+
+// Scopes can be dropped out of order, so remove this one rather than the last.
+fn leave(id: u64) {
+    SCOPES.with_borrow_mut(|scopes| {
+        if let Some(index) = scopes.iter().rposition(|scope| scope.id == id) {
+            scopes.remove(index);
+        }
+    });
+}
+
+fn innermost() -> Option<Arc<ScopeState>> {
+    SCOPES.with_borrow(|scopes| scopes.last().cloned())
+}
+
+/// Reports progress of operations on the current thread until dropped.
+///
+/// Scopes may be nested; only the innermost scope receives reports.
+///
+/// A scope doesn't follow an async task to another thread. For async
+/// operations, use [`with_progress`] instead.
+#[must_use = "progress is only reported while the scope is alive"]
+pub struct ProgressScope {
+    id: u64,
+    // Scopes are tied to the thread that created them.
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl ProgressScope {
+    /// Start reporting progress to `callback`.
+    pub fn new<F>(callback: F) -> Self
+    where
+        F: Fn(&Progress) -> bool + Send + Sync + 'static,
+    {
+        Self::from_callback(Arc::new(callback))
+    }
+
+    /// Start reporting progress to a shared callback.
+    pub fn from_callback(callback: Arc<ProgressCallback>) -> Self {
+        Self {
+            id: enter(ScopeState::new(callback)),
+            _not_send: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Drop for ProgressScope {
+    fn drop(&mut self) {
+        leave(self.id);
+    }
+}
+
+/// Run `future`, reporting the progress of the operations it performs to
+/// `callback`.
+///
+/// Unlike a [`ProgressScope`], the callback follows the future to whichever
+/// thread polls it, so this works with multi-threaded async runtimes.
+///
+/// ```
+/// use std::io::Cursor;
+///
+/// use c2pa::{progress::with_progress, Error, Reader};
+///
+/// # async fn example() {
+/// let result = with_progress(
+///     |_progress| false, // cancel at the first report
+///     Reader::from_stream_async(
+///         "image/jpeg",
+///         Cursor::new(include_bytes!("../tests/fixtures/CA.jpg")),
+///     ),
+/// )
+/// .await;
+/// assert!(matches!(result, Err(Error::OperationCancelled)));
+/// # }
+/// ```
+pub async fn with_progress<C, F>(callback: C, future: F) -> F::Output
+where
+    C: Fn(&Progress) -> bool + Send + Sync + 'static,
+    F: Future,
+{
+    let state = ScopeState::new(Arc::new(callback));
+    let mut future = std::pin::pin!(future);
+
+    // Enter the scope on whichever thread polls the future, for that poll only.
+    std::future::poll_fn(move |cx| {
+        let _entered = Entered(enter(state.clone()));
+        future.as_mut().poll(cx)
+    })
+    .await
+}
+
+// Leaves a scope when dropped, even if polling panics.
+struct Entered(u64);
+
+impl Drop for Entered {
+    fn drop(&mut self) {
+        leave(self.0);
+    }
+}
+
+/// Sets the phase reported by hashing until the returned guard is dropped.
+pub(crate) struct PhaseGuard {
+    scope: Option<Arc<ScopeState>>,
+    previous: Option<ProgressPhase>,
+}
+
+impl Drop for PhaseGuard {
+    fn drop(&mut self) {
+        if let Some(scope) = &self.scope {
+            scope.set_phase(self.previous);
+        }
+    }
+}
+
+/// Report hashing done from here on as `phase`.
+pub(crate) fn enter_phase(phase: ProgressPhase) -> PhaseGuard {
+    let scope = innermost();
+    let previous = scope
+        .as_ref()
+        .and_then(|scope| scope.set_phase(Some(phase)));
+
+    PhaseGuard { scope, previous }
+}
+
+/// The phase to report for hashing, which depends on whether the caller is
+/// signing or validating.
+pub(crate) fn hash_phase() -> ProgressPhase {
+    innermost()
+        .and_then(|scope| scope.phase())
+        .unwrap_or(ProgressPhase::Hashing)
+}
+
+/// The active scope's callback and hashing phase, to hand to worker threads.
+pub(crate) fn current() -> Option<(Arc<ProgressCallback>, ProgressPhase)> {
+    innermost().map(|scope| {
+        (
+            scope.callback.clone(),
+            scope.phase().unwrap_or(ProgressPhase::Hashing),
+        )
+    })
+}
+
+/// Returns `true` if a [`ProgressScope`] is active on this thread.
+pub(crate) fn is_active() -> bool {
+    SCOPES.with_borrow(|scopes| !scopes.is_empty())
+}
+
+/// The chunk size to use for a step that would otherwise read `max` bytes at a time.
+pub(crate) fn chunk_size(max: usize) -> usize {
+    if is_active() {
+        max.min(PROGRESS_CHUNK_SIZE)
+    } else {
+        max
+    }
+}
+
+/// Report progress to the active scope, if any.
+///
+/// Returns [`Error::OperationCancelled`] if the callback asks to cancel, or
+/// has already done so.
+pub(crate) fn report(
+    phase: ProgressPhase,
+    bytes_processed: u64,
+    total_bytes: Option<u64>,
+) -> Result<()> {
+    let Some(scope) = innermost() else {
+        return Ok(());
+    };
+
+    // Call back without holding the borrow, so the callback can use the SDK.
+    let keep_going = !scope.cancelled.load(Ordering::Relaxed)
+        && (scope.callback)(&Progress {
+            phase,
+            bytes_processed,
+            total_bytes,
+        });
+
+    if keep_going {
+        Ok(())
+    } else {
+        scope.cancelled.store(true, Ordering::Relaxed);
+        Err(Error::OperationCancelled)
+    }
+}
+
+/// Copy all of `reader` to `writer`, reporting progress as [`ProgressPhase::Writing`].
+pub(crate) fn copy<R, W>(reader: &mut R, writer: &mut W) -> Result<u64>
+where
+    R: Read + ?Sized,
+    W: Write + ?Sized,
+{
+    if !is_active() {
+        return Ok(std::io::copy(reader, writer)?);
+    }
+
+    let mut buf = vec![0u8; 64 * 1024];
+    let mut copied = 0u64;
+    let mut last_report = 0u64;
+
+    loop {
+        let len = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        writer.write_all(&buf[..len])?;
+        copied += len as u64;
+
+        if copied - last_report >= PROGRESS_CHUNK_SIZE as u64 {
+            report(ProgressPhase::Writing, copied, None)?;
+            last_report = copied;
+        }
+    }
+
+    report(ProgressPhase::Writing, copied, Some(copied))?;
+    Ok(copied)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{io::Cursor, sync::Mutex};
+
+    use super::*;
+    use crate::utils::hash_utils::hash_stream_by_alg;
+
+    #[test]
+    fn reports_and_cancels() {
+        let data = vec![7u8; 3 * PROGRESS_CHUNK_SIZE];
+
+        // No scope, nothing to report to.
+        assert!(report(ProgressPhase::Hashing, 1, None).is_ok());
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        {
+            let reports = reports.clone();
+            let _scope = ProgressScope::new(move |progress| {
+                reports.lock().unwrap().push(*progress);
+                true
+            });
+
+            hash_stream_by_alg("sha256", &mut Cursor::new(&data), None, true).unwrap();
+
+            let _phase = enter_phase(ProgressPhase::Validating);
+            let mut output = Vec::new();
+            copy(&mut Cursor::new(&data), &mut output).unwrap();
+            assert_eq!(output, data);
+        }
+
+        let reports = reports.lock().unwrap();
+        let hashing: Vec<_> = reports
+            .iter()
+            .filter(|p| p.phase == ProgressPhase::Hashing)
+            .collect();
+        assert_eq!(hashing.len(), 3);
+        assert_eq!(hashing[2].bytes_processed, data.len() as u64);
+        assert_eq!(hashing[2].total_bytes, Some(data.len() as u64));
+        assert_eq!(
+            reports.last().unwrap().phase,
+            ProgressPhase::Writing,
+            "copies always report as writing"
+        );
+
+        // Cancel at the first report.
+        let _scope = ProgressScope::new(|progress| progress.bytes_processed == 0);
+        assert!(matches!(
+            hash_stream_by_alg("sha256", &mut Cursor::new(&data), None, true),
+            Err(Error::OperationCancelled)
+        ));
+
+        // Nested scopes are independent.
+        {
+            let _inner = ProgressScope::new(|_| true);
+            assert!(report(ProgressPhase::Hashing, 1, None).is_ok());
+        }
+
+        // Once cancelled, the scope stays cancelled.
+        assert!(matches!(
+            report(ProgressPhase::Writing, 1, None),
+            Err(Error::OperationCancelled)
+        ));
+    }
+
+    #[test]
+    fn scopes_dropped_out_of_order() {
+        let outer = ProgressScope::new(|_| false);
+        let inner = ProgressScope::new(|_| true);
+
+        // Dropping the outer scope leaves the inner one receiving reports.
+        drop(outer);
+        assert!(report(ProgressPhase::Hashing, 1, None).is_ok());
+
+        drop(inner);
+        assert!(!is_active());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn with_progress_follows_the_task() {
+        let data = vec![7u8; 2 * PROGRESS_CHUNK_SIZE];
+        let reports = Arc::new(Mutex::new(0));
+
+        let counted = reports.clone();
+        let result = tokio::spawn(with_progress(
+            move |_| {
+                *counted.lock().unwrap() += 1;
+                true
+            },
+            async move {
+                // Give the runtime a chance to resume the task on another thread.
+                tokio::task::yield_now().await;
+                hash_stream_by_alg("sha256", &mut Cursor::new(&data), None, true)
+            },
+        ))
+        .await
+        .unwrap();
+
+        assert!(result.is_ok());
+        assert_eq!(*reports.lock().unwrap(), 2);
+        assert!(!is_active());
+    }
+}
@@ -0,0 +1,547 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+//! Follow ingredients to the assets they came from.
+//!
+//! A [`Reader`](crate::Reader) only sees the manifests embedded in one asset.
+//! [`ProvenanceGraph`] instead asks an [`AssetRepository`] for the file behind
+//! each ingredient, validates it, checks that its manifest is the one the
+//! ingredient recorded, and then walks that file's ingredients in turn.
+//!
+//! ```
+//! use std::io::Cursor;
+//!
+//! use c2pa::provenance_graph::{IngredientLink, LinkStatus, ProvenanceGraph, RepositoryAsset};
+//!
+//! // A repository that has none of the ingredients.
+//! let repository = |_link: &IngredientLink| -> c2pa::Result<Option<RepositoryAsset>> { Ok(None) };
+//!
+//! let graph = ProvenanceGraph::build(
+//!     "CA.jpg",
+//!     "image/jpeg",
+//!     Cursor::new(include_bytes!("../tests/fixtures/CA.jpg")),
+//!     &repository,
+//! )?;
+//!
+//! assert!(graph
+//!     .links()
+//!     .iter()
+//!     .all(|link| link.status == LinkStatus::Missing));
+//! # Ok::<(), c2pa::Error>(())
+//! ```
+
+use std::{collections::HashMap, io::Seek};
+
+use serde::Serialize;
+
+use crate::{
+    assertion::AssertionBase,
+    assertions::{Ingredient, Relationship},
+    asset_io::CAIRead,
+    crypto::base64,
+    settings::get_settings_value,
+    status_tracker::StatusTracker,
+    store::Store,
+    utils::hash_utils::{hash_stream_by_alg, vec_compare},
+    Error, Result, ValidationResults, ValidationState,
+};
+
+/// The identifiers an ingredient recorded for the asset it came from.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct IngredientLink {
+    /// The title of the ingredient.
+    pub title: Option<String>,
+
+    /// The media type of the ingredient.
+    pub format: Option<String>,
+
+    /// The XMP instance ID of the ingredient.
+    pub instance_id: Option<String>,
+
+    /// The XMP document ID of the ingredient.
+    pub document_id: Option<String>,
+
+    /// The label of the ingredient's active manifest, if it had one.
+    pub manifest_label: Option<String>,
+
+    /// The base64 hash of the ingredient's active manifest, if it had one.
+    pub manifest_hash: Option<String>,
+
+    /// How the ingredient was used.
+    pub relationship: Relationship,
+}
+
+/// An asset found in an [`AssetRepository`].
+pub struct RepositoryAsset {
+    /// An identifier that is unique within the repository, such as a path or
+    /// content address. It is used to recognize assets that are reached more
+    /// than once.
+    pub id: String,
+
+    /// The media type or extension of the asset.
+    pub format: String,
+
+    /// The asset data.
+    pub data: Box<dyn CAIRead>,
+}
+
+/// Locates the assets that ingredients came from.
+///
+/// Closures with the same signature as [`AssetRepository::find`] implement this trait.
+pub trait AssetRepository {
+    /// Returns the asset matching `link`, or `None` if it is not in the repository.
+    fn find(&self, link: &IngredientLink) -> Result<Option<RepositoryAsset>>;
+}
+
+impl<F> AssetRepository for F
+where
+    F: Fn(&IngredientLink) -> Result<Option<RepositoryAsset>>,
+{
+    fn find(&self, link: &IngredientLink) -> Result<Option<RepositoryAsset>> {
+        self(link)
+    }
+}
+
+/// An asset in a [`ProvenanceGraph`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProvenanceNode {
+    /// The repository identifier of the asset.
+    pub id: String,
+
+    /// The media type or extension of the asset.
+    pub format: String,
+
+    /// The label of the asset's active manifest, if it has one.
+    pub manifest_label: Option<String>,
+
+    /// The result of validating the asset's manifest store, if it has one.
+    pub validation_state: Option<ValidationState>,
+
+    /// Why the asset's manifest store could not be read, if it could not.
+    pub error: Option<String>,
+}
+
+/// How an ingredient relates to the asset located for it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkStatus {
+    /// The located asset has the manifest the ingredient recorded or, for an
+    /// ingredient without a manifest, the data hash it recorded.
+    Matched,
+
+    /// The located asset does not have the manifest or data hash the ingredient recorded.
+    Mismatched,
+
+    /// An asset was located, but the ingredient recorded neither a manifest nor a
+    /// data hash to check it against.
+    Unverified,
+
+    /// The repository has no asset for the ingredient.
+    Missing,
+
+    /// The located asset is already on the path from the root, so following it would loop.
+    Cycle,
+}
+
+/// An ingredient, from the asset that used it to the asset located for it.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProvenanceLink {
+    /// The index of the node for the asset that has the ingredient.
+    pub parent: usize,
+
+    /// The index of the node for the located asset, unless it is missing.
+    pub child: Option<usize>,
+
+    /// The identifiers the ingredient recorded.
+    pub ingredient: IngredientLink,
+
+    /// Whether the located asset matches the ingredient.
+    pub status: LinkStatus,
+}
+
+/// The provenance of an asset, as found by following its ingredients through
+/// an [`AssetRepository`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ProvenanceGraph {
+    nodes: Vec<ProvenanceNode>,
+    links: Vec<ProvenanceLink>,
+}
+
+impl ProvenanceGraph {
+    /// Builds the graph for an asset, looking its ingredients up in `repository`.
+    ///
+    /// `id` identifies the root asset, so that an ingredient that turns out to
+    /// be the root is reported as a cycle.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn build(
+        id: &str,
+        format: &str,
+        stream: impl std::io::Read + std::io::Seek + Send + 'static,
+        repository: &dyn AssetRepository,
+    ) -> Result<Self> {
+        Self::build_from_asset(
+            RepositoryAsset {
+                id: id.to_owned(),
+                format: format.to_owned(),
+                data: Box::new(stream),
+            },
+            repository,
+        )
+    }
+
+    /// Builds the graph for an asset, looking its ingredients up in `repository`.
+    ///
+    /// `id` identifies the root asset, so that an ingredient that turns out to
+    /// be the root is reported as a cycle.
+    #[cfg(target_arch = "wasm32")]
+    pub fn build(
+        id: &str,
+        format: &str,
+        stream: impl std::io::Read + std::io::Seek + 'static,
+        repository: &dyn AssetRepository,
+    ) -> Result<Self> {
+        Self::build_from_asset(
+            RepositoryAsset {
+                id: id.to_owned(),
+                format: format.to_owned(),
+                data: Box::new(stream),
+            },
+            repository,
+        )
+    }
+
+    /// Builds the graph for an asset that is already in the repository.
+    pub fn build_from_asset(
+        root: RepositoryAsset,
+        repository: &dyn AssetRepository,
+    ) -> Result<Self> {
+        let mut walk = Walk {
+            graph: Self::default(),
+            stores: Vec::new(),
+            streams: Vec::new(),
+            visited: HashMap::new(),
+            path: Vec::new(),
+            repository,
+        };
+
+        walk.visit(root)?;
+        Ok(walk.graph)
+    }
+
+    /// The assets in the graph. The first is the root.
+    pub fn nodes(&self) -> &[ProvenanceNode] {
+        &self.nodes
+    }
+
+    /// The ingredient links between assets.
+    pub fn links(&self) -> &[ProvenanceLink] {
+        &self.links
+    }
+
+    /// The links for the ingredients of the node at `index`.
+    pub fn ingredients_of(&self, index: usize) -> impl Iterator<Item = &ProvenanceLink> {
+        self.links.iter().filter(move |link| link.parent == index)
+    }
+
+    /// Returns `true` if every ingredient was located and matched.
+    pub fn is_complete(&self) -> bool {
+        self.links
+            .iter()
+            .all(|link| link.status == LinkStatus::Matched)
+    }
+}
+
+// state of a depth first walk over the ingredients
+struct Walk<'a> {
+    graph: ProvenanceGraph,
+    stores: Vec<Option<Store>>, // manifest store of each node, by index
+    streams: Vec<Box<dyn CAIRead>>, // asset data of each node, by index
+    visited: HashMap<String, usize>,
+    path: Vec<usize>, // nodes from the root to the current one
+    repository: &'a dyn AssetRepository,
+}
+
+impl Walk<'_> {
+    // adds the asset and everything reachable from it, returning its node index
+    fn visit(&mut self, mut asset: RepositoryAsset) -> Result<usize> {
+        let index = self.graph.nodes.len();
+        let (node, store) = load_asset(&mut asset);
+        self.visited.insert(asset.id, index);
+        self.graph.nodes.push(node);
+        self.stores.push(store);
+        self.streams.push(asset.data);
+        self.path.push(index);
+
+        let ingredients: Vec<Ingredient> = self.stores[index]
+            .as_ref()
+            .and_then(Store::provenance_claim)
+            .map(|claim| claim.ingredient_assertions())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|assertion| Ingredient::from_assertion(assertion.assertion()).ok())
+            .collect();
+
+        for ingredient in &ingredients {
+            let link = IngredientLink::from_assertion(ingredient);
+
+            let (child, status) = match self.repository.find(&link)? {
+                None => (None, LinkStatus::Missing),
+                // an asset reached again is only a cycle if it leads back to itself
+                Some(found) => match self.visited.get(&found.id) {
+                    Some(&child) if self.path.contains(&child) => (Some(child), LinkStatus::Cycle),
+                    Some(&child) => (Some(child), self.link_status(child, ingredient)),
+                    None => {
+                        let child = self.visit(found)?;
+                        (Some(child), self.link_status(child, ingredient))
+                    }
+                },
+            };
+
+            self.graph.links.push(ProvenanceLink {
+                parent: index,
+                child,
+                ingredient: link,
+                status,
+            });
+        }
+
+        self.path.pop();
+        Ok(index)
+    }
+
+    // checks the located asset has the manifest the ingredient recorded,
+    // by the hashes that validation uses for ingredients
+    fn link_status(&mut self, child: usize, ingredient: &Ingredient) -> LinkStatus {
+        let Some(c2pa_manifest) = ingredient.c2pa_manifest() else {
+            return self.data_status(child, ingredient);
+        };
+
+        let label = Store::manifest_label_from_path(&c2pa_manifest.url());
+        let Some((store, claim)) = self.stores[child]
+            .as_ref()
+            .filter(|store| store.provenance_label().as_deref() == Some(label.as_str()))
+            .and_then(|store| Some((store, store.provenance_claim()?)))
+        else {
+            return LinkStatus::Mismatched;
+        };
+
+        let hashes = store.get_manifest_box_hashes(claim);
+
+        // a later redaction changes the manifest but not the signature
+        let signature_matches = ingredient
+            .signature()
+            .is_some_and(|signature| vec_compare(&signature.hash(), &hashes.signature_box_hash));
+
+        if vec_compare(&c2pa_manifest.hash(), &hashes.manifest_box_hash) || signature_matches {
+            LinkStatus::Matched
+        } else {
+            LinkStatus::Mismatched
+        }
+    }
+
+    // checks the located asset against the hash of the external data an
+    // ingredient without a manifest recorded
+    fn data_status(&mut self, child: usize, ingredient: &Ingredient) -> LinkStatus {
+        let Some(data) = ingredient
+            .data
+            .as_ref()
+            .filter(|data| !data.url().starts_with("self#jumbf="))
+        else {
+            return LinkStatus::Unverified;
+        };
+
+        let alg = data.alg().unwrap_or_else(|| "sha256".to_owned());
+        let stream = &mut self.streams[child];
+        let hash = stream
+            .rewind()
+            .map_err(Error::from)
+            .and_then(|_| hash_stream_by_alg(&alg, stream, None, true));
+
+        match hash {
+            Ok(hash) if vec_compare(&hash, &data.hash()) => LinkStatus::Matched,
+            _ => LinkStatus::Mismatched,
+        }
+    }
+}
+
+// reads and validates the manifest store of an asset, if it has one
+fn load_asset(asset: &mut RepositoryAsset) -> (ProvenanceNode, Option<Store>) {
+    let mut node = ProvenanceNode {
+        id: asset.id.clone(),
+        format: asset.format.clone(),
+        manifest_label: None,
+        validation_state: None,
+        error: None,
+    };
+
+    let verify = get_settings_value::<bool>("verify.verify_after_reading").unwrap_or(true);
+    let mut validation_log = StatusTracker::default();
+
+    match Store::from_stream(&asset.format, &mut asset.data, verify, &mut validation_log) {
+        Ok(store) => {
+            node.manifest_label = store.provenance_label();
+            if verify {
+                let results = ValidationResults::from_store(&store, &validation_log);
+                node.validation_state = Some(results.validation_state());
+            }
+            (node, Some(store))
+        }
+        Err(Error::JumbfNotFound) => (node, None),
+        Err(e) => {
+            node.error = Some(e.to_string());
+            (node, None)
+        }
+    }
+}
+
+impl IngredientLink {
+    fn from_assertion(ingredient: &Ingredient) -> Self {
+        let manifest = ingredient.c2pa_manifest();
+
+        Self {
+            title: ingredient.title.clone(),
+            format: ingredient.format.clone(),
+            instance_id: ingredient.instance_id.clone(),
+            document_id: ingredient.document_id.clone(),
+            manifest_label: manifest
+                .as_ref()
+                .map(|m| Store::manifest_label_from_path(&m.url())),
+            manifest_hash: manifest.map(|m| base64::encode(&m.hash())),
+            relationship: ingredient.relationship.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::{
+        hashed_uri::HashedUri,
+        utils::{hash_utils::hash_by_alg, test_signer::test_signer},
+        Builder, SigningAlg,
+    };
+
+    const CLEAN_IMAGE: &[u8] = include_bytes!("../tests/fixtures/IMG_0003.jpg");
+
+    // signs the clean image, optionally with a signed ingredient named "a.jpg"
+    fn sign(title: &str, ingredient: Option<&[u8]>) -> Vec<u8> {
+        let mut builder =
+            Builder::from_json(&serde_json::json!({ "title": title }).to_string()).unwrap();
+        if let Some(ingredient) = ingredient {
+            builder
+                .add_ingredient_from_stream(
+                    r#"{"title": "a.jpg", "relationship": "parentOf"}"#,
+                    "image/jpeg",
+                    &mut Cursor::new(ingredient),
+                )
+                .unwrap();
+        }
+
+        let mut output = Cursor::new(Vec::new());
+        builder
+            .sign(
+                test_signer(SigningAlg::Ps256).as_ref(),
+                "image/jpeg",
+                &mut Cursor::new(CLEAN_IMAGE),
+                &mut output,
+            )
+            .unwrap();
+        output.into_inner()
+    }
+
+    // a repository that finds ingredients titled "a.jpg" as `asset`
+    fn build(root: &[u8], asset: Option<(&str, &[u8])>) -> ProvenanceGraph {
+        let repository = |link: &IngredientLink| -> Result<Option<RepositoryAsset>> {
+            Ok(asset
+                .filter(|_| link.title.as_deref() == Some("a.jpg"))
+                .map(|(id, data)| RepositoryAsset {
+                    id: id.to_owned(),
+                    format: "image/jpeg".to_owned(),
+                    data: Box::new(Cursor::new(data.to_vec())),
+                }))
+        };
+
+        ProvenanceGraph::build("b", "image/jpeg", Cursor::new(root.to_vec()), &repository).unwrap()
+    }
+
+    #[test]
+    fn walks_ingredients() {
+        let a = sign("a.jpg", None);
+        let b = sign("b.jpg", Some(&a));
+
+        let graph = build(&b, Some(("a", &a)));
+        assert_eq!(graph.nodes().len(), 2);
+        assert_eq!(graph.nodes()[1].id, "a");
+        assert!(graph.nodes()[1].validation_state.is_some());
+
+        let links: Vec<_> = graph.ingredients_of(0).collect();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].child, Some(1));
+        assert_eq!(links[0].ingredient.relationship, Relationship::ParentOf);
+        assert_eq!(links[0].status, LinkStatus::Matched);
+        assert!(graph.is_complete());
+
+        // a different asset in place of the ingredient
+        let other = sign("a.jpg", None);
+        let graph = build(&b, Some(("a", &other)));
+        assert_eq!(graph.links()[0].status, LinkStatus::Mismatched);
+        assert!(!graph.is_complete());
+
+        // the repository hands back the root asset
+        let graph = build(&b, Some(("b", &b)));
+        assert_eq!(graph.nodes().len(), 1);
+        assert_eq!(graph.links()[0].status, LinkStatus::Cycle);
+        assert_eq!(graph.links()[0].child, Some(0));
+
+        let graph = build(&b, None);
+        assert_eq!(graph.links()[0].status, LinkStatus::Missing);
+        assert_eq!(graph.links()[0].child, None);
+    }
+
+    #[test]
+    fn checks_data_hash_without_manifest() {
+        let repository = |_: &IngredientLink| -> Result<Option<RepositoryAsset>> { Ok(None) };
+        let mut walk = Walk {
+            graph: ProvenanceGraph::default(),
+            stores: vec![None],
+            streams: vec![Box::new(Cursor::new(CLEAN_IMAGE))],
+            visited: HashMap::new(),
+            path: Vec::new(),
+            repository: &repository,
+        };
+
+        let data = |hash: &[u8]| {
+            Some(HashedUri::new(
+                "https://example.com/a.jpg".to_owned(),
+                Some("sha256".to_owned()),
+                hash,
+            ))
+        };
+
+        let mut ingredient = Ingredient::new_v3(Relationship::ComponentOf);
+        assert_eq!(walk.link_status(0, &ingredient), LinkStatus::Unverified);
+
+        ingredient.data = data(&hash_by_alg("sha256", CLEAN_IMAGE, None));
+        assert_eq!(walk.link_status(0, &ingredient), LinkStatus::Matched);
+        // the stream is rewound before each check
+        assert_eq!(walk.link_status(0, &ingredient), LinkStatus::Matched);
+
+        ingredient.data = data(&[0; 32]);
+        assert_eq!(walk.link_status(0, &ingredient), LinkStatus::Mismatched);
+    }
+}
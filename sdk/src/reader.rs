@@ -32,10 +32,15 @@ use serde_with::skip_serializing_none;
 #[cfg(feature = "file_io")]
 use crate::utils::io_utils::uri_to_path;
 use crate::{
+    assertions::{c2pa_action, labels::ACTIONS, Actions, CollectionMembers},
+    asset_io::{CAIRead, StreamFactory},
+    crjson::CrJson,
     crypto::base64,
     dynamic_assertion::PartialClaim,
     error::{Error, Result},
-    jumbf::labels::{manifest_label_from_uri, to_absolute_uri, to_relative_uri},
+    jumbf::labels::{
+        assertion_label_from_uri, manifest_label_from_uri, to_absolute_uri, to_relative_uri,
+    },
     jumbf_io,
     manifest::StoreOptions,
     manifest_store_report::ManifestStoreReport,
@@ -103,6 +108,29 @@ pub struct Reader {
     assertion_values: HashMap<String, Value>,
 }
 
+/// An assertion that was redacted from an ingredient's manifest.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+pub struct Redaction {
+    /// The JUMBF URI of the redacted assertion.
+    pub uri: String,
+
+    /// The label of the manifest the assertion was removed from.
+    pub manifest: Option<String>,
+
+    /// The label of the redacted assertion.
+    pub assertion: Option<String>,
+
+    /// The label of the manifest that declared the redaction.
+    pub redacted_by: String,
+
+    /// The issuer of the certificate that signed the redacting manifest.
+    pub issuer: Option<String>,
+
+    /// The reason given by the matching `c2pa.redacted` action, if any.
+    pub reason: Option<String>,
+}
+
 type ValidationFn =
     dyn Fn(&str, &crate::ManifestAssertion, &mut StatusTracker) -> Option<serde_json::Value>;
 
@@ -140,6 +168,91 @@ impl Reader {
         Self::from_store(store, &validation_log)
     }
 
+    /// Create a manifest store [`Reader`] from a stream, validating it at `level`.
+    ///
+    /// This overrides the `verify.validation_level` setting for this call only.
+    /// # Arguments
+    /// * `format` - The format of the stream.
+    /// * `stream` - The stream to read from.
+    /// * `level` - How much of the manifest store to validate.
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    ///
+    /// use c2pa::{Reader, ValidationLevel, ValidationState};
+    /// let stream = Cursor::new(include_bytes!("../tests/fixtures/CA.jpg"));
+    /// let reader =
+    ///     Reader::from_stream_at_level("image/jpeg", stream, ValidationLevel::Structural).unwrap();
+    /// assert_eq!(reader.validation_state(), ValidationState::WellFormed);
+    /// ```
+    #[async_generic()]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_stream_at_level(
+        format: &str,
+        mut stream: impl Read + Seek + Send,
+        level: crate::ValidationLevel,
+    ) -> Result<Reader> {
+        let verify = get_settings_value::<bool>("verify.verify_after_reading")?; // defaults to true
+        let mut validation_log = StatusTracker::default();
+
+        let store = if _sync {
+            Store::from_stream(format, &mut stream, false, &mut validation_log)
+        } else {
+            Store::from_stream_async(format, &mut stream, false, &mut validation_log).await
+        }?;
+
+        if verify {
+            stream.rewind()?;
+            let mut asset_data = crate::claim::ClaimAssetData::Stream(&mut stream, format);
+            if _sync {
+                Store::verify_store_at_level(&store, &mut asset_data, level, &mut validation_log)
+            } else {
+                Store::verify_store_at_level_async(
+                    &store,
+                    &mut asset_data,
+                    level,
+                    &mut validation_log,
+                )
+                .await
+            }?;
+        }
+
+        Self::from_store(store, &validation_log)
+    }
+
+    /// Create a manifest store [`Reader`] from a [`StreamFactory`].
+    ///
+    /// The factory is asked for a new reader whenever one is needed, so when
+    /// the `core.parallel_hashing` setting is enabled the asset's box hashes
+    /// and BMFF Merkle leaves are hashed on several threads, and the ranges of
+    /// a data hash are read ahead on several threads.
+    /// # Arguments
+    /// * `format` - The format of the asset.
+    /// * `factory` - Opens independent readers over the same asset.
+    /// # Example
+    /// ```
+    /// use c2pa::{settings::Settings, Reader};
+    ///
+    /// Settings::from_toml(r#"core.parallel_hashing = true"#).unwrap();
+    /// let bytes: &[u8] = include_bytes!("../tests/fixtures/CA.jpg");
+    /// let reader = Reader::from_stream_factory("image/jpeg", &bytes).unwrap();
+    /// println!("{}", reader.json());
+    /// ```
+    #[async_generic()]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_stream_factory(format: &str, factory: &dyn StreamFactory) -> Result<Reader> {
+        let verify = get_settings_value::<bool>("verify.verify_after_reading")?; // defaults to true
+        let mut validation_log = StatusTracker::default();
+
+        let store = if _sync {
+            Store::from_stream_factory(format, factory, verify, &mut validation_log)
+        } else {
+            Store::from_stream_factory_async(format, factory, verify, &mut validation_log).await
+        }?;
+
+        Self::from_store(store, &validation_log)
+    }
+
     #[async_generic()]
     #[cfg(target_arch = "wasm32")]
     pub fn from_stream(format: &str, mut stream: impl Read + Seek) -> Result<Reader> {
@@ -176,6 +289,21 @@ impl Reader {
         let path = path.as_ref();
         let format = crate::format_from_path(path).ok_or(crate::Error::UnsupportedType)?;
         let mut file = File::open(path)?;
+
+        // reopen the file for each worker so hashes can be computed in parallel;
+        // assets without an embedded manifest fall through to the sidecar check
+        #[cfg(not(target_arch = "wasm32"))]
+        if get_settings_value::<bool>("core.parallel_hashing").unwrap_or(false) {
+            let result = if _sync {
+                Self::from_stream_factory(&format, &path)
+            } else {
+                Self::from_stream_factory_async(&format, &path).await
+            };
+            if !matches!(result, Err(Error::JumbfNotFound)) {
+                return result;
+            }
+        }
+
         let result = if _sync {
             Self::from_stream(&format, &mut file)
         } else {
@@ -327,6 +455,61 @@ impl Reader {
         }
     }
 
+    /// Create a [`Reader`] from the standalone manifest of a collection of files.
+    /// Each file listed in the collection hash is hashed again, missing and
+    /// altered files are reported in the validation status.
+    /// # Arguments
+    /// * `c2pa_data` - A C2PA manifest store in JUMBF format.
+    /// * `members` - The uri and stream of each file in the collection.
+    /// # Returns
+    /// A [`Reader`] for the manifest store.
+    /// # Errors
+    /// This function returns an [`Error`] if the c2pa_data is not valid, or severe errors occur in validation.
+    /// You must check validation status for non-severe errors.
+    #[async_generic()]
+    pub fn from_collection<'a>(
+        c2pa_data: &[u8],
+        members: impl IntoIterator<Item = (&'a str, &'a mut (impl Read + Seek + Send + 'a))>,
+    ) -> Result<Reader> {
+        let verify = get_settings_value::<bool>("verify.verify_after_reading")?; // defaults to true
+        let mut validation_log = StatusTracker::default();
+
+        let members = CollectionMembers::Streams(
+            members
+                .into_iter()
+                .map(|(uri, stream)| (uri.to_owned(), stream as &mut dyn CAIRead))
+                .collect(),
+        );
+
+        let store = if _sync {
+            Store::from_collection(c2pa_data, members, verify, &mut validation_log)
+        } else {
+            Store::from_collection_async(c2pa_data, members, verify, &mut validation_log).await
+        }?;
+
+        Self::from_store(store, &validation_log)
+    }
+
+    #[cfg(feature = "file_io")]
+    /// Loads a [`Reader`] from the standalone manifest of a collection whose
+    /// files are stored below `base_path`.
+    pub fn from_collection_folder<P: AsRef<std::path::Path>>(
+        c2pa_data: &[u8],
+        base_path: P,
+    ) -> Result<Reader> {
+        let verify = get_settings_value::<bool>("verify.verify_after_reading")?; // defaults to true
+        let mut validation_log = StatusTracker::default();
+
+        let store = Store::from_collection(
+            c2pa_data,
+            CollectionMembers::Folder(base_path.as_ref()),
+            verify,
+            &mut validation_log,
+        )?;
+
+        Self::from_store(store, &validation_log)
+    }
+
     /// replace byte arrays with base64 encoded strings
     fn hash_to_b64(mut value: Value) -> Value {
         use std::collections::VecDeque;
@@ -375,7 +558,7 @@ impl Reader {
     /// * `reader_json` - The reader json to update
     /// # Returns
     /// The updated reader json
-    fn to_json_formatted(&self) -> Result<Value> {
+    pub(crate) fn to_json_formatted(&self) -> Result<Value> {
         let mut json = serde_json::to_value(self).map_err(Error::JsonError)?;
 
         // If we ran post-validation, we need to update the assertion values in the report
@@ -450,6 +633,24 @@ impl Reader {
         }
     }
 
+    /// Get the manifest store as a crJSON (JSON-LD) string.
+    ///
+    /// See the [`crjson`](crate::crjson) module for the format.
+    pub fn crjson(&self) -> Result<String> {
+        let crjson = CrJson::from_reader(self)?;
+        serde_json::to_string_pretty(&crjson).map_err(Error::JsonError)
+    }
+
+    /// Create a manifest store [`Reader`] from a crJSON string made by [`Reader::crjson`].
+    ///
+    /// Like [`Reader::from_json`], the result only describes the manifest store
+    /// and has not been validated again. crJSON doesn't include certificate
+    /// chains, so the signature info of each manifest has none.
+    pub fn from_crjson(json: &str) -> Result<Reader> {
+        let crjson: CrJson = serde_json::from_str(json).map_err(Error::JsonError)?;
+        serde_json::from_value(crjson.to_reader_json()?).map_err(Error::JsonError)
+    }
+
     /// Returns the remote url of the manifest if this [`Reader`] obtained the manifest remotely.
     pub fn remote_url(&self) -> Option<&str> {
         self.store.remote_url()
@@ -555,6 +756,87 @@ impl Reader {
         &self.manifests
     }
 
+    /// Returns every redaction declared by a manifest in the store, with who made it
+    /// and the reason recorded in its `c2pa.redacted` action.
+    ///
+    /// Redactions are listed by manifest, starting with the active manifest and
+    /// then following the order of the manifest store.
+    pub fn redactions(&self) -> Vec<Redaction> {
+        let mut redactions = Vec::new();
+
+        for label in self.manifest_labels_in_order() {
+            let Some(manifest) = self.manifests.get(label) else {
+                continue;
+            };
+            let Some(uris) = manifest.redactions() else {
+                continue;
+            };
+
+            let redacted_actions: Vec<_> = manifest
+                .assertions()
+                .iter()
+                .filter(|a| a.label().starts_with(ACTIONS))
+                .filter_map(|a| a.to_assertion::<Actions>().ok())
+                .flat_map(|actions| actions.actions)
+                .filter(|action| action.action() == c2pa_action::REDACTED)
+                .collect();
+
+            for uri in uris {
+                let manifest_label = manifest_label_from_uri(uri);
+                let assertion_label = assertion_label_from_uri(uri);
+
+                let reason = redacted_actions
+                    .iter()
+                    .find(|action| {
+                        action
+                            .get_parameter("redacted")
+                            .and_then(crate::cbor_types::value_cbor_to_type::<String>)
+                            .is_some_and(|redacted| {
+                                manifest_label_from_uri(&redacted) == manifest_label
+                                    && assertion_label_from_uri(&redacted) == assertion_label
+                            })
+                    })
+                    .and_then(|action| action.reason().map(str::to_owned));
+
+                redactions.push(Redaction {
+                    uri: uri.to_owned(),
+                    manifest: manifest_label,
+                    assertion: assertion_label,
+                    redacted_by: label.to_owned(),
+                    issuer: manifest.issuer(),
+                    reason,
+                });
+            }
+        }
+
+        redactions
+    }
+
+    // Manifest labels with the active manifest first, then in store order.
+    // Manifests that aren't in the store (as after deserializing a `Reader`)
+    // follow in label order.
+    fn manifest_labels_in_order(&self) -> Vec<&str> {
+        let active = self.active_manifest.as_deref();
+        let mut labels: Vec<&str> = active.into_iter().collect();
+
+        for claim in self.store.claims() {
+            if !labels.contains(&claim.label()) {
+                labels.push(claim.label());
+            }
+        }
+
+        let mut remaining: Vec<&str> = self
+            .manifests
+            .keys()
+            .map(String::as_str)
+            .filter(|label| !labels.contains(label))
+            .collect();
+        remaining.sort_unstable();
+        labels.append(&mut remaining);
+
+        labels
+    }
+
     /// Given a label, return the associated [`Manifest`], if it exists.
     /// # Arguments
     /// * `label` - The label of the requested [`Manifest`].
@@ -941,6 +1223,92 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reader_validation_levels() -> Result<()> {
+        use crate::{ValidationCheck, ValidationLevel};
+
+        // the asset has been modified after signing, which only hashing can detect
+        const TAMPERED: &[u8] = include_bytes!("../tests/fixtures/XCA.jpg");
+
+        crate::settings::set_settings_value("verify.validation_level", "signature")?;
+        let reader = Reader::from_stream("image/jpeg", Cursor::new(TAMPERED))?;
+        let results = reader.validation_results().unwrap();
+        assert_eq!(results.skipped_checks(), [ValidationCheck::HardBinding]);
+        assert_eq!(reader.validation_state(), ValidationState::Valid);
+        assert!(reader.json().contains("\"skippedChecks\""));
+
+        // trust is still checked, but a partial pass is never reported as trusted
+        let reader = Reader::from_stream("image/jpeg", Cursor::new(IMAGE_COMPLEX_MANIFEST))?;
+        let active_manifest = reader.validation_results().unwrap().active_manifest();
+        assert!(active_manifest
+            .unwrap()
+            .success()
+            .iter()
+            .any(|s| s.code() == crate::validation_status::SIGNING_CREDENTIAL_TRUSTED));
+        assert_eq!(reader.validation_state(), ValidationState::Valid);
+
+        crate::settings::set_settings_value("verify.validation_level", "structural")?;
+        let reader = Reader::from_stream("image/jpeg", Cursor::new(TAMPERED))?;
+        let results = reader.validation_results().unwrap();
+        assert_eq!(
+            results.skipped_checks(),
+            ValidationLevel::Structural.skipped_checks()
+        );
+        assert!(!results
+            .active_manifest()
+            .unwrap()
+            .success()
+            .iter()
+            .any(|s| s.code() == crate::validation_status::CLAIM_SIGNATURE_VALIDATED));
+        // an unchecked signature is never reported as valid
+        assert_eq!(reader.validation_state(), ValidationState::WellFormed);
+
+        crate::settings::set_settings_value("verify.validation_level", "full")?;
+        let reader = Reader::from_stream("image/jpeg", Cursor::new(TAMPERED))?;
+        assert!(reader
+            .validation_results()
+            .unwrap()
+            .skipped_checks()
+            .is_empty());
+        assert_eq!(reader.validation_state(), ValidationState::Invalid);
+
+        // a per-call level overrides the setting
+        let reader = Reader::from_stream_at_level(
+            "image/jpeg",
+            Cursor::new(TAMPERED),
+            ValidationLevel::Signature,
+        )?;
+        assert_eq!(
+            reader.validation_results().unwrap().skipped_checks(),
+            [ValidationCheck::HardBinding]
+        );
+        assert_eq!(reader.validation_state(), ValidationState::Valid);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "file_io", not(target_arch = "wasm32")))]
+    fn test_reader_parallel_hashing() -> Result<()> {
+        crate::settings::set_settings_value("core.parallel_hashing", true)?;
+
+        let from_factory = Reader::from_stream_factory("image/jpeg", &IMAGE_WITH_MANIFEST)?;
+        let from_stream = Reader::from_stream("image/jpeg", Cursor::new(IMAGE_WITH_MANIFEST))?;
+        assert_eq!(
+            from_factory.validation_state(),
+            from_stream.validation_state()
+        );
+        assert!(from_factory.is_embedded());
+
+        let reader = Reader::from_file("tests/fixtures/XCA.jpg")?;
+        assert_eq!(
+            reader.validation_status().unwrap()[0].code(),
+            crate::validation_status::ASSERTION_DATAHASH_MISMATCH
+        );
+
+        crate::settings::reset_default_settings()?;
+        Ok(())
+    }
+
     #[test]
     #[cfg(not(target_os = "wasi"))] // todo: enable when disable we find out wasi trust issues
     fn test_reader_trusted() -> Result<()> {
@@ -950,6 +1318,31 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(not(target_os = "wasi"))] // todo: enable when disable we find out wasi trust issues
+    fn test_reader_trust_reports() -> Result<()> {
+        // Trust reports are only built on request.
+        let reader =
+            Reader::from_stream("image/jpeg", std::io::Cursor::new(IMAGE_COMPLEX_MANIFEST))?;
+        assert!(reader
+            .validation_results()
+            .unwrap()
+            .trust_reports()
+            .is_empty());
+
+        crate::settings::set_settings_value("verify.trust_reports", true)?;
+        let reader =
+            Reader::from_stream("image/jpeg", std::io::Cursor::new(IMAGE_COMPLEX_MANIFEST))?;
+        crate::settings::reset_default_settings()?;
+
+        let trust_reports = reader.validation_results().unwrap().trust_reports();
+        assert!(!trust_reports.is_empty());
+        assert!(trust_reports
+            .iter()
+            .all(|report| report.trusted && report.matched.is_some()));
+        Ok(())
+    }
+
     #[test]
     /// Test that the reader can validate a file with nested assertion errors
     fn test_reader_from_file_nested_errors() -> Result<()> {
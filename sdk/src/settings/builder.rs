@@ -422,6 +422,10 @@ pub(crate) struct BuilderSettings {
 
     // Whether or not existing OCSP responses should be overridden by new values.
     pub certificate_status_should_override: Option<bool>,
+
+    // Whether an incomplete signer certificate chain should be completed before signing,
+    // using `trust.intermediates` and optionally the AIA caIssuers URLs of each certificate.
+    pub certificate_chain_completion: Option<CertificateChainCompletion>,
 }
 
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -431,6 +435,15 @@ pub(crate) enum OcspFetch {
     Active,
 }
 
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum CertificateChainCompletion {
+    /// Only use the configured intermediate certificate pool.
+    Local,
+    /// Use the intermediate pool and fetch missing issuers from AIA caIssuers URLs.
+    Fetch,
+}
+
 impl SettingsValidate for BuilderSettings {
     fn validate(&self) -> Result<()> {
         self.actions.validate()?;
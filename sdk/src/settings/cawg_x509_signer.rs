@@ -0,0 +1,413 @@
+// Copyright 2025 Adobe. All rights reserved.
+// This file is licensed to you under the Apache License,
+// Version 2.0 (http://www.apache.org/licenses/LICENSE-2.0)
+// or the MIT license (http://opensource.org/licenses/MIT),
+// at your option.
+
+// Unless required by applicable law or agreed to in writing,
+// this software is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR REPRESENTATIONS OF ANY KIND, either express or
+// implied. See the LICENSE-MIT and LICENSE-APACHE files for the
+// specific language governing permissions and limitations under
+// each license.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto::raw_signature::{AsyncRawSigner, RawSigner},
+    dynamic_assertion::{AsyncDynamicAssertion, DynamicAssertion},
+    identity::{
+        builder::{AsyncIdentityAssertionBuilder, IdentityAssertionBuilder},
+        x509::{AsyncX509CredentialHolder, X509CredentialHolder},
+    },
+    settings::{signer::SignerSettings, Settings, SettingsValidate},
+    AsyncSigner, Error, Result, Signer, SigningAlg,
+};
+
+/// Settings for the X.509 credential holder of a CAWG identity assertion.
+///
+/// When configured, [Builder::sign][crate::Builder::sign] adds an identity
+/// assertion signed by this credential to every manifest it signs. The
+/// credential is configured in the same way as the `signer` settings:
+///
+/// ```toml
+/// [cawg_x509_signer]
+/// referenced_assertions = ["c2pa.actions.v2"]
+/// roles = ["cawg.creator"]
+///
+/// [cawg_x509_signer.local]
+/// alg = "ed25519"
+/// sign_cert = "..."
+/// private_key = "..."
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct CawgX509SignerSettings {
+    // Signer that holds the X.509 credential.
+    #[serde(flatten)]
+    signer: SignerSettings,
+    // Labels of assertions that the identity assertion should reference.
+    #[serde(default)]
+    referenced_assertions: Vec<String>,
+    // Roles of the named actor, such as `cawg.creator`.
+    #[serde(default)]
+    roles: Vec<String>,
+}
+
+impl CawgX509SignerSettings {
+    fn from_settings() -> Result<Option<Self>> {
+        match Settings::get_value::<Option<Self>>("cawg_x509_signer") {
+            // Unset settings are left out of the configuration.
+            Err(Error::NotFound) => Ok(None),
+            result => result,
+        }
+    }
+
+    /// Returns the identity assertion described by the `cawg_x509_signer`
+    /// settings, or `None` if they aren't specified.
+    pub(crate) fn identity_assertion() -> Result<Option<IdentityAssertionBuilder>> {
+        let Some(Self {
+            signer,
+            referenced_assertions,
+            roles,
+        }) = Self::from_settings()?
+        else {
+            return Ok(None);
+        };
+
+        let mut iab = IdentityAssertionBuilder::for_credential_holder(
+            X509CredentialHolder::from_raw_signer(signer.raw_signer()?),
+        );
+        iab.add_referenced_assertions(&as_strs(&referenced_assertions));
+        iab.add_roles(&as_strs(&roles));
+        Ok(Some(iab))
+    }
+
+    /// Returns the async identity assertion described by the
+    /// `cawg_x509_signer` settings, or `None` if they aren't specified.
+    pub(crate) fn async_identity_assertion() -> Result<Option<AsyncIdentityAssertionBuilder>> {
+        let Some(Self {
+            signer,
+            referenced_assertions,
+            roles,
+        }) = Self::from_settings()?
+        else {
+            return Ok(None);
+        };
+
+        let mut iab = AsyncIdentityAssertionBuilder::for_credential_holder(
+            AsyncX509CredentialHolder::from_async_raw_signer(signer.async_raw_signer()?),
+        );
+        iab.add_referenced_assertions(&as_strs(&referenced_assertions));
+        iab.add_roles(&as_strs(&roles));
+        Ok(Some(iab))
+    }
+}
+
+fn as_strs(strings: &[String]) -> Vec<&str> {
+    strings.iter().map(String::as_str).collect()
+}
+
+impl SettingsValidate for CawgX509SignerSettings {
+    fn validate(&self) -> Result<()> {
+        self.signer.validate()
+    }
+}
+
+/// Adds an identity assertion to the dynamic assertions of the [Signer] it
+/// wraps, so that the caller's signer doesn't need to know about it.
+pub(crate) struct CawgX509Signer<'a> {
+    signer: &'a dyn Signer,
+    identity_assertion: Mutex<Option<IdentityAssertionBuilder>>,
+}
+
+impl<'a> CawgX509Signer<'a> {
+    pub(crate) fn new(signer: &'a dyn Signer, iab: IdentityAssertionBuilder) -> Self {
+        Self {
+            signer,
+            identity_assertion: Mutex::new(Some(iab)),
+        }
+    }
+}
+
+impl Signer for CawgX509Signer<'_> {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        self.signer.sign(data)
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.signer.alg()
+    }
+
+    fn certs(&self) -> Result<Vec<Vec<u8>>> {
+        self.signer.certs()
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.signer.reserve_size()
+    }
+
+    fn ocsp_val(&self) -> Option<Vec<u8>> {
+        self.signer.ocsp_val()
+    }
+
+    fn time_authority_url(&self) -> Option<String> {
+        self.signer.time_authority_url()
+    }
+
+    fn timestamp_request_headers(&self) -> Option<Vec<(String, String)>> {
+        self.signer.timestamp_request_headers()
+    }
+
+    fn timestamp_request_body(&self, message: &[u8]) -> Result<Vec<u8>> {
+        self.signer.timestamp_request_body(message)
+    }
+
+    fn send_timestamp_request(&self, message: &[u8]) -> Option<Result<Vec<u8>>> {
+        self.signer.send_timestamp_request(message)
+    }
+
+    fn direct_cose_handling(&self) -> bool {
+        self.signer.direct_cose_handling()
+    }
+
+    fn raw_signer(&self) -> Option<Box<&dyn RawSigner>> {
+        self.signer.raw_signer()
+    }
+
+    fn dynamic_assertions(&self) -> Vec<Box<dyn DynamicAssertion>> {
+        let mut dynamic_assertions = self.signer.dynamic_assertions();
+        if let Some(iab) = self
+            .identity_assertion
+            .lock()
+            .ok()
+            .and_then(|mut iab| iab.take())
+        {
+            dynamic_assertions.push(Box::new(iab));
+        }
+        dynamic_assertions
+    }
+}
+
+/// Adds an identity assertion to the dynamic assertions of the
+/// [AsyncSigner] it wraps.
+pub(crate) struct AsyncCawgX509Signer<'a> {
+    signer: &'a dyn AsyncSigner,
+    identity_assertion: Mutex<Option<AsyncIdentityAssertionBuilder>>,
+}
+
+impl<'a> AsyncCawgX509Signer<'a> {
+    pub(crate) fn new(signer: &'a dyn AsyncSigner, iab: AsyncIdentityAssertionBuilder) -> Self {
+        Self {
+            signer,
+            identity_assertion: Mutex::new(Some(iab)),
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl AsyncSigner for AsyncCawgX509Signer<'_> {
+    async fn sign(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        self.signer.sign(data).await
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.signer.alg()
+    }
+
+    fn certs(&self) -> Result<Vec<Vec<u8>>> {
+        self.signer.certs()
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.signer.reserve_size()
+    }
+
+    fn time_authority_url(&self) -> Option<String> {
+        self.signer.time_authority_url()
+    }
+
+    fn timestamp_request_headers(&self) -> Option<Vec<(String, String)>> {
+        self.signer.timestamp_request_headers()
+    }
+
+    fn timestamp_request_body(&self, message: &[u8]) -> Result<Vec<u8>> {
+        self.signer.timestamp_request_body(message)
+    }
+
+    async fn send_timestamp_request(&self, message: &[u8]) -> Option<Result<Vec<u8>>> {
+        self.signer.send_timestamp_request(message).await
+    }
+
+    async fn ocsp_val(&self) -> Option<Vec<u8>> {
+        self.signer.ocsp_val().await
+    }
+
+    fn direct_cose_handling(&self) -> bool {
+        self.signer.direct_cose_handling()
+    }
+
+    fn dynamic_assertions(&self) -> Vec<Box<dyn AsyncDynamicAssertion>> {
+        let mut dynamic_assertions = self.signer.dynamic_assertions();
+        if let Some(iab) = self
+            .identity_assertion
+            .lock()
+            .ok()
+            .and_then(|mut iab| iab.take())
+        {
+            dynamic_assertions.push(Box::new(iab));
+        }
+        dynamic_assertions
+    }
+
+    fn async_raw_signer(&self) -> Option<Box<&dyn AsyncRawSigner>> {
+        self.signer.async_raw_signer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::{Cursor, Seek};
+
+    use c2pa_macros::c2pa_test_async;
+    #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    use crate::{
+        identity::{
+            tests::fixtures::{cert_chain_and_private_key_for_alg, manifest_json, parent_json},
+            x509::X509SignatureVerifier,
+            IdentityAssertion,
+        },
+        status_tracker::StatusTracker,
+        Builder, Reader,
+    };
+
+    const TEST_IMAGE: &[u8] = include_bytes!("../../tests/fixtures/CA.jpg");
+    const TEST_THUMBNAIL: &[u8] = include_bytes!("../../tests/fixtures/thumbnail.jpg");
+
+    fn load_cawg_x509_signer_settings(alg: &str) -> Result<()> {
+        let (sign_cert, private_key) = cert_chain_and_private_key_for_alg(SigningAlg::Ed25519);
+        Settings::from_toml(
+            &toml::toml! {
+                [cawg_x509_signer]
+                referenced_assertions = ["org.test.assertion"]
+                roles = ["cawg.creator"]
+
+                [cawg_x509_signer.local]
+                alg = alg
+                sign_cert = (String::from_utf8(sign_cert).unwrap())
+                private_key = (String::from_utf8(private_key).unwrap())
+            }
+            .to_string(),
+        )
+    }
+
+    fn builder(source: &mut Cursor<&[u8]>) -> Builder {
+        let mut builder = Builder::from_json(&manifest_json()).unwrap();
+        builder
+            .add_ingredient_from_stream(parent_json(), "image/jpeg", source)
+            .unwrap();
+        builder
+            .add_resource("thumbnail.jpg", Cursor::new(TEST_THUMBNAIL))
+            .unwrap();
+        builder
+    }
+
+    async fn check_identity_assertion(dest: &mut Cursor<Vec<u8>>) {
+        dest.rewind().unwrap();
+        let reader = Reader::from_stream("image/jpeg", dest).unwrap();
+        let manifest = reader.active_manifest().unwrap();
+
+        let mut st = StatusTracker::default();
+        let mut ia_iter = IdentityAssertion::from_manifest(manifest, &mut st);
+
+        let ia = ia_iter.next().unwrap().unwrap();
+        assert!(ia_iter.next().is_none());
+        drop(ia_iter);
+
+        let sp = ia.signer_payload();
+        assert_eq!(sp.roles, vec!["cawg.creator".to_owned()]);
+        assert!(sp
+            .referenced_assertions
+            .iter()
+            .any(|a| a.url().ends_with("org.test.assertion")));
+
+        let sig_info = ia
+            .validate(manifest, &mut st, &X509SignatureVerifier {})
+            .await
+            .unwrap();
+        assert_eq!(sig_info.cert_info.alg, Some(SigningAlg::Ed25519));
+    }
+
+    #[c2pa_test_async]
+    async fn sign_with_cawg_x509_signer() {
+        Settings::reset().unwrap();
+        load_cawg_x509_signer_settings("ed25519").unwrap();
+
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+        let signer = Settings::signer().unwrap();
+        builder(&mut source)
+            .sign(signer.as_ref(), "image/jpeg", &mut source, &mut dest)
+            .unwrap();
+
+        check_identity_assertion(&mut dest).await;
+        Settings::reset().unwrap();
+    }
+
+    #[c2pa_test_async]
+    async fn sign_async_with_cawg_x509_signer() {
+        Settings::reset().unwrap();
+        load_cawg_x509_signer_settings("ed25519").unwrap();
+
+        let mut source = Cursor::new(TEST_IMAGE);
+        let mut dest = Cursor::new(Vec::new());
+        let signer = Settings::async_signer().unwrap();
+        builder(&mut source)
+            .sign_async(signer.as_ref(), "image/jpeg", &mut source, &mut dest)
+            .await
+            .unwrap();
+
+        check_identity_assertion(&mut dest).await;
+        Settings::reset().unwrap();
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn alg_is_case_insensitive() {
+        Settings::reset().unwrap();
+
+        load_cawg_x509_signer_settings("Ed25519").unwrap();
+        assert!(CawgX509SignerSettings::identity_assertion()
+            .unwrap()
+            .is_some());
+
+        assert!(load_cawg_x509_signer_settings("ed448").is_err());
+        Settings::reset().unwrap();
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(target_arch = "wasm32", not(target_os = "wasi")),
+        wasm_bindgen_test
+    )]
+    fn no_identity_assertion_without_settings() {
+        Settings::reset().unwrap();
+        assert!(CawgX509SignerSettings::identity_assertion()
+            .unwrap()
+            .is_none());
+        assert!(CawgX509SignerSettings::async_identity_assertion()
+            .unwrap()
+            .is_none());
+    }
+}
@@ -12,6 +12,7 @@
 // each license.
 
 pub(crate) mod builder;
+pub(crate) mod cawg_x509_signer;
 pub(crate) mod signer;
 
 #[cfg(feature = "file_io")]
@@ -21,11 +22,19 @@ use std::{
     io::{BufRead, BufReader, Cursor},
 };
 
+use cawg_x509_signer::CawgX509SignerSettings;
 use config::{Config, FileFormat};
 use serde_derive::{Deserialize, Serialize};
 use signer::SignerSettings;
 
-use crate::{crypto::base64, settings::builder::BuilderSettings, Error, Result, Signer};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::AsyncSigner;
+use crate::{
+    crypto::{base64, cose::TrustList},
+    settings::builder::BuilderSettings,
+    validation_results::ValidationLevel,
+    Error, Result, Signer,
+};
 
 thread_local!(
     static SETTINGS: RefCell<Config> =
@@ -46,11 +55,25 @@ pub(crate) trait SettingsValidate {
 pub(crate) struct Trust {
     user_anchors: Option<String>,
     trust_anchors: Option<String>,
+    trust_list: Option<String>,
     trust_config: Option<String>,
     allowed_list: Option<String>,
+    intermediates: Option<String>,
 }
 
 impl Trust {
+    // trust settings with nothing configured
+    fn empty() -> Self {
+        Self {
+            user_anchors: None,
+            trust_anchors: None,
+            trust_list: None,
+            trust_config: None,
+            allowed_list: None,
+            intermediates: None,
+        }
+    }
+
     // load PEMs
     fn load_trust_from_data(&self, trust_data: &[u8]) -> Result<Vec<Vec<u8>>> {
         let mut certs = Vec::new();
@@ -105,12 +128,7 @@ impl Default for Trust {
         // load test config store for unit tests
         #[cfg(test)]
         {
-            let mut trust = Self {
-                user_anchors: None,
-                trust_anchors: None,
-                trust_config: None,
-                allowed_list: None,
-            };
+            let mut trust = Self::empty();
 
             trust.trust_config = Some(
                 String::from_utf8_lossy(include_bytes!(
@@ -129,12 +147,7 @@ impl Default for Trust {
         }
         #[cfg(not(test))]
         {
-            Self {
-                user_anchors: None,
-                trust_anchors: None,
-                trust_config: None,
-                allowed_list: None,
-            }
+            Self::empty()
         }
     }
 }
@@ -145,6 +158,10 @@ impl SettingsValidate for Trust {
             self.test_load_trust(ta.as_bytes())?;
         }
 
+        if let Some(tl) = &self.trust_list {
+            TrustList::from_json(tl.as_bytes())?;
+        }
+
         if let Some(pa) = &self.user_anchors {
             self.test_load_trust(pa.as_bytes())?;
         }
@@ -153,6 +170,10 @@ impl SettingsValidate for Trust {
             self.test_load_trust(al.as_bytes())?;
         }
 
+        if let Some(im) = &self.intermediates {
+            self.load_trust_from_data(im.as_bytes())?;
+        }
+
         Ok(())
     }
 }
@@ -173,6 +194,9 @@ pub(crate) struct Core {
     compress_manifests: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     max_memory_usage: Option<u64>,
+    parallel_hashing: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_hash_workers: Option<usize>,
     // TODO: pending https://github.com/contentauth/c2pa-rs/pull/1180
     // prefer_update_manifests: bool,
 }
@@ -187,8 +211,10 @@ impl Default for Core {
             prefer_box_hash: false,
             merkle_tree_chunk_size_in_kb: None,
             merkle_tree_max_proofs: 5,
-            compress_manifests: true,
+            compress_manifests: false,
             max_memory_usage: None,
+            parallel_hashing: false,
+            max_hash_workers: None,
             // prefer_update_manifests: true,
         }
     }
@@ -196,6 +222,12 @@ impl Default for Core {
 
 impl SettingsValidate for Core {
     fn validate(&self) -> Result<()> {
+        if self.max_hash_workers == Some(0) {
+            return Err(Error::BadParam(
+                "core.max_hash_workers must be at least 1".to_string(),
+            ));
+        }
+
         match self.hash_alg.as_str() {
             "sha256" | "sha384" | "sha512" => Ok(()),
             _ => Err(Error::UnsupportedType),
@@ -216,6 +248,8 @@ pub(crate) struct Verify {
     check_ingredient_trust: bool,
     skip_ingredient_conflict_resolution: bool,
     strict_v1_validation: bool,
+    validation_level: ValidationLevel,
+    trust_reports: bool,
 }
 
 impl Default for Verify {
@@ -230,6 +264,8 @@ impl Default for Verify {
             check_ingredient_trust: true,
             skip_ingredient_conflict_resolution: false,
             strict_v1_validation: false,
+            validation_level: ValidationLevel::Full,
+            trust_reports: false,
         }
     }
 }
@@ -249,11 +285,20 @@ pub struct Settings {
     version_major: usize,
     version_minor: usize,
     trust: Trust,
+    // trust policy for time stamp authorities, falls back to `trust` if not configured
+    #[serde(default = "Trust::empty")]
+    timestamp_trust: Trust,
+    // trust policy for X.509 certificates in CAWG identity assertions
+    #[serde(default = "Trust::empty")]
+    cawg_trust: Trust,
     core: Core,
     verify: Verify,
     builder: BuilderSettings,
     #[serde(skip_serializing_if = "Option::is_none")]
     signer: Option<SignerSettings>,
+    // credential holder for a CAWG identity assertion added when signing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cawg_x509_signer: Option<CawgX509SignerSettings>,
 }
 
 impl Settings {
@@ -376,9 +421,10 @@ impl Settings {
                 .build()
                 .map_err(|_e| Error::OtherError("could not update configuration".into()))?;
 
-            update_config
-                .get::<T>(value_path)
-                .map_err(|_| Error::NotFound)
+            update_config.get::<T>(value_path).map_err(|e| match e {
+                config::ConfigError::NotFound(_) => Error::NotFound,
+                e => Error::BadParam(e.to_string()),
+            })
         })
     }
 
@@ -414,6 +460,15 @@ impl Settings {
     pub fn signer() -> Result<Box<dyn Signer>> {
         SignerSettings::signer()
     }
+
+    /// Returns the constructed async signer from the `signer` field.
+    ///
+    /// If the signer settings aren't specified, this function will return [Error::MissingSignerSettings].
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn async_signer() -> Result<Box<dyn AsyncSigner + Send + Sync>> {
+        SignerSettings::async_signer()
+    }
 }
 
 impl Default for Settings {
@@ -422,10 +477,13 @@ impl Default for Settings {
             version_major: MAJOR_VERSION,
             version_minor: MINOR_VERSION,
             trust: Default::default(),
+            timestamp_trust: Trust::empty(),
+            cawg_trust: Trust::empty(),
             core: Default::default(),
             verify: Default::default(),
             builder: Default::default(),
             signer: None,
+            cawg_x509_signer: None,
         }
     }
 }
@@ -440,9 +498,13 @@ impl SettingsValidate for Settings {
         if let Some(signer) = &self.signer {
             signer.validate()?;
         }
+        if let Some(cawg_x509_signer) = &self.cawg_x509_signer {
+            cawg_x509_signer.validate()?;
+        }
         self.trust.validate()?;
+        self.timestamp_trust.validate()?;
+        self.cawg_trust.validate()?;
         self.core.validate()?;
-        self.trust.validate()?;
         self.builder.validate()
     }
 }
@@ -719,6 +781,30 @@ pub mod tests {
 
         reset_default_settings().unwrap();
     }
+
+    #[test]
+    fn test_trust_list_setting() {
+        let trust_list =
+            include_str!("../../tests/fixtures/certs/trust/trust_list.json").to_string();
+        let settings = toml::toml! {
+            [trust]
+            trust_list = trust_list
+        }
+        .to_string();
+        Settings::from_toml(&settings).unwrap();
+
+        // PEM data is not a trust list
+        let pems =
+            include_str!("../../tests/fixtures/certs/trust/test_cert_root_bundle.pem").to_string();
+        let settings = toml::toml! {
+            [trust]
+            trust_list = pems
+        }
+        .to_string();
+        assert!(Settings::from_toml(&settings).is_err());
+
+        reset_default_settings().unwrap();
+    }
     #[test]
     fn test_hidden_setting() {
         let secret = toml::toml! {
@@ -758,7 +844,7 @@ pub mod tests {
             salt_jumbf_boxes = true
             prefer_box_hash = false
             prefer_bmff_merkle_tree = false
-            compress_manifests = true
+            compress_manifests = false
 
             [Verify]
             verify_after_reading = true
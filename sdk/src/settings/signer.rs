@@ -11,17 +11,32 @@
 // specific language governing permissions and limitations under
 // each license.
 
+#[cfg(not(target_arch = "wasm32"))]
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     create_signer,
+    crypto::{
+        raw_signature::{
+            signer::async_signer_from_raw_signer, signer_from_cert_chain_and_private_key,
+            AsyncRawSigner, RawSigner,
+        },
+        time_stamp::{LocalTimeStampAuthority, LocalTimeStampSigner, TimeStampProvider},
+    },
     settings::{Settings, SettingsValidate},
     Error, Result, Signer, SigningAlg,
 };
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+    crypto::{raw_signature::RawSignerError, time_stamp::AsyncTimeStampProvider},
+    AsyncSigner,
+};
 
 /// Settings for configuring a local or remote [Signer][crate::Signer].
 ///
-/// A [Signer][crate::Signer] can be obtained by calling [BuilderSettings::signer].
+/// A [Signer][crate::Signer] can be obtained by calling [BuilderSettings::signer],
+/// and an [AsyncSigner][crate::AsyncSigner] by calling [BuilderSettings::async_signer].
 #[allow(unused)]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -29,6 +44,7 @@ pub(crate) enum SignerSettings {
     /// A signer configured locally.
     Local {
         // Algorithm to use for signing.
+        #[serde(with = "signing_alg")]
         alg: SigningAlg,
         // Certificate used for signing (PEM format).
         sign_cert: String,
@@ -36,6 +52,8 @@ pub(crate) enum SignerSettings {
         private_key: String,
         // Time stamp authority URL for signing.
         tsa_url: Option<String>,
+        // Time stamp authority run in-process instead of contacting `tsa_url`.
+        local_tsa: Option<LocalTsaSettings>,
     },
     /// A signer configured remotely.
     Remote {
@@ -44,16 +62,71 @@ pub(crate) enum SignerSettings {
         // A POST request with a byte stream will be sent to this URL.
         url: String,
         // Algorithm to use for signing.
+        #[serde(with = "signing_alg")]
         alg: SigningAlg,
         // Certificate used for signing (PEM format).
         sign_cert: String,
         // Time stamp authority URL for signing.
         tsa_url: Option<String>,
     },
+    /// A signer whose private key is held on a PKCS#11 token.
+    #[cfg(all(feature = "pkcs11", not(target_arch = "wasm32")))]
+    Pkcs11 {
+        // Path to the PKCS#11 module (shared library).
+        module_path: String,
+        // Label of the token holding the private key.
+        token_label: String,
+        // `CKA_ID` of the private key and its certificate (hex encoded).
+        key_id: String,
+        // User PIN for the token.
+        pin: Option<String>,
+        // Algorithm to use for signing.
+        #[serde(with = "signing_alg")]
+        alg: SigningAlg,
+        // Time stamp authority URL for signing.
+        tsa_url: Option<String>,
+    },
+}
+
+/// Settings for a time stamp authority that issues time stamps locally
+/// rather than through a remote service.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct LocalTsaSettings {
+    // Algorithm to use for signing time stamps.
+    #[serde(with = "signing_alg")]
+    alg: SigningAlg,
+    // Time stamp authority certificate chain (PEM format).
+    sign_cert: String,
+    // Time stamp authority private key (PEM format).
+    private_key: String,
+}
+
+// Signing algorithms are written with the lowercase names of `SigningAlg`'s
+// `Display` implementation, such as `es256`, but are matched without regard
+// to case.
+mod signing_alg {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::SigningAlg;
+
+    pub(super) fn serialize<S: Serializer>(
+        alg: &SigningAlg,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(alg)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SigningAlg, D::Error> {
+        String::deserialize(deserializer)?
+            .to_lowercase()
+            .parse()
+            .map_err(D::Error::custom)
+    }
 }
 
 impl SignerSettings {
-    // TODO: add async signer
     /// Returns the constructed signer from the [BuilderSettings::signer] field.
     ///
     /// If the signer settings aren't specified, this function will return [Error::MissingSignerSettings][crate::Error::MissingSignerSettings].
@@ -66,12 +139,23 @@ impl SignerSettings {
                     sign_cert,
                     private_key,
                     tsa_url,
-                } => create_signer::from_keys(
-                    sign_cert.as_bytes(),
-                    private_key.as_bytes(),
-                    alg,
-                    tsa_url.to_owned(),
-                ),
+                    local_tsa,
+                } => match local_tsa {
+                    Some(local_tsa) => create_signer::from_keys_with_local_tsa(
+                        sign_cert.as_bytes(),
+                        private_key.as_bytes(),
+                        alg,
+                        local_tsa.sign_cert.as_bytes(),
+                        local_tsa.private_key.as_bytes(),
+                        local_tsa.alg,
+                    ),
+                    None => create_signer::from_keys(
+                        sign_cert.as_bytes(),
+                        private_key.as_bytes(),
+                        alg,
+                        tsa_url.to_owned(),
+                    ),
+                },
                 #[cfg(not(target_arch = "wasm32"))]
                 SignerSettings::Remote {
                     url,
@@ -87,6 +171,28 @@ impl SignerSettings {
                 })),
                 #[cfg(target_arch = "wasm32")]
                 SignerSettings::Remote { .. } => Err(Error::WasmNoRemoteSigner),
+                #[cfg(all(feature = "pkcs11", not(target_arch = "wasm32")))]
+                SignerSettings::Pkcs11 {
+                    module_path,
+                    token_label,
+                    key_id,
+                    pin,
+                    alg,
+                    tsa_url,
+                } => {
+                    let key_id = hex::decode(&key_id).map_err(|_| {
+                        Error::BadParam("signer.pkcs11.key_id must be hex encoded".to_string())
+                    })?;
+
+                    create_signer::from_pkcs11(
+                        module_path,
+                        &token_label,
+                        &key_id,
+                        pin.as_deref(),
+                        alg,
+                        tsa_url,
+                    )
+                }
             },
             #[cfg(test)]
             _ => Ok(crate::utils::test_signer::test_signer(SigningAlg::Ps256)),
@@ -94,6 +200,180 @@ impl SignerSettings {
             _ => Err(Error::MissingSignerSettings),
         }
     }
+
+    /// Returns the constructed async signer from the [BuilderSettings::signer] field.
+    ///
+    /// Remote signing and time stamp requests are made over a non-blocking HTTP
+    /// client, so the signer can be used from within an async runtime.
+    ///
+    /// If the signer settings aren't specified, this function will return [Error::MissingSignerSettings][crate::Error::MissingSignerSettings].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn async_signer() -> Result<Box<dyn AsyncSigner + Send + Sync>> {
+        let signer_info = Settings::get_value::<Option<SignerSettings>>("signer");
+        match signer_info {
+            Ok(Some(signer_info)) => match signer_info {
+                SignerSettings::Local {
+                    alg,
+                    sign_cert,
+                    private_key,
+                    tsa_url,
+                    local_tsa,
+                } => match local_tsa {
+                    Some(local_tsa) => create_signer::async_from_keys_with_local_tsa(
+                        sign_cert.as_bytes(),
+                        private_key.as_bytes(),
+                        alg,
+                        local_tsa.sign_cert.as_bytes(),
+                        local_tsa.private_key.as_bytes(),
+                        local_tsa.alg,
+                    ),
+                    None => create_signer::async_from_keys(
+                        sign_cert.as_bytes(),
+                        private_key.as_bytes(),
+                        alg,
+                        tsa_url,
+                    ),
+                },
+                SignerSettings::Remote {
+                    url,
+                    alg,
+                    sign_cert,
+                    tsa_url,
+                } => Ok(Box::new(RemoteSigner {
+                    url,
+                    alg,
+                    reserve_size: 10000 + sign_cert.len(),
+                    certs: vec![sign_cert.into_bytes()],
+                    tsa_url,
+                })),
+                #[cfg(feature = "pkcs11")]
+                SignerSettings::Pkcs11 {
+                    module_path,
+                    token_label,
+                    key_id,
+                    pin,
+                    alg,
+                    tsa_url,
+                } => {
+                    let key_id = hex::decode(&key_id).map_err(|_| {
+                        Error::BadParam("signer.pkcs11.key_id must be hex encoded".to_string())
+                    })?;
+
+                    create_signer::async_from_pkcs11(
+                        module_path,
+                        &token_label,
+                        &key_id,
+                        pin.as_deref(),
+                        alg,
+                        tsa_url,
+                    )
+                }
+            },
+            #[cfg(test)]
+            _ => Ok(crate::utils::test_signer::async_test_signer(
+                SigningAlg::Ps256,
+            )),
+            #[cfg(not(test))]
+            _ => Err(Error::MissingSignerSettings),
+        }
+    }
+}
+
+impl SignerSettings {
+    /// Returns a [RawSigner] for these settings.
+    ///
+    /// Credential holders, such as the one configured by `cawg_x509_signer`,
+    /// sign with a raw signer rather than a [Signer].
+    pub(crate) fn raw_signer(self) -> Result<Box<dyn RawSigner + Send + Sync>> {
+        match self {
+            SignerSettings::Local {
+                alg,
+                sign_cert,
+                private_key,
+                tsa_url,
+                local_tsa,
+            } => match local_tsa {
+                Some(local_tsa) => Ok(Box::new(LocalTimeStampSigner::new(
+                    signer_from_cert_chain_and_private_key(
+                        sign_cert.as_bytes(),
+                        private_key.as_bytes(),
+                        alg,
+                        None,
+                    )?,
+                    LocalTimeStampAuthority::from_cert_chain_and_private_key(
+                        local_tsa.sign_cert.as_bytes(),
+                        local_tsa.private_key.as_bytes(),
+                        local_tsa.alg,
+                    )?,
+                ))),
+                None => Ok(signer_from_cert_chain_and_private_key(
+                    sign_cert.as_bytes(),
+                    private_key.as_bytes(),
+                    alg,
+                    tsa_url,
+                )?),
+            },
+            #[cfg(not(target_arch = "wasm32"))]
+            SignerSettings::Remote {
+                url,
+                alg,
+                sign_cert,
+                tsa_url,
+            } => Ok(Box::new(RemoteSigner {
+                url,
+                alg,
+                reserve_size: 10000 + sign_cert.len(),
+                certs: vec![sign_cert.into_bytes()],
+                tsa_url,
+            })),
+            #[cfg(target_arch = "wasm32")]
+            SignerSettings::Remote { .. } => Err(Error::WasmNoRemoteSigner),
+            #[cfg(all(feature = "pkcs11", not(target_arch = "wasm32")))]
+            SignerSettings::Pkcs11 {
+                module_path,
+                token_label,
+                key_id,
+                pin,
+                alg,
+                tsa_url,
+            } => {
+                let key_id = hex::decode(&key_id).map_err(|_| {
+                    Error::BadParam("signer.pkcs11.key_id must be hex encoded".to_string())
+                })?;
+
+                Ok(crate::crypto::raw_signature::signer_from_pkcs11_token(
+                    std::path::Path::new(&module_path),
+                    &token_label,
+                    &key_id,
+                    pin.as_deref(),
+                    alg,
+                    tsa_url,
+                )?)
+            }
+        }
+    }
+
+    /// Returns an [AsyncRawSigner] for these settings.
+    ///
+    /// Remote signing requests are made without blocking.
+    pub(crate) fn async_raw_signer(self) -> Result<Box<dyn AsyncRawSigner + Send + Sync>> {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            SignerSettings::Remote {
+                url,
+                alg,
+                sign_cert,
+                tsa_url,
+            } => Ok(Box::new(RemoteSigner {
+                url,
+                alg,
+                reserve_size: 10000 + sign_cert.len(),
+                certs: vec![sign_cert.into_bytes()],
+                tsa_url,
+            })),
+            settings => Ok(async_signer_from_raw_signer(settings.raw_signer()?)),
+        }
+    }
 }
 
 impl SettingsValidate for SignerSettings {
@@ -103,6 +383,26 @@ impl SettingsValidate for SignerSettings {
             return Err(Error::WasmNoRemoteSigner);
         }
 
+        if let SignerSettings::Local {
+            tsa_url: Some(_),
+            local_tsa: Some(_),
+            ..
+        } = self
+        {
+            return Err(Error::BadParam(
+                "signer.local.tsa_url and signer.local.local_tsa cannot both be set".to_string(),
+            ));
+        }
+
+        #[cfg(all(feature = "pkcs11", not(target_arch = "wasm32")))]
+        if let SignerSettings::Pkcs11 { key_id, .. } = self {
+            if hex::decode(key_id).is_err() {
+                return Err(Error::BadParam(
+                    "signer.pkcs11.key_id must be hex encoded".to_string(),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -118,8 +418,8 @@ pub(crate) struct RemoteSigner {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-impl Signer for RemoteSigner {
-    fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+impl RemoteSigner {
+    fn post(&self, data: &[u8]) -> Result<Vec<u8>> {
         use std::io::Read;
 
         let response = ureq::post(&self.url)
@@ -129,10 +429,72 @@ impl Signer for RemoteSigner {
         response
             .into_body()
             .into_reader()
-            .take(self.reserve_size as u64)
+            .take(self.reserve_size as u64 + 1)
             .read_to_end(&mut bytes)?;
+        self.check_size(bytes)
+    }
+
+    async fn post_async(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        let mut response = reqwest::Client::new()
+            .post(&self.url)
+            .body(data)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|_| Error::FailedToRemoteSign)?;
+
+        let mut bytes: Vec<u8> = Vec::with_capacity(self.reserve_size);
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|_| Error::FailedToRemoteSign)?
+        {
+            bytes.extend_from_slice(&chunk);
+            if bytes.len() > self.reserve_size {
+                break;
+            }
+        }
+        self.check_size(bytes)
+    }
+
+    // A signature that doesn't fit in the reserved space can't be used.
+    fn check_size(&self, bytes: Vec<u8>) -> Result<Vec<u8>> {
+        if bytes.len() > self.reserve_size {
+            return Err(Error::CoseSigboxTooSmall);
+        }
         Ok(bytes)
     }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Signer for RemoteSigner {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        self.post(data)
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.alg
+    }
+
+    fn certs(&self) -> Result<Vec<Vec<u8>>> {
+        Ok(self.certs.clone())
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.reserve_size
+    }
+
+    fn time_authority_url(&self) -> Option<String> {
+        self.tsa_url.clone()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl AsyncSigner for RemoteSigner {
+    async fn sign(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        self.post_async(data).await
+    }
 
     fn alg(&self) -> SigningAlg {
         self.alg
@@ -151,6 +513,63 @@ impl Signer for RemoteSigner {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl RawSigner for RemoteSigner {
+    fn sign(&self, data: &[u8]) -> std::result::Result<Vec<u8>, RawSignerError> {
+        self.post(data)
+            .map_err(|e| RawSignerError::InternalError(e.to_string()))
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.alg
+    }
+
+    fn cert_chain(&self) -> std::result::Result<Vec<Vec<u8>>, RawSignerError> {
+        Ok(self.certs.clone())
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.reserve_size
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TimeStampProvider for RemoteSigner {
+    fn time_stamp_service_url(&self) -> Option<String> {
+        self.tsa_url.clone()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl AsyncRawSigner for RemoteSigner {
+    async fn sign(&self, data: Vec<u8>) -> std::result::Result<Vec<u8>, RawSignerError> {
+        self.post_async(data)
+            .await
+            .map_err(|e| RawSignerError::InternalError(e.to_string()))
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.alg
+    }
+
+    fn cert_chain(&self) -> std::result::Result<Vec<Vec<u8>>, RawSignerError> {
+        Ok(self.certs.clone())
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.reserve_size
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl AsyncTimeStampProvider for RemoteSigner {
+    fn time_stamp_service_url(&self) -> Option<String> {
+        self.tsa_url.clone()
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     #![allow(clippy::unwrap_used)]
@@ -199,6 +618,55 @@ pub mod tests {
         assert!(signer.sign(&[1, 2, 3]).is_ok());
     }
 
+    #[test]
+    fn test_make_local_signer_with_local_tsa() {
+        #[cfg(target_os = "wasi")]
+        Settings::reset().unwrap();
+
+        let alg = SigningAlg::Ps384;
+        let (sign_cert, private_key) = test_signer::cert_chain_and_private_key_for_alg(alg);
+        let (tsa_cert, tsa_key) =
+            test_signer::cert_chain_and_private_key_for_alg(SigningAlg::Es256);
+        Settings::from_toml(
+            &toml::toml! {
+                [signer.local]
+                alg = (alg.to_string())
+                sign_cert = (String::from_utf8(sign_cert.to_vec()).unwrap())
+                private_key = (String::from_utf8(private_key.to_vec()).unwrap())
+
+                [signer.local.local_tsa]
+                alg = "es256"
+                sign_cert = (String::from_utf8(tsa_cert.to_vec()).unwrap())
+                private_key = (String::from_utf8(tsa_key.to_vec()).unwrap())
+            }
+            .to_string(),
+        )
+        .unwrap();
+
+        let signer = Settings::signer().unwrap();
+        assert_eq!(signer.alg(), alg);
+        assert_eq!(signer.time_authority_url(), None);
+        assert!(signer.send_timestamp_request(&[1, 2, 3]).unwrap().is_ok());
+
+        // A remote and a local time stamp authority can't both be configured.
+        assert!(Settings::from_toml(
+            &toml::toml! {
+                [signer.local]
+                alg = (alg.to_string())
+                sign_cert = (String::from_utf8(sign_cert.to_vec()).unwrap())
+                private_key = (String::from_utf8(private_key.to_vec()).unwrap())
+                tsa_url = "http://timestamp.digicert.com"
+
+                [signer.local.local_tsa]
+                alg = "es256"
+                sign_cert = (String::from_utf8(tsa_cert.to_vec()).unwrap())
+                private_key = (String::from_utf8(tsa_key.to_vec()).unwrap())
+            }
+            .to_string(),
+        )
+        .is_err());
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     #[test]
     fn test_make_remote_signer() {
@@ -236,4 +704,174 @@ pub mod tests {
 
         mock.assert();
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_make_async_local_signer() {
+        let alg = SigningAlg::Es384;
+        let (sign_cert, private_key) = test_signer::cert_chain_and_private_key_for_alg(alg);
+        Settings::from_toml(
+            &toml::toml! {
+                [signer.local]
+                alg = (alg.to_string())
+                sign_cert = (String::from_utf8(sign_cert.to_vec()).unwrap())
+                private_key = (String::from_utf8(private_key.to_vec()).unwrap())
+                tsa_url = "http://timestamp.example.com"
+            }
+            .to_string(),
+        )
+        .unwrap();
+
+        let signer = Settings::async_signer().unwrap();
+        assert_eq!(signer.alg(), alg);
+        assert_eq!(
+            signer.time_authority_url().as_deref(),
+            Some("http://timestamp.example.com")
+        );
+        assert!(signer.async_raw_signer().is_some());
+        assert!(signer.sign(vec![1, 2, 3]).await.is_ok());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_make_async_local_signer_with_local_tsa() {
+        let alg = SigningAlg::Ps384;
+        let (sign_cert, private_key) = test_signer::cert_chain_and_private_key_for_alg(alg);
+        let (tsa_cert, tsa_key) =
+            test_signer::cert_chain_and_private_key_for_alg(SigningAlg::Es256);
+        Settings::from_toml(
+            &toml::toml! {
+                [signer.local]
+                alg = (alg.to_string())
+                sign_cert = (String::from_utf8(sign_cert.to_vec()).unwrap())
+                private_key = (String::from_utf8(private_key.to_vec()).unwrap())
+
+                [signer.local.local_tsa]
+                alg = "es256"
+                sign_cert = (String::from_utf8(tsa_cert.to_vec()).unwrap())
+                private_key = (String::from_utf8(tsa_key.to_vec()).unwrap())
+            }
+            .to_string(),
+        )
+        .unwrap();
+
+        let signer = Settings::async_signer().unwrap();
+        assert_eq!(signer.time_authority_url(), None);
+        assert!(signer
+            .send_timestamp_request(&[1, 2, 3])
+            .await
+            .unwrap()
+            .is_ok());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_make_async_remote_signer() {
+        use httpmock::MockServer;
+
+        use crate::create_signer;
+
+        let alg = SigningAlg::Ps384;
+        let (sign_cert, private_key) = test_signer::cert_chain_and_private_key_for_alg(alg);
+
+        let signer = create_signer::from_keys(sign_cert, private_key, alg, None).unwrap();
+        let signed_bytes = signer.sign(&[1, 2, 3]).unwrap();
+
+        let server = MockServer::start_async().await;
+        let mock = remote_signer_mock_server(&server, &signed_bytes);
+
+        Settings::from_toml(
+            &toml::toml! {
+                [signer.remote]
+                url = (server.base_url())
+                alg = (alg.to_string())
+                sign_cert = (String::from_utf8(sign_cert.to_vec()).unwrap())
+                tsa_url = "http://timestamp.example.com"
+            }
+            .to_string(),
+        )
+        .unwrap();
+
+        let signer = Settings::async_signer().unwrap();
+        assert_eq!(signer.alg(), alg);
+        assert_eq!(
+            signer.time_authority_url().as_deref(),
+            Some("http://timestamp.example.com")
+        );
+        assert_eq!(signer.sign(vec![1, 2, 3]).await.unwrap(), signed_bytes);
+
+        mock.assert_async().await;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_remote_signer_rejects_oversized_signature() {
+        use httpmock::MockServer;
+
+        let alg = SigningAlg::Ps384;
+        let (sign_cert, _) = test_signer::cert_chain_and_private_key_for_alg(alg);
+
+        // The reserve size is 10000 bytes plus the certificate.
+        let signed_bytes = vec![0u8; 10001 + sign_cert.len()];
+
+        let server = MockServer::start_async().await;
+        let mock = remote_signer_mock_server(&server, &signed_bytes);
+
+        Settings::from_toml(
+            &toml::toml! {
+                [signer.remote]
+                url = (server.base_url())
+                alg = (alg.to_string())
+                sign_cert = (String::from_utf8(sign_cert.to_vec()).unwrap())
+            }
+            .to_string(),
+        )
+        .unwrap();
+
+        let signer = Settings::async_signer().unwrap();
+        assert!(matches!(
+            signer.sign(vec![1, 2, 3]).await,
+            Err(crate::Error::CoseSigboxTooSmall)
+        ));
+
+        let signer = Settings::signer().unwrap();
+        assert!(matches!(
+            signer.sign(&[1, 2, 3]),
+            Err(crate::Error::CoseSigboxTooSmall)
+        ));
+
+        mock.assert_hits_async(2).await;
+    }
+
+    #[cfg(all(feature = "pkcs11", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_make_pkcs11_signer() {
+        // Settings are parsed and validated, but the module doesn't exist.
+        Settings::from_toml(
+            &toml::toml! {
+                [signer.pkcs11]
+                module_path = "/nonexistent/libpkcs11.so"
+                token_label = "c2pa"
+                key_id = "01"
+                alg = "es256"
+            }
+            .to_string(),
+        )
+        .unwrap();
+
+        assert!(Settings::signer().is_err());
+        assert!(Settings::async_signer().is_err());
+
+        assert!(Settings::from_toml(
+            &toml::toml! {
+                [signer.pkcs11]
+                module_path = "/nonexistent/libpkcs11.so"
+                token_label = "c2pa"
+                key_id = "not hex"
+                alg = "es256"
+            }
+            .to_string(),
+        )
+        .is_err());
+    }
 }
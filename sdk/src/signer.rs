@@ -192,8 +192,6 @@ pub trait AsyncSigner: Sync {
     /// The default implementation will send the request to the URL
     /// provided by [`Self::time_authority_url()`], if any.
     async fn send_timestamp_request(&self, message: &[u8]) -> Option<Result<Vec<u8>>> {
-        // NOTE: This is currently synchronous, but may become
-        // async in the future.
         if let Some(url) = self.time_authority_url() {
             if let Ok(body) = self.timestamp_request_body(message) {
                 let headers: Option<Vec<(String, String)>> = self.timestamp_request_headers();
@@ -603,3 +601,60 @@ impl Signer for RawSignerWrapper {
         Some(Box::new(&*self.0))
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(dead_code)] // Not used in all configurations.
+pub(crate) struct AsyncRawSignerWrapper(pub(crate) Box<dyn AsyncRawSigner + Sync + Send>);
+
+#[cfg(target_arch = "wasm32")]
+#[allow(dead_code)] // Not used in all configurations.
+pub(crate) struct AsyncRawSignerWrapper(pub(crate) Box<dyn AsyncRawSigner>);
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl AsyncSigner for AsyncRawSignerWrapper {
+    async fn sign(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        self.0.sign(data).await.map_err(|e| e.into())
+    }
+
+    fn alg(&self) -> SigningAlg {
+        self.0.alg()
+    }
+
+    fn certs(&self) -> Result<Vec<Vec<u8>>> {
+        self.0.cert_chain().map_err(|e| e.into())
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.0.reserve_size()
+    }
+
+    async fn ocsp_val(&self) -> Option<Vec<u8>> {
+        self.0.ocsp_response().await
+    }
+
+    fn time_authority_url(&self) -> Option<String> {
+        self.0.time_stamp_service_url()
+    }
+
+    fn timestamp_request_headers(&self) -> Option<Vec<(String, String)>> {
+        self.0.time_stamp_request_headers()
+    }
+
+    fn timestamp_request_body(&self, message: &[u8]) -> Result<Vec<u8>> {
+        self.0
+            .time_stamp_request_body(message)
+            .map_err(|e| e.into())
+    }
+
+    async fn send_timestamp_request(&self, message: &[u8]) -> Option<Result<Vec<u8>>> {
+        self.0
+            .send_time_stamp_request(message)
+            .await
+            .map(|r| r.map_err(|e| e.into()))
+    }
+
+    fn async_raw_signer(&self) -> Option<Box<&dyn AsyncRawSigner>> {
+        Some(Box::new(&*self.0))
+    }
+}
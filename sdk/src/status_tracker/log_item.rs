@@ -138,6 +138,9 @@ pub struct LogItem {
 
     /// Ingredient URI (for ingredient-related logs)
     pub ingredient_uri: Option<Cow<'static, str>>,
+
+    /// Name of the trust policy that was applied (for trust-related logs)
+    pub trust_policy: Option<Cow<'static, str>>,
 }
 
 impl Default for LogItem {
@@ -154,6 +157,7 @@ impl Default for LogItem {
             err_val: None,
             validation_status: None,
             ingredient_uri: None,
+            trust_policy: None,
         }
     }
 }
@@ -209,6 +213,23 @@ impl LogItem {
         }
     }
 
+    /// Record the trust policy that was applied.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use c2pa::log_item;
+    /// let log = log_item!("test1", "test item 1", "test func").trust_policy("time_stamp");
+    /// assert_eq!(log.trust_policy.as_deref(), Some("time_stamp"));
+    /// ```
+    #[must_use]
+    pub fn trust_policy(self, policy: &'static str) -> Self {
+        LogItem {
+            trust_policy: Some(policy.into()),
+            ..self
+        }
+    }
+
     /// Set the log item kind to [`LogKind::Success`] and add it to the
     /// [`StatusTracker`].
     pub fn success(mut self, tracker: &mut StatusTracker) {
@@ -285,10 +306,7 @@ mod tests {
 
     use std::borrow::Cow;
 
-    use crate::{
-        log_item,
-        status_tracker::{LogItem, LogKind, StatusTracker},
-    };
+    use crate::status_tracker::{LogItem, LogKind, StatusTracker};
 
     #[test]
     fn r#macro() {
@@ -360,6 +378,7 @@ mod tests {
                 err_val: None,
                 validation_status: None,
                 ingredient_uri: None,
+                trust_policy: None,
             }
         );
     }
@@ -17,6 +17,8 @@ use std::{fmt::Debug, iter::Iterator};
 
 use log::{error, info};
 
+use crate::{crypto::cose::TrustReport, validation_results::ValidationCheck};
+
 /// A `StatusTracker` is used in the validation logic of c2pa-rs and
 /// related crates to control error-handling behavior and optionally
 /// aggregate log messages as they are generated.
@@ -26,6 +28,8 @@ pub struct StatusTracker {
     logged_items: Vec<LogItem>,
     ingredient_uris: Vec<String>,
     current_uri: Vec<String>,
+    skipped_checks: Vec<ValidationCheck>,
+    trust_reports: Vec<TrustReport>,
 }
 
 impl StatusTracker {
@@ -36,9 +40,16 @@ impl StatusTracker {
             logged_items: vec![],
             ingredient_uris: vec![],
             current_uri: vec![],
+            skipped_checks: vec![],
+            trust_reports: vec![],
         }
     }
 
+    /// Returns the [`ErrorBehavior`] this status tracker was created with.
+    pub fn error_behavior(&self) -> ErrorBehavior {
+        self.error_behavior
+    }
+
     /// Returns the current list of validation log items.
     pub fn logged_items(&self) -> &[LogItem] {
         &self.logged_items
@@ -55,6 +66,38 @@ impl StatusTracker {
         for log_item in other.logged_items() {
             self.add_non_error(log_item.clone());
         }
+        for check in other.skipped_checks() {
+            self.skip_check(*check);
+        }
+        self.trust_reports
+            .extend(other.trust_reports().iter().cloned());
+    }
+
+    /// Records that a group of checks was not run.
+    pub fn skip_check(&mut self, check: ValidationCheck) {
+        if !self.skipped_checks.contains(&check) {
+            self.skipped_checks.push(check);
+        }
+    }
+
+    /// Returns the checks that were not run.
+    pub fn skipped_checks(&self) -> &[ValidationCheck] {
+        &self.skipped_checks
+    }
+
+    /// Records the report explaining a trust decision.
+    ///
+    /// If the report has no URL, it is attributed to the current URI.
+    pub fn add_trust_report(&mut self, mut report: TrustReport) {
+        if report.url.is_none() {
+            report.url = self.current_uri.last().cloned();
+        }
+        self.trust_reports.push(report);
+    }
+
+    /// Returns the reports explaining the trust decisions made so far.
+    pub fn trust_reports(&self) -> &[TrustReport] {
+        &self.trust_reports
     }
 
     /// Adds a non-error [`LogItem`] to this status tracker.
@@ -177,22 +220,17 @@ impl StatusTracker {
 /// [`add_error`] function is called.
 ///
 /// [`add_error`]: StatusTracker::add_error
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default)]
 pub enum ErrorBehavior {
     /// If an error is encountered, stop validation immediately.
     StopOnFirstError,
 
     /// If an error is encountered, log it and continue validation as much as
     /// possible.
+    #[default]
     ContinueWhenPossible,
 }
 
-impl Default for ErrorBehavior {
-    fn default() -> Self {
-        Self::ContinueWhenPossible
-    }
-}
-
 mod log_item;
 pub use log_item::{LogItem, LogKind};
 
@@ -39,21 +39,22 @@ use crate::{
     assertion::{Assertion, AssertionBase, AssertionData, AssertionDecodeError},
     assertions::{
         labels::{self, CLAIM},
-        BmffHash, CertificateStatus, DataBox, DataHash, DataMap, ExclusionsMap, Ingredient,
-        MerkleMap, Relationship, SubsetMap, TimeStamp, User, UserCbor, VecByteBuf,
+        BmffHash, CertificateStatus, CollectionMembers, DataBox, DataHash, DataMap, ExclusionsMap,
+        Ingredient, MerkleMap, Relationship, SubsetMap, TimeStamp, User, UserCbor, VecByteBuf,
     },
     asset_handlers::bmff_io::read_bmff_c2pa_boxes,
     asset_io::{
         CAIRead, CAIReadWrite, HashBlockObjectType, HashObjectPositions, RemoteRefEmbedType,
+        StreamFactory,
     },
     claim::{check_ocsp_status, Claim, ClaimAssertion, ClaimAssetData, RemoteManifest},
-    cose_sign::{cose_sign, cose_sign_async},
+    cose_sign::{cose_sign, cose_sign_async, SignatureReserve},
     cose_validator::{verify_cose, verify_cose_async},
     crypto::{
         asn1::rfc3161::TstInfo,
         cose::{
             fetch_and_check_ocsp_response, parse_cose_sign1, CertificateTrustPolicy,
-            TimeStampStorage,
+            TimeStampStorage, TrustList, TrustPolicyRole,
         },
         hash::sha256,
         ocsp::OcspResponse,
@@ -88,8 +89,11 @@ use crate::{
         is_zero,
         patch::patch_bytes,
     },
-    validation_results::validation_codes::{
-        ASSERTION_CBOR_INVALID, ASSERTION_JSON_INVALID, ASSERTION_MISSING, CLAIM_MALFORMED,
+    validation_results::{
+        validation_codes::{
+            ASSERTION_CBOR_INVALID, ASSERTION_JSON_INVALID, ASSERTION_MISSING, CLAIM_MALFORMED,
+        },
+        ValidationLevel,
     },
     validation_status::{self, ALGORITHM_UNSUPPORTED},
     AsyncSigner, Signer,
@@ -115,6 +119,7 @@ pub(crate) struct StoreValidationInfo<'a> {
     pub timestamps: HashMap<String, TstInfo>,     // list of timestamp assertions for each claim
     pub update_manifest_size: usize,              // offset needed to correct for update manifests
     pub certificate_statuses: HashMap<String, Vec<Vec<u8>>>, // list of certificate status assertions for each serial
+    pub level: ValidationLevel,                              // which checks to run
 }
 
 /// A `Store` maintains a list of `Claim` structs.
@@ -169,6 +174,12 @@ impl Store {
             let _v = store.add_trust(ta.as_bytes());
         }
 
+        if let Ok(Some(tl)) = get_settings_value::<Option<String>>("trust.trust_list") {
+            if let Err(e) = store.add_trust_list(tl.as_bytes()) {
+                error!("could not load trust.trust_list setting: {e}");
+            }
+        }
+
         if let Ok(Some(pa)) = get_settings_value::<Option<String>>("trust.user_anchors") {
             let _v = store.add_user_trust_anchors(pa.as_bytes());
         }
@@ -181,6 +192,17 @@ impl Store {
             let _v = store.add_trust_allowed_list(al.as_bytes());
         }
 
+        if let Ok(Some(im)) = get_settings_value::<Option<String>>("trust.intermediates") {
+            let _v = store.add_intermediate_certificates(im.as_bytes());
+        }
+
+        // time stamp authorities may be trusted independently of claim signers
+        match CertificateTrustPolicy::from_settings("timestamp_trust", TrustPolicyRole::TimeStamp) {
+            Ok(Some(tsa_ctp)) => store.ctp.set_time_stamp_policy(tsa_ctp),
+            Ok(None) => (),
+            Err(e) => error!("could not load timestamp_trust settings: {e}"),
+        }
+
         store
     }
 
@@ -213,6 +235,13 @@ impl Store {
         Ok(self.ctp.add_trust_anchors(trust_vec)?)
     }
 
+    /// Load a C2PA trust list in JSON format. Its anchors are only trusted for
+    /// signatures made within their trust windows.
+    pub fn add_trust_list(&mut self, trust_list: &[u8]) -> Result<()> {
+        self.ctp.add_trust_list(&TrustList::from_json(trust_list)?);
+        Ok(())
+    }
+
     // Load set of user trust anchors used for certificate validation. [u8] to the
     /// user trust anchors is passed in the trust_vec variable.  This can be called multiple times
     /// if there are additional trust stores.
@@ -229,6 +258,13 @@ impl Store {
         Ok(self.ctp.add_end_entity_credentials(allowed_vec)?)
     }
 
+    /// Load a pool of intermediate certificates used to complete certificate
+    /// chains that are missing issuers. [u8] containing the PEM certificates is
+    /// passed in the intermediates_vec variable.
+    pub fn add_intermediate_certificates(&mut self, intermediates_vec: &[u8]) -> Result<()> {
+        Ok(self.ctp.add_intermediate_certificates(intermediates_vec)?)
+    }
+
     /// Clear all existing trust anchors
     #[cfg(feature = "v1_api")]
     pub fn clear_trust_anchors(&mut self) {
@@ -536,13 +572,13 @@ impl Store {
         &self,
         claim: &Claim,
         signer: &dyn AsyncSigner,
-        box_size: usize,
+        reserve: &SignatureReserve,
     ))]
-    pub fn sign_claim(
+    pub(crate) fn sign_claim(
         &self,
         claim: &Claim,
         signer: &dyn Signer,
-        box_size: usize,
+        reserve: &SignatureReserve,
     ) -> Result<Vec<u8>> {
         let claim_bytes = claim.data()?;
 
@@ -557,7 +593,7 @@ impl Store {
                 // Let the signer do all the COSE processing and return the structured COSE data.
                 return signer.sign(&claim_bytes); // do not verify remote signers (we never did)
             } else {
-                cose_sign(signer, &claim_bytes, box_size, tss)
+                cose_sign(signer, &claim_bytes, reserve, tss)
             }
         } else {
             if signer.direct_cose_handling() {
@@ -565,7 +601,7 @@ impl Store {
                 return signer.sign(claim_bytes.clone()).await;
             // do not verify remote signers (we never did)
             } else {
-                cose_sign_async(signer, &claim_bytes, box_size, tss).await
+                cose_sign_async(signer, &claim_bytes, reserve, tss).await
             }
         };
         match result {
@@ -695,10 +731,7 @@ impl Store {
         }
     }
 
-    fn add_assertion_to_jumbf_store(
-        store: &mut CAIAssertionStore,
-        claim_assertion: &ClaimAssertion,
-    ) -> Result<()> {
+    fn assertion_to_jumbf_box(claim_assertion: &ClaimAssertion) -> Result<Box<dyn BMFFBox>> {
         // Grab assertion data object.
         let d = claim_assertion.assertion().decode_data();
 
@@ -709,7 +742,7 @@ impl Store {
                 if let Some(salt) = claim_assertion.salt() {
                     json_data.set_salt(salt.clone())?;
                 }
-                store.add_assertion(Box::new(json_data));
+                Ok(Box::new(json_data))
             }
             AssertionData::Binary(_) => {
                 // TODO: Handle other binary box types if needed.
@@ -722,7 +755,7 @@ impl Store {
                 if let Some(salt) = claim_assertion.salt() {
                     data.set_salt(salt.clone())?;
                 }
-                store.add_assertion(Box::new(data));
+                Ok(Box::new(data))
             }
             AssertionData::Cbor(_) => {
                 let mut cbor_data = CAICBORAssertionBox::new(&claim_assertion.label());
@@ -730,7 +763,7 @@ impl Store {
                 if let Some(salt) = claim_assertion.salt() {
                     cbor_data.set_salt(salt.clone())?;
                 }
-                store.add_assertion(Box::new(cbor_data));
+                Ok(Box::new(cbor_data))
             }
             AssertionData::Uuid(s, _) => {
                 let mut uuid_data = CAIUUIDAssertionBox::new(&claim_assertion.label());
@@ -738,10 +771,9 @@ impl Store {
                 if let Some(salt) = claim_assertion.salt() {
                     uuid_data.set_salt(salt.clone())?;
                 }
-                store.add_assertion(Box::new(uuid_data));
+                Ok(Box::new(uuid_data))
             }
         }
-        Ok(())
     }
 
     // look for old style hashing to determine if this is a pre 1.0 claim
@@ -938,7 +970,7 @@ impl Store {
     /// Convert this claims store to a JUMBF box.
     #[allow(unused)] // used in tests
     pub fn to_jumbf(&self, signer: &dyn Signer) -> Result<Vec<u8>> {
-        self.to_jumbf_internal(signer.reserve_size())
+        self.to_jumbf_internal(SignatureReserve::new(signer)?.size())
     }
 
     /// Convert this claims store to a JUMBF box.
@@ -951,9 +983,29 @@ impl Store {
         // Create the CAI block.
         let mut cai_block = Cai::new();
 
+        let compress = get_settings_value::<bool>("core.compress_manifests").unwrap_or(false);
+        let provenance_label = self.provenance_label();
+
         // Add claims and assertions in this store to the JUMBF store.
         for claim in self.claims() {
-            let cai_store = Store::build_manifest_box(claim, min_reserve_size)?;
+            let is_active = provenance_label.as_deref() == Some(claim.label());
+
+            // The active manifest is patched in place after this call, so its
+            // size must not depend on the values still to be filled in. Only its
+            // assertions that are already final are compressed, one at a time.
+            // A claim read from a file is written back as it was read.
+            let compress_assertions = compress && is_active && !claim.has_original_data();
+            let cai_store =
+                Store::build_manifest_box(claim, min_reserve_size, compress_assertions)?;
+
+            // Manifests that are already signed are compressed whole.
+            if compress && !is_active {
+                let compressed = BrotliCompressedBox::compress(&cai_store)?;
+                if compressed.box_size()? < cai_store.box_size()? {
+                    cai_block.add_box(Box::new(compressed));
+                    continue;
+                }
+            }
 
             // add the completed CAI store into the CAI block.
             cai_block.add_box(Box::new(cai_store));
@@ -970,7 +1022,11 @@ impl Store {
         }
     }
 
-    fn build_manifest_box(claim: &Claim, min_reserve_size: usize) -> Result<CAIStore> {
+    fn build_manifest_box(
+        claim: &Claim,
+        min_reserve_size: usize,
+        compress_assertions: bool,
+    ) -> Result<CAIStore> {
         // box label
         let label = claim.label();
 
@@ -984,7 +1040,17 @@ impl Store {
                     // add assertions to CAI assertion store.
                     let cas = claim.claim_assertion_store();
                     for assertion in cas {
-                        Store::add_assertion_to_jumbf_store(&mut a_store, assertion)?;
+                        let assertion_box = Store::assertion_to_jumbf_box(assertion)?;
+
+                        if compress_assertions && !claim.is_patched_assertion(&assertion.label()) {
+                            let compressed = BrotliCompressedBox::compress(assertion_box.as_ref())?;
+                            if compressed.box_size()? < assertion_box.box_size()? {
+                                a_store.add_assertion(Box::new(compressed));
+                                continue;
+                            }
+                        }
+
+                        a_store.add_assertion(assertion_box);
                     }
 
                     cai_store.add_box(Box::new(a_store)); // add the assertion store to the manifest
@@ -1078,7 +1144,7 @@ impl Store {
         let mut hash_bytes = Vec::with_capacity(4096);
 
         // build box
-        let mut cai_store = Store::build_manifest_box(claim, 0)?;
+        let mut cai_store = Store::build_manifest_box(claim, 0, false)?;
 
         // add salt if requested
         if let Some(salt) = salt {
@@ -1749,6 +1815,7 @@ impl Store {
         &'a self,
         claim: &'a Claim,
         asset_data: &mut ClaimAssetData<'_>,
+        level: ValidationLevel,
         validation_log: &mut StatusTracker,
     ) -> Result<StoreValidationInfo<'a>> {
         let mut svi = StoreValidationInfo::default();
@@ -1780,8 +1847,8 @@ impl Store {
 
         // get the manifest offset size if needed
         // it is not needed for BMFF hash bindings since update manifests always appear
-        // in last BMFF box
-        if claim.update_manifest() && !is_bmff {
+        // in last BMFF box, nor when the hashes will not be checked
+        if claim.update_manifest() && !is_bmff && level == ValidationLevel::Full {
             let locations = match asset_data {
                 #[cfg(feature = "file_io")]
                 ClaimAssetData::Path(path) => {
@@ -1810,6 +1877,14 @@ impl Store {
                     let format = typ.to_owned();
                     object_locations_from_stream(&format, reader)?
                 }
+                ClaimAssetData::Factory(factory, typ) => {
+                    let format = typ.to_owned();
+                    let mut reader = factory.open_stream()?;
+
+                    object_locations_from_stream(&format, &mut reader)?
+                }
+                // collections have no embedded manifest to locate
+                ClaimAssetData::Collection(_) => Vec::new(),
             };
 
             if let Some(manifest_loc) = locations
@@ -1828,8 +1903,9 @@ impl Store {
             }
         }
 
-        // get the timestamp assertions
-        for found_claim in svi.manifest_map.values() {
+        // get the timestamp assertions, which are only needed to check signatures
+        let check_timestamps = level != ValidationLevel::Structural;
+        for found_claim in svi.manifest_map.values().filter(|_| check_timestamps) {
             let timestamp_assertions = found_claim.timestamp_assertions();
             for ta in timestamp_assertions {
                 let timestamp_assertion =
@@ -1908,7 +1984,7 @@ impl Store {
             .failure(validation_log, Error::UnreferencedManifest)?;
         }
 
-        Ok(svi)
+        Ok(StoreValidationInfo { level, ..svi })
     }
 
     /// Verify Store
@@ -1916,11 +1992,32 @@ impl Store {
     /// xmp_str: String containing entire XMP block of the asset
     /// asset_bytes: bytes of the asset to be verified
     /// validation_log: If present all found errors are logged and returned, other wise first error causes exit and is returned
+    ///
+    /// The checks run depend on the `verify.validation_level` setting.
     #[async_generic]
     pub fn verify_store(
         store: &Store,
         asset_data: &mut ClaimAssetData<'_>,
         validation_log: &mut StatusTracker,
+    ) -> Result<()> {
+        let level = get_settings_value::<ValidationLevel>("verify.validation_level")?;
+
+        if _sync {
+            Store::verify_store_at_level(store, asset_data, level, validation_log)
+        } else {
+            Store::verify_store_at_level_async(store, asset_data, level, validation_log).await
+        }
+    }
+
+    /// Validate the store, running only the checks included in `level`.
+    ///
+    /// Skipped checks are recorded in `validation_log`.
+    #[async_generic]
+    pub fn verify_store_at_level(
+        store: &Store,
+        asset_data: &mut ClaimAssetData<'_>,
+        level: ValidationLevel,
+        validation_log: &mut StatusTracker,
     ) -> Result<()> {
         let claim = match store.provenance_claim() {
             Some(c) => c,
@@ -1933,8 +2030,14 @@ impl Store {
             }
         };
 
+        // hashing from here on checks bindings rather than creating them
+        let _phase = crate::progress::enter_phase(crate::progress::ProgressPhase::Validating);
+
         // get info needed to complete validation
-        let svi = store.get_store_validation_info(claim, asset_data, validation_log)?;
+        let svi = store.get_store_validation_info(claim, asset_data, level, validation_log)?;
+        for check in level.skipped_checks() {
+            validation_log.skip_check(*check);
+        }
 
         if _sync {
             // verify the provenance claim
@@ -1960,12 +2063,20 @@ impl Store {
         calc_hashes: bool,
     ) -> Result<Vec<DataHash>> {
         let mut file = std::fs::File::open(asset_path)?;
-        Self::generate_data_hashes_for_stream(&mut file, alg, block_locations, calc_hashes)
+        Self::generate_data_hashes_for_stream(
+            &mut file,
+            Some(&asset_path),
+            alg,
+            block_locations,
+            calc_hashes,
+        )
     }
 
-    // generate a list of AssetHashes based on the location of objects in the stream
+    // generate a list of AssetHashes based on the location of objects in the stream,
+    // reading it on several threads when `factory` is provided and parallel hashing is enabled
     fn generate_data_hashes_for_stream<R>(
         stream: &mut R,
+        factory: Option<&dyn StreamFactory>,
         alg: &str,
         block_locations: &mut Vec<HashObjectPositions>,
         calc_hashes: bool,
@@ -1984,7 +2095,7 @@ impl Store {
         let mut hashes: Vec<DataHash> = Vec::new();
 
         // sort blocks by offset
-        block_locations.sort_by(|a, b| a.offset.cmp(&b.offset));
+        block_locations.sort_by_key(|a| a.offset);
 
         // generate default data hash that excludes jumbf block
         // find the first jumbf block (ours are always in order)
@@ -2027,7 +2138,7 @@ impl Store {
                     ));
                 }
 
-                dh.gen_hash_from_stream(stream)?;
+                dh.gen_hash_from_stream_with_factory(stream, factory)?;
             } else {
                 if block_end > block_start {
                     dh.add_exclusion(HashRange::new(
@@ -2348,14 +2459,14 @@ impl Store {
         format: &str,
         asset_reader: Option<&mut dyn CAIRead>,
     ) -> Result<Vec<u8>> {
-        let mut jumbf_bytes =
-            self.prep_embeddable_store(signer.reserve_size(), dh, asset_reader)?;
+        let reserve = SignatureReserve::with_size(signer, signer.reserve_size())?;
+        let mut jumbf_bytes = self.prep_embeddable_store(reserve.size(), dh, asset_reader)?;
 
         // sign contents
         let pc = self.provenance_claim().ok_or(Error::ClaimEncoding)?;
-        let sig = self.sign_claim(pc, signer, signer.reserve_size())?;
+        let sig = self.sign_claim(pc, signer, &reserve)?;
 
-        let sig_placeholder = Store::sign_claim_placeholder(pc, signer.reserve_size());
+        let sig_placeholder = Store::sign_claim_placeholder(pc, reserve.size());
 
         self.finish_embeddable_store(&sig, &sig_placeholder, &mut jumbf_bytes, format)
     }
@@ -2378,16 +2489,14 @@ impl Store {
         format: &str,
         asset_reader: Option<&mut dyn CAIRead>,
     ) -> Result<Vec<u8>> {
-        let mut jumbf_bytes =
-            self.prep_embeddable_store(signer.reserve_size(), dh, asset_reader)?;
+        let reserve = SignatureReserve::with_size_async(signer, signer.reserve_size()).await?;
+        let mut jumbf_bytes = self.prep_embeddable_store(reserve.size(), dh, asset_reader)?;
 
         // sign contents
         let pc = self.provenance_claim().ok_or(Error::ClaimEncoding)?;
-        let sig = self
-            .sign_claim_async(pc, signer, signer.reserve_size())
-            .await?;
+        let sig = self.sign_claim_async(pc, signer, &reserve).await?;
 
-        let sig_placeholder = Store::sign_claim_placeholder(pc, signer.reserve_size());
+        let sig_placeholder = Store::sign_claim_placeholder(pc, reserve.size());
 
         self.finish_embeddable_store(&sig, &sig_placeholder, &mut jumbf_bytes, format)
     }
@@ -2427,6 +2536,9 @@ impl Store {
     /// Returns a finalized, signed manifest.  The client is required to have
     /// included the necessary box hash assertion with the pregenerated hashes.
     pub fn get_box_hashed_embeddable_manifest(&mut self, signer: &dyn Signer) -> Result<Vec<u8>> {
+        let reserve = SignatureReserve::new(signer)?;
+        let reserve_size = reserve.size();
+
         let pc = self.provenance_claim().ok_or(Error::ClaimEncoding)?;
 
         // make sure there is only one
@@ -2441,11 +2553,11 @@ impl Store {
             return Err(Error::BadParam("Missing box hash assertion".to_string()));
         }
 
-        let mut jumbf_bytes = self.to_jumbf_internal(signer.reserve_size())?;
+        let mut jumbf_bytes = self.to_jumbf_internal(reserve_size)?;
 
         // sign contents
-        let sig = self.sign_claim(pc, signer, signer.reserve_size())?;
-        let sig_placeholder = Store::sign_claim_placeholder(pc, signer.reserve_size());
+        let sig = self.sign_claim(pc, signer, &reserve)?;
+        let sig_placeholder = Store::sign_claim_placeholder(pc, reserve_size);
 
         if sig_placeholder.len() != sig.len() {
             return Err(Error::CoseSigboxTooSmall);
@@ -2463,6 +2575,9 @@ impl Store {
         &mut self,
         signer: &dyn AsyncSigner,
     ) -> Result<Vec<u8>> {
+        let reserve = SignatureReserve::new_async(signer).await?;
+        let reserve_size = reserve.size();
+
         let pc = self.provenance_claim().ok_or(Error::ClaimEncoding)?;
 
         // make sure there is only one
@@ -2477,13 +2592,58 @@ impl Store {
             return Err(Error::BadParam("Missing box hash assertion".to_string()));
         }
 
-        let mut jumbf_bytes = self.to_jumbf_internal(signer.reserve_size())?;
+        let mut jumbf_bytes = self.to_jumbf_internal(reserve_size)?;
 
         // sign contents
-        let sig = self
-            .sign_claim_async(pc, signer, signer.reserve_size())
-            .await?;
-        let sig_placeholder = Store::sign_claim_placeholder(pc, signer.reserve_size());
+        let sig = self.sign_claim_async(pc, signer, &reserve).await?;
+        let sig_placeholder = Store::sign_claim_placeholder(pc, reserve_size);
+
+        if sig_placeholder.len() != sig.len() {
+            return Err(Error::CoseSigboxTooSmall);
+        }
+
+        patch_bytes(&mut jumbf_bytes, &sig_placeholder, &sig)
+            .map_err(|_| Error::JumbfCreationError)?;
+
+        Ok(jumbf_bytes)
+    }
+
+    /// Returns a finalized, signed manifest for a collection of files.  The
+    /// claim must include a collection hash assertion listing the files.
+    #[async_generic(async_signature(&mut self, signer: &dyn AsyncSigner))]
+    pub fn get_collection_hashed_manifest(&mut self, signer: &dyn Signer) -> Result<Vec<u8>> {
+        let reserve = if _sync {
+            SignatureReserve::new(signer)?
+        } else {
+            SignatureReserve::new_async(signer).await?
+        };
+        let reserve_size = reserve.size();
+
+        let pc = self.provenance_claim().ok_or(Error::ClaimEncoding)?;
+
+        // make sure there is only one
+        if pc.hash_assertions().len() != 1 {
+            return Err(Error::BadParam(
+                "Claim must have exactly one hash binding assertion".to_string(),
+            ));
+        }
+
+        // only allow collection hash assertions to be present
+        if pc.collection_hash_assertions().is_empty() {
+            return Err(Error::BadParam(
+                "Missing collection hash assertion".to_string(),
+            ));
+        }
+
+        let mut jumbf_bytes = self.to_jumbf_internal(reserve_size)?;
+
+        // sign contents
+        let sig = if _sync {
+            self.sign_claim(pc, signer, &reserve)
+        } else {
+            self.sign_claim_async(pc, signer, &reserve).await
+        }?;
+        let sig_placeholder = Store::sign_claim_placeholder(pc, reserve_size);
 
         if sig_placeholder.len() != sig.len() {
             return Err(Error::CoseSigboxTooSmall);
@@ -2532,10 +2692,18 @@ impl Store {
 
         let pc = self.provenance_claim_mut().ok_or(Error::ClaimEncoding)?;
         // always add dynamic assertions as gathered assertions
-        assertions
+        let uris = assertions
             .iter()
             .map(|a| pc.add_gathered_assertion_with_salt(a, &DefaultSalt::default()))
-            .collect()
+            .collect::<Result<Vec<_>>>()?;
+
+        for uri in &uris {
+            if let Some(label) = jumbf::labels::assertion_label_from_uri(&uri.url()) {
+                pc.add_patched_assertion(&label);
+            }
+        }
+
+        Ok(uris)
     }
 
     /// Write the dynamic assertions to the manifest.
@@ -2556,8 +2724,6 @@ impl Store {
             return Ok(false);
         }
 
-        let mut final_assertions = Vec::new();
-
         for (da, uri) in dyn_assertions.iter().zip(dyn_uris.iter()) {
             let label = crate::jumbf::labels::assertion_label_from_uri(&uri.url())
                 .ok_or(Error::BadParam("write_dynamic_assertions".to_string()))?;
@@ -2569,23 +2735,31 @@ impl Store {
                 da.content(&label, Some(da_size), preliminary_claim).await?
             };
 
-            match da_data {
+            // The placeholder label may carry an instance suffix when several
+            // dynamic assertions share a label, so build the assertion from the
+            // raw label and replace the placeholder with that exact instance.
+            let assertion = match da_data {
                 DynamicAssertionContent::Cbor(data) => {
-                    final_assertions.push(UserCbor::new(&label, data).to_assertion()?);
+                    UserCbor::new(&da.label(), data).to_assertion()?
                 }
                 DynamicAssertionContent::Json(data) => {
-                    final_assertions.push(User::new(&label, &data).to_assertion()?);
+                    User::new(&da.label(), &data).to_assertion()?
                 }
                 DynamicAssertionContent::Binary(format, data) => {
                     todo!("Binary dynamic assertions not yet supported");
                 }
+            };
+
+            let pc = self.provenance_claim_mut().ok_or(Error::ClaimEncoding)?;
+            pc.update_assertion(assertion, |ca| ca.label() == label, |_, a| Ok(a))?;
+
+            // Later dynamic assertions see this one with its final hash.
+            if let Some(written) = pc.assertions().iter().find(|a| a.url() == uri.url()) {
+                preliminary_claim.update_assertion(written);
             }
         }
 
         let pc = self.provenance_claim_mut().ok_or(Error::ClaimEncoding)?;
-        for assertion in final_assertions {
-            pc.replace_assertion(assertion)?;
-        }
 
         // clear the provenance claim data since the contents are now different
         pc.clear_data();
@@ -2664,6 +2838,9 @@ impl Store {
         output_path: &Path,
         signer: &dyn Signer,
     ) -> Result<()> {
+        let reserve = SignatureReserve::new(signer)?;
+        let reserve_size = reserve.size();
+
         match get_supported_file_extension(asset_path) {
             Some(ext) => {
                 if !is_bmff_format(&ext) {
@@ -2693,7 +2870,7 @@ impl Store {
             asset_path,
             fragments,
             output_path,
-            signer.reserve_size(),
+            reserve_size,
         )?;
 
         let mut preliminary_claim = PartialClaim::default();
@@ -2716,7 +2893,7 @@ impl Store {
             let pc = temp_store.provenance_claim().ok_or(Error::ClaimEncoding)?;
             match pc.remote_manifest() {
                 RemoteManifest::NoRemote | RemoteManifest::EmbedWithRemote(_) => {
-                    jumbf_bytes = temp_store.to_jumbf_internal(signer.reserve_size())?;
+                    jumbf_bytes = temp_store.to_jumbf_internal(reserve_size)?;
 
                     // save the jumbf to the output path
                     save_jumbf_to_file(&jumbf_bytes, &dest_path, Some(&dest_path))?;
@@ -2734,7 +2911,7 @@ impl Store {
                     }
 
                     // regenerate the jumbf because the cbor changed
-                    jumbf_bytes = temp_store.to_jumbf_internal(signer.reserve_size())?;
+                    jumbf_bytes = temp_store.to_jumbf_internal(reserve_size)?;
                 }
                 _ => (),
             };
@@ -2742,8 +2919,8 @@ impl Store {
 
         // sign the claim
         let pc = temp_store.provenance_claim().ok_or(Error::ClaimEncoding)?;
-        let sig = temp_store.sign_claim(pc, signer, signer.reserve_size())?;
-        let sig_placeholder = Store::sign_claim_placeholder(pc, signer.reserve_size());
+        let sig = temp_store.sign_claim(pc, signer, &reserve)?;
+        let sig_placeholder = Store::sign_claim_placeholder(pc, reserve_size);
 
         match temp_store.finish_save(jumbf_bytes, &dest_path, sig, &sig_placeholder) {
             Ok(_) => Ok(()),
@@ -2771,6 +2948,13 @@ impl Store {
         output_stream: &mut dyn CAIReadWrite,
         signer: &dyn Signer,
     ) -> Result<Vec<u8>> {
+        let reserve = if _sync {
+            SignatureReserve::new(signer)?
+        } else {
+            SignatureReserve::new_async(signer).await?
+        };
+        let reserve_size = reserve.size();
+
         let dynamic_assertions = signer.dynamic_assertions();
 
         let da_uris = if _sync {
@@ -2787,12 +2971,8 @@ impl Store {
         let mut intermediate_stream = Cursor::new(intermediate_output);
 
         #[allow(unused_mut)] // Not mutable in the non-async case.
-        let mut jumbf_bytes = self.start_save_stream(
-            format,
-            input_stream,
-            &mut intermediate_stream,
-            signer.reserve_size(),
-        )?;
+        let mut jumbf_bytes =
+            self.start_save_stream(format, input_stream, &mut intermediate_stream, reserve_size)?;
 
         let mut preliminary_claim = PartialClaim::default();
         {
@@ -2818,7 +2998,7 @@ impl Store {
             let pc = self.provenance_claim().ok_or(Error::ClaimEncoding)?;
             match pc.remote_manifest() {
                 RemoteManifest::NoRemote | RemoteManifest::EmbedWithRemote(_) => {
-                    jumbf_bytes = self.to_jumbf_internal(signer.reserve_size())?;
+                    jumbf_bytes = self.to_jumbf_internal(reserve_size)?;
 
                     intermediate_stream.rewind()?;
                     save_jumbf_to_stream(
@@ -2834,12 +3014,11 @@ impl Store {
 
         let pc = self.provenance_claim().ok_or(Error::ClaimEncoding)?;
         let sig = if _sync {
-            self.sign_claim(pc, signer, signer.reserve_size())
+            self.sign_claim(pc, signer, &reserve)
         } else {
-            self.sign_claim_async(pc, signer, signer.reserve_size())
-                .await
+            self.sign_claim_async(pc, signer, &reserve).await
         }?;
-        let sig_placeholder = Store::sign_claim_placeholder(pc, signer.reserve_size());
+        let sig_placeholder = Store::sign_claim_placeholder(pc, reserve_size);
 
         intermediate_stream.rewind()?;
         match self.finish_save_stream(
@@ -2863,9 +3042,10 @@ impl Store {
                         // verify the store
                         let mut validation_log =
                             StatusTracker::with_error_behavior(ErrorBehavior::StopOnFirstError);
-                        Store::verify_store(
+                        Store::verify_store_at_level(
                             self,
                             &mut crate::claim::ClaimAssetData::Stream(output_stream, format),
+                            ValidationLevel::Full,
                             &mut validation_log,
                         )?;
                     }
@@ -2889,6 +3069,10 @@ impl Store {
         format: &str,
         input_stream: &mut dyn CAIRead,
     ) -> Result<Vec<u8>> {
+        // any assertion may be patched by a ManifestPatchCallback before signing
+        let pc = self.provenance_claim_mut().ok_or(Error::ClaimEncoding)?;
+        pc.mark_assertions_patched();
+
         let intermediate_output: Vec<u8> = Vec::new();
         let mut intermediate_stream = Cursor::new(intermediate_output);
 
@@ -2941,6 +3125,9 @@ impl Store {
 
         let pc_mut = store.provenance_claim_mut().ok_or(Error::ClaimEncoding)?;
 
+        // the manifest must be rewritten at the size it was placed with
+        pc_mut.mark_assertions_patched();
+
         // save the current assertions set so that we can check them after callback;
         let claim_assertions = pc_mut.claim_assertion_store().clone();
 
@@ -3067,11 +3254,11 @@ impl Store {
         // sign the updated manfiest
         let pc = store.provenance_claim().ok_or(Error::ClaimEncoding)?;
         let sig = if _sync {
-            store.sign_claim(pc, signer, signer.reserve_size())?
+            let reserve = SignatureReserve::with_size(signer, signer.reserve_size())?;
+            store.sign_claim(pc, signer, &reserve)?
         } else {
-            store
-                .sign_claim_async(pc, signer, signer.reserve_size())
-                .await?
+            let reserve = SignatureReserve::with_size_async(signer, signer.reserve_size()).await?;
+            store.sign_claim_async(pc, signer, &reserve).await?
         };
         let sig_placeholder = Store::sign_claim_placeholder(pc, signer.reserve_size());
 
@@ -3140,6 +3327,9 @@ impl Store {
         signer: &dyn Signer,
         dest_path: &Path,
     ) -> Result<Vec<u8>> {
+        let reserve = SignatureReserve::new(signer)?;
+        let reserve_size = reserve.size();
+
         // set up temp dir, contents auto deleted
 
         let td = tempdirectory()?;
@@ -3150,11 +3340,11 @@ impl Store {
                 .ok_or_else(|| Error::BadParam("invalid destination path".to_string()))?,
         );
 
-        let jumbf_bytes = self.start_save(asset_path, &temp_file, signer.reserve_size())?;
+        let jumbf_bytes = self.start_save(asset_path, &temp_file, reserve_size)?;
 
         let pc = self.provenance_claim().ok_or(Error::ClaimEncoding)?;
-        let sig = self.sign_claim(pc, signer, signer.reserve_size())?;
-        let sig_placeholder = Store::sign_claim_placeholder(pc, signer.reserve_size());
+        let sig = self.sign_claim(pc, signer, &reserve)?;
+        let sig_placeholder = Store::sign_claim_placeholder(pc, reserve_size);
 
         // get correct output path for remote manifest
         let output_path = match pc.remote_manifest() {
@@ -3195,6 +3385,9 @@ impl Store {
         signer: &dyn AsyncSigner,
         dest_path: &Path,
     ) -> Result<Vec<u8>> {
+        let reserve = SignatureReserve::new_async(signer).await?;
+        let reserve_size = reserve.size();
+
         // set up temp dir, contents auto deleted
         let td = tempdirectory()?;
         let temp_path = td.path();
@@ -3204,13 +3397,11 @@ impl Store {
                 .ok_or_else(|| Error::BadParam("invalid destination path".to_string()))?,
         );
 
-        let jumbf_bytes = self.start_save(asset_path, &temp_file, signer.reserve_size())?;
+        let jumbf_bytes = self.start_save(asset_path, &temp_file, reserve_size)?;
 
         let pc = self.provenance_claim().ok_or(Error::ClaimEncoding)?;
-        let sig = self
-            .sign_claim_async(pc, signer, signer.reserve_size())
-            .await?;
-        let sig_placeholder = Store::sign_claim_placeholder(pc, signer.reserve_size());
+        let sig = self.sign_claim_async(pc, signer, &reserve).await?;
+        let sig_placeholder = Store::sign_claim_placeholder(pc, reserve_size);
 
         // get correct output path for remote manifest
         let output_path = match pc.remote_manifest() {
@@ -3447,6 +3638,7 @@ impl Store {
                 } else {
                     Store::generate_data_hashes_for_stream(
                         &mut intermediate_stream,
+                        None,
                         pc.alg(),
                         &mut hash_ranges,
                         false,
@@ -3487,12 +3679,30 @@ impl Store {
                 output_stream.rewind()?;
                 let mut new_hash_ranges = object_locations_from_stream(format, output_stream)?;
                 if !pc.update_manifest() {
-                    let updated_hashes = Store::generate_data_hashes_for_stream(
-                        output_stream,
-                        pc.alg(),
-                        &mut new_hash_ranges,
-                        true,
-                    )?;
+                    let updated_hashes =
+                        if get_settings_value::<bool>("core.parallel_hashing").unwrap_or(false) {
+                            // the output can't be reopened, so hash its ranges from a copy
+                            drop(intermediate_stream);
+                            let mut output = Vec::new();
+                            output_stream.rewind()?;
+                            output_stream.read_to_end(&mut output)?;
+
+                            Store::generate_data_hashes_for_stream(
+                                &mut Cursor::new(output.as_slice()),
+                                Some(&output.as_slice()),
+                                pc.alg(),
+                                &mut new_hash_ranges,
+                                true,
+                            )?
+                        } else {
+                            Store::generate_data_hashes_for_stream(
+                                output_stream,
+                                None,
+                                pc.alg(),
+                                &mut new_hash_ranges,
+                                true,
+                            )?
+                        };
 
                     // patch existing claim hash with updated data
                     for hash in updated_hashes {
@@ -4183,6 +4393,41 @@ impl Store {
         }
     }
 
+    /// Load store from an asset that can be opened more than once
+    /// so that verification may hash the asset from several threads
+    #[async_generic]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_stream_factory(
+        format: &str,
+        factory: &dyn StreamFactory,
+        verify: bool,
+        validation_log: &mut StatusTracker,
+    ) -> Result<Self> {
+        let (manifest_bytes, remote_url) = {
+            let mut stream = factory.open_stream()?;
+            Store::load_jumbf_from_stream(format, &mut stream)?
+        };
+
+        let mut store = Store::from_jumbf(&manifest_bytes, validation_log)?;
+
+        if verify {
+            let mut asset_data = ClaimAssetData::Factory(factory, format);
+            if _sync {
+                Store::verify_store(&store, &mut asset_data, validation_log)
+            } else {
+                Store::verify_store_async(&store, &mut asset_data, validation_log).await
+            }?;
+        }
+
+        if remote_url.is_none() {
+            store.embedded = true;
+        } else {
+            store.remote_url = remote_url;
+        }
+
+        Ok(store)
+    }
+
     /// Load store from a stream
     #[async_generic]
     #[cfg(not(target_arch = "wasm32"))]
@@ -4323,6 +4568,27 @@ impl Store {
         Ok(store)
     }
 
+    /// Load store from the manifest data of a collection and its files
+    #[async_generic()]
+    pub(crate) fn from_collection(
+        c2pa_data: &[u8],
+        members: CollectionMembers<'_>,
+        verify: bool,
+        validation_log: &mut StatusTracker,
+    ) -> Result<Self> {
+        let store = Store::from_jumbf(c2pa_data, validation_log)?;
+
+        if verify {
+            let mut asset_data = ClaimAssetData::Collection(members);
+            if _sync {
+                Store::verify_store(&store, &mut asset_data, validation_log)
+            } else {
+                Store::verify_store_async(&store, &mut asset_data, validation_log).await
+            }?;
+        }
+        Ok(store)
+    }
+
     /// Load Store from a in-memory asset
     /// asset_type: asset extension or mime type
     /// data: reference to bytes of the the file
@@ -4648,7 +4914,7 @@ impl Store {
                 // build mapping of ingredients and those claims that reference it
                 svi.ingredient_references
                     .entry(ingredient_label)
-                    .or_insert(HashSet::from_iter(vec![claim_label.clone()].into_iter()))
+                    .or_insert(HashSet::from_iter(vec![claim_label.clone()]))
                     .insert(claim_label.clone());
 
                 // recurse nested ingredients
@@ -4802,12 +5068,10 @@ impl Store {
                         } else {
                             let new_version = match claim
                                 .claim_ingredient_store()
-                                .iter()
-                                .filter_map(|(label, _conflict)| {
-                                    match manifest_label_to_parts(label) {
-                                        Some(mp) => mp.version,
-                                        None => None,
-                                    }
+                                .keys()
+                                .filter_map(|label| match manifest_label_to_parts(label) {
+                                    Some(mp) => mp.version,
+                                    None => None,
                                 })
                                 .max()
                             {
@@ -5064,6 +5328,7 @@ pub mod tests {
                 .get_store_validation_info(
                     store.claims()[0],
                     &mut ClaimAssetData::Path(&ap),
+                    ValidationLevel::Full,
                     &mut report,
                 )
                 .unwrap();
@@ -8369,7 +8634,7 @@ pub mod tests {
 
                 fn content(
                     &self,
-                    _label: &str,
+                    label: &str,
                     _size: Option<usize>,
                     claim: &PartialClaim,
                 ) -> Result<DynamicAssertionContent> {
@@ -8380,6 +8645,9 @@ pub mod tests {
                         })
                         .any(|a| a.url().contains("c2pa.hash")));
 
+                    // The claim includes the placeholder for this assertion.
+                    assert!(claim.assertions().any(|a| a.url().ends_with(label)));
+
                     let assertion = TestAssertion {
                         my_tag: "some value I will replace".to_string(),
                     };
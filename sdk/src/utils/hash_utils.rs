@@ -23,9 +23,17 @@ use serde::{Deserialize, Serialize};
 // direct sha functions
 use sha2::{Digest, Sha256, Sha384, Sha512};
 
-use crate::{utils::io_utils::stream_len, Error, Result};
+use crate::{
+    asset_io::{CAIRead, StreamFactory},
+    progress,
+    settings::get_settings_value,
+    utils::io_utils::stream_len,
+    Error, Result,
+};
 
 const MAX_HASH_BUF: usize = 256 * 1024 * 1024; // cap memory usage to 256MB
+#[cfg(not(target_arch = "wasm32"))]
+const MIN_PARALLEL_BUF: usize = 1024 * 1024; // smallest read for a parallel hashing worker
 
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 /// Defines a hash range to be used with `hash_stream_by_alg`
@@ -120,14 +128,22 @@ impl Hasher {
 pub fn hash_by_alg(alg: &str, data: &[u8], exclusions: Option<Vec<HashRange>>) -> Vec<u8> {
     let mut reader = Cursor::new(data);
 
-    hash_stream_by_alg(alg, &mut reader, exclusions, true).unwrap_or_default()
+    hash_ranges_by_alg(alg, &mut reader, exclusions, true, false, MAX_HASH_BUF).unwrap_or_default()
 }
 
 // Return hash inclusive bytes for desired hashing algorithm.
 pub fn hash_by_alg_with_inclusions(alg: &str, data: &[u8], inclusions: Vec<HashRange>) -> Vec<u8> {
     let mut reader = Cursor::new(data);
 
-    hash_stream_by_alg(alg, &mut reader, Some(inclusions), false).unwrap_or_default()
+    hash_ranges_by_alg(
+        alg,
+        &mut reader,
+        Some(inclusions),
+        false,
+        false,
+        MAX_HASH_BUF,
+    )
+    .unwrap_or_default()
 }
 
 // Return hash bytes for asset using desired hashing algorithm.
@@ -197,24 +213,17 @@ pub fn hash_stream_by_alg<R>(
 where
     R: Read + Seek + ?Sized,
 {
-    let mut bmff_v2_starts: Vec<u64> = Vec::new();
-
-    use Hasher::*;
-    let mut hasher_enum = match alg {
-        "sha256" => SHA256(Sha256::new()),
-        "sha384" => SHA384(Sha384::new()),
-        "sha512" => SHA512(Sha512::new()),
-        _ => {
-            return Err(Error::UnsupportedType);
-        }
-    };
-
-    let data_len = stream_len(data)?;
-    data.rewind()?;
+    hash_ranges_by_alg(alg, data, hash_range, is_exclusion, true, MAX_HASH_BUF)
+}
 
-    if data_len < 1 {
-        return Err(Error::OtherError("no data to hash".into()));
-    }
+// Resolve `hash_range` into the ranges of `data_len` bytes to hash, in order, along with
+// the BMFF V2 offsets to hash between them (each also present as a single byte range).
+fn ranges_to_hash(
+    data_len: u64,
+    hash_range: Option<Vec<HashRange>>,
+    is_exclusion: bool,
+) -> Result<(Vec<RangeInclusive<u64>>, Vec<u64>)> {
+    let mut bmff_v2_starts: Vec<u64> = Vec::new();
 
     let ranges = match hash_range {
         Some(mut hr) if !hr.is_empty() => {
@@ -334,6 +343,64 @@ where
         }
     };
 
+    Ok((ranges, bmff_v2_starts))
+}
+
+// In-memory hashes are quick, so only streams report progress.
+// `max_buf` caps the size of each read.
+fn hash_ranges_by_alg<R>(
+    alg: &str,
+    data: &mut R,
+    hash_range: Option<Vec<HashRange>>,
+    is_exclusion: bool,
+    report_progress: bool,
+    max_buf: usize,
+) -> Result<Vec<u8>>
+where
+    R: Read + Seek + ?Sized,
+{
+    use Hasher::*;
+    let mut hasher_enum = match alg {
+        "sha256" => SHA256(Sha256::new()),
+        "sha384" => SHA384(Sha384::new()),
+        "sha512" => SHA512(Sha512::new()),
+        _ => {
+            return Err(Error::UnsupportedType);
+        }
+    };
+
+    let data_len = stream_len(data)?;
+    data.rewind()?;
+
+    if data_len < 1 {
+        return Err(Error::OtherError("no data to hash".into()));
+    }
+
+    let (ranges, bmff_v2_starts) = ranges_to_hash(data_len, hash_range, is_exclusion)?;
+
+    // report progress against the data actually hashed
+    let report_progress = report_progress && progress::is_active();
+    let phase = progress::hash_phase();
+    let total_bytes: u64 = ranges
+        .iter()
+        .filter(|r| !(bmff_v2_starts.contains(r.start()) && r.end() == r.start()))
+        .map(|r| r.end() - r.start() + 1)
+        .sum();
+    let mut bytes_processed = 0u64;
+    let mut report = |len: u64| {
+        bytes_processed += len;
+        if report_progress {
+            progress::report(phase, bytes_processed, Some(total_bytes))
+        } else {
+            Ok(())
+        }
+    };
+    let max_chunk = if report_progress {
+        progress::chunk_size(max_buf)
+    } else {
+        max_buf
+    };
+
     if cfg!(feature = "no_interleaved_io") || cfg!(target_arch = "wasm32") {
         // hash the data for ranges
         for r in ranges {
@@ -351,13 +418,15 @@ where
             data.seek(SeekFrom::Start(*start))?;
 
             loop {
-                let mut chunk = vec![0u8; std::cmp::min(chunk_left as usize, MAX_HASH_BUF)];
+                let mut chunk = vec![0u8; std::cmp::min(chunk_left as usize, max_chunk)];
 
                 data.read_exact(&mut chunk)?;
 
                 hasher_enum.update(&chunk);
 
                 chunk_left -= chunk.len() as u64;
+                report(chunk.len() as u64)?;
+
                 if chunk_left == 0 {
                     break;
                 }
@@ -379,13 +448,14 @@ where
             // move to start of range
             data.seek(SeekFrom::Start(*start))?;
 
-            let mut chunk = vec![0u8; std::cmp::min(chunk_left as usize, MAX_HASH_BUF)];
+            let mut chunk = vec![0u8; std::cmp::min(chunk_left as usize, max_chunk)];
             data.read_exact(&mut chunk)?;
 
             loop {
                 let (tx, rx) = std::sync::mpsc::channel();
 
                 chunk_left -= chunk.len() as u64;
+                let chunk_len = chunk.len() as u64;
 
                 std::thread::spawn(move || {
                     hasher_enum.update(&chunk);
@@ -398,17 +468,19 @@ where
                         Ok(hasher) => hasher,
                         Err(_) => return Err(Error::ThreadReceiveError),
                     };
+                    report(chunk_len)?;
                     break;
                 }
 
                 // read next chunk while we wait for hash
-                let mut next_chunk = vec![0u8; std::cmp::min(chunk_left as usize, MAX_HASH_BUF)];
+                let mut next_chunk = vec![0u8; std::cmp::min(chunk_left as usize, max_chunk)];
                 data.read_exact(&mut next_chunk)?;
 
                 hasher_enum = match rx.recv() {
                     Ok(hasher) => hasher,
                     Err(_) => return Err(Error::ThreadReceiveError),
                 };
+                report(chunk_len)?;
 
                 chunk = next_chunk;
             }
@@ -419,6 +491,366 @@ where
     Ok(Hasher::finalize(hasher_enum))
 }
 
+/// An independent hash to compute as part of [`hash_batch`].
+#[derive(Clone, Debug)]
+pub(crate) struct HashJob {
+    pub alg: String,
+    pub ranges: Option<Vec<HashRange>>,
+    pub is_exclusion: bool,
+}
+
+impl HashJob {
+    pub(crate) fn new(alg: &str, ranges: Option<Vec<HashRange>>, is_exclusion: bool) -> Self {
+        Self {
+            alg: alg.to_owned(),
+            ranges,
+            is_exclusion,
+        }
+    }
+}
+
+/*  Computes independent hashes over the same asset, returning them in the order of `jobs`.
+
+    When the `core.parallel_hashing` setting is enabled and a `factory` can open more readers,
+    the jobs are shared among worker threads, each with its own reader.  Otherwise they are
+    hashed one after another from `reader`.  Each job is still one sequential digest, so this
+    only helps when there are several, such as box hash maps or BMFF Merkle leaves.
+*/
+pub(crate) fn hash_batch(
+    reader: &mut dyn CAIRead,
+    factory: Option<&dyn StreamFactory>,
+    jobs: &[HashJob],
+) -> Result<Vec<Vec<u8>>> {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(factory) = factory {
+        let workers = parallel_workers(jobs.len());
+        if workers > 1 {
+            return hash_batch_parallel(factory, jobs, workers);
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = factory;
+
+    jobs.iter()
+        .map(|job| hash_stream_by_alg(&job.alg, reader, job.ranges.clone(), job.is_exclusion))
+        .collect()
+}
+
+// number of threads to hash `jobs` independent hashes with, based on settings
+#[cfg(not(target_arch = "wasm32"))]
+fn parallel_workers(jobs: usize) -> usize {
+    if jobs < 2 || !get_settings_value::<bool>("core.parallel_hashing").unwrap_or(false) {
+        return 1;
+    }
+
+    get_settings_value::<Option<usize>>("core.max_hash_workers")
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        })
+        .clamp(1, jobs)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn hash_batch_parallel(
+    factory: &dyn StreamFactory,
+    jobs: &[HashJob],
+    workers: usize,
+) -> Result<Vec<Vec<u8>>> {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
+
+    // every worker may hold two reads at once, so split the memory budget accordingly
+    let memory = get_settings_value::<Option<u64>>("core.max_memory_usage")
+        .ok()
+        .flatten()
+        .map_or(MAX_HASH_BUF, |m| usize::try_from(m).unwrap_or(MAX_HASH_BUF));
+    let max_buf = (memory / (workers * 2)).clamp(MIN_PARALLEL_BUF, MAX_HASH_BUF);
+
+    // settings and progress are per thread, so hand the progress scope to the workers
+    let progress = progress::current();
+
+    let next_job = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<Vec<u8>>>>> =
+        Mutex::new((0..jobs.len()).map(|_| None).collect());
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                let _progress = progress.clone().map(|(callback, phase)| {
+                    let scope = progress::ProgressScope::from_callback(callback);
+                    (progress::enter_phase(phase), scope)
+                });
+
+                let mut stream = None;
+                loop {
+                    let index = next_job.fetch_add(1, Ordering::Relaxed);
+                    let Some(job) = jobs.get(index) else {
+                        break;
+                    };
+
+                    let result = match stream.as_mut() {
+                        Some(stream) => Ok(stream),
+                        None => factory.open_stream().map(|s| stream.insert(s)),
+                    }
+                    .and_then(|stream| {
+                        hash_ranges_by_alg(
+                            &job.alg,
+                            stream,
+                            job.ranges.clone(),
+                            job.is_exclusion,
+                            true,
+                            max_buf,
+                        )
+                    });
+
+                    let failed = result.is_err();
+                    if let Ok(mut results) = results.lock() {
+                        results[index] = Some(result);
+                    }
+
+                    // no point hashing the rest once one has failed
+                    if failed {
+                        next_job.store(jobs.len(), Ordering::Relaxed);
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    let results = results
+        .into_inner()
+        .map_err(|_| Error::ThreadReceiveError)?;
+
+    // report the first failure in job order so the outcome doesn't depend on scheduling;
+    // jobs skipped after a failure have no result
+    let mut hashes = Vec::with_capacity(jobs.len());
+    for result in results {
+        match result {
+            Some(Ok(hash)) => hashes.push(hash),
+            Some(Err(e)) => return Err(e),
+            None => (),
+        }
+    }
+
+    if hashes.len() == jobs.len() {
+        Ok(hashes)
+    } else {
+        Err(Error::ThreadReceiveError)
+    }
+}
+
+/*  Like `hash_stream_by_alg`, but when the `core.parallel_hashing` setting is enabled and a
+    `factory` can open more readers, the hashed ranges are split into pieces that worker threads
+    read ahead, each with its own reader.  The digest is still computed over the pieces in order,
+    so this overlaps reading the asset with hashing it even when there is a single hash, such as
+    a data hash.
+*/
+pub(crate) fn hash_stream_by_alg_with_factory<R>(
+    alg: &str,
+    data: &mut R,
+    factory: Option<&dyn StreamFactory>,
+    hash_range: Option<Vec<HashRange>>,
+    is_exclusion: bool,
+) -> Result<Vec<u8>>
+where
+    R: Read + Seek + ?Sized,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(factory) = factory {
+        let workers = parallel_workers(usize::MAX);
+        if workers > 1 {
+            return hash_ranges_parallel(alg, factory, hash_range, is_exclusion, workers);
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = factory;
+
+    hash_stream_by_alg(alg, data, hash_range, is_exclusion)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn hash_ranges_parallel(
+    alg: &str,
+    factory: &dyn StreamFactory,
+    hash_range: Option<Vec<HashRange>>,
+    is_exclusion: bool,
+    workers: usize,
+) -> Result<Vec<u8>> {
+    use std::{
+        collections::BTreeMap,
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            mpsc, Condvar, Mutex,
+        },
+    };
+
+    // a BMFF V2 offset to hash, or the start and length of bytes to read
+    enum Piece {
+        Offset(u64),
+        Read(usize),
+    }
+
+    use Hasher::*;
+    let mut hasher_enum = match alg {
+        "sha256" => SHA256(Sha256::new()),
+        "sha384" => SHA384(Sha384::new()),
+        "sha512" => SHA512(Sha512::new()),
+        _ => {
+            return Err(Error::UnsupportedType);
+        }
+    };
+
+    let data_len = stream_len(&mut factory.open_stream()?)?;
+    if data_len < 1 {
+        return Err(Error::OtherError("no data to hash".into()));
+    }
+
+    let (ranges, bmff_v2_starts) = ranges_to_hash(data_len, hash_range, is_exclusion)?;
+
+    // every piece read ahead is held in memory, so split the memory budget accordingly
+    let memory = get_settings_value::<Option<u64>>("core.max_memory_usage")
+        .ok()
+        .flatten()
+        .map_or(MAX_HASH_BUF, |m| usize::try_from(m).unwrap_or(MAX_HASH_BUF));
+    let window = workers * 2;
+    let max_buf = (memory / window).clamp(MIN_PARALLEL_BUF, MAX_HASH_BUF) as u64;
+
+    // enough pieces to keep every worker busy
+    let total_bytes: u64 = ranges
+        .iter()
+        .filter(|r| !(bmff_v2_starts.contains(r.start()) && r.end() == r.start()))
+        .map(|r| r.end() - r.start() + 1)
+        .sum();
+    let piece_size = (total_bytes / window as u64).clamp(MIN_PARALLEL_BUF as u64, max_buf);
+
+    let mut pieces = Vec::new();
+    let mut reads: Vec<(u64, u64)> = Vec::new();
+    for r in ranges {
+        let (start, end) = (*r.start(), *r.end());
+        if bmff_v2_starts.contains(&start) && end == start {
+            pieces.push(Piece::Offset(start));
+            continue;
+        }
+
+        let mut offset = start;
+        while offset <= end {
+            let len = piece_size.min(end - offset + 1);
+            pieces.push(Piece::Read(reads.len()));
+            reads.push((offset, len));
+            offset += len;
+        }
+    }
+
+    let workers = workers.min(reads.len()).max(1);
+    let next_read = AtomicUsize::new(0);
+    let abort = AtomicBool::new(false);
+    // number of reads hashed so far; workers stay within `window` reads of it
+    let hashed = (Mutex::new(0usize), Condvar::new());
+
+    // settings and progress are per thread, so report from this one
+    let report_progress = progress::is_active();
+    let phase = progress::hash_phase();
+    let mut bytes_processed = 0u64;
+
+    std::thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel::<(usize, Result<Vec<u8>>)>();
+
+        for _ in 0..workers {
+            let tx = tx.clone();
+            let (next_read, abort, hashed, reads) = (&next_read, &abort, &hashed, &reads);
+            scope.spawn(move || {
+                let mut stream = None;
+                loop {
+                    let index = next_read.fetch_add(1, Ordering::Relaxed);
+                    let Some(&(start, len)) = reads.get(index) else {
+                        break;
+                    };
+
+                    let (lock, cvar) = hashed;
+                    let mut done = lock.lock().unwrap_or_else(|e| e.into_inner());
+                    while index >= *done + window && !abort.load(Ordering::Relaxed) {
+                        done = cvar.wait(done).unwrap_or_else(|e| e.into_inner());
+                    }
+                    drop(done);
+
+                    if abort.load(Ordering::Relaxed) {
+                        break;
+                    }
+
+                    let result = match stream.as_mut() {
+                        Some(stream) => Ok(stream),
+                        None => factory.open_stream().map(|s| stream.insert(s)),
+                    }
+                    .and_then(|stream| {
+                        let mut chunk = vec![0u8; len as usize];
+                        stream.seek(SeekFrom::Start(start))?;
+                        stream.read_exact(&mut chunk)?;
+                        Ok(chunk)
+                    });
+
+                    let failed = result.is_err();
+                    if tx.send((index, result)).is_err() || failed {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        // hash the pieces in order as they arrive
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+        let result = pieces.iter().try_for_each(|piece| match piece {
+            Piece::Offset(offset) => {
+                hasher_enum.update(&offset.to_be_bytes());
+                Ok(())
+            }
+            Piece::Read(index) => {
+                let chunk = loop {
+                    if let Some(chunk) = pending.remove(index) {
+                        break chunk;
+                    }
+                    let (i, chunk) = rx.recv().map_err(|_| Error::ThreadReceiveError)?;
+                    pending.insert(i, chunk);
+                }?;
+
+                hasher_enum.update(&chunk);
+
+                next += 1;
+                let (lock, cvar) = &hashed;
+                *lock.lock().unwrap_or_else(|e| e.into_inner()) = next;
+                cvar.notify_all();
+
+                bytes_processed += chunk.len() as u64;
+                if report_progress {
+                    progress::report(phase, bytes_processed, Some(total_bytes))
+                } else {
+                    Ok(())
+                }
+            }
+        });
+
+        // release any workers still waiting to read ahead
+        if result.is_err() {
+            abort.store(true, Ordering::Relaxed);
+            let (lock, cvar) = &hashed;
+            let _done = lock.lock().unwrap_or_else(|e| e.into_inner());
+            cvar.notify_all();
+        }
+
+        result
+    })?;
+
+    Ok(Hasher::finalize(hasher_enum))
+}
+
 // verify the hash using the specified algorithm
 pub fn verify_by_alg(
     alg: &str,
@@ -431,35 +863,52 @@ pub fn verify_by_alg(
     vec_compare(hash, &data_hash)
 }
 
-// verify the hash using the specified algorithm
+// verify the hash using the specified algorithm, only failing if the caller cancelled
 pub fn verify_asset_by_alg(
     alg: &str,
     hash: &[u8],
     asset_path: &Path,
     exclusions: Option<Vec<HashRange>>,
-) -> bool {
+) -> Result<bool> {
     // hash with the same algorithm as target
-    if let Ok(data_hash) = hash_asset_by_alg(alg, asset_path, exclusions) {
-        vec_compare(hash, &data_hash)
-    } else {
-        false
+    match hash_asset_by_alg(alg, asset_path, exclusions) {
+        Ok(data_hash) => Ok(vec_compare(hash, &data_hash)),
+        Err(Error::OperationCancelled) => Err(Error::OperationCancelled),
+        Err(_) => Ok(false),
     }
 }
 
+// verify the hash using the specified algorithm, only failing if the caller cancelled
 pub fn verify_stream_by_alg<R>(
     alg: &str,
     hash: &[u8],
     reader: &mut R,
     hash_range: Option<Vec<HashRange>>,
     is_exclusion: bool,
-) -> bool
+) -> Result<bool>
 where
     R: Read + Seek + ?Sized,
 {
-    if let Ok(data_hash) = hash_stream_by_alg(alg, reader, hash_range, is_exclusion) {
-        vec_compare(hash, &data_hash)
-    } else {
-        false
+    verify_stream_by_alg_with_factory(alg, hash, reader, None, hash_range, is_exclusion)
+}
+
+// verify the hash of a stream, reading its ranges on several threads if possible,
+// see `hash_stream_by_alg_with_factory`
+pub(crate) fn verify_stream_by_alg_with_factory<R>(
+    alg: &str,
+    hash: &[u8],
+    reader: &mut R,
+    factory: Option<&dyn StreamFactory>,
+    hash_range: Option<Vec<HashRange>>,
+    is_exclusion: bool,
+) -> Result<bool>
+where
+    R: Read + Seek + ?Sized,
+{
+    match hash_stream_by_alg_with_factory(alg, reader, factory, hash_range, is_exclusion) {
+        Ok(data_hash) => Ok(vec_compare(hash, &data_hash)),
+        Err(Error::OperationCancelled) => Err(Error::OperationCancelled),
+        Err(_) => Ok(false),
     }
 }
 
@@ -473,3 +922,131 @@ pub fn concat_and_hash(alg: &str, left: &[u8], right: Option<&[u8]>) -> Vec<u8>
 
     hash_by_alg(alg, &temp, None)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::settings::{reset_default_settings, set_settings_value};
+
+    // Opens readers that record how many of them are reading at once.
+    #[cfg(not(target_arch = "wasm32"))]
+    struct ConcurrencyProbe<'a> {
+        data: &'a [u8],
+        active: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    struct ProbeReader<'a> {
+        probe: &'a ConcurrencyProbe<'a>,
+        cursor: Cursor<&'a [u8]>,
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl Read for ProbeReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            use std::sync::atomic::Ordering;
+
+            let active = self.probe.active.fetch_add(1, Ordering::SeqCst) + 1;
+            self.probe.peak.fetch_max(active, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            self.probe.active.fetch_sub(1, Ordering::SeqCst);
+
+            self.cursor.read(buf)
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl Seek for ProbeReader<'_> {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            self.cursor.seek(pos)
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl StreamFactory for ConcurrencyProbe<'_> {
+        fn open_stream(&self) -> Result<Box<dyn CAIRead + '_>> {
+            Ok(Box::new(ProbeReader {
+                probe: self,
+                cursor: Cursor::new(self.data),
+            }))
+        }
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn parallel_batch_matches_sequential() {
+        let data: Vec<u8> = (0..5 * MIN_PARALLEL_BUF).map(|i| (i % 251) as u8).collect();
+        let chunk = MIN_PARALLEL_BUF as u64;
+        let jobs: Vec<HashJob> = (0..8)
+            .map(|i| {
+                HashJob::new(
+                    "sha256",
+                    Some(vec![HashRange::new(i * chunk / 2, chunk)]),
+                    i % 2 == 0,
+                )
+            })
+            .collect();
+        let factory = data.as_slice();
+
+        let sequential = hash_batch(&mut Cursor::new(&data), Some(&factory), &jobs).unwrap();
+
+        set_settings_value("core.parallel_hashing", true).unwrap();
+        set_settings_value("core.max_hash_workers", 4).unwrap();
+        let parallel = hash_batch(&mut Cursor::new(&data), Some(&factory), &jobs);
+        reset_default_settings().unwrap();
+
+        assert_eq!(parallel.unwrap(), sequential);
+        assert_eq!(
+            sequential[1],
+            hash_stream_by_alg(
+                "sha256",
+                &mut Cursor::new(&data),
+                Some(vec![HashRange::new(chunk / 2, chunk)]),
+                false
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn parallel_data_hash_reads_ranges_concurrently() {
+        let data: Vec<u8> = (0..8 * MIN_PARALLEL_BUF).map(|i| (i % 251) as u8).collect();
+        let exclusions = vec![
+            HashRange::new(1000, 5000),
+            HashRange::new(3 * MIN_PARALLEL_BUF as u64, 1234),
+        ];
+        let probe = ConcurrencyProbe {
+            data: &data,
+            active: Default::default(),
+            peak: Default::default(),
+        };
+
+        let sequential = hash_stream_by_alg(
+            "sha256",
+            &mut Cursor::new(&data),
+            Some(exclusions.clone()),
+            true,
+        )
+        .unwrap();
+
+        set_settings_value("core.parallel_hashing", true).unwrap();
+        set_settings_value("core.max_hash_workers", 4).unwrap();
+        let parallel = hash_stream_by_alg_with_factory(
+            "sha256",
+            &mut Cursor::new(&data),
+            Some(&probe),
+            Some(exclusions),
+            true,
+        );
+        reset_default_settings().unwrap();
+
+        assert_eq!(parallel.unwrap(), sequential);
+        assert!(probe.peak.load(std::sync::atomic::Ordering::SeqCst) > 1);
+    }
+}
@@ -466,10 +466,13 @@ impl crate::signer::RemoteSigner for TempRemoteSigner {
 
         // this would happen on some remote server
         // TEMPORARY: Assume v1 until we plumb things through further.
+        let reserve =
+            crate::cose_sign::SignatureReserve::with_size_async(&signer, self.reserve_size())
+                .await?;
         crate::cose_sign::cose_sign_async(
             &signer,
             claim_bytes,
-            self.reserve_size(),
+            &reserve,
             TimeStampStorage::V1_sigTst,
         )
         .await
@@ -506,10 +509,15 @@ impl AsyncSigner for TempAsyncRemoteSigner {
 
         // this would happen on some remote server
         // TEMPORARY: Assume V1 until we plumb through further.
+        let reserve = crate::cose_sign::SignatureReserve::with_size_async(
+            &signer,
+            AsyncSigner::reserve_size(self),
+        )
+        .await?;
         crate::cose_sign::cose_sign_async(
             &signer,
             &claim_bytes,
-            AsyncSigner::reserve_size(self),
+            &reserve,
             TimeStampStorage::V1_sigTst,
         )
         .await
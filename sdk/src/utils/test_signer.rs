@@ -13,15 +13,13 @@
 
 #![allow(clippy::unwrap_used)] // This mod is only used in test code.
 
-use async_trait::async_trait;
-
 use crate::{
     crypto::raw_signature::{
         async_signer_from_cert_chain_and_private_key, signer_from_cert_chain_and_private_key,
-        AsyncRawSigner, SigningAlg,
+        SigningAlg,
     },
-    signer::RawSignerWrapper,
-    AsyncSigner, Result, Signer,
+    signer::{AsyncRawSignerWrapper, RawSignerWrapper},
+    AsyncSigner, Signer,
 };
 
 /// Creates a [`Signer`] instance for testing purposes using test credentials.
@@ -38,7 +36,7 @@ pub(crate) fn test_signer(alg: SigningAlg) -> Box<dyn Signer> {
 pub(crate) fn test_cawg_signer(
     alg: SigningAlg,
     referenced_assertions: &[&str],
-) -> Result<Box<dyn Signer>> {
+) -> crate::Result<Box<dyn Signer>> {
     let (cert_chain, private_key) = cert_chain_and_private_key_for_alg(alg);
 
     let c2pa_raw_signer =
@@ -116,62 +114,20 @@ pub(crate) fn cert_chain_and_private_key_for_alg(
             include_bytes!("../../tests/fixtures/certs/ed25519.pub"),
             include_bytes!("../../tests/fixtures/certs/ed25519.pem"),
         ),
-    }
-}
 
-#[cfg(not(target_arch = "wasm32"))]
-struct AsyncRawSignerWrapper(Box<dyn AsyncRawSigner + Sync + Send>);
-
-#[allow(dead_code)] // TEMPORARY: Not used on WASM
-#[cfg(target_arch = "wasm32")]
-struct AsyncRawSignerWrapper(Box<dyn AsyncRawSigner>);
-
-#[allow(dead_code)] // TEMPORARY: Not used on WASM
-#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
-#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
-impl AsyncSigner for AsyncRawSignerWrapper {
-    async fn sign(&self, data: Vec<u8>) -> Result<Vec<u8>> {
-        self.0.sign(data).await.map_err(|e| e.into())
-    }
-
-    fn alg(&self) -> SigningAlg {
-        self.0.alg()
-    }
-
-    fn certs(&self) -> Result<Vec<Vec<u8>>> {
-        self.0.cert_chain().map_err(|e| e.into())
-    }
-
-    fn reserve_size(&self) -> usize {
-        self.0.reserve_size()
-    }
-
-    async fn ocsp_val(&self) -> Option<Vec<u8>> {
-        self.0.ocsp_response().await
-    }
-
-    fn time_authority_url(&self) -> Option<String> {
-        self.0.time_stamp_service_url()
-    }
-
-    fn timestamp_request_headers(&self) -> Option<Vec<(String, String)>> {
-        self.0.time_stamp_request_headers()
-    }
-
-    fn timestamp_request_body(&self, message: &[u8]) -> Result<Vec<u8>> {
-        self.0
-            .time_stamp_request_body(message)
-            .map_err(|e| e.into())
-    }
+        SigningAlg::MlDsa44 => (
+            include_bytes!("../../tests/fixtures/certs/mldsa44.pub"),
+            include_bytes!("../../tests/fixtures/certs/mldsa44.pem"),
+        ),
 
-    async fn send_timestamp_request(&self, message: &[u8]) -> Option<Result<Vec<u8>>> {
-        self.0
-            .send_time_stamp_request(message)
-            .await
-            .map(|r| r.map_err(|e| e.into()))
-    }
+        SigningAlg::MlDsa65 => (
+            include_bytes!("../../tests/fixtures/certs/mldsa65.pub"),
+            include_bytes!("../../tests/fixtures/certs/mldsa65.pem"),
+        ),
 
-    fn async_raw_signer(&self) -> Option<Box<&dyn AsyncRawSigner>> {
-        Some(Box::new(&*self.0))
+        SigningAlg::MlDsa87 => (
+            include_bytes!("../../tests/fixtures/certs/mldsa87.pub"),
+            include_bytes!("../../tests/fixtures/certs/mldsa87.pem"),
+        ),
     }
 }
@@ -18,6 +18,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     assertion::AssertionBase,
     assertions::Ingredient,
+    crypto::cose::TrustReport,
     jumbf::labels::manifest_label_from_uri,
     status_tracker::{LogKind, StatusTracker},
     store::Store,
@@ -29,15 +30,71 @@ use crate::{
 /// Indicates if the manifest store is valid and trusted.
 ///
 /// The Trusted state implies the manifest store is valid and the active signature is trusted.
+///
+/// More states may be added in future releases, so `match`es on this enum
+/// need a wildcard arm.
+#[non_exhaustive]
 pub enum ValidationState {
     /// Errors were found in the manifest store.
     Invalid,
+    /// The manifest store is well formed, but its signatures were not checked
+    /// because of the validation level.
+    WellFormed,
     /// No errors were found in validation, but the active signature is not trusted.
     Valid,
     /// The manifest store is valid and the active signature is trusted.
     Trusted,
 }
 
+/// How much of a manifest store to validate.
+///
+/// Set with the `verify.validation_level` setting. Levels below
+/// [`Full`](ValidationLevel::Full) are cheaper but leave checks undone, which
+/// [`ValidationResults::skipped_checks`] records.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationLevel {
+    /// Parse the manifest store and check the claims and assertion stores are
+    /// well formed and consistent, without checking signatures or hashing the asset.
+    ///
+    /// Stores that pass are reported as [`ValidationState::WellFormed`].
+    Structural,
+    /// Also check the claim signatures, time stamps and signing credentials,
+    /// without hashing the asset.
+    Signature,
+    /// Run every check, including the hard bindings to the asset.
+    #[default]
+    Full,
+}
+
+impl ValidationLevel {
+    /// The checks that are not run at this level.
+    pub fn skipped_checks(self) -> &'static [ValidationCheck] {
+        match self {
+            Self::Structural => &[
+                ValidationCheck::ClaimSignature,
+                ValidationCheck::HardBinding,
+            ],
+            Self::Signature => &[ValidationCheck::HardBinding],
+            Self::Full => &[],
+        }
+    }
+}
+
+/// A group of validation checks that a [`ValidationLevel`] may skip.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ValidationCheck {
+    /// Claim signatures, including their time stamps, certificate trust and
+    /// revocation status.
+    ClaimSignature,
+    /// Hashes binding the active manifest to the asset.
+    HardBinding,
+}
+
 #[derive(Clone, Serialize, Default, Deserialize, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "json_schema", derive(JsonSchema))]
 /// Contains a set of success, informational, and failure validation status codes.
@@ -97,11 +154,29 @@ pub struct ValidationResults {
 
     #[serde(rename = "ingredientDeltas", skip_serializing_if = "Option::is_none")]
     ingredient_deltas: Option<Vec<IngredientDeltaValidationResult>>, // List of any changes/deltas between the current and previous validation results for each ingredient's manifest. Present if the the ingredient is a C2PA asset.
+
+    #[serde(
+        rename = "trustReports",
+        skip_serializing_if = "Vec::is_empty",
+        default
+    )]
+    trust_reports: Vec<TrustReport>, // Explanations of the trust decisions made while validating. Not part of the C2PA validation results, so never written to ingredient assertions.
+
+    #[serde(
+        rename = "skippedChecks",
+        skip_serializing_if = "Vec::is_empty",
+        default
+    )]
+    skipped_checks: Vec<ValidationCheck>, // Checks not run because of the validation level. Not part of the C2PA validation results either.
 }
 
 impl ValidationResults {
     pub(crate) fn from_store(store: &Store, validation_log: &StatusTracker) -> Self {
-        let mut results = ValidationResults::default();
+        let mut results = ValidationResults {
+            trust_reports: validation_log.trust_reports().to_vec(),
+            skipped_checks: validation_log.skipped_checks().to_vec(),
+            ..Default::default()
+        };
 
         let mut statuses: Vec<ValidationStatus> = validation_log
             .logged_items()
@@ -180,6 +255,9 @@ impl ValidationResults {
     }
 
     /// Returns the [ValidationState] of the manifest store based on the validation results.
+    ///
+    /// If any checks were skipped the state is at best [ValidationState::Valid], since
+    /// a trusted state requires a full validation.
     pub fn validation_state(&self) -> ValidationState {
         let mut is_trusted = true; // Assume the state is trusted until proven otherwise
         if let Some(active_manifest) = self.active_manifest.as_ref() {
@@ -198,7 +276,12 @@ impl ValidationResults {
                 }
             }
         }
-        if is_trusted {
+        if self
+            .skipped_checks
+            .contains(&ValidationCheck::ClaimSignature)
+        {
+            ValidationState::WellFormed
+        } else if is_trusted && self.skipped_checks.is_empty() {
             ValidationState::Trusted
         } else {
             ValidationState::Valid
@@ -283,6 +366,26 @@ impl ValidationResults {
         self.ingredient_deltas.as_ref()
     }
 
+    /// Returns the reports explaining each trust decision made during validation.
+    pub fn trust_reports(&self) -> &[TrustReport] {
+        &self.trust_reports
+    }
+
+    /// Returns the checks that were skipped because of the validation level.
+    ///
+    /// When this is empty, the manifest store was fully validated.
+    pub fn skipped_checks(&self) -> &[ValidationCheck] {
+        &self.skipped_checks
+    }
+
+    /// Returns these results without the trust reports and skipped checks,
+    /// which C2PA does not define, as written to an ingredient assertion.
+    pub(crate) fn without_reports(mut self) -> Self {
+        self.trust_reports.clear();
+        self.skipped_checks.clear();
+        self
+    }
+
     pub fn add_active_manifest(mut self, scm: StatusCodes) -> Self {
         self.active_manifest = Some(scm);
         self
@@ -58,6 +58,9 @@ pub struct ValidationStatus {
 
     #[serde(skip)]
     ingredient_uri: Option<String>,
+
+    #[serde(rename = "trustPolicy", skip_serializing_if = "Option::is_none")]
+    trust_policy: Option<String>,
 }
 
 fn default_log_kind() -> LogKind {
@@ -72,6 +75,7 @@ impl ValidationStatus {
             explanation: None,
             success: None,
             ingredient_uri: None,
+            trust_policy: None,
             kind: LogKind::Success,
         }
     }
@@ -106,6 +110,12 @@ impl ValidationStatus {
         self.ingredient_uri.as_deref()
     }
 
+    /// Returns the name of the trust policy that was applied, if this status
+    /// reports the result of a trust check.
+    pub fn trust_policy(&self) -> Option<&str> {
+        self.trust_policy.as_deref()
+    }
+
     /// Sets the internal JUMBF reference to the entity was validated.
     pub fn set_url<S: Into<String>>(mut self, url: S) -> Self {
         self.url = Some(url.into());
@@ -185,6 +195,7 @@ impl ValidationStatus {
                 if let Some(ingredient_uri) = &item.ingredient_uri {
                     vi = vi.set_ingredient_uri(ingredient_uri.to_string());
                 }
+                vi.trust_policy = item.trust_policy.as_ref().map(|p| p.to_string());
                 vi
             }),
             // If we don't have a validation_status, then make one from the err_val
@@ -232,3 +243,28 @@ pub fn status_for_store(store: &Store, validation_log: &StatusTracker) -> Vec<Va
 // -- unofficial status code --
 
 pub(crate) const STATUS_PRERELEASE: &str = "com.adobe.prerelease";
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_trust_policy_json() {
+        let item = crate::log_item!("Cose_Sign1", "time stamp untrusted", "test")
+            .validation_status(TIMESTAMP_UNTRUSTED)
+            .trust_policy("time_stamp");
+
+        let status = ValidationStatus::from_log_item(&item).unwrap();
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["trustPolicy"], "time_stamp");
+
+        let status: ValidationStatus = serde_json::from_value(json).unwrap();
+        assert_eq!(status.trust_policy(), Some("time_stamp"));
+
+        // omitted when no trust policy applied
+        let json = serde_json::to_value(ValidationStatus::new(CLAIM_SIGNATURE_VALIDATED)).unwrap();
+        assert!(json.get("trustPolicy").is_none());
+    }
+}
@@ -452,6 +452,12 @@ pub async fn validate_async(alg: SigningAlg, sig: &[u8], data: &[u8], pkey: &[u8
             )
             .await
         }
+        // WebCrypto doesn't implement ML-DSA, so use the Rust-native validator.
+        SigningAlg::MlDsa44 | SigningAlg::MlDsa65 | SigningAlg::MlDsa87 => {
+            let validator = crate::crypto::raw_signature::validator_for_signing_alg(alg)
+                .ok_or(Error::UnsupportedType)?;
+            Ok(validator.validate(sig, data, pkey).is_ok())
+        }
     }
 }
 